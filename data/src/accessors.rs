@@ -22,6 +22,8 @@ pub mod guid;
 pub mod identifier;
 /// The `Integer` accessor.
 pub mod integer;
+/// The `Interval` accessor.
+pub mod interval;
 /// The `Object` accessor.
 pub mod object;
 /// The `Reference` accessor.
@@ -36,17 +38,30 @@ pub mod string;
 pub mod strings;
 /// All concrete temporal accessors.
 pub mod temporal;
+/// The `TimeSeries` accessor.
+pub mod time_series;
 /// The `Tuple` accessor.
 pub mod tuple;
 
 #[cfg(test)]
 mod tests {
+    mod blob_test;
     mod boolean_test;
+    mod byte_string_test;
+    mod character_test;
     mod float_test;
+    mod guid_test;
     mod integer_test;
+    mod interval_test;
     mod list_test;
     mod map_test;
     mod sequence_test;
     mod set_test;
+    mod string_operations_test;
+    mod time_series_test;
+    mod transcoding_test;
     mod tuple_test;
+    mod utf16_string_test;
+    mod utf32_string_test;
+    mod utf8_string_test;
 }
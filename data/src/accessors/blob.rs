@@ -1,10 +1,28 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    io::{self, Read, Seek, SeekFrom, Write},
+    rc::Rc,
+};
+
 use crate::{
     adaptors::blob_adaptor::BlobAdaptor,
+    data_spec_builders::blob_spec_builder::BlobSpecBuilder,
     primitive_def::Accessor,
+    primitive_specs::blob_spec::BlobSpec,
+    provider_error::ProviderError,
     set_equal_to::{SetEqualTo, SetEqualToError},
+    spec_compatibility::SpecCompatibility,
+    variable::Variable,
 };
 
 /// An accessor for BLOBs.
+/// <p>
+/// A BLOB is a sequence of bytes that is accessed piecewise: bytes are read and
+/// written at offsets, in caller-provided buffers, so that large BLOBs need not be
+/// held in memory at once. [`Blob::reader`] and [`Blob::cursor`] adapt a BLOB to
+/// the `std::io` traits.
+/// </p>
 pub struct Blob {
     // The adaptor for the BLOB.
     adaptor: Box<dyn BlobAdaptor>,
@@ -15,12 +33,314 @@ impl Blob {
     pub fn new(adaptor: Box<dyn BlobAdaptor>) -> Self {
         Self { adaptor }
     }
+
+    /// Returns the BLOB's specification.
+    pub fn spec(&self) -> &Rc<BlobSpec> {
+        self.adaptor.spec()
+    }
+
+    /// Returns the BLOB's media type, if the spec declares one.
+    pub fn media_type(&self) -> Option<&str> {
+        self.spec().media_type()
+    }
+
+    /// Returns the length of the BLOB in bytes.
+    pub fn len(&self) -> u64 {
+        self.adaptor.len()
+    }
+
+    /// Returns whether the BLOB is empty.
+    pub fn is_empty(&self) -> bool {
+        self.adaptor.is_empty()
+    }
+
+    /// Reads bytes starting at the offset into the buffer and returns the number of bytes read,
+    /// which is zero at or beyond the end of the BLOB.
+    pub fn read_at(&self, offset: u64, buffer: &mut [u8]) -> Result<usize, BlobError> {
+        self.adaptor.read(offset, buffer)
+    }
+
+    /// Writes the bytes starting at the offset, extending the BLOB as needed.
+    /// Writing beyond the end of the BLOB fills the gap with zeros.
+    pub fn write_at(&mut self, offset: u64, bytes: &[u8]) -> Result<(), BlobError> {
+        self.adaptor.write(offset, bytes)
+    }
+
+    /// Appends bytes to the end of the BLOB.
+    pub fn append(&mut self, bytes: &[u8]) -> Result<(), BlobError> {
+        self.write_at(self.len(), bytes)
+    }
+
+    /// Shortens the BLOB to the given length. Has no effect if the BLOB is not longer.
+    pub fn truncate(&mut self, len: u64) -> Result<(), BlobError> {
+        self.adaptor.truncate(len)
+    }
+
+    /// Clears the BLOB.
+    pub fn clear(&mut self) -> Result<(), BlobError> {
+        self.truncate(0)
+    }
+
+    /// Returns all of the BLOB's bytes.
+    pub fn bytes(&self) -> Result<Vec<u8>, BlobError> {
+        let mut bytes = vec![0; self.len() as usize];
+        let count = self.read_at(0, &mut bytes)?;
+        bytes.truncate(count);
+        Ok(bytes)
+    }
+
+    /// Replaces the BLOB's bytes.
+    pub fn set_bytes(&mut self, bytes: &[u8]) -> Result<(), BlobError> {
+        self.write_at(0, bytes)?;
+        self.truncate(bytes.len() as u64)
+    }
+
+    /// Returns an iterator over the BLOB's bytes in chunks of the given size.
+    /// The last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// If the chunk size is zero.
+    pub fn chunks(&self, chunk_size: usize) -> BlobChunks<'_> {
+        if chunk_size == 0 {
+            panic!("The chunk size must be greater than zero.");
+        }
+        BlobChunks {
+            blob: self,
+            offset: 0,
+            chunk_size,
+        }
+    }
+
+    /// Returns a reader that reads the BLOB from its start and supports seeking.
+    pub fn reader(&self) -> BlobReader<'_> {
+        BlobReader {
+            blob: self,
+            position: 0,
+        }
+    }
+
+    /// Returns a cursor that reads and writes the BLOB from its start and supports seeking.
+    pub fn cursor(&mut self) -> BlobCursor<'_> {
+        BlobCursor {
+            blob: self,
+            position: 0,
+        }
+    }
 }
 
 impl SetEqualTo for Blob {
-    fn set_equal_to(&mut self, _other: &Self) -> Result<(), SetEqualToError> {
-        todo!("Implement set_equal_to for Blob");
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.spec().check_compatible_with(other.spec())?;
+        let bytes = other.bytes()?;
+        self.set_bytes(&bytes)?;
+        Ok(())
     }
 }
 
 impl Accessor for Blob {}
+
+impl PartialEq for Blob {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.bytes(), other.bytes()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Blob {}
+
+impl Hash for Blob {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.bytes().unwrap_or_default().hash(state);
+    }
+}
+
+impl Display for Blob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.media_type() {
+            Some(media_type) => write!(f, "<{} bytes of {}>", self.len(), media_type),
+            None => write!(f, "<{} bytes>", self.len()),
+        }
+    }
+}
+
+impl Debug for Blob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl TryFrom<&[u8]> for Variable {
+    type Error = BlobError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let spec = BlobSpecBuilder::new().build();
+        let mut var = Variable::new(&spec);
+        var.blob_mut().set_bytes(value)?;
+        Ok(var)
+    }
+}
+
+impl TryFrom<Variable> for Vec<u8> {
+    type Error = BlobError;
+
+    fn try_from(value: Variable) -> Result<Self, Self::Error> {
+        value.blob().bytes()
+    }
+}
+
+/// An iterator over the bytes of a BLOB in chunks, returned by [`Blob::chunks`].
+pub struct BlobChunks<'a> {
+    blob: &'a Blob,
+    offset: u64,
+    chunk_size: usize,
+}
+
+impl Iterator for BlobChunks<'_> {
+    type Item = Result<Vec<u8>, BlobError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = vec![0; self.chunk_size];
+        match self.blob.read_at(self.offset, &mut chunk) {
+            Ok(0) => None,
+            Ok(count) => {
+                self.offset += count as u64;
+                chunk.truncate(count);
+                Some(Ok(chunk))
+            }
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+/// A reader over a BLOB, returned by [`Blob::reader`].
+pub struct BlobReader<'a> {
+    blob: &'a Blob,
+    position: u64,
+}
+
+impl Read for BlobReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self
+            .blob
+            .read_at(self.position, buf)
+            .map_err(io::Error::other)?;
+        self.position += count as u64;
+        Ok(count)
+    }
+}
+
+impl Seek for BlobReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.position = seek_position(self.position, self.blob.len(), pos)?;
+        Ok(self.position)
+    }
+}
+
+/// A cursor over a BLOB that reads and writes, returned by [`Blob::cursor`].
+pub struct BlobCursor<'a> {
+    blob: &'a mut Blob,
+    position: u64,
+}
+
+impl Read for BlobCursor<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self
+            .blob
+            .read_at(self.position, buf)
+            .map_err(io::Error::other)?;
+        self.position += count as u64;
+        Ok(count)
+    }
+}
+
+impl Write for BlobCursor<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.blob
+            .write_at(self.position, buf)
+            .map_err(io::Error::other)?;
+        self.position += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for BlobCursor<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.position = seek_position(self.position, self.blob.len(), pos)?;
+        Ok(self.position)
+    }
+}
+
+/// Returns the position a seek leads to. Seeking beyond the end is allowed,
+/// but seeking before the start is not.
+fn seek_position(position: u64, len: u64, pos: SeekFrom) -> io::Result<u64> {
+    let (base, delta) = match pos {
+        SeekFrom::Start(offset) => return Ok(offset),
+        SeekFrom::End(delta) => (len, delta),
+        SeekFrom::Current(delta) => (position, delta),
+    };
+    base.checked_add_signed(delta).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Cannot seek before the start of the BLOB",
+        )
+    })
+}
+
+/// An error that can occur when accessing a BLOB.
+#[derive(Debug, PartialEq)]
+pub enum BlobError {
+    /// A provider error.
+    ProviderError(ProviderError),
+    /// An error indicating that a write would exceed the BLOB's maximum size.
+    /// Holds the maximum size and the length the BLOB would have had.
+    MaxSizeViolation(u64, u64),
+    /// An error indicating that a write cannot be held by the BLOB's storage.
+    /// Holds the offset and the number of bytes of the write.
+    WriteOutOfRange(u64, u64),
+}
+
+impl std::fmt::Display for BlobError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlobError::ProviderError(err) => write!(f, "Provider error: {}", err),
+            BlobError::MaxSizeViolation(max_size, length) => write!(
+                f,
+                "A length of {} bytes would exceed the maximum size of {} bytes",
+                length, max_size
+            ),
+            BlobError::WriteOutOfRange(offset, length) => write!(
+                f,
+                "A write of {} bytes at offset {} cannot be held by the BLOB's storage",
+                length, offset
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BlobError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BlobError::ProviderError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProviderError> for BlobError {
+    fn from(error: ProviderError) -> Self {
+        BlobError::ProviderError(error)
+    }
+}
+
+impl From<BlobError> for SetEqualToError {
+    fn from(error: BlobError) -> Self {
+        SetEqualToError::BlobError(error)
+    }
+}
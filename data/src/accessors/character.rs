@@ -1,16 +1,441 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    rc::Rc,
+};
+
 use crate::{
+    adaptors::character_adaptor::CharacterAdaptor,
+    data_spec::DataSpec,
+    data_spec_builders::character_spec_builder::CharacterSpecBuilder,
     primitive_def::Accessor,
+    primitive_specs::character_spec::{CharacterSpec, CharacterStorage},
+    provider_error::ProviderError,
     set_equal_to::{SetEqualTo, SetEqualToError},
+    spec_compatibility::SpecCompatibility,
+    variable::Variable,
 };
 
 /// The accessor for characters.
-pub struct Character {}
+/// <p>
+/// A character holds a single Unicode scalar value. The spec's storage limits the
+/// characters it can hold to those that fit its code unit; see [`CharacterStorage`].
+/// </p>
+pub struct Character {
+    adaptor: Box<dyn CharacterAdaptor>,
+}
+
+impl Character {
+    /// Creates a new character accessor.
+    pub fn new(adaptor: Box<dyn CharacterAdaptor>) -> Self {
+        Self { adaptor }
+    }
+
+    /// Returns the character's specification.
+    pub fn spec(&self) -> &Rc<CharacterSpec> {
+        self.adaptor.spec()
+    }
+
+    /// Returns the value of the character.
+    pub fn character(&self) -> Result<char, CharacterError> {
+        self.adaptor.character()
+    }
+
+    /// Sets the value of the character.
+    /// Fails if the character's storage cannot represent the value.
+    pub fn set_character(&mut self, value: char) -> Result<(), CharacterError> {
+        self.adaptor.set_character(value)
+    }
+
+    /// Returns the character's Unicode general category.
+    pub fn general_category(&self) -> Result<GeneralCategory, CharacterError> {
+        Ok(GeneralCategory::of(self.character()?))
+    }
+
+    /// Returns whether the character has the Unicode Alphabetic property.
+    pub fn is_alphabetic(&self) -> Result<bool, CharacterError> {
+        Ok(self.character()?.is_alphabetic())
+    }
+
+    /// Returns whether the character is a number, i.e. in one of the general categories Nd, Nl, or No.
+    pub fn is_numeric(&self) -> Result<bool, CharacterError> {
+        Ok(self.character()?.is_numeric())
+    }
+
+    /// Returns whether the character is alphabetic or numeric.
+    pub fn is_alphanumeric(&self) -> Result<bool, CharacterError> {
+        Ok(self.character()?.is_alphanumeric())
+    }
+
+    /// Returns whether the character has the Unicode White_Space property.
+    pub fn is_whitespace(&self) -> Result<bool, CharacterError> {
+        Ok(self.character()?.is_whitespace())
+    }
+
+    /// Returns whether the character is a control character.
+    pub fn is_control(&self) -> Result<bool, CharacterError> {
+        Ok(self.character()?.is_control())
+    }
+
+    /// Returns whether the character has the Unicode Uppercase property.
+    pub fn is_uppercase(&self) -> Result<bool, CharacterError> {
+        Ok(self.character()?.is_uppercase())
+    }
+
+    /// Returns whether the character has the Unicode Lowercase property.
+    pub fn is_lowercase(&self) -> Result<bool, CharacterError> {
+        Ok(self.character()?.is_lowercase())
+    }
+
+    /// Returns the upper-case mapping of the character.
+    /// <p>
+    /// The mapping may consist of more than one character, as in "ß" to "SS".
+    /// </p>
+    pub fn to_uppercase(&self) -> Result<String, CharacterError> {
+        Ok(self.character()?.to_uppercase().collect())
+    }
+
+    /// Returns the lower-case mapping of the character.
+    pub fn to_lowercase(&self) -> Result<String, CharacterError> {
+        Ok(self.character()?.to_lowercase().collect())
+    }
+}
 
 impl SetEqualTo for Character {
-    fn set_equal_to(&mut self, _other: &Self) -> Result<(), SetEqualToError> {
-        // Implement the logic to set this Character equal to another Character.
-        todo!("Implement set_equal_to for Character");
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.spec().check_compatible_with(other.spec())?;
+        let value = other.character()?;
+        self.set_character(value)?;
+        Ok(())
     }
 }
 
 impl Accessor for Character {}
+
+impl PartialEq for Character {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.character(), other.character()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Character {}
+
+impl PartialOrd for Character {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Character {
+    /// Characters are ordered by their Unicode scalar values.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.character()
+            .unwrap_or_default()
+            .cmp(&other.character().unwrap_or_default())
+    }
+}
+
+impl Hash for Character {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.character().unwrap_or_default().hash(state);
+    }
+}
+
+impl Display for Character {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.character().unwrap_or_default())
+    }
+}
+
+impl Debug for Character {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.character().unwrap_or_default())
+    }
+}
+
+impl TryFrom<char> for Variable {
+    type Error = CharacterError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let spec = CharacterSpecBuilder::new()
+            .set_storage(CharacterStorage::Scalar)
+            .build();
+        let mut var = Variable::new(&spec);
+        var.character_mut().set_character(value)?;
+        Ok(var)
+    }
+}
+
+impl TryFrom<Variable> for char {
+    type Error = CharacterError;
+
+    fn try_from(value: Variable) -> Result<Self, Self::Error> {
+        value.character().character()
+    }
+}
+
+/// An iterator over the characters of a string, yielding character variables.
+/// <p>
+/// Returned by [`String::characters`](crate::accessors::string::String::characters).
+/// </p>
+pub struct Characters {
+    spec: Rc<DataSpec>,
+    chars: std::vec::IntoIter<char>,
+}
+
+impl Characters {
+    /// Returns an iterator that yields the characters as variables with the given spec.
+    pub(crate) fn new(spec: Rc<DataSpec>, value: &str) -> Self {
+        Self {
+            spec,
+            chars: value.chars().collect::<Vec<_>>().into_iter(),
+        }
+    }
+}
+
+impl Iterator for Characters {
+    type Item = Result<Variable, CharacterError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.chars.next()?;
+        let mut var = Variable::new(&self.spec);
+        Some(var.character_mut().set_character(c).map(|_| var))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+/// GeneralCategory defines an enumeration of the Unicode general categories.
+/// <p>
+/// Every character belongs to exactly one general category. The categories are
+/// grouped into the major classes Letter, Mark, Number, Punctuation, Symbol,
+/// Separator, and Other.
+/// </p>
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum GeneralCategory {
+    /// Lu: an uppercase letter.
+    UppercaseLetter,
+    /// Ll: a lowercase letter.
+    LowercaseLetter,
+    /// Lt: a digraph whose first part is uppercase.
+    TitlecaseLetter,
+    /// Lm: a modifier letter.
+    ModifierLetter,
+    /// Lo: other letters, including syllables and ideographs.
+    OtherLetter,
+    /// Mn: a nonspacing combining mark.
+    NonspacingMark,
+    /// Mc: a spacing combining mark.
+    SpacingMark,
+    /// Me: an enclosing combining mark.
+    EnclosingMark,
+    /// Nd: a decimal digit.
+    DecimalNumber,
+    /// Nl: a letterlike numeric character.
+    LetterNumber,
+    /// No: a numeric character of other type.
+    OtherNumber,
+    /// Pc: a connecting punctuation mark, like a tie.
+    ConnectorPunctuation,
+    /// Pd: a dash or hyphen punctuation mark.
+    DashPunctuation,
+    /// Ps: an opening punctuation mark of a pair.
+    OpenPunctuation,
+    /// Pe: a closing punctuation mark of a pair.
+    ClosePunctuation,
+    /// Pi: an initial quotation mark.
+    InitialPunctuation,
+    /// Pf: a final quotation mark.
+    FinalPunctuation,
+    /// Po: a punctuation mark of other type.
+    OtherPunctuation,
+    /// Sm: a symbol of mathematical use.
+    MathSymbol,
+    /// Sc: a currency sign.
+    CurrencySymbol,
+    /// Sk: a non-letterlike modifier symbol.
+    ModifierSymbol,
+    /// So: a symbol of other type.
+    OtherSymbol,
+    /// Zs: a space character of non-zero width.
+    SpaceSeparator,
+    /// Zl: U+2028 LINE SEPARATOR only.
+    LineSeparator,
+    /// Zp: U+2029 PARAGRAPH SEPARATOR only.
+    ParagraphSeparator,
+    /// Cc: a C0 or C1 control code.
+    Control,
+    /// Cf: a format control character.
+    Format,
+    /// Cs: a surrogate code point. Never the category of a character value.
+    Surrogate,
+    /// Co: a private-use character.
+    PrivateUse,
+    /// Cn: a reserved unassigned code point or a noncharacter.
+    Unassigned,
+}
+
+impl GeneralCategory {
+    /// Returns the general category of the given character.
+    pub fn of(c: char) -> GeneralCategory {
+        use unicode_general_category::GeneralCategory as Ucd;
+        match unicode_general_category::get_general_category(c) {
+            Ucd::UppercaseLetter => Self::UppercaseLetter,
+            Ucd::LowercaseLetter => Self::LowercaseLetter,
+            Ucd::TitlecaseLetter => Self::TitlecaseLetter,
+            Ucd::ModifierLetter => Self::ModifierLetter,
+            Ucd::OtherLetter => Self::OtherLetter,
+            Ucd::NonspacingMark => Self::NonspacingMark,
+            Ucd::SpacingMark => Self::SpacingMark,
+            Ucd::EnclosingMark => Self::EnclosingMark,
+            Ucd::DecimalNumber => Self::DecimalNumber,
+            Ucd::LetterNumber => Self::LetterNumber,
+            Ucd::OtherNumber => Self::OtherNumber,
+            Ucd::ConnectorPunctuation => Self::ConnectorPunctuation,
+            Ucd::DashPunctuation => Self::DashPunctuation,
+            Ucd::OpenPunctuation => Self::OpenPunctuation,
+            Ucd::ClosePunctuation => Self::ClosePunctuation,
+            Ucd::InitialPunctuation => Self::InitialPunctuation,
+            Ucd::FinalPunctuation => Self::FinalPunctuation,
+            Ucd::OtherPunctuation => Self::OtherPunctuation,
+            Ucd::MathSymbol => Self::MathSymbol,
+            Ucd::CurrencySymbol => Self::CurrencySymbol,
+            Ucd::ModifierSymbol => Self::ModifierSymbol,
+            Ucd::OtherSymbol => Self::OtherSymbol,
+            Ucd::SpaceSeparator => Self::SpaceSeparator,
+            Ucd::LineSeparator => Self::LineSeparator,
+            Ucd::ParagraphSeparator => Self::ParagraphSeparator,
+            Ucd::Control => Self::Control,
+            Ucd::Format => Self::Format,
+            Ucd::Surrogate => Self::Surrogate,
+            Ucd::PrivateUse => Self::PrivateUse,
+            _ => Self::Unassigned,
+        }
+    }
+
+    /// Returns the two-letter abbreviation of the category, such as "Lu".
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::UppercaseLetter => "Lu",
+            Self::LowercaseLetter => "Ll",
+            Self::TitlecaseLetter => "Lt",
+            Self::ModifierLetter => "Lm",
+            Self::OtherLetter => "Lo",
+            Self::NonspacingMark => "Mn",
+            Self::SpacingMark => "Mc",
+            Self::EnclosingMark => "Me",
+            Self::DecimalNumber => "Nd",
+            Self::LetterNumber => "Nl",
+            Self::OtherNumber => "No",
+            Self::ConnectorPunctuation => "Pc",
+            Self::DashPunctuation => "Pd",
+            Self::OpenPunctuation => "Ps",
+            Self::ClosePunctuation => "Pe",
+            Self::InitialPunctuation => "Pi",
+            Self::FinalPunctuation => "Pf",
+            Self::OtherPunctuation => "Po",
+            Self::MathSymbol => "Sm",
+            Self::CurrencySymbol => "Sc",
+            Self::ModifierSymbol => "Sk",
+            Self::OtherSymbol => "So",
+            Self::SpaceSeparator => "Zs",
+            Self::LineSeparator => "Zl",
+            Self::ParagraphSeparator => "Zp",
+            Self::Control => "Cc",
+            Self::Format => "Cf",
+            Self::Surrogate => "Cs",
+            Self::PrivateUse => "Co",
+            Self::Unassigned => "Cn",
+        }
+    }
+
+    /// Returns whether the category is in the major class Letter.
+    pub fn is_letter(&self) -> bool {
+        self.abbreviation().starts_with('L')
+    }
+
+    /// Returns whether the category is in the major class Mark.
+    pub fn is_mark(&self) -> bool {
+        self.abbreviation().starts_with('M')
+    }
+
+    /// Returns whether the category is in the major class Number.
+    pub fn is_number(&self) -> bool {
+        self.abbreviation().starts_with('N')
+    }
+
+    /// Returns whether the category is in the major class Punctuation.
+    pub fn is_punctuation(&self) -> bool {
+        self.abbreviation().starts_with('P')
+    }
+
+    /// Returns whether the category is in the major class Symbol.
+    pub fn is_symbol(&self) -> bool {
+        self.abbreviation().starts_with('S')
+    }
+
+    /// Returns whether the category is in the major class Separator.
+    pub fn is_separator(&self) -> bool {
+        self.abbreviation().starts_with('Z')
+    }
+
+    /// Returns whether the category is in the major class Other.
+    pub fn is_other(&self) -> bool {
+        self.abbreviation().starts_with('C')
+    }
+}
+
+impl Display for GeneralCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+/// An error that can occur when accessing a character.
+#[derive(Debug, PartialEq)]
+pub enum CharacterError {
+    /// A provider error.
+    ProviderError(ProviderError),
+    /// An error indicating that the character's storage cannot represent a value.
+    /// Holds the rejected value.
+    UnrepresentableCharacter(char),
+}
+
+impl std::fmt::Display for CharacterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CharacterError::ProviderError(err) => write!(f, "Provider error: {}", err),
+            CharacterError::UnrepresentableCharacter(c) => write!(
+                f,
+                "Character {:?} cannot be represented by the character's storage",
+                c
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CharacterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CharacterError::ProviderError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProviderError> for CharacterError {
+    fn from(error: ProviderError) -> Self {
+        CharacterError::ProviderError(error)
+    }
+}
+
+impl From<CharacterError> for SetEqualToError {
+    fn from(error: CharacterError) -> Self {
+        SetEqualToError::CharacterError(error)
+    }
+}
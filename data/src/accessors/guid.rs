@@ -1,10 +1,40 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    rc::Rc,
+};
+
+use uuid::Uuid;
+
 use crate::{
     adaptors::guid_adaptor::GuidAdaptor,
+    data_spec_builders::guid_spec_builder::GuidSpecBuilder,
     primitive_def::Accessor,
+    primitive_specs::guid_spec::{GuidSpec, GuidVersion},
+    provider_error::ProviderError,
     set_equal_to::{SetEqualTo, SetEqualToError},
+    spec_compatibility::SpecCompatibility,
+    variable::Variable,
 };
 
+/// GuidFormat defines an enumeration of the textual forms of a GUID.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum GuidFormat {
+    /// The canonical form, as in `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Hyphenated,
+    /// Without hyphens, as in `67e5504410b1426f9247bb680e5fe0c8`.
+    Simple,
+    /// In braces, as in `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    Braced,
+    /// As a URN, as in `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Urn,
+}
+
 /// The accessor for GUIDs.
+/// <p>
+/// GUIDs are ordered by their bytes, so version 7 GUIDs sort by creation time.
+/// A new GUID variable holds the nil GUID.
+/// </p>
 pub struct Guid {
     adaptor: Box<dyn GuidAdaptor>,
 }
@@ -14,12 +44,202 @@ impl Guid {
     pub fn new(adaptor: Box<dyn GuidAdaptor>) -> Self {
         Self { adaptor }
     }
+
+    /// Returns the GUID's specification.
+    pub fn spec(&self) -> &Rc<GuidSpec> {
+        self.adaptor.spec()
+    }
+
+    /// Returns the GUID's bytes in big-endian order.
+    pub fn bytes(&self) -> Result<[u8; 16], GuidError> {
+        self.adaptor.bytes()
+    }
+
+    /// Sets the GUID's bytes in big-endian order.
+    /// Fails if the spec pins a version and the bytes are neither of that version nor nil.
+    pub fn set_bytes(&mut self, bytes: &[u8; 16]) -> Result<(), GuidError> {
+        self.adaptor.set_bytes(bytes)
+    }
+
+    /// Returns whether the GUID is the nil GUID, whose bits are all zero.
+    pub fn is_nil(&self) -> Result<bool, GuidError> {
+        Ok(self.bytes()? == [0; 16])
+    }
+
+    /// Returns the GUID's version, or `None` for the nil GUID and GUIDs of an unknown version.
+    pub fn version(&self) -> Result<Option<GuidVersion>, GuidError> {
+        let uuid = Uuid::from_bytes(self.bytes()?);
+        Ok(GuidVersion::from_number(uuid.get_version_num() as u8).filter(|_| !uuid.is_nil()))
+    }
+
+    /// Sets the GUID to a newly generated one of the spec's pinned version,
+    /// or to a random (version 4) GUID if the spec does not pin a version.
+    /// Fails if the pinned version cannot be generated.
+    pub fn generate(&mut self) -> Result<(), GuidError> {
+        match self.spec().version() {
+            None | Some(GuidVersion::V4) => self.generate_v4(),
+            Some(GuidVersion::V7) => self.generate_v7(),
+            Some(version) => Err(GuidError::UnsupportedGeneration(version)),
+        }
+    }
+
+    /// Sets the GUID to a newly generated random (version 4) GUID.
+    pub fn generate_v4(&mut self) -> Result<(), GuidError> {
+        self.set_bytes(Uuid::new_v4().as_bytes())
+    }
+
+    /// Sets the GUID to a newly generated time-ordered (version 7) GUID.
+    pub fn generate_v7(&mut self) -> Result<(), GuidError> {
+        self.set_bytes(Uuid::now_v7().as_bytes())
+    }
+
+    /// Sets the GUID from a string in any of the forms of [`GuidFormat`].
+    /// Hexadecimal digits may be upper or lower case.
+    pub fn set_from_string(&mut self, value: &str) -> Result<(), GuidError> {
+        let uuid =
+            Uuid::try_parse(value).map_err(|err| GuidError::InvalidFormat(err.to_string()))?;
+        self.set_bytes(uuid.as_bytes())
+    }
+
+    /// Returns the GUID as a lower-case string in the given form.
+    pub fn format(&self, format: GuidFormat) -> Result<String, GuidError> {
+        let uuid = Uuid::from_bytes(self.bytes()?);
+        Ok(match format {
+            GuidFormat::Hyphenated => uuid.hyphenated().to_string(),
+            GuidFormat::Simple => uuid.simple().to_string(),
+            GuidFormat::Braced => uuid.braced().to_string(),
+            GuidFormat::Urn => uuid.urn().to_string(),
+        })
+    }
 }
 
 impl SetEqualTo for Guid {
-    fn set_equal_to(&mut self, _other: &Self) -> Result<(), SetEqualToError> {
-        todo!("Implement set_equal_to for Guid");
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.spec().check_compatible_with(other.spec())?;
+        let bytes = other.bytes()?;
+        self.set_bytes(&bytes)?;
+        Ok(())
     }
 }
 
 impl Accessor for Guid {}
+
+impl PartialEq for Guid {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.bytes(), other.bytes()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Guid {}
+
+impl PartialOrd for Guid {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Guid {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.bytes()
+            .unwrap_or_default()
+            .cmp(&other.bytes().unwrap_or_default())
+    }
+}
+
+impl Hash for Guid {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.bytes().unwrap_or_default().hash(state);
+    }
+}
+
+impl Display for Guid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            Uuid::from_bytes(self.bytes().unwrap_or_default()).hyphenated()
+        )
+    }
+}
+
+impl Debug for Guid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl TryFrom<[u8; 16]> for Variable {
+    type Error = GuidError;
+
+    fn try_from(value: [u8; 16]) -> Result<Self, Self::Error> {
+        let spec = GuidSpecBuilder::new().build();
+        let mut var = Variable::new(&spec);
+        var.guid_mut().set_bytes(&value)?;
+        Ok(var)
+    }
+}
+
+impl TryFrom<Variable> for [u8; 16] {
+    type Error = GuidError;
+
+    fn try_from(value: Variable) -> Result<Self, Self::Error> {
+        value.guid().bytes()
+    }
+}
+
+/// An error that can occur when accessing a GUID.
+#[derive(Debug, PartialEq)]
+pub enum GuidError {
+    /// A provider error.
+    ProviderError(ProviderError),
+    /// An error indicating that a GUID is not of the spec's pinned version.
+    /// Holds the pinned and the actual version numbers.
+    VersionMismatch(u8, u8),
+    /// An error indicating that GUIDs of a version cannot be generated.
+    UnsupportedGeneration(GuidVersion),
+    /// Invalid GUID format.
+    InvalidFormat(String),
+}
+
+impl Display for GuidError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuidError::ProviderError(err) => write!(f, "Provider error: {}", err),
+            GuidError::VersionMismatch(expected, actual) => write!(
+                f,
+                "A version {} GUID is not accepted where version {} is required",
+                actual, expected
+            ),
+            GuidError::UnsupportedGeneration(version) => write!(
+                f,
+                "Generating {} GUIDs is not supported; only V4 and V7 GUIDs can be generated",
+                version
+            ),
+            GuidError::InvalidFormat(msg) => write!(f, "Invalid GUID: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for GuidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GuidError::ProviderError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProviderError> for GuidError {
+    fn from(error: ProviderError) -> Self {
+        GuidError::ProviderError(error)
+    }
+}
+
+impl From<GuidError> for SetEqualToError {
+    fn from(error: GuidError) -> Self {
+        SetEqualToError::GuidError(error)
+    }
+}
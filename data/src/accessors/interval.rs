@@ -0,0 +1,503 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::Hash,
+    rc::Rc,
+};
+
+use crate::{
+    adaptors::interval_adaptor::IntervalAdaptor,
+    primitive_def::Accessor,
+    primitive_specs::interval_spec::{BoundType, IntervalSpec},
+    provider_error::ProviderError,
+    set_equal_to::{SetEqualTo, SetEqualToError},
+    spec_compatibility::{SpecCompatibility, SpecError},
+    variable::Variable,
+};
+
+/// AllenRelation defines an enumeration of the thirteen relations one non-empty interval can
+/// have to another, following Allen's interval algebra.
+/// <p>
+/// Exactly one relation holds between any two non-empty intervals. Values are treated as
+/// continuous, so that [1, 3) meets [3, 5), [1, 3] overlaps [3, 5] and [1, 3) is before (3, 5).
+/// </p>
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AllenRelation {
+    /// The interval ends before the other starts, leaving a gap.
+    Before,
+    /// The interval ends where the other starts, without a gap or a shared value.
+    Meets,
+    /// The interval starts before the other and ends within it.
+    Overlaps,
+    /// The interval starts with the other and ends before it.
+    Starts,
+    /// The interval starts after the other and ends before it.
+    During,
+    /// The interval starts after the other and ends with it.
+    Finishes,
+    /// The interval starts and ends with the other.
+    Equals,
+    /// The interval starts before the other and ends with it.
+    FinishedBy,
+    /// The interval starts before the other and ends after it.
+    Contains,
+    /// The interval starts with the other and ends after it.
+    StartedBy,
+    /// The interval starts within the other and ends after it.
+    OverlappedBy,
+    /// The interval starts where the other ends, without a gap or a shared value.
+    MetBy,
+    /// The interval starts after the other ends, leaving a gap.
+    After,
+}
+
+impl AllenRelation {
+    /// Returns the relation the other interval has to this one.
+    pub fn inverse(&self) -> Self {
+        match self {
+            Self::Before => Self::After,
+            Self::Meets => Self::MetBy,
+            Self::Overlaps => Self::OverlappedBy,
+            Self::Starts => Self::StartedBy,
+            Self::During => Self::Contains,
+            Self::Finishes => Self::FinishedBy,
+            Self::Equals => Self::Equals,
+            Self::FinishedBy => Self::Finishes,
+            Self::Contains => Self::During,
+            Self::StartedBy => Self::Starts,
+            Self::OverlappedBy => Self::Overlaps,
+            Self::MetBy => Self::Meets,
+            Self::After => Self::Before,
+        }
+    }
+
+    /// Returns true if intervals with this relation share no values; false otherwise.
+    pub fn is_disjoint(&self) -> bool {
+        matches!(self, Self::Before | Self::Meets | Self::MetBy | Self::After)
+    }
+}
+
+impl Display for AllenRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Before => "Before",
+                Self::Meets => "Meets",
+                Self::Overlaps => "Overlaps",
+                Self::Starts => "Starts",
+                Self::During => "During",
+                Self::Finishes => "Finishes",
+                Self::Equals => "Equals",
+                Self::FinishedBy => "FinishedBy",
+                Self::Contains => "Contains",
+                Self::StartedBy => "StartedBy",
+                Self::OverlappedBy => "OverlappedBy",
+                Self::MetBy => "MetBy",
+                Self::After => "After",
+            }
+        )
+    }
+}
+
+/// A start or end of an interval, ordered so that an open start lies just after its value and
+/// an open end lies just before it.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Endpoint<'a> {
+    value: &'a Variable,
+    offset: i8,
+}
+
+impl<'a> Endpoint<'a> {
+    fn start(value: &'a Variable, bound: BoundType) -> Self {
+        let offset = match bound {
+            BoundType::Closed => 0,
+            BoundType::Open => 1,
+        };
+        Self { value, offset }
+    }
+
+    fn end(value: &'a Variable, bound: BoundType) -> Self {
+        let offset = match bound {
+            BoundType::Closed => 0,
+            BoundType::Open => -1,
+        };
+        Self { value, offset }
+    }
+
+    /// Returns the bound type of the endpoint.
+    fn bound(&self) -> BoundType {
+        if self.offset == 0 {
+            BoundType::Closed
+        } else {
+            BoundType::Open
+        }
+    }
+
+    /// Returns true if this end is immediately followed by the start, so that no value lies
+    /// between them and none belongs to both.
+    fn meets(&self, start: &Endpoint) -> bool {
+        self.value == start.value && start.offset - self.offset == 1
+    }
+}
+
+/// An accessor for intervals of ordered values.
+pub struct Interval {
+    /// The adaptor for the interval.
+    adaptor: Box<dyn IntervalAdaptor>,
+}
+
+impl Interval {
+    /// Creates a new `Interval` accessor using the provided adaptor.
+    pub fn new(adaptor: Box<dyn IntervalAdaptor>) -> Self {
+        Self { adaptor }
+    }
+
+    /// Returns the interval's specification.
+    pub fn spec(&self) -> &Rc<IntervalSpec> {
+        self.adaptor.spec()
+    }
+
+    /// Returns the interval's start value.
+    pub fn start(&self) -> Result<&Variable, IntervalError> {
+        self.adaptor.start()
+    }
+
+    /// Returns the interval's end value.
+    pub fn end(&self) -> Result<&Variable, IntervalError> {
+        self.adaptor.end()
+    }
+
+    /// Returns the bound type of the interval's start.
+    pub fn start_bound(&self) -> Result<BoundType, IntervalError> {
+        self.adaptor.start_bound()
+    }
+
+    /// Returns the bound type of the interval's end.
+    pub fn end_bound(&self) -> Result<BoundType, IntervalError> {
+        self.adaptor.end_bound()
+    }
+
+    /// Sets the interval's start and end values with the default bound types of its spec.
+    pub fn set(&mut self, start: Variable, end: Variable) -> Result<(), IntervalError> {
+        let (start_bound, end_bound) = (self.spec().start_bound(), self.spec().end_bound());
+        self.adaptor.set(start, start_bound, end, end_bound)
+    }
+
+    /// Sets the interval's start and end values and their bound types.
+    pub fn set_with_bounds(
+        &mut self,
+        start: Variable,
+        start_bound: BoundType,
+        end: Variable,
+        end_bound: BoundType,
+    ) -> Result<(), IntervalError> {
+        self.adaptor.set(start, start_bound, end, end_bound)
+    }
+
+    /// Returns true if the interval contains no values, as (3, 3) or [3, 3) do; false otherwise.
+    pub fn is_empty(&self) -> Result<bool, IntervalError> {
+        Ok(self.start_point()? > self.end_point()?)
+    }
+
+    /// Returns true if the interval contains the value; false otherwise.
+    pub fn contains(&self, value: &Variable) -> Result<bool, IntervalError> {
+        let point = Endpoint::start(value, BoundType::Closed);
+        Ok(self.start_point()? <= point && point <= self.end_point()?)
+    }
+
+    /// Returns the Allen relation this interval has to the other, or an error if either is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::accessors::interval::AllenRelation;
+    /// use data::data_spec_builders::{interval_spec_builder::IntervalSpecBuilder, integer_spec_builder::IntegerSpecBuilder};
+    /// use data::primitive_specs::integer_spec::{IntegerEncoding, IntegerStorage};
+    /// use data::variable::Variable;
+    ///
+    /// let spec = IntervalSpecBuilder::new()
+    ///     .set_value_spec(IntegerSpecBuilder::new()
+    ///         .set_encoding(IntegerEncoding::Signed)
+    ///         .set_storage(IntegerStorage::B64)
+    ///         .build())
+    ///     .build()
+    ///     .unwrap();
+    /// let mut morning = Variable::new(&spec);
+    /// morning.interval_mut().set(Variable::try_from(8i64).unwrap(), Variable::try_from(12i64).unwrap()).unwrap();
+    /// let mut afternoon = Variable::new(&spec);
+    /// afternoon.interval_mut().set(Variable::try_from(12i64).unwrap(), Variable::try_from(17i64).unwrap()).unwrap();
+    ///
+    /// assert_eq!(morning.interval().relation(afternoon.interval()).unwrap(), AllenRelation::Meets);
+    /// ```
+    pub fn relation(&self, other: &Interval) -> Result<AllenRelation, IntervalError> {
+        let (start, end) = self.non_empty_points()?;
+        let (other_start, other_end) = other.non_empty_points()?;
+        if end < other_start {
+            return Ok(if end.meets(&other_start) {
+                AllenRelation::Meets
+            } else {
+                AllenRelation::Before
+            });
+        }
+        if other_end < start {
+            return Ok(if other_end.meets(&start) {
+                AllenRelation::MetBy
+            } else {
+                AllenRelation::After
+            });
+        }
+        Ok(match (start.cmp(&other_start), end.cmp(&other_end)) {
+            (Ordering::Equal, Ordering::Equal) => AllenRelation::Equals,
+            (Ordering::Equal, Ordering::Less) => AllenRelation::Starts,
+            (Ordering::Equal, Ordering::Greater) => AllenRelation::StartedBy,
+            (Ordering::Greater, Ordering::Equal) => AllenRelation::Finishes,
+            (Ordering::Less, Ordering::Equal) => AllenRelation::FinishedBy,
+            (Ordering::Greater, Ordering::Less) => AllenRelation::During,
+            (Ordering::Less, Ordering::Greater) => AllenRelation::Contains,
+            (Ordering::Less, Ordering::Less) => AllenRelation::Overlaps,
+            (Ordering::Greater, Ordering::Greater) => AllenRelation::OverlappedBy,
+        })
+    }
+
+    /// Returns true if the interval shares at least one value with the other; false otherwise.
+    pub fn intersects(&self, other: &Interval) -> Result<bool, IntervalError> {
+        Ok(!self.relation(other)?.is_disjoint())
+    }
+
+    /// Sets the interval to the values it shares with the other, or returns an error if they
+    /// share none.
+    pub fn intersect(&mut self, other: &Interval) -> Result<(), IntervalError> {
+        if self.relation(other)?.is_disjoint() {
+            return Err(IntervalError::Disjoint);
+        }
+        let (start, end) = {
+            let start = self.start_point()?.max(other.start_point()?);
+            let end = self.end_point()?.min(other.end_point()?);
+            (
+                (start.value.try_clone()?, start.bound()),
+                (end.value.try_clone()?, end.bound()),
+            )
+        };
+        self.set_with_bounds(start.0, start.1, end.0, end.1)
+    }
+
+    /// Sets the interval to the values it or the other contains, or returns an error if a gap
+    /// lies between them.
+    pub fn unite(&mut self, other: &Interval) -> Result<(), IntervalError> {
+        if matches!(
+            self.relation(other)?,
+            AllenRelation::Before | AllenRelation::After
+        ) {
+            return Err(IntervalError::Disjoint);
+        }
+        let (start, end) = {
+            let start = self.start_point()?.min(other.start_point()?);
+            let end = self.end_point()?.max(other.end_point()?);
+            (
+                (start.value.try_clone()?, start.bound()),
+                (end.value.try_clone()?, end.bound()),
+            )
+        };
+        self.set_with_bounds(start.0, start.1, end.0, end.1)
+    }
+
+    /// Sets the interval to the gap between it and the other, which is empty if they meet, or
+    /// returns an error if they share values.
+    pub fn set_to_gap(&mut self, other: &Interval) -> Result<(), IntervalError> {
+        let relation = self.relation(other)?;
+        if !relation.is_disjoint() {
+            return Err(IntervalError::Overlap);
+        }
+        let (start, end) = {
+            let (first_end, second_start) =
+                if matches!(relation, AllenRelation::Before | AllenRelation::Meets) {
+                    (self.end_point()?, other.start_point()?)
+                } else {
+                    (other.end_point()?, self.start_point()?)
+                };
+            (
+                (first_end.value.try_clone()?, first_end.bound().flipped()),
+                (
+                    second_start.value.try_clone()?,
+                    second_start.bound().flipped(),
+                ),
+            )
+        };
+        self.set_with_bounds(start.0, start.1, end.0, end.1)
+    }
+
+    fn start_point(&self) -> Result<Endpoint<'_>, IntervalError> {
+        Ok(Endpoint::start(self.start()?, self.start_bound()?))
+    }
+
+    fn end_point(&self) -> Result<Endpoint<'_>, IntervalError> {
+        Ok(Endpoint::end(self.end()?, self.end_bound()?))
+    }
+
+    fn non_empty_points(&self) -> Result<(Endpoint<'_>, Endpoint<'_>), IntervalError> {
+        let (start, end) = (self.start_point()?, self.end_point()?);
+        if start > end {
+            return Err(IntervalError::Empty(self.to_string()));
+        }
+        Ok((start, end))
+    }
+}
+
+impl SetEqualTo for Interval {
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.spec().as_ref().check_compatible_with(other.spec())?;
+        self.set_with_bounds(
+            other.start()?.try_clone()?,
+            other.start_bound()?,
+            other.end()?.try_clone()?,
+            other.end_bound()?,
+        )?;
+        Ok(())
+    }
+}
+
+impl Accessor for Interval {}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Interval {}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Intervals are ordered by their start and then their end points. Intervals with the same points
+/// but different specs are ordered by their specs' descriptions.
+impl Ord for Interval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.start_point()
+            .ok()
+            .cmp(&other.start_point().ok())
+            .then_with(|| self.end_point().ok().cmp(&other.end_point().ok()))
+            .then_with(|| {
+                if self.spec() == other.spec() {
+                    Ordering::Equal
+                } else {
+                    self.spec().to_string().cmp(&other.spec().to_string())
+                }
+            })
+    }
+}
+
+impl Hash for Interval {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        if let (Ok(start), Ok(end)) = (self.start_point(), self.end_point()) {
+            start.value.hash(state);
+            start.offset.hash(state);
+            end.value.hash(state);
+            end.offset.hash(state);
+        }
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.start_point(), self.end_point()) {
+            (Ok(start), Ok(end)) => write!(
+                f,
+                "{}{}, {}{}",
+                match start.bound() {
+                    BoundType::Closed => "[",
+                    BoundType::Open => "(",
+                },
+                start.value,
+                end.value,
+                match end.bound() {
+                    BoundType::Closed => "]",
+                    BoundType::Open => ")",
+                }
+            ),
+            _ => write!(f, "Interval: Error"),
+        }
+    }
+}
+
+impl Debug for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Errors that can occur when working with intervals.
+#[derive(Debug, PartialEq)]
+pub enum IntervalError {
+    /// A provider error.
+    ProviderError(ProviderError),
+    /// An error indicating that the value specification is not compatible with the interval's
+    /// value specification.
+    ValueSpecError(SpecError),
+    /// An error indicating that the start value is after the end value.
+    StartAfterEnd(String, String),
+    /// An error indicating that the operation requires a non-empty interval.
+    Empty(String),
+    /// An error indicating that the intervals share no values, or have a gap between them.
+    Disjoint,
+    /// An error indicating that the intervals share values, so there is no gap between them.
+    Overlap,
+    /// An error that occurred while copying a start or end value.
+    ValueError(Box<SetEqualToError>),
+}
+
+impl From<ProviderError> for IntervalError {
+    fn from(err: ProviderError) -> Self {
+        IntervalError::ProviderError(err)
+    }
+}
+
+impl From<SpecError> for IntervalError {
+    fn from(err: SpecError) -> Self {
+        IntervalError::ValueSpecError(err)
+    }
+}
+
+impl From<SetEqualToError> for IntervalError {
+    fn from(err: SetEqualToError) -> Self {
+        IntervalError::ValueError(Box::new(err))
+    }
+}
+
+impl std::fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntervalError::ProviderError(err) => write!(f, "Provider Error: {}", err),
+            IntervalError::ValueSpecError(err) => write!(f, "{}", err),
+            IntervalError::StartAfterEnd(start, end) => {
+                write!(f, "Start {} is after end {}", start, end)
+            }
+            IntervalError::Empty(interval) => write!(f, "Interval {} is empty", interval),
+            IntervalError::Disjoint => write!(f, "Intervals are disjoint"),
+            IntervalError::Overlap => write!(f, "Intervals overlap, so there is no gap"),
+            IntervalError::ValueError(err) => write!(f, "Value Error: {:?}", err),
+        }
+    }
+}
+
+impl std::error::Error for IntervalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IntervalError::ProviderError(e) => Some(e),
+            IntervalError::ValueSpecError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<IntervalError> for SetEqualToError {
+    fn from(error: IntervalError) -> Self {
+        SetEqualToError::IntervalError(error)
+    }
+}
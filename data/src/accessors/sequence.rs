@@ -79,6 +79,8 @@ pub enum SequenceError {
     ReadOnlyError,
     /// An error indicating that another sequence specification is not compatible with this sequence's specification.
     SpecError(SpecError),
+    /// An error that occurred while computing a value of the sequence.
+    ValueError(Box<SetEqualToError>),
 }
 
 impl From<ProviderError> for SequenceError {
@@ -95,6 +97,7 @@ impl std::fmt::Display for SequenceError {
                 write!(f, "Sequences are read only and thus cannot be modified.")
             }
             SequenceError::SpecError(err) => write!(f, "Specification error: {}", err),
+            SequenceError::ValueError(err) => write!(f, "Value error: {:?}", err),
         }
     }
 }
@@ -105,10 +108,17 @@ impl std::error::Error for SequenceError {
             SequenceError::ProviderError(e) => Some(e),
             SequenceError::ReadOnlyError => None,
             SequenceError::SpecError(e) => Some(e),
+            SequenceError::ValueError(_) => None,
         }
     }
 }
 
+impl From<SetEqualToError> for SequenceError {
+    fn from(error: SetEqualToError) -> Self {
+        SequenceError::ValueError(Box::new(error))
+    }
+}
+
 impl From<SequenceError> for SetEqualToError {
     fn from(error: SequenceError) -> Self {
         SetEqualToError::SequenceError(error)
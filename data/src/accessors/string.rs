@@ -1,2 +1,352 @@
+use std::rc::Rc;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    accessors::{
+        character::Characters,
+        collections::list::{List, ListError},
+    },
+    data_spec::DataSpec,
+    data_spec_builders::{
+        character_spec_builder::CharacterSpecBuilder, list_spec_builder::ListSpecBuilder,
+        string_spec_builder::StringSpecBuilder,
+    },
+    primitive_specs::{
+        character_spec::CharacterStorage,
+        list_spec::ListStorage,
+        string_spec::{StringEncoding, StringSpec, StringStorage},
+    },
+    provider_error::ProviderError,
+    set_equal_to::SetEqualToError,
+    variable::Variable,
+};
+
 /// An accessor trait for string values.
-pub trait String {}
+/// <p>
+/// String is implemented by all string accessors, regardless of their encoding,
+/// and provides access to their values as Rust strings along with common string
+/// operations. Indices are character indices unless stated otherwise, and strings
+/// produced by an operation are variable-size strings with the same encoding
+/// (and code page) as the string they came from.
+/// </p>
+pub trait String {
+    /// Returns the string's specification.
+    fn spec(&self) -> &Rc<StringSpec>;
+
+    /// Returns the value of the string.
+    fn string(&self) -> Result<std::string::String, StringError>;
+
+    /// Sets the value of the string.
+    fn set_string(&mut self, value: &str) -> Result<(), StringError>;
+
+    /// Returns the value of the string, applying the policy to anything that
+    /// does not represent a character.
+    fn string_with_policy(
+        &self,
+        _policy: TranscodingPolicy,
+    ) -> Result<std::string::String, StringError> {
+        self.string()
+    }
+
+    /// Sets the value of the string, applying the policy to characters that the
+    /// string's encoding cannot represent.
+    fn set_string_with_policy(
+        &mut self,
+        value: &str,
+        _policy: TranscodingPolicy,
+    ) -> Result<(), StringError> {
+        self.set_string(value)
+    }
+
+    /// Sets the value of the string to the value of a string of any encoding.
+    /// <p>
+    /// Unlike set_equal_to, this also performs lossy conversions, such as from
+    /// UTF-8 to Latin-1, handling unrepresentable characters according to the policy.
+    /// </p>
+    fn transcode_from(
+        &mut self,
+        source: &dyn String,
+        policy: TranscodingPolicy,
+    ) -> Result<(), StringError> {
+        let value = source.string_with_policy(policy)?;
+        self.set_string_with_policy(&value, policy)
+    }
+
+    /// Returns the number of characters in the string.
+    fn char_count(&self) -> Result<usize, StringError> {
+        Ok(self.string()?.chars().count())
+    }
+
+    /// Returns the number of extended grapheme clusters in the string.
+    fn grapheme_count(&self) -> Result<usize, StringError> {
+        Ok(self.string()?.graphemes(true).count())
+    }
+
+    /// Returns the characters from `start` (inclusive) to `end` (exclusive).
+    fn substring(&self, start: usize, end: usize) -> Result<Variable, StringError> {
+        let value = self.string()?;
+        check_range(start, end, value.chars().count())?;
+        let substring: std::string::String = value.chars().skip(start).take(end - start).collect();
+        new_string_variable(self.spec(), &substring)
+    }
+
+    /// Returns the grapheme clusters from `start` (inclusive) to `end` (exclusive).
+    fn grapheme_substring(&self, start: usize, end: usize) -> Result<Variable, StringError> {
+        let value = self.string()?;
+        check_range(start, end, value.graphemes(true).count())?;
+        let substring: std::string::String = value
+            .graphemes(true)
+            .skip(start)
+            .take(end - start)
+            .collect();
+        new_string_variable(self.spec(), &substring)
+    }
+
+    /// Returns the character index of the first occurrence of the pattern, if any.
+    fn find(&self, pattern: &str) -> Result<Option<usize>, StringError> {
+        let value = self.string()?;
+        Ok(value
+            .find(pattern)
+            .map(|byte_index| value[..byte_index].chars().count()))
+    }
+
+    /// Returns whether the string contains the pattern.
+    fn contains(&self, pattern: &str) -> Result<bool, StringError> {
+        Ok(self.string()?.contains(pattern))
+    }
+
+    /// Returns whether the string starts with the pattern.
+    fn starts_with(&self, pattern: &str) -> Result<bool, StringError> {
+        Ok(self.string()?.starts_with(pattern))
+    }
+
+    /// Returns whether the string ends with the pattern.
+    fn ends_with(&self, pattern: &str) -> Result<bool, StringError> {
+        Ok(self.string()?.ends_with(pattern))
+    }
+
+    /// Returns an iterator over the string's characters as character variables.
+    /// <p>
+    /// The characters of a byte string are byte characters with the string's code page;
+    /// the characters of a Unicode string are scalar characters.
+    /// </p>
+    fn characters(&self) -> Result<Characters, StringError> {
+        let mut builder = CharacterSpecBuilder::new();
+        if *self.spec().encoding() == StringEncoding::Byte {
+            builder.set_storage(CharacterStorage::Byte);
+            if let Some(code_page) = self.spec().code_page() {
+                builder.set_code_page(*code_page);
+            }
+        } else {
+            builder.set_storage(CharacterStorage::Scalar);
+        }
+        Ok(Characters::new(builder.build(), &self.string()?))
+    }
+
+    /// Splits the string at each occurrence of the separator and returns the parts as a list of strings.
+    fn split(&self, separator: &str) -> Result<Variable, StringError> {
+        let value = self.string()?;
+        let list_spec = ListSpecBuilder::new()
+            .set_value_spec(variable_size_spec(self.spec()))
+            .set_storage(ListStorage::VariableSize)
+            .build();
+        let mut list_var = Variable::new(&list_spec);
+        let list = list_var.list_mut();
+        for part in value.split(separator) {
+            list.push(new_string_variable(self.spec(), part)?)?;
+        }
+        Ok(list_var)
+    }
+
+    /// Sets the string to the strings of the list, separated by the separator.
+    /// The list's strings may be of any encoding.
+    fn join(&mut self, list: &List, separator: &str) -> Result<(), StringError> {
+        let mut parts = Vec::with_capacity(list.len());
+        for (index, element) in list.iter().enumerate() {
+            let element = element?;
+            if !element.is_string() {
+                return Err(StringError::NotAString(index));
+            }
+            parts.push(element.string().string()?);
+        }
+        self.set_string(&parts.join(separator))
+    }
+
+    /// Removes leading and trailing whitespace.
+    fn trim(&mut self) -> Result<(), StringError> {
+        let value = self.string()?;
+        self.set_string(value.trim())
+    }
+
+    /// Removes leading whitespace.
+    fn trim_start(&mut self) -> Result<(), StringError> {
+        let value = self.string()?;
+        self.set_string(value.trim_start())
+    }
+
+    /// Removes trailing whitespace.
+    fn trim_end(&mut self) -> Result<(), StringError> {
+        let value = self.string()?;
+        self.set_string(value.trim_end())
+    }
+
+    /// Converts the string to upper case, following Unicode case mapping.
+    /// <p>
+    /// The mapping may change the string's length, as in "ß" to "SS".
+    /// </p>
+    fn make_uppercase(&mut self) -> Result<(), StringError> {
+        let value = self.string()?;
+        self.set_string(&value.to_uppercase())
+    }
+
+    /// Converts the string to lower case, following Unicode case mapping.
+    fn make_lowercase(&mut self) -> Result<(), StringError> {
+        let value = self.string()?;
+        self.set_string(&value.to_lowercase())
+    }
+}
+
+/// Checks that `start..end` is a valid range for a string of `length` characters or graphemes.
+fn check_range(start: usize, end: usize, length: usize) -> Result<(), StringError> {
+    if start > end {
+        return Err(StringError::IndexOutOfBounds(start, end));
+    }
+    if end > length {
+        return Err(StringError::IndexOutOfBounds(end, length));
+    }
+    Ok(())
+}
+
+/// Returns a variable-size string spec with the same encoding and code page as the given spec.
+fn variable_size_spec(spec: &StringSpec) -> Rc<DataSpec> {
+    let mut builder = StringSpecBuilder::new(*spec.encoding());
+    builder.set_storage(StringStorage::VariableSize);
+    if let Some(code_page) = spec.code_page() {
+        builder.set_code_page(*code_page);
+    }
+    builder.build()
+}
+
+/// Returns a new variable-size string variable with the same encoding and code page as the given spec.
+fn new_string_variable(spec: &StringSpec, value: &str) -> Result<Variable, StringError> {
+    let mut var = Variable::new(&variable_size_spec(spec));
+    var.string_mut().set_string(value)?;
+    Ok(var)
+}
+
+/// TranscodingPolicy defines how characters are handled that cannot be
+/// represented while converting between string encodings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TranscodingPolicy {
+    /// Fail with an error.
+    Error,
+    /// Substitute the given character, which must itself be representable.
+    Replace(char),
+}
+
+/// Errors that can occur when working with strings.
+#[derive(Debug, PartialEq)]
+pub enum StringError {
+    /// A provider error.
+    ProviderError(ProviderError),
+    /// An error indicating that a value does not fit into a fixed-size string.
+    /// Holds the fixed size and the length of the rejected value, both in code units.
+    FixedSizeViolation(usize, usize),
+    /// An error indicating that a value would exceed the string's fixed capacity.
+    /// Holds the fixed capacity and the length of the rejected value, both in code units.
+    FixedCapacityViolation(usize, usize),
+    /// An error indicating that an index is out of bounds.
+    /// Holds the index and the bound it exceeds.
+    IndexOutOfBounds(usize, usize),
+    /// An error indicating that a UTF-16 code unit sequence contains an unpaired surrogate.
+    /// Holds the index of the offending code unit.
+    LoneSurrogate(usize),
+    /// An error indicating that a UTF-32 code unit is not a Unicode scalar value.
+    /// Holds the index and the value of the offending code unit.
+    InvalidScalarValue(usize, u32),
+    /// An error indicating that a byte does not represent a character in the byte string's code page.
+    /// Holds the index and the value of the offending byte.
+    UnmappableByte(usize, u8),
+    /// An error indicating that a character cannot be represented in the byte string's code page.
+    /// Holds the character index and the offending character.
+    UnmappableCharacter(usize, char),
+    /// An error indicating that a list element is not a string.
+    /// Holds the index of the element.
+    NotAString(usize),
+    /// A list error.
+    ListError(ListError),
+}
+
+impl std::fmt::Display for StringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StringError::ProviderError(err) => write!(f, "Provider error: {}", err),
+            StringError::FixedSizeViolation(size, length) => write!(
+                f,
+                "A value of length {} does not fit into a fixed-size string of size {}",
+                length, size
+            ),
+            StringError::FixedCapacityViolation(capacity, length) => write!(
+                f,
+                "A value of length {} would exceed the fixed capacity of {}",
+                length, capacity
+            ),
+            StringError::IndexOutOfBounds(index, length) => write!(
+                f,
+                "Index {} is out of bounds for a string of length {}",
+                index, length
+            ),
+            StringError::LoneSurrogate(index) => {
+                write!(f, "Unpaired surrogate at code unit {}", index)
+            }
+            StringError::InvalidScalarValue(index, value) => write!(
+                f,
+                "Code unit {} has value {:#X}, which is not a Unicode scalar value",
+                index, value
+            ),
+            StringError::UnmappableByte(index, byte) => write!(
+                f,
+                "Byte {:#04X} at index {} does not represent a character in the code page",
+                byte, index
+            ),
+            StringError::UnmappableCharacter(index, c) => write!(
+                f,
+                "Character {:?} at index {} cannot be represented in the code page",
+                c, index
+            ),
+            StringError::NotAString(index) => {
+                write!(f, "The list element at index {} is not a string", index)
+            }
+            StringError::ListError(err) => write!(f, "List error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for StringError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StringError::ProviderError(err) => Some(err),
+            StringError::ListError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProviderError> for StringError {
+    fn from(error: ProviderError) -> Self {
+        StringError::ProviderError(error)
+    }
+}
+
+impl From<ListError> for StringError {
+    fn from(error: ListError) -> Self {
+        StringError::ListError(error)
+    }
+}
+
+impl From<StringError> for SetEqualToError {
+    fn from(error: StringError) -> Self {
+        SetEqualToError::StringError(error)
+    }
+}
@@ -1,11 +1,25 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::Range,
+    rc::Rc,
+};
+
 use crate::{
+    accessors::string::{StringError, TranscodingPolicy},
     adaptors::string_adaptors::byte_string_adaptor::ByteStringAdaptor,
     primitive_def::Accessor,
+    primitive_specs::string_spec::{ByteCodePage, StringSpec},
     set_equal_to::{SetEqualTo, SetEqualToError},
+    spec_compatibility::SpecCompatibility,
 };
 
 /// ByteString provides access to <i>byte strings</i>, which are strings of 8-bit
 /// characters.
+/// <p>
+/// The bytes are interpreted through the spec's code page when converting to and
+/// from Rust strings. A byte string without a code page is interpreted as ASCII.
+/// </p>
 pub struct ByteString {
     // The adaptor for the byte string.
     adaptor: Box<dyn ByteStringAdaptor>,
@@ -16,12 +30,202 @@ impl ByteString {
     pub fn new(adaptor: Box<dyn ByteStringAdaptor>) -> Self {
         Self { adaptor }
     }
+
+    /// Returns the string's specification.
+    pub fn spec(&self) -> &Rc<StringSpec> {
+        self.adaptor.spec()
+    }
+
+    /// Returns the code page used to interpret the string's bytes.
+    pub fn code_page(&self) -> ByteCodePage {
+        self.spec().code_page().unwrap_or(ByteCodePage::Ascii)
+    }
+
+    /// Returns the string's bytes.
+    pub fn bytes(&self) -> Result<Vec<u8>, StringError> {
+        self.adaptor.bytes()
+    }
+
+    /// Returns the byte at the specified index.
+    pub fn byte(&self, index: usize) -> Result<u8, StringError> {
+        self.adaptor.byte(index)
+    }
+
+    /// Sets the byte at the specified index.
+    pub fn set_byte(&mut self, index: usize, value: u8) -> Result<(), StringError> {
+        self.adaptor.set_byte(index, value)
+    }
+
+    /// Returns the bytes in the specified range.
+    pub fn slice(&self, range: Range<usize>) -> Result<Vec<u8>, StringError> {
+        self.adaptor.slice(range.start, range.end)
+    }
+
+    /// Sets the string's bytes.
+    pub fn set_bytes(&mut self, bytes: &[u8]) -> Result<(), StringError> {
+        self.adaptor.set_bytes(bytes)
+    }
+
+    /// Appends bytes to the end of the string.
+    pub fn append_bytes(&mut self, bytes: &[u8]) -> Result<(), StringError> {
+        self.adaptor.append_bytes(bytes)
+    }
+
+    /// Returns the value of the string, decoded with the string's code page.
+    /// Fails if a byte does not represent a character in the code page.
+    pub fn string(&self) -> Result<String, StringError> {
+        self.string_with_policy(TranscodingPolicy::Error)
+    }
+
+    /// Returns the value of the string, decoded with the string's code page.
+    /// Bytes that do not represent a character are handled according to the policy.
+    pub fn string_with_policy(&self, policy: TranscodingPolicy) -> Result<String, StringError> {
+        let code_page = self.code_page();
+        self.bytes()?
+            .into_iter()
+            .enumerate()
+            .map(|(index, byte)| match (code_page.decode(byte), policy) {
+                (Some(c), _) => Ok(c),
+                (None, TranscodingPolicy::Replace(replacement)) => Ok(replacement),
+                (None, TranscodingPolicy::Error) => Err(StringError::UnmappableByte(index, byte)),
+            })
+            .collect()
+    }
+
+    /// Sets the value of the string, encoded with the string's code page.
+    /// Fails if a character cannot be represented in the code page.
+    pub fn set_string(&mut self, value: &str) -> Result<(), StringError> {
+        self.set_string_with_policy(value, TranscodingPolicy::Error)
+    }
+
+    /// Sets the value of the string, encoded with the string's code page.
+    /// Characters that cannot be represented are handled according to the policy.
+    pub fn set_string_with_policy(
+        &mut self,
+        value: &str,
+        policy: TranscodingPolicy,
+    ) -> Result<(), StringError> {
+        let bytes = self.encode(value, policy)?;
+        self.set_bytes(&bytes)
+    }
+
+    /// Appends a value to the end of the string, encoded with the string's code page.
+    /// Fails if a character cannot be represented in the code page.
+    pub fn append(&mut self, value: &str) -> Result<(), StringError> {
+        let bytes = self.encode(value, TranscodingPolicy::Error)?;
+        self.append_bytes(&bytes)
+    }
+
+    /// Returns the length of the string in bytes.
+    pub fn len(&self) -> usize {
+        self.adaptor.len()
+    }
+
+    /// Returns whether the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.adaptor.is_empty()
+    }
+
+    /// Clears the string.
+    pub fn clear(&mut self) -> Result<(), StringError> {
+        self.adaptor.clear()
+    }
+
+    fn encode(&self, value: &str, policy: TranscodingPolicy) -> Result<Vec<u8>, StringError> {
+        let code_page = self.code_page();
+        value
+            .chars()
+            .enumerate()
+            .map(|(index, c)| match (code_page.encode(c), policy) {
+                (Some(byte), _) => Ok(byte),
+                (None, TranscodingPolicy::Replace(replacement)) => code_page
+                    .encode(replacement)
+                    .ok_or(StringError::UnmappableCharacter(index, replacement)),
+                (None, TranscodingPolicy::Error) => Err(StringError::UnmappableCharacter(index, c)),
+            })
+            .collect()
+    }
 }
 
 impl SetEqualTo for ByteString {
-    fn set_equal_to(&mut self, _other: &Self) -> Result<(), SetEqualToError> {
-        todo!("Implement set_equal_to for ByteString");
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.spec().check_compatible_with(other.spec())?;
+        let bytes = other.bytes()?;
+        self.set_bytes(&bytes)?;
+        Ok(())
+    }
+}
+
+impl crate::accessors::string::String for ByteString {
+    fn spec(&self) -> &Rc<StringSpec> {
+        self.spec()
+    }
+
+    fn string(&self) -> Result<String, StringError> {
+        self.string()
+    }
+
+    fn set_string(&mut self, value: &str) -> Result<(), StringError> {
+        self.set_string(value)
+    }
+
+    fn string_with_policy(&self, policy: TranscodingPolicy) -> Result<String, StringError> {
+        self.string_with_policy(policy)
+    }
+
+    fn set_string_with_policy(
+        &mut self,
+        value: &str,
+        policy: TranscodingPolicy,
+    ) -> Result<(), StringError> {
+        self.set_string_with_policy(value, policy)
     }
 }
 
 impl Accessor for ByteString {}
+
+impl PartialEq for ByteString {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.bytes(), other.bytes()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ByteString {}
+
+impl PartialOrd for ByteString {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByteString {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.bytes()
+            .unwrap_or_default()
+            .cmp(&other.bytes().unwrap_or_default())
+    }
+}
+
+impl Hash for ByteString {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.bytes().unwrap_or_default().hash(state);
+    }
+}
+
+impl Display for ByteString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = self
+            .string_with_policy(TranscodingPolicy::Replace(char::REPLACEMENT_CHARACTER))
+            .unwrap_or_default();
+        write!(f, "{}", value)
+    }
+}
+
+impl Debug for ByteString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.to_string())
+    }
+}
@@ -1,10 +1,23 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    rc::Rc,
+};
+
 use crate::{
+    accessors::string::StringError,
     adaptors::string_adaptors::utf16_string_adaptor::Utf16StringAdaptor,
     primitive_def::Accessor,
+    primitive_specs::string_spec::StringSpec,
     set_equal_to::{SetEqualTo, SetEqualToError},
+    spec_compatibility::SpecCompatibility,
 };
 
 /// Utf16String provides access to Unicode UTF-16 encoded strings.
+/// <p>
+/// Lengths and indices are measured in 16-bit code units.
+/// Strings are ordered by their Unicode scalar values.
+/// </p>
 pub struct Utf16String {
     // The adaptor for the UTF-16 string.
     adaptor: Box<dyn Utf16StringAdaptor>,
@@ -15,12 +28,153 @@ impl Utf16String {
     pub fn new(adaptor: Box<dyn Utf16StringAdaptor>) -> Self {
         Self { adaptor }
     }
+
+    /// Returns the string's specification.
+    pub fn spec(&self) -> &Rc<StringSpec> {
+        self.adaptor.spec()
+    }
+
+    /// Returns the string's code units.
+    pub fn code_units(&self) -> Result<Vec<u16>, StringError> {
+        self.adaptor.code_units()
+    }
+
+    /// Returns the code unit at the specified index.
+    pub fn code_unit(&self, index: usize) -> Result<u16, StringError> {
+        self.adaptor.code_unit(index)
+    }
+
+    /// Sets the string's code units.
+    pub fn set_code_units(&mut self, units: &[u16]) -> Result<(), StringError> {
+        self.adaptor.set_code_units(units)
+    }
+
+    /// Appends code units to the end of the string.
+    pub fn append_code_units(&mut self, units: &[u16]) -> Result<(), StringError> {
+        self.adaptor.append_code_units(units)
+    }
+
+    /// Returns the value of the string.
+    pub fn string(&self) -> Result<String, StringError> {
+        Ok(self.chars()?.collect())
+    }
+
+    /// Sets the value of the string.
+    pub fn set_string(&mut self, value: &str) -> Result<(), StringError> {
+        self.set_code_units(&value.encode_utf16().collect::<Vec<u16>>())
+    }
+
+    /// Appends a value to the end of the string.
+    pub fn append(&mut self, value: &str) -> Result<(), StringError> {
+        self.append_code_units(&value.encode_utf16().collect::<Vec<u16>>())
+    }
+
+    /// Returns an iterator over the string's characters.
+    pub fn chars(&self) -> Result<Utf16Chars, StringError> {
+        Ok(Utf16Chars {
+            inner: char::decode_utf16(self.code_units()?),
+        })
+    }
+
+    /// Returns the length of the string in code units.
+    pub fn len(&self) -> usize {
+        self.adaptor.len()
+    }
+
+    /// Returns whether the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.adaptor.is_empty()
+    }
+
+    /// Clears the string.
+    pub fn clear(&mut self) -> Result<(), StringError> {
+        self.adaptor.clear()
+    }
+}
+
+/// An iterator over the characters of a UTF-16 string.
+/// <p>
+/// Surrogate pairs are combined into a single character.
+/// </p>
+pub struct Utf16Chars {
+    inner: std::char::DecodeUtf16<std::vec::IntoIter<u16>>,
+}
+
+impl Iterator for Utf16Chars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Adaptors only hold well-formed UTF-16, so decoding cannot fail.
+        self.inner
+            .next()
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
 }
 
 impl SetEqualTo for Utf16String {
-    fn set_equal_to(&mut self, _other: &Self) -> Result<(), SetEqualToError> {
-        todo!("Implement set_equal_to for Utf16String");
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.spec().check_compatible_with(other.spec())?;
+        let units = other.code_units()?;
+        self.set_code_units(&units)?;
+        Ok(())
+    }
+}
+
+impl crate::accessors::string::String for Utf16String {
+    fn spec(&self) -> &Rc<StringSpec> {
+        self.spec()
+    }
+
+    fn string(&self) -> Result<String, StringError> {
+        self.string()
+    }
+
+    fn set_string(&mut self, value: &str) -> Result<(), StringError> {
+        self.set_string(value)
     }
 }
 
 impl Accessor for Utf16String {}
+
+impl PartialEq for Utf16String {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.code_units(), other.code_units()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Utf16String {}
+
+impl PartialOrd for Utf16String {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Utf16String {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.string()
+            .unwrap_or_default()
+            .cmp(&other.string().unwrap_or_default())
+    }
+}
+
+impl Hash for Utf16String {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.code_units().unwrap_or_default().hash(state);
+    }
+}
+
+impl Display for Utf16String {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.string().unwrap_or_default())
+    }
+}
+
+impl Debug for Utf16String {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.string().unwrap_or_default())
+    }
+}
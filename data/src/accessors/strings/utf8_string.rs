@@ -1,10 +1,24 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    rc::Rc,
+};
+
 use crate::{
+    accessors::string::StringError,
     adaptors::string_adaptors::utf8_string_adaptor::Utf8StringAdaptor,
+    data_spec_builders::string_spec_builder::StringSpecBuilder,
     primitive_def::Accessor,
+    primitive_specs::string_spec::{StringEncoding, StringSpec, StringStorage},
     set_equal_to::{SetEqualTo, SetEqualToError},
+    spec_compatibility::SpecCompatibility,
+    variable::Variable,
 };
 
 /// Utf8String provides access to Unicode UTF-8 encoded strings.
+/// <p>
+/// Lengths are measured in code units, which for UTF-8 are bytes.
+/// </p>
 pub struct Utf8String {
     // The adaptor for the UTF-8 string.
     adaptor: Box<dyn Utf8StringAdaptor>,
@@ -15,12 +29,129 @@ impl Utf8String {
     pub fn new(adaptor: Box<dyn Utf8StringAdaptor>) -> Self {
         Self { adaptor }
     }
+
+    /// Returns the string's specification.
+    pub fn spec(&self) -> &Rc<StringSpec> {
+        self.adaptor.spec()
+    }
+
+    /// Returns the value of the string.
+    pub fn string(&self) -> Result<String, StringError> {
+        self.adaptor.string()
+    }
+
+    /// Sets the value of the string.
+    pub fn set_string(&mut self, value: &str) -> Result<(), StringError> {
+        self.adaptor.set_string(value)
+    }
+
+    /// Appends a value to the end of the string.
+    pub fn append(&mut self, value: &str) -> Result<(), StringError> {
+        self.adaptor.append(value)
+    }
+
+    /// Returns the length of the string in bytes.
+    pub fn len(&self) -> usize {
+        self.adaptor.len()
+    }
+
+    /// Returns whether the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.adaptor.is_empty()
+    }
+
+    /// Clears the string.
+    pub fn clear(&mut self) -> Result<(), StringError> {
+        self.adaptor.clear()
+    }
 }
 
 impl SetEqualTo for Utf8String {
-    fn set_equal_to(&mut self, _other: &Self) -> Result<(), SetEqualToError> {
-        todo!("Implement set_equal_to for Utf8String");
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.spec().check_compatible_with(other.spec())?;
+        let value = other.string()?;
+        self.set_string(&value)?;
+        Ok(())
+    }
+}
+
+impl crate::accessors::string::String for Utf8String {
+    fn spec(&self) -> &Rc<StringSpec> {
+        self.spec()
+    }
+
+    fn string(&self) -> Result<String, StringError> {
+        self.string()
+    }
+
+    fn set_string(&mut self, value: &str) -> Result<(), StringError> {
+        self.set_string(value)
     }
 }
 
 impl Accessor for Utf8String {}
+
+impl PartialEq for Utf8String {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.string(), other.string()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Utf8String {}
+
+impl PartialOrd for Utf8String {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Utf8String {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.string()
+            .unwrap_or_default()
+            .cmp(&other.string().unwrap_or_default())
+    }
+}
+
+impl Hash for Utf8String {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.string().unwrap_or_default().hash(state);
+    }
+}
+
+impl Display for Utf8String {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.string().unwrap_or_default())
+    }
+}
+
+impl Debug for Utf8String {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.string().unwrap_or_default())
+    }
+}
+
+impl TryFrom<&str> for Variable {
+    type Error = StringError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let spec = StringSpecBuilder::new(StringEncoding::Utf8)
+            .set_storage(StringStorage::VariableSize)
+            .build();
+        let mut var = Variable::new(&spec);
+        let string = var.utf8_string_mut();
+        string.set_string(value)?;
+        Ok(var)
+    }
+}
+
+impl TryFrom<Variable> for String {
+    type Error = StringError;
+
+    fn try_from(value: Variable) -> Result<Self, Self::Error> {
+        value.utf8_string().string()
+    }
+}
@@ -1,13 +1,27 @@
+/// The `BusinessCalendar` for business days with weekends, holidays and rolling conventions.
+pub mod business_calendar;
+/// The `Clock` trait and the system and fixed clocks for setting temporal values to now.
+pub mod clock;
 /// The `Date` accessor.
 pub mod date;
 /// The `DateTime` accessor.
 pub mod date_time;
 /// The `DayToSecondDuration` accessor.
 pub mod day_to_second_duration;
+/// The `FormatPattern` for formatting and parsing temporal values with strftime-like patterns.
+pub mod format_pattern;
+/// The `Locale` for the names of months and days of the week in formatted and parsed dates.
+pub mod locale;
+/// The `RecurrenceRule` for expanding RFC 5545 recurrence rules into sequences of dates and date-times.
+pub mod recurrence;
 /// The `Time` accessor.
 pub mod time;
 /// The `TimeBuilder` for setting built times via a `Time` accessor.
 pub mod time_builder;
+/// The `TimeZone` type for named time zones read from TZif files.
+pub mod time_zone;
+/// The `UnixTimeUnit` for converting temporal values to and from Unix timestamps.
+pub mod unix_time;
 /// The `YearToMonthDuration` accessor.
 pub mod year_to_month_duration;
 /// The `ZonedDateTime` accessor.
@@ -20,12 +34,34 @@ pub(crate) mod set_from_objects;
 /// Module for setting temporal accessors from strings.
 pub(crate) mod set_from_strings;
 
+/// The number of nanoseconds in a microsecond.
+pub(crate) const NANOSECONDS_PER_MICROSECOND: i128 = 1_000;
+/// The number of nanoseconds in a millisecond.
+pub(crate) const NANOSECONDS_PER_MILLISECOND: i128 = 1_000_000;
+/// The number of nanoseconds in a second.
+pub(crate) const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+/// The number of nanoseconds in a minute.
+pub(crate) const NANOSECONDS_PER_MINUTE: i128 = 60 * NANOSECONDS_PER_SECOND;
+/// The number of nanoseconds in an hour.
+pub(crate) const NANOSECONDS_PER_HOUR: i128 = 60 * NANOSECONDS_PER_MINUTE;
+/// The number of nanoseconds in a day.
+pub(crate) const NANOSECONDS_PER_DAY: i128 = 24 * NANOSECONDS_PER_HOUR;
+/// The number of days from January 1, 0001 to January 1, 1970.
+pub(crate) const DAYS_TO_UNIX_EPOCH: i128 = 719_162;
+
 #[cfg(test)]
 mod tests {
+    mod business_calendar_test;
+    mod clock_test;
     mod date_test;
     mod date_time_test;
     mod day_to_second_duration_test;
+    mod format_pattern_test;
+    mod locale_test;
+    mod recurrence_test;
     mod time_test;
+    mod time_zone_test;
+    mod unix_time_test;
     mod year_to_month_duration_test;
     mod zoned_date_time_test;
     mod zoned_time_test;
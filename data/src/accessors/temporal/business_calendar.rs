@@ -0,0 +1,418 @@
+use std::{
+    fmt::{Debug, Display},
+    path::Path,
+    rc::Rc,
+};
+
+use crate::{
+    accessors::{
+        collections::set::{Set, SetError},
+        temporal::date::{Date, DateError, DayOfWeek, day_of_week_from_signed_days},
+    },
+    adaptors::temporal_adaptors::date_adaptor::date_from_signed_days,
+    data_spec::DataSpec,
+    data_spec_builders::{date_spec_builder::DateSpecBuilder, set_spec_builder::SetSpecBuilder},
+    primitive_specs::{date_spec::DateRange, set_spec::SetElementOrdering},
+    variable::Variable,
+};
+
+/// RollConvention defines an enumeration of the ways a date that is not a business day is moved
+/// to one that is.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RollConvention {
+    /// The date is moved to the next business day.
+    Following,
+    /// The date is moved to the next business day, unless that lies in the next month, in which
+    /// case it is moved to the previous business day.
+    ModifiedFollowing,
+    /// The date is moved to the previous business day.
+    Preceding,
+}
+
+impl Display for RollConvention {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Following => "Following",
+                Self::ModifiedFollowing => "Modified Following",
+                Self::Preceding => "Preceding",
+            }
+        )
+    }
+}
+
+/// A named business-day calendar, in which the days that are neither weekend days nor holidays
+/// are business days.
+/// <p>
+/// The holidays are an ordered `Set` of `Date`s that can be changed through
+/// [`BusinessCalendar::holidays_mut`].
+/// </p>
+/// <p>
+/// Calendars can be read from text in which each line is empty, a comment starting with `#`, a
+/// `name:` or `weekend:` entry, or a holiday in the form `YYYY-MM-DD` followed by an optional
+/// description. The weekend is given by the English names of its days, in full or abbreviated to
+/// three letters, separated by commas, and is Saturday and Sunday unless given.
+/// </p>
+///
+/// ```text
+/// # Holidays of the exchange
+/// name: XETR
+/// weekend: Sat, Sun
+/// 2025-01-01 New Year's Day
+/// 2025-12-25 Christmas Day
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use data::accessors::temporal::business_calendar::{BusinessCalendar, RollConvention};
+/// use data::data_spec_builders::date_spec_builder::DateSpecBuilder;
+/// use data::variable::Variable;
+///
+/// let calendar = BusinessCalendar::from_text("example", "2025-12-25\n2025-12-26").unwrap();
+/// let mut var = Variable::new(&DateSpecBuilder::new().build());
+/// let date = var.date_mut();
+/// date.set_date(2025, 12, 24).unwrap();
+///
+/// calendar.add_business_days(date, 1).unwrap();
+/// assert_eq!(date.to_string(), "2025-12-29");
+///
+/// date.set_date(2025, 5, 31).unwrap();
+/// calendar.roll(date, RollConvention::ModifiedFollowing).unwrap();
+/// assert_eq!(date.to_string(), "2025-05-30");
+/// ```
+pub struct BusinessCalendar {
+    name: String,
+    weekend: Vec<DayOfWeek>,
+    holidays: Variable,
+    // an extended date can be looked up among holidays of any range
+    probe_spec: Rc<DataSpec>,
+}
+
+impl BusinessCalendar {
+    /// Creates a calendar with the given name and weekend days and without holidays.
+    pub fn new(name: &str, weekend: &[DayOfWeek]) -> BusinessCalendar {
+        let holidays_spec = SetSpecBuilder::new()
+            .set_value_spec(DateSpecBuilder::new().build())
+            .set_storage(SetElementOrdering::Ordered)
+            .build();
+        BusinessCalendar {
+            name: name.to_string(),
+            weekend: distinct(weekend),
+            holidays: Variable::new(&holidays_spec),
+            probe_spec: DateSpecBuilder::new()
+                .set_range(DateRange::Extended)
+                .build(),
+        }
+    }
+
+    /// Loads a calendar from a text file, named after the file's stem unless it has a `name:`
+    /// entry.
+    pub fn load(path: impl AsRef<Path>) -> Result<BusinessCalendar, BusinessCalendarError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|error| {
+            BusinessCalendarError::Io(format!("Cannot read '{}': {}", path.display(), error))
+        })?;
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Self::from_text(&name, &text)
+    }
+
+    /// Creates a calendar from the given text, named with the given name unless it has a `name:`
+    /// entry.
+    pub fn from_text(name: &str, text: &str) -> Result<BusinessCalendar, BusinessCalendarError> {
+        let mut calendar = Self::new(name, &[DayOfWeek::Saturday, DayOfWeek::Sunday]);
+        for (index, line) in text.lines().enumerate() {
+            let invalid = |msg: String| {
+                BusinessCalendarError::InvalidData(format!("line {}: {}", index + 1, msg))
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            // holidays start with their year, entries with their key
+            let is_holiday = line.starts_with(|c: char| c.is_ascii_digit() || c == '+' || c == '-');
+            if let (false, Some((key, value))) = (is_holiday, line.split_once(':')) {
+                let value = value.trim();
+                match key.trim().to_ascii_lowercase().as_str() {
+                    "name" => calendar.name = value.to_string(),
+                    "weekend" => {
+                        let mut weekend = Vec::new();
+                        for day in value
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|day| !day.is_empty())
+                        {
+                            weekend.push(
+                                parse_day_of_week(day)
+                                    .ok_or_else(|| invalid(format!("'{}' is not a day", day)))?,
+                            );
+                        }
+                        calendar.weekend = distinct(&weekend);
+                    }
+                    key => return Err(invalid(format!("unknown entry '{}'", key))),
+                }
+                continue;
+            }
+            let date = line.split_whitespace().next().unwrap_or_default();
+            let mut holiday = calendar.new_holiday();
+            holiday
+                .date_mut()
+                .set_from_string(date)
+                .map_err(|error| invalid(format!("'{}' is not a date: {}", date, error)))?;
+            calendar.holidays.set_mut().insert(holiday)?;
+        }
+        Ok(calendar)
+    }
+
+    /// Returns the calendar's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the calendar's weekend days.
+    pub fn weekend(&self) -> &[DayOfWeek] {
+        &self.weekend
+    }
+
+    /// Returns true if the day of the week is a weekend day; false otherwise.
+    pub fn is_weekend(&self, day_of_week: DayOfWeek) -> bool {
+        self.weekend.contains(&day_of_week)
+    }
+
+    /// Returns the calendar's holidays.
+    pub fn holidays(&self) -> &Set {
+        self.holidays.set()
+    }
+
+    /// Returns the calendar's holidays for changing them.
+    pub fn holidays_mut(&mut self) -> &mut Set {
+        self.holidays.set_mut()
+    }
+
+    /// Adds the date to the calendar's holidays.
+    /// Returns `true` if the date was added, `false` if it was already a holiday.
+    pub fn add_holiday(&mut self, date: &Date) -> Result<bool, BusinessCalendarError> {
+        let mut holiday = self.new_holiday();
+        holiday.date_mut().set_signed_days(date.signed_days()?)?;
+        Ok(self.holidays.set_mut().insert(holiday)?)
+    }
+
+    /// Returns true if the date is neither a weekend day nor a holiday; false otherwise.
+    pub fn is_business_day(&self, date: &Date) -> Result<bool, BusinessCalendarError> {
+        self.is_business_day_at(date.signed_days()?)
+    }
+
+    /// Moves the date to a business day by the given convention if it is not one.
+    /// The date is left unchanged if no business day lies within the range of its spec.
+    pub fn roll(
+        &self,
+        date: &mut Date,
+        convention: RollConvention,
+    ) -> Result<(), BusinessCalendarError> {
+        let days = date.signed_days()?;
+        let rolled = match convention {
+            RollConvention::Following => self.next_business_day(date, days, 1)?,
+            RollConvention::Preceding => self.next_business_day(date, days, -1)?,
+            RollConvention::ModifiedFollowing => {
+                let following = self.next_business_day(date, days, 1);
+                match following {
+                    Ok(following) if same_month(following, days) => following,
+                    _ => self.next_business_day(date, days, -1)?,
+                }
+            }
+        };
+        Ok(date.set_signed_days(rolled)?)
+    }
+
+    /// Adds the number of business days to the date, which moves back for negative numbers.
+    /// Each step moves to the next business day, so that adding one business day to a Friday
+    /// before a weekend results in the following Monday, whether or not the date is a business
+    /// day itself.
+    /// The date is left unchanged if the result is out of bounds.
+    pub fn add_business_days(
+        &self,
+        date: &mut Date,
+        business_days: i64,
+    ) -> Result<(), BusinessCalendarError> {
+        let mut days = date.signed_days()?;
+        let step = business_days.signum();
+        for _ in 0..business_days.unsigned_abs() {
+            days = self.next_business_day(date, days + step, step)?;
+        }
+        Ok(date.set_signed_days(days)?)
+    }
+
+    /// Returns the number of business days from the start date, inclusive, to the end date,
+    /// exclusive, which is negative if the end date is before the start date.
+    pub fn business_days_between(
+        &self,
+        start: &Date,
+        end: &Date,
+    ) -> Result<i64, BusinessCalendarError> {
+        let (start, end) = (start.signed_days()?, end.signed_days()?);
+        if end < start {
+            return Ok(-self.business_days_in(end, start)?);
+        }
+        self.business_days_in(start, end)
+    }
+
+    /// Returns the number of business days from start, inclusive, to end, exclusive.
+    fn business_days_in(&self, start: i64, end: i64) -> Result<i64, BusinessCalendarError> {
+        let days = end - start;
+        let weekdays_per_week = (7 - self.weekend.len()) as i64;
+        let mut business_days = days / 7 * weekdays_per_week;
+        for day in start + days / 7 * 7..end {
+            if !self.is_weekend(day_of_week_from_signed_days(day)) {
+                business_days += 1;
+            }
+        }
+        for holiday in self.holidays.set().iter() {
+            let holiday = holiday?.date().signed_days()?;
+            if (start..end).contains(&holiday)
+                && !self.is_weekend(day_of_week_from_signed_days(holiday))
+            {
+                business_days -= 1;
+            }
+        }
+        Ok(business_days)
+    }
+
+    /// Returns the days of the first business day from the given days on, stepping forward or
+    /// back by the step, within the range of the date's spec.
+    fn next_business_day(
+        &self,
+        date: &Date,
+        mut days: i64,
+        step: i64,
+    ) -> Result<i64, BusinessCalendarError> {
+        if self.weekend.len() == 7 {
+            return Err(BusinessCalendarError::NoBusinessDays(self.name.clone()));
+        }
+        loop {
+            // fails once the days leave the range of the date's spec
+            date.check_year(date_from_signed_days(days).0)?;
+            if self.is_business_day_at(days)? {
+                return Ok(days);
+            }
+            days += step;
+        }
+    }
+
+    /// Returns true if the given days are a business day; false otherwise.
+    fn is_business_day_at(&self, days: i64) -> Result<bool, BusinessCalendarError> {
+        if self.is_weekend(day_of_week_from_signed_days(days)) {
+            return Ok(false);
+        }
+        let mut probe = Variable::new(&self.probe_spec);
+        probe.date_mut().set_signed_days(days)?;
+        Ok(!self.holidays.set().contains(&probe)?)
+    }
+
+    /// Returns a new date variable with the spec of the calendar's holidays.
+    fn new_holiday(&self) -> Variable {
+        let value_spec = self.holidays.set().spec().value_spec().clone();
+        Variable::new(&value_spec.unwrap())
+    }
+}
+
+impl Display for BusinessCalendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Debug for BusinessCalendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BusinessCalendar {{ name: {}, weekend: {:?}, holidays: {} }}",
+            self.name,
+            self.weekend,
+            self.holidays.set()
+        )
+    }
+}
+
+/// Returns the days of the week without repetitions.
+fn distinct(days_of_week: &[DayOfWeek]) -> Vec<DayOfWeek> {
+    let mut distinct_days = Vec::new();
+    for day in days_of_week {
+        if !distinct_days.contains(day) {
+            distinct_days.push(*day);
+        }
+    }
+    distinct_days
+}
+
+/// Returns true if the given signed days lie in the same month; false otherwise.
+fn same_month(days: i64, other_days: i64) -> bool {
+    let (year, month, _) = date_from_signed_days(days);
+    let (other_year, other_month, _) = date_from_signed_days(other_days);
+    (year, month) == (other_year, other_month)
+}
+
+/// Parses the English name of a day of the week, in full or abbreviated to three letters.
+fn parse_day_of_week(name: &str) -> Option<DayOfWeek> {
+    (0..7).filter_map(DayOfWeek::from_index).find(|day| {
+        let full_name = day.to_string();
+        name.eq_ignore_ascii_case(&full_name) || name.eq_ignore_ascii_case(&full_name[..3])
+    })
+}
+
+/// Errors that can occur when working with business calendars.
+#[derive(Debug, PartialEq)]
+pub enum BusinessCalendarError {
+    /// Indicates that a calendar file could not be read.
+    Io(String),
+    /// Indicates that the text of a calendar is not valid.
+    InvalidData(String),
+    /// Indicates that every day of the week of the named calendar is a weekend day.
+    NoBusinessDays(String),
+    /// An error of a date, such as a result out of the range of its spec.
+    DateError(DateError),
+    /// An error of the holiday set.
+    SetError(SetError),
+}
+
+impl Display for BusinessCalendarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BusinessCalendarError::Io(msg) => write!(f, "I/O error: {}", msg),
+            BusinessCalendarError::InvalidData(msg) => {
+                write!(f, "Invalid business calendar: {}", msg)
+            }
+            BusinessCalendarError::NoBusinessDays(name) => {
+                write!(f, "Business calendar {} has no business days.", name)
+            }
+            BusinessCalendarError::DateError(err) => write!(f, "Date error: {}", err),
+            BusinessCalendarError::SetError(err) => write!(f, "Set error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for BusinessCalendarError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BusinessCalendarError::DateError(err) => Some(err),
+            BusinessCalendarError::SetError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<DateError> for BusinessCalendarError {
+    fn from(error: DateError) -> Self {
+        BusinessCalendarError::DateError(error)
+    }
+}
+
+impl From<SetError> for BusinessCalendarError {
+    fn from(error: SetError) -> Self {
+        BusinessCalendarError::SetError(error)
+    }
+}
@@ -0,0 +1,180 @@
+use std::{cell::Cell, rc::Rc, time::SystemTime};
+
+use crate::{
+    accessors::temporal::{
+        DAYS_TO_UNIX_EPOCH, NANOSECONDS_PER_DAY, NANOSECONDS_PER_SECOND, time_zone::TimeZone,
+        unix_time::unix_nanoseconds_from_system_time,
+    },
+    primitive_specs::time_spec::TimeResolution,
+};
+
+/// A source of the current instant, from which temporal values are set to now.
+/// <p>
+/// A clock tells the current instant as nanoseconds since the Unix epoch and the offset from UTC
+/// of its local time, which the local temporal values, such as `Date`, `Time` and `DateTime`,
+/// are set to. The [`SystemClock`] reads the operating system's clock, and the [`FixedClock`]
+/// holds an instant that only changes when it is set or advanced, for tests.
+/// </p>
+pub trait Clock {
+    /// Returns the current instant as the number of nanoseconds since the Unix epoch,
+    /// 1970-01-01T00:00:00Z, which is negative for earlier instants.
+    fn unix_nanoseconds(&self) -> i128;
+
+    /// Returns the offset from UTC in seconds of the clock's local time at the given instant, in
+    /// whole seconds since the Unix epoch. The default implementation returns 0, so that the
+    /// local time is UTC.
+    fn local_offset_seconds(&self, _unix_seconds: i64) -> i32 {
+        0
+    }
+}
+
+/// A clock that reads the current instant from the operating system.
+/// <p>
+/// The system clock's local time is UTC unless it is created with a time zone.
+/// </p>
+///
+/// # Examples
+///
+/// ```rust
+/// use data::accessors::temporal::clock::SystemClock;
+/// use data::data_spec_builders::time_spec_builder::TimeSpecBuilder;
+/// use data::primitive_specs::time_spec::{TimeResolution, TimeType};
+/// use data::variable::Variable;
+///
+/// let spec = TimeSpecBuilder::new()
+///     .set_time_type(TimeType::Local)
+///     .set_resolution(TimeResolution::Second)
+///     .build();
+/// let mut var = Variable::new(&spec);
+///
+/// // the fraction of the second is truncated to the time's resolution
+/// var.time_mut().set_to_now(&SystemClock::new()).unwrap();
+/// assert_eq!(var.time().millisecond().unwrap(), 0);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SystemClock {
+    time_zone: Option<Rc<TimeZone>>,
+}
+
+impl SystemClock {
+    /// Creates a system clock whose local time is UTC.
+    pub fn new() -> Self {
+        Self { time_zone: None }
+    }
+
+    /// Creates a system clock whose local time is the given time zone's.
+    pub fn with_time_zone(time_zone: Rc<TimeZone>) -> Self {
+        Self {
+            time_zone: Some(time_zone),
+        }
+    }
+
+    /// Returns the clock's time zone, if it has one.
+    pub fn time_zone(&self) -> Option<&Rc<TimeZone>> {
+        self.time_zone.as_ref()
+    }
+}
+
+impl Clock for SystemClock {
+    fn unix_nanoseconds(&self) -> i128 {
+        unix_nanoseconds_from_system_time(&SystemTime::now())
+    }
+
+    fn local_offset_seconds(&self, unix_seconds: i64) -> i32 {
+        match &self.time_zone {
+            Some(time_zone) => time_zone.local_time_type(unix_seconds).offset_seconds(),
+            None => 0,
+        }
+    }
+}
+
+/// A clock that holds a fixed instant and local offset, which change only when they are set or
+/// the clock is advanced.
+///
+/// # Examples
+///
+/// ```rust
+/// use data::accessors::temporal::clock::FixedClock;
+/// use data::data_spec_builders::date_spec_builder::DateSpecBuilder;
+/// use data::variable::Variable;
+///
+/// // 2024-06-15T23:00:00Z
+/// let clock = FixedClock::new(1_718_492_400 * 1_000_000_000);
+/// let mut var = Variable::new(&DateSpecBuilder::new().build());
+///
+/// var.date_mut().set_to_now(&clock).unwrap();
+/// assert_eq!(var.to_string(), "2024-06-15");
+///
+/// // the local time is two hours ahead of UTC, where it is already the next day
+/// clock.set_local_offset_seconds(7_200);
+/// var.date_mut().set_to_now(&clock).unwrap();
+/// assert_eq!(var.to_string(), "2024-06-16");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct FixedClock {
+    unix_nanoseconds: Cell<i128>,
+    local_offset_seconds: Cell<i32>,
+}
+
+impl FixedClock {
+    /// Creates a fixed clock at the given number of nanoseconds since the Unix epoch, whose local
+    /// time is UTC.
+    pub fn new(unix_nanoseconds: i128) -> Self {
+        Self {
+            unix_nanoseconds: Cell::new(unix_nanoseconds),
+            local_offset_seconds: Cell::new(0),
+        }
+    }
+
+    /// Sets the clock to the given number of nanoseconds since the Unix epoch.
+    pub fn set_unix_nanoseconds(&self, unix_nanoseconds: i128) {
+        self.unix_nanoseconds.set(unix_nanoseconds);
+    }
+
+    /// Moves the clock forward by the given number of nanoseconds, or backward if negative.
+    pub fn advance(&self, nanoseconds: i128) {
+        self.unix_nanoseconds
+            .set(self.unix_nanoseconds.get() + nanoseconds);
+    }
+
+    /// Sets the offset from UTC in seconds of the clock's local time.
+    pub fn set_local_offset_seconds(&self, offset_seconds: i32) {
+        self.local_offset_seconds.set(offset_seconds);
+    }
+}
+
+impl Clock for FixedClock {
+    fn unix_nanoseconds(&self) -> i128 {
+        self.unix_nanoseconds.get()
+    }
+
+    fn local_offset_seconds(&self, _unix_seconds: i64) -> i32 {
+        self.local_offset_seconds.get()
+    }
+}
+
+/// Reads the clock once and returns the current instant, in nanoseconds from January 1, 0001
+/// 00:00:00Z and truncated to the given resolution, and the clock's local offset from UTC in
+/// seconds at that instant.
+pub(crate) fn now(clock: &dyn Clock, resolution: Option<&TimeResolution>) -> (i128, i32) {
+    let unix_nanoseconds = clock.unix_nanoseconds();
+    let unix_nanoseconds = match resolution {
+        Some(resolution) => {
+            unix_nanoseconds - unix_nanoseconds.rem_euclid(resolution.nanoseconds() as i128)
+        }
+        None => unix_nanoseconds,
+    };
+    let offset_seconds =
+        clock.local_offset_seconds(unix_nanoseconds.div_euclid(NANOSECONDS_PER_SECOND) as i64);
+    (
+        unix_nanoseconds + DAYS_TO_UNIX_EPOCH * NANOSECONDS_PER_DAY,
+        offset_seconds,
+    )
+}
+
+/// Reads the clock once and returns its current local date-time, in nanoseconds from January 1,
+/// 0001 00:00:00 and truncated to the given resolution.
+pub(crate) fn local_now(clock: &dyn Clock, resolution: Option<&TimeResolution>) -> i128 {
+    let (utc_nanoseconds, offset_seconds) = now(clock, resolution);
+    utc_nanoseconds + offset_seconds as i128 * NANOSECONDS_PER_SECOND
+}
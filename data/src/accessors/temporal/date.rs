@@ -2,18 +2,36 @@ use std::{
     fmt::{Debug, Display},
     hash::Hash,
     rc::Rc,
+    time::SystemTime,
 };
 
 use crate::{
-    adaptors::temporal_adaptors::date_adaptor::DateAdaptor,
+    accessors::temporal::{
+        NANOSECONDS_PER_DAY,
+        clock::{Clock, local_now},
+        day_to_second_duration::{DayToSecondDuration, DayToSecondDurationError},
+        format_pattern::{FormatError, FormatPattern, PatternFields},
+        locale::Locale,
+        unix_time::{
+            NANOSECONDS_TO_UNIX_EPOCH, UnixTimeUnit, system_time_from_unix_nanoseconds,
+            timestamp_from_unix_nanoseconds, unix_nanoseconds_from_system_time,
+            unix_nanoseconds_from_timestamp,
+        },
+        year_to_month_duration::{YearToMonthDuration, YearToMonthDurationError},
+    },
+    adaptors::temporal_adaptors::date_adaptor::{
+        DateAdaptor, date_from_days, date_from_signed_days, days_from_date, is_julian_leap_year,
+        is_proleptic_leap_year, julian_date_from_signed_days, signed_days_from_date,
+        signed_days_from_julian_date,
+    },
     primitive_def::Accessor,
-    primitive_specs::date_spec::DateSpec,
+    primitive_specs::date_spec::{DateRange, DateSpec},
     provider_error::ProviderError,
     set_equal_to::{SetEqualTo, SetEqualToError},
 };
 
 /// Enumeration of the days of the week.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum DayOfWeek {
     /// Sunday
     Sunday,
@@ -46,12 +64,58 @@ impl std::fmt::Display for DayOfWeek {
     }
 }
 
+impl DayOfWeek {
+    /// Returns the index of the day of the week, from 0 for Monday to 6 for Sunday.
+    pub fn index(&self) -> usize {
+        match self {
+            DayOfWeek::Monday => 0,
+            DayOfWeek::Tuesday => 1,
+            DayOfWeek::Wednesday => 2,
+            DayOfWeek::Thursday => 3,
+            DayOfWeek::Friday => 4,
+            DayOfWeek::Saturday => 5,
+            DayOfWeek::Sunday => 6,
+        }
+    }
+
+    /// Returns the day of the week with the given index, from 0 for Monday to 6 for Sunday, or
+    /// `None` if the index is greater than 6.
+    pub fn from_index(index: usize) -> Option<DayOfWeek> {
+        match index {
+            0 => Some(DayOfWeek::Monday),
+            1 => Some(DayOfWeek::Tuesday),
+            2 => Some(DayOfWeek::Wednesday),
+            3 => Some(DayOfWeek::Thursday),
+            4 => Some(DayOfWeek::Friday),
+            5 => Some(DayOfWeek::Saturday),
+            6 => Some(DayOfWeek::Sunday),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the day of the week in the given locale.
+    pub fn name<'a>(&self, locale: &'a Locale) -> &'a str {
+        locale.day_of_week_name(*self)
+    }
+
+    /// Returns the abbreviated name of the day of the week in the given locale.
+    pub fn abbreviated_name<'a>(&self, locale: &'a Locale) -> &'a str {
+        locale.abbreviated_day_of_week_name(*self)
+    }
+}
+
 /// Accessor for date values.
 /// <p>
 /// Date values represent a date on the Gregorian calendar, with day precision.
 /// Date values range from January 1, 0001 Anno Domini (Common Era) to December 31, 9999 Anno
 /// Domini (Common Era).
 /// </p>
+/// <p>
+/// Dates of the extended range of the date's spec extend the Gregorian calendar proleptically,
+/// with astronomically numbered years from -999999 to 999999. The `proleptic_date` and
+/// `signed_days` methods access the dates before January 1, 0001, which the methods with
+/// unsigned years and days reject.
+/// </p>
 pub struct Date {
     adaptor: Box<dyn DateAdaptor>,
 }
@@ -87,26 +151,151 @@ impl Date {
     ///
     /// ```
     pub fn set_date(&mut self, year: u32, month: u32, day: u32) -> Result<(), DateError> {
-        if (year < 1) || (year > 9999) {
-            return Err(DateError::YearOutOfBounds(year));
-        }
-        if (month < 1) || (month > 12) {
-            return Err(DateError::MonthOutOfBounds(month));
-        }
-        let is_leap = is_leap_year(year);
-        let days_in_month = days_in_month(is_leap, month);
-        if day < 1 || day > days_in_month {
-            return Err(DateError::DayOutOfBounds(
-                day,
-                month_name(month).to_string(),
-                days_in_month,
-            ));
-        }
-        self.adaptor.set_date(year, month, day)?;
-        Ok(())
+        self.set_year_month_day(year as i64, month, day)
+    }
+
+    /// Sets the date's value to the specified year, month, and day of the proleptic Gregorian
+    /// calendar, where year 0 is the year 1 B.C. (B.C.E.), year -1 is the year 2 B.C. (B.C.E.),
+    /// and so on. The year must lie in the range of the date's spec.
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::date_spec_builder::DateSpecBuilder;
+    /// use data::primitive_specs::date_spec::DateRange;
+    /// use data::variable::Variable;
+    ///
+    /// let mut var = Variable::new(&DateSpecBuilder::new().set_range(DateRange::Extended).build());
+    /// let date_mut = var.date_mut();
+    ///
+    /// // the Ides of March, 44 B.C., on the proleptic Gregorian calendar
+    /// date_mut.set_proleptic_date(-43, 3, 13).unwrap();
+    /// assert_eq!(date_mut.proleptic_date().unwrap(), (-43, 3, 13));
+    /// assert_eq!(date_mut.julian_date().unwrap(), (-43, 3, 15));
+    /// assert_eq!(date_mut.to_string(), "-000043-03-13");
+    /// assert!(date_mut.date().is_err());
+    /// ```
+    pub fn set_proleptic_date(&mut self, year: i32, month: u32, day: u32) -> Result<(), DateError> {
+        self.set_year_month_day(year as i64, month, day)
+    }
+
+    /// Returns the date as a tuple of (year, month, day) of the proleptic Gregorian calendar,
+    /// with the year numbered astronomically.
+    pub fn proleptic_date(&self) -> Result<(i32, u32, u32), DateError> {
+        let (year, month, day) = date_from_signed_days(self.adaptor.signed_days()?);
+        Ok((year as i32, month, day))
+    }
+
+    /// Sets the date to the given signed number of whole days from January 1, 0001, which is
+    /// negative for earlier dates.
+    pub fn set_signed_days(&mut self, days: i64) -> Result<(), DateError> {
+        self.check_year(date_from_signed_days(days).0)?;
+        self.adaptor.set_signed_days(days)
+    }
+
+    /// Returns the signed number of whole days from January 1, 0001 to the date, which is
+    /// negative for earlier dates.
+    pub fn signed_days(&self) -> Result<i64, DateError> {
+        self.adaptor.signed_days()
+    }
+
+    /// Sets the date from the specified year, month, and day of the proleptic Julian calendar,
+    /// with the year numbered astronomically. The date is converted to the Gregorian calendar,
+    /// whose year must lie in the range of the date's spec.
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::date_spec_builder::DateSpecBuilder;
+    /// use data::variable::Variable;
+    ///
+    /// let mut var = Variable::new(&DateSpecBuilder::new().build());
+    /// let date_mut = var.date_mut();
+    ///
+    /// // the day before the Gregorian calendar was introduced
+    /// date_mut.set_julian_date(1582, 10, 4).unwrap();
+    /// assert_eq!(date_mut.date().unwrap(), (1582, 10, 14));
+    /// ```
+    pub fn set_julian_date(&mut self, year: i32, month: u32, day: u32) -> Result<(), DateError> {
+        let year = year as i64;
+        check_month_and_day(month, day, is_julian_leap_year(year))?;
+        self.set_signed_days(signed_days_from_julian_date(year, month, day))
+    }
+
+    /// Returns the date as a tuple of (year, month, day) of the proleptic Julian calendar, with
+    /// the year numbered astronomically.
+    pub fn julian_date(&self) -> Result<(i32, u32, u32), DateError> {
+        let (year, month, day) = julian_date_from_signed_days(self.adaptor.signed_days()?);
+        Ok((year as i32, month, day))
+    }
+
+    /// Sets the date to the current local date of the given clock.
+    pub fn set_to_now(&mut self, clock: &dyn Clock) -> Result<(), DateError> {
+        let days = local_now(clock, None).div_euclid(NANOSECONDS_PER_DAY);
+        self.set_signed_days(days as i64)
+    }
+
+    /// Sets the date to the UTC date of the instant of the given Unix timestamp, counted in the
+    /// given unit since 1970-01-01T00:00:00Z. The time of day of the instant is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::accessors::temporal::unix_time::UnixTimeUnit;
+    /// use data::data_spec_builders::date_spec_builder::DateSpecBuilder;
+    /// use data::variable::Variable;
+    ///
+    /// let mut var = Variable::new(&DateSpecBuilder::new().build());
+    /// let date_mut = var.date_mut();
+    ///
+    /// date_mut.set_from_unix_timestamp(1_718_454_896, UnixTimeUnit::Seconds).unwrap();
+    /// assert_eq!(date_mut.date().unwrap(), (2024, 6, 15));
+    /// assert_eq!(date_mut.unix_timestamp(UnixTimeUnit::Seconds).unwrap(), 1_718_409_600);
+    /// ```
+    pub fn set_from_unix_timestamp(
+        &mut self,
+        timestamp: i64,
+        unit: UnixTimeUnit,
+    ) -> Result<(), DateError> {
+        self.set_from_unix_nanoseconds(unix_nanoseconds_from_timestamp(timestamp, unit))
+    }
+
+    /// Returns the Unix timestamp of the date's midnight in UTC, counted in the given unit since
+    /// 1970-01-01T00:00:00Z. Fails if the timestamp does not fit into an `i64`.
+    pub fn unix_timestamp(&self, unit: UnixTimeUnit) -> Result<i64, DateError> {
+        timestamp_from_unix_nanoseconds(self.unix_nanoseconds()?, unit)
+            .map_err(DateError::TimestampOutOfRange)
+    }
+
+    /// Sets the date to the UTC date of the given system time.
+    pub fn set_from_system_time(&mut self, system_time: &SystemTime) -> Result<(), DateError> {
+        self.set_from_unix_nanoseconds(unix_nanoseconds_from_system_time(system_time))
+    }
+
+    /// Returns the system time of the date's midnight in UTC. Fails if the platform's system time
+    /// cannot represent it.
+    pub fn system_time(&self) -> Result<SystemTime, DateError> {
+        system_time_from_unix_nanoseconds(self.unix_nanoseconds()?)
+            .map_err(DateError::TimestampOutOfRange)
+    }
+
+    /// Sets the date to the UTC date of the instant in nanoseconds since the Unix epoch.
+    fn set_from_unix_nanoseconds(&mut self, unix_nanoseconds: i128) -> Result<(), DateError> {
+        let days = (unix_nanoseconds + NANOSECONDS_TO_UNIX_EPOCH).div_euclid(NANOSECONDS_PER_DAY);
+        let days = i64::try_from(days).map_err(|_| {
+            DateError::TimestampOutOfRange(format!(
+                "An instant {} nanoseconds from the Unix epoch is out of the range of a date.",
+                unix_nanoseconds
+            ))
+        })?;
+        self.set_signed_days(days)
+    }
+
+    /// Returns the nanoseconds from the Unix epoch to the date's midnight in UTC.
+    fn unix_nanoseconds(&self) -> Result<i128, DateError> {
+        Ok(self.signed_days()? as i128 * NANOSECONDS_PER_DAY - NANOSECONDS_TO_UNIX_EPOCH)
     }
 
-    /// Sets the date's value from a string in an ISO 8601 format: (YYYY-MM-DD), or (YYYYMMDD).
+    /// Sets the date's value from a string in an ISO 8601 format: a calendar date (YYYY-MM-DD),
+    /// or (YYYYMMDD), a week date (YYYY-Www-D), or (YYYYWwwD), an ordinal date (YYYY-DDD), or
+    /// (YYYYDDD), or an expanded calendar date with a signed year (±YYYYYY-MM-DD), as the
+    /// date's `Display` writes the years out of the range 0 to 9999.
     /// Note that a truncated date such as "2024-06" is not supported and will result in an error.
     ///
     /// # Examples
@@ -156,6 +345,29 @@ impl Date {
         self.adaptor.date()
     }
 
+    /// Sets the date's value from a string in the layout of the given format pattern, which must
+    /// contain the year with the month and day, or with the day of the year.
+    /// The date is left unchanged if the string does not match the pattern.
+    pub fn set_from_format(
+        &mut self,
+        date_str: &str,
+        pattern: &FormatPattern,
+    ) -> Result<(), FormatError> {
+        let (year, month, day) = pattern.parse(date_str)?.date()?;
+        self.set_proleptic_date(year, month, day)?;
+        Ok(())
+    }
+
+    /// Formats the date with the given format pattern, which may not contain time or offset
+    /// tokens. The year is that of the proleptic Gregorian calendar, so that dates before the
+    /// year 1 of an extended range can be formatted.
+    pub fn format(&self, pattern: &FormatPattern) -> Result<String, FormatError> {
+        pattern.format(&PatternFields {
+            date: Some(self.proleptic_date()?),
+            ..Default::default()
+        })
+    }
+
     /// Returns the year component of the date.
     pub fn year(&self) -> Result<u32, DateError> {
         self.adaptor.year()
@@ -171,52 +383,311 @@ impl Date {
         self.adaptor.day()
     }
 
+    /// Sets the date to the given number of whole days from January 1, 0001.
+    pub fn set_days(&mut self, days: u32) -> Result<(), DateError> {
+        self.set_signed_days(days as i64)
+    }
+
+    /// Returns the number of whole days from January 1, 0001 to the date.
+    pub fn days(&self) -> Result<u32, DateError> {
+        if self.adaptor.stores_date_as_days() {
+            return self.adaptor.days();
+        }
+        let (year, month, day) = self.date()?;
+        Ok(days_from_date(year, month, day))
+    }
+
     /// Returns the day of the week on which this date falls.
     pub fn day_of_week(&self) -> Result<DayOfWeek, DateError> {
-        let (year, month, day) = self.date().unwrap();
-        let q = day as i32;
-        let m = if month < 3 { month + 12 } else { month } as i32;
-        let k = if month < 3 { year - 1 } else { year } as i32 % 100;
-        let j = if month < 3 { year - 1 } else { year } as i32 / 100;
-
-        let h = (q + ((13 * (m + 1)) / 5) + k + (k / 4) + (j / 4) + (5 * j)) % 7;
-
-        match h {
-            0 => Ok(DayOfWeek::Saturday),
-            1 => Ok(DayOfWeek::Sunday),
-            2 => Ok(DayOfWeek::Monday),
-            3 => Ok(DayOfWeek::Tuesday),
-            4 => Ok(DayOfWeek::Wednesday),
-            5 => Ok(DayOfWeek::Thursday),
-            6 => Ok(DayOfWeek::Friday),
-            _ => unreachable!(),
-        }
+        Ok(day_of_week_from_signed_days(self.adaptor.signed_days()?))
     }
 
     /// Returns the number of days from January 1 to the date represented in this date.
     pub fn day_of_year(&self) -> Result<u32, DateError> {
-        let (year, month, day) = self.date()?;
+        let (year, month, day) = self.proleptic_date()?;
         let mut day_of_year = day;
-        let is_leap = is_leap_year(year);
+        let is_leap = is_proleptic_leap_year(year as i64);
         for m in 1..month {
             day_of_year += days_in_month(is_leap, m);
         }
         Ok(day_of_year)
     }
+
+    /// Returns the ISO 8601 week number of the date, from 1 to 53.
+    /// ISO weeks start on Monday, and week 1 is the week that contains the first Thursday of the
+    /// year, so early January dates may fall in the last week of the previous year and late
+    /// December dates in week 1 of the next year.
+    pub fn iso_week(&self) -> Result<u32, DateError> {
+        Ok(iso_week_date_from_days(self.days()?).1)
+    }
+
+    /// Returns the ISO 8601 week-numbering year of the date, which is the year of the Thursday of
+    /// the date's ISO week.
+    pub fn iso_week_year(&self) -> Result<u32, DateError> {
+        Ok(iso_week_date_from_days(self.days()?).0)
+    }
+
+    /// Returns the date as an ISO 8601 week date tuple of (week-numbering year, week, weekday),
+    /// where the weekday runs from 1 for Monday to 7 for Sunday.
+    pub fn iso_week_date(&self) -> Result<(u32, u32, u32), DateError> {
+        Ok(iso_week_date_from_days(self.days()?))
+    }
+
+    /// Sets the date from an ISO 8601 week date, where the weekday runs from 1 for Monday to 7
+    /// for Sunday.
+    /// The date is left unchanged if the week date is out of bounds.
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::date_spec_builder::DateSpecBuilder;
+    /// use data::variable::Variable;
+    ///
+    /// let mut var = Variable::new(&DateSpecBuilder::new().build());
+    /// let date_mut = var.date_mut();
+    ///
+    /// // Monday of week 1 of 2025 is December 30, 2024
+    /// date_mut.set_iso_week_date(2025, 1, 1).unwrap();
+    /// assert_eq!(date_mut.date().unwrap(), (2024, 12, 30));
+    /// assert_eq!(date_mut.iso_week_year().unwrap(), 2025);
+    /// assert_eq!(date_mut.to_iso_week_date_string().unwrap(), "2025-W01-1");
+    /// ```
+    pub fn set_iso_week_date(
+        &mut self,
+        year: u32,
+        week: u32,
+        weekday: u32,
+    ) -> Result<(), DateError> {
+        if year < 1 {
+            return Err(DateError::YearOutOfBounds(year));
+        }
+        self.check_year(year as i64)?;
+        let weeks = iso_weeks_in_year(year);
+        if week < 1 || week > weeks {
+            return Err(DateError::WeekOutOfBounds(week, year, weeks));
+        }
+        if !(1..=7).contains(&weekday) {
+            return Err(DateError::WeekdayOutOfBounds(weekday));
+        }
+        self.set_days(first_iso_week_days(year) + (week - 1) * 7 + weekday - 1)
+    }
+
+    /// Sets the date from an ISO 8601 ordinal date, the year and the day of the year.
+    /// The date is left unchanged if the ordinal date is out of bounds.
+    pub fn set_ordinal_date(&mut self, year: u32, day_of_year: u32) -> Result<(), DateError> {
+        self.check_year(year as i64)?;
+        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+        if day_of_year < 1 || day_of_year > days_in_year {
+            return Err(DateError::DayOfYearOutOfBounds(
+                day_of_year,
+                year,
+                days_in_year,
+            ));
+        }
+        self.set_signed_days(signed_days_from_date(year as i64, 1, 1) + day_of_year as i64 - 1)
+    }
+
+    /// Returns the date as an ISO 8601 week date string (YYYY-Www-D).
+    pub fn to_iso_week_date_string(&self) -> Result<String, DateError> {
+        let (year, week, weekday) = self.iso_week_date()?;
+        Ok(format!("{:04}-W{:02}-{}", year, week, weekday))
+    }
+
+    /// Returns the date as an ISO 8601 ordinal date string (YYYY-DDD).
+    pub fn to_ordinal_date_string(&self) -> Result<String, DateError> {
+        Ok(format!("{:04}-{:03}", self.year()?, self.day_of_year()?))
+    }
+
+    /// Adds the year-to-month duration to the date. When the resulting month is shorter than the
+    /// date's day, the day is clamped to the last day of that month, so that January 31 plus one
+    /// month is the last day of February.
+    /// The date is left unchanged if the result is out of bounds.
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::date_spec_builder::DateSpecBuilder;
+    /// use data::data_spec_builders::duration_spec_builder::DurationSpecBuilder;
+    /// use data::primitive_specs::duration_spec::DurationType;
+    /// use data::variable::Variable;
+    ///
+    /// let mut date = Variable::new(&DateSpecBuilder::new().build());
+    /// let mut duration = Variable::new(
+    ///     &DurationSpecBuilder::new()
+    ///         .set_type(DurationType::YearToMonth)
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    /// date.date_mut().set_date(2024, 1, 31).unwrap();
+    /// duration.year_to_month_duration_mut().set_duration(0, 1).unwrap();
+    ///
+    /// date.date_mut()
+    ///     .add_year_to_month_duration(duration.year_to_month_duration())
+    ///     .unwrap();
+    /// assert_eq!(date.date().date().unwrap(), (2024, 2, 29));
+    /// ```
+    pub fn add_year_to_month_duration(
+        &mut self,
+        duration: &YearToMonthDuration,
+    ) -> Result<(), DateError> {
+        self.add_months(total_months(duration)?)
+    }
+
+    /// Subtracts the year-to-month duration from the date, clamping the day to the last day of
+    /// the resulting month when needed.
+    /// The date is left unchanged if the result is out of bounds.
+    pub fn subtract_year_to_month_duration(
+        &mut self,
+        duration: &YearToMonthDuration,
+    ) -> Result<(), DateError> {
+        self.add_months(-total_months(duration)?)
+    }
+
+    /// Adds the whole days of the day-to-second duration to the date; the duration's hours and
+    /// finer fields are ignored.
+    /// The date is left unchanged if the result is out of bounds.
+    pub fn add_day_to_second_duration(
+        &mut self,
+        duration: &DayToSecondDuration,
+    ) -> Result<(), DateError> {
+        self.add_days(duration.days()? as i64)
+    }
+
+    /// Subtracts the whole days of the day-to-second duration from the date; the duration's hours
+    /// and finer fields are ignored.
+    /// The date is left unchanged if the result is out of bounds.
+    pub fn subtract_day_to_second_duration(
+        &mut self,
+        duration: &DayToSecondDuration,
+    ) -> Result<(), DateError> {
+        self.add_days(-(duration.days()? as i64))
+    }
+
+    /// Sets the day-to-second duration to the number of days from this date to the end date,
+    /// which is negative if the end date is before this date.
+    pub fn days_until(
+        &self,
+        end: &Date,
+        duration: &mut DayToSecondDuration,
+    ) -> Result<(), DateError> {
+        let days = end.signed_days()? - self.signed_days()?;
+        duration.set_duration(days as i32, 0, 0, 0, 0, 0, 0)?;
+        Ok(())
+    }
+
+    /// Sets the year-to-month duration to the number of whole months from this date to the end
+    /// date, which is negative if the end date is before this date. The months are those that
+    /// can be added to this date without passing the end date, so that there is one month from
+    /// January 31 to the last day of February.
+    pub fn months_until(
+        &self,
+        end: &Date,
+        duration: &mut YearToMonthDuration,
+    ) -> Result<(), DateError> {
+        let (year, month, day) = self.proleptic_date()?;
+        let start = (year as i64, month, day);
+        let end_days = end.signed_days()?;
+        let (end_year, end_month, _) = end.proleptic_date()?;
+        let mut months =
+            (end_year as i64 * 12 + end_month as i64) - (start.0 * 12 + start.1 as i64);
+        // step back one month when adding the months to this date passes the end date
+        let (year, month, day) = add_months_to_date(start, months);
+        let days = signed_days_from_date(year, month, day);
+        if months > 0 && days > end_days {
+            months -= 1;
+        } else if months < 0 && days < end_days {
+            months += 1;
+        }
+        duration.set_duration((months / 12) as i32, (months % 12) as i32)?;
+        Ok(())
+    }
+
+    /// Adds the given number of months to the date, clamping the day to the month's last day.
+    fn add_months(&mut self, months: i64) -> Result<(), DateError> {
+        let (year, month, day) = self.proleptic_date()?;
+        let (year, month, day) = add_months_to_date((year as i64, month, day), months);
+        self.set_year_month_day(year, month, day)
+    }
+
+    /// Adds the given number of days to the date.
+    fn add_days(&mut self, days: i64) -> Result<(), DateError> {
+        self.set_signed_days(self.adaptor.signed_days()? + days)
+    }
+
+    /// Sets the date to the given year, month, and day of the proleptic Gregorian calendar after
+    /// checking them against the date's range and the calendar.
+    fn set_year_month_day(&mut self, year: i64, month: u32, day: u32) -> Result<(), DateError> {
+        self.check_year(year)?;
+        check_month_and_day(month, day, is_proleptic_leap_year(year))?;
+        if year >= 1 {
+            return self.adaptor.set_date(year as u32, month, day);
+        }
+        self.adaptor
+            .set_signed_days(signed_days_from_date(year, month, day))
+    }
+
+    /// Returns an error unless the year lies in the range of the date's spec. Years out of the
+    /// standard range are reported as `YearOutOfBounds`, with years before 1 reported as 0.
+    pub(crate) fn check_year(&self, year: i64) -> Result<(), DateError> {
+        match *self.spec().range() {
+            range if range.contains(year) => Ok(()),
+            DateRange::Standard => Err(DateError::YearOutOfBounds(
+                year.clamp(0, u32::MAX as i64) as u32
+            )),
+            range => Err(DateError::YearOutOfRange(year, range)),
+        }
+    }
+}
+
+/// Returns an error unless the month and the day of the month are valid in a year that is a leap
+/// year or not.
+fn check_month_and_day(month: u32, day: u32, is_leap_year: bool) -> Result<(), DateError> {
+    if (month < 1) || (month > 12) {
+        return Err(DateError::MonthOutOfBounds(month));
+    }
+    let days_in_month = days_in_month(is_leap_year, month);
+    if day < 1 || day > days_in_month {
+        return Err(DateError::DayOutOfBounds(
+            day,
+            month_name(month).to_string(),
+            days_in_month,
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the total number of months of a year-to-month duration.
+fn total_months(duration: &YearToMonthDuration) -> Result<i64, DateError> {
+    let (years, months) = duration.duration()?;
+    Ok(years as i64 * 12 + months as i64)
+}
+
+/// Returns the date that lies the given number of months after the given (year, month, day),
+/// clamping the day to the last day of the resulting month.
+fn add_months_to_date(date: (i64, u32, u32), months: i64) -> (i64, u32, u32) {
+    let (year, month, day) = date;
+    let total = year * 12 + (month as i64 - 1) + months;
+    let (year, month) = (total.div_euclid(12), total.rem_euclid(12) as u32 + 1);
+    (
+        year,
+        month,
+        day.min(days_in_month(is_proleptic_leap_year(year), month)),
+    )
 }
 
 impl Accessor for Date {}
 
 impl Display for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (year, month, day) = self.date().unwrap();
-        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+        let (year, month, day) = self.proleptic_date().unwrap();
+        if (0..=9999).contains(&year) {
+            write!(f, "{:04}-{:02}-{:02}", year, month, day)
+        } else {
+            // the expanded ISO 8601 representation with a sign and six digits
+            write!(f, "{:+07}-{:02}-{:02}", year, month, day)
+        }
     }
 }
 
 impl Debug for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (year, month, day) = self.date().unwrap();
+        let (year, month, day) = self.proleptic_date().unwrap();
         write!(
             f,
             "Date {{ year: {}, month: {}, day: {} }}",
@@ -228,11 +699,11 @@ impl Debug for Date {
 impl SetEqualTo for Date {
     fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
         if self.adaptor.stores_date_as_days() && other.adaptor.stores_date_as_days() {
-            self.adaptor.set_days(other.adaptor.days()?)?;
+            self.set_signed_days(other.adaptor.signed_days()?)?;
             return Ok(());
         }
-        let other_date = other.date()?;
-        self.set_date(other_date.0, other_date.1, other_date.2)?;
+        let other_date = other.proleptic_date()?;
+        self.set_proleptic_date(other_date.0, other_date.1, other_date.2)?;
         Ok(())
     }
 }
@@ -240,7 +711,7 @@ impl SetEqualTo for Date {
 impl PartialEq for Date {
     fn eq(&self, other: &Self) -> bool {
         if self.adaptor.stores_date_as_days() && other.adaptor.stores_date_as_days() {
-            return self.adaptor.days().unwrap() == other.adaptor.days().unwrap();
+            return self.adaptor.signed_days().unwrap() == other.adaptor.signed_days().unwrap();
         }
         self.date() == other.date()
     }
@@ -253,9 +724,9 @@ impl PartialOrd for Date {
         if self.adaptor.stores_date_as_days() && other.adaptor.stores_date_as_days() {
             return self
                 .adaptor
-                .days()
+                .signed_days()
                 .unwrap()
-                .partial_cmp(&other.adaptor.days().unwrap());
+                .partial_cmp(&other.adaptor.signed_days().unwrap());
         }
         self.date().unwrap().partial_cmp(&other.date().unwrap())
     }
@@ -270,7 +741,7 @@ impl Ord for Date {
 impl Hash for Date {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         if self.adaptor.stores_date_as_days() {
-            self.adaptor.days().unwrap().hash(state);
+            self.adaptor.signed_days().unwrap().hash(state);
         } else {
             let (year, month, day) = self.date().unwrap();
             year.hash(state);
@@ -280,6 +751,34 @@ impl Hash for Date {
     }
 }
 
+/// Returns the ISO 8601 week date of the given number of days from January 1, 0001, which was a
+/// Monday.
+fn iso_week_date_from_days(days: u32) -> (u32, u32, u32) {
+    let weekday = days % 7 + 1;
+    let thursday = days - (weekday - 1) + 3;
+    let (year, _, _) = date_from_days(thursday);
+    let week = (thursday - days_from_date(year, 1, 1)) / 7 + 1;
+    (year, week, weekday)
+}
+
+/// Returns the number of days from January 1, 0001 to the Monday of week 1 of the given ISO
+/// week-numbering year, which is the week that contains January 4.
+fn first_iso_week_days(year: u32) -> u32 {
+    let january_4 = days_from_date(year, 1, 4);
+    january_4 - january_4 % 7
+}
+
+/// Returns the number of ISO weeks, 52 or 53, in the given week-numbering year.
+fn iso_weeks_in_year(year: u32) -> u32 {
+    // December 28 always falls in the last week of its year
+    iso_week_date_from_days(days_from_date(year, 12, 28)).1
+}
+
+/// Returns the day of the week of the given signed days from January 1, 0001, which was a Monday.
+pub(crate) fn day_of_week_from_signed_days(days: i64) -> DayOfWeek {
+    DayOfWeek::from_index(days.rem_euclid(7) as usize).unwrap()
+}
+
 /// Determines if a given year is a leap year.
 pub(crate) fn is_leap_year(year: u32) -> bool {
     (year % 4 == 0) && ((year % 100 != 0) || (year % 400 == 0))
@@ -295,7 +794,8 @@ pub(crate) fn days_in_month(is_leap_year: bool, month: u32) -> u32 {
     DAYS_IN_MONTH[(month - 1) as usize]
 }
 
-/// Returns the name of the month for the given month number.
+/// Returns the English name of the month for the given month number. See
+/// [`Locale::month_name`] for the names in other languages.
 pub fn month_name(month: u32) -> &'static str {
     match month {
         1 => "January",
@@ -321,12 +821,28 @@ pub enum DateError {
     ProviderError(ProviderError),
     /// Year out of bounds.
     YearOutOfBounds(u32),
+    /// Year out of the extended range of the date's spec.
+    YearOutOfRange(i64, DateRange),
+    /// A date before January 1, 0001, accessed with unsigned years or days.
+    DateBeforeYearOne(i64),
     /// Month out of bounds.
     MonthOutOfBounds(u32),
     /// Day out of bounds.
     DayOutOfBounds(u32, String, u32),
+    /// ISO week out of bounds for the week-numbering year.
+    WeekOutOfBounds(u32, u32, u32),
+    /// ISO weekday out of bounds.
+    WeekdayOutOfBounds(u32),
+    /// Day of the year out of bounds for the year.
+    DayOfYearOutOfBounds(u32, u32, u32),
     /// Invalid date format.
     InvalidFormat(String),
+    /// A Unix timestamp or system time out of the range of the date or of its target type.
+    TimestampOutOfRange(String),
+    /// An error of a year-to-month duration used in date arithmetic.
+    YearToMonthDurationError(YearToMonthDurationError),
+    /// An error of a day-to-second duration used in date arithmetic.
+    DayToSecondDurationError(DayToSecondDurationError),
 }
 
 impl Display for DateError {
@@ -336,6 +852,23 @@ impl Display for DateError {
             DateError::YearOutOfBounds(year) => {
                 write!(f, "Year value {} is out of bounds (1 to 9999).", year)
             }
+            DateError::YearOutOfRange(year, range) => {
+                write!(
+                    f,
+                    "Year value {} is out of the {} date range ({} to {}).",
+                    year,
+                    range,
+                    range.min_year(),
+                    range.max_year()
+                )
+            }
+            DateError::DateBeforeYearOne(year) => {
+                write!(
+                    f,
+                    "The date in year {} lies before January 1, 0001; use the proleptic date or signed days.",
+                    year
+                )
+            }
             DateError::MonthOutOfBounds(month) => {
                 write!(f, "Month value {} is out of bounds (1 to 12).", month)
             }
@@ -346,9 +879,35 @@ impl Display for DateError {
                     day, month_name, max_day
                 )
             }
+            DateError::WeekOutOfBounds(week, year, max_week) => {
+                write!(
+                    f,
+                    "Week value {} is out of bounds for ISO week-numbering year {}. Valid range is 1 to {}.",
+                    week, year, max_week
+                )
+            }
+            DateError::WeekdayOutOfBounds(weekday) => {
+                write!(f, "Weekday value {} is out of bounds (1 to 7).", weekday)
+            }
+            DateError::DayOfYearOutOfBounds(day_of_year, year, max_day) => {
+                write!(
+                    f,
+                    "Day of the year value {} is out of bounds for year {}. Valid range is 1 to {}.",
+                    day_of_year, year, max_day
+                )
+            }
             DateError::InvalidFormat(msg) => {
                 write!(f, "{}", msg)
             }
+            DateError::TimestampOutOfRange(msg) => {
+                write!(f, "Timestamp out of range: {}", msg)
+            }
+            DateError::YearToMonthDurationError(err) => {
+                write!(f, "Year-to-month duration error: {}", err)
+            }
+            DateError::DayToSecondDurationError(err) => {
+                write!(f, "Day-to-second duration error: {}", err)
+            }
         }
     }
 }
@@ -357,6 +916,8 @@ impl std::error::Error for DateError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             DateError::ProviderError(err) => Some(err),
+            DateError::YearToMonthDurationError(err) => Some(err),
+            DateError::DayToSecondDurationError(err) => Some(err),
             _ => None,
         }
     }
@@ -368,6 +929,18 @@ impl From<ProviderError> for DateError {
     }
 }
 
+impl From<YearToMonthDurationError> for DateError {
+    fn from(error: YearToMonthDurationError) -> Self {
+        DateError::YearToMonthDurationError(error)
+    }
+}
+
+impl From<DayToSecondDurationError> for DateError {
+    fn from(error: DayToSecondDurationError) -> Self {
+        DateError::DayToSecondDurationError(error)
+    }
+}
+
 impl From<DateError> for SetEqualToError {
     fn from(error: DateError) -> Self {
         SetEqualToError::DateError(error)
@@ -1,11 +1,33 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    time::SystemTime,
+};
+
 use crate::{
-    accessors::temporal::{date::Date, time::Time},
+    accessors::temporal::{
+        NANOSECONDS_PER_DAY,
+        clock::{Clock, local_now},
+        date::{Date, DateError},
+        format_pattern::{FormatError, FormatPattern, PatternFields},
+        time::{Time, TimeError},
+        unix_time::{
+            NANOSECONDS_TO_UNIX_EPOCH, UnixTimeUnit, system_time_from_unix_nanoseconds,
+            timestamp_from_unix_nanoseconds, unix_nanoseconds_from_system_time,
+            unix_nanoseconds_from_timestamp,
+        },
+    },
     adaptors::temporal_adaptors::{date_adaptor::DateAdaptor, time_adaptor::TimeAdaptor},
     primitive_def::Accessor,
-    set_equal_to::SetEqualTo,
+    provider_error::ProviderError,
+    set_equal_to::{SetEqualTo, SetEqualToError},
 };
 
 /// Accessor for date-time values.
+/// <p>
+/// A date-time is a date combined with a time of day, without reference to a time zone.
+/// Date-times are ordered by their date and then by their time.
+/// </p>
 pub struct DateTime {
     date: Date,
     time: Time,
@@ -21,21 +43,374 @@ impl DateTime {
     }
 
     /// Returns the date-time's date component.
-    fn date(&self) -> &Date {
+    pub fn date(&self) -> &Date {
         &self.date
     }
 
+    /// Returns the date-time's date component as mutable.
+    pub fn date_mut(&mut self) -> &mut Date {
+        &mut self.date
+    }
+
     /// Returns the date-time's time component.
     pub fn time(&self) -> &Time {
         &self.time
     }
+
+    /// Returns the date-time's time component as mutable.
+    pub fn time_mut(&mut self) -> &mut Time {
+        &mut self.time
+    }
+
+    /// Sets the date-time to the specified date and time of day.
+    /// The date-time is left unchanged if either the date or the time is invalid.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_date_time(
+        &mut self,
+        year: u32,
+        month: u32,
+        day: u32,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        millisecond: u16,
+        microsecond: u16,
+        nanosecond: u16,
+    ) -> Result<(), DateTimeError> {
+        let (previous_year, previous_month, previous_day) = self.date.date()?;
+        self.date.set_date(year, month, day)?;
+        if let Err(error) =
+            self.time
+                .set_time(hour, minute, second, millisecond, microsecond, nanosecond)
+        {
+            self.date
+                .set_date(previous_year, previous_month, previous_day)?;
+            return Err(error.into());
+        }
+        Ok(())
+    }
+
+    /// Sets the date-time to the current local date and time of the given clock, read once, with
+    /// the time truncated to the resolution of the time's spec.
+    /// The date-time is left unchanged if the current date is out of the date's range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::accessors::temporal::clock::FixedClock;
+    /// use data::data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder;
+    /// use data::primitive_specs::date_time_spec::DateTimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DateTimeSpecBuilder::new()
+    ///     .set_date_time_type(DateTimeType::Local)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    ///
+    /// // 2024-06-15T23:30:00Z, with the local time two hours ahead of UTC
+    /// let clock = FixedClock::new(1_718_494_200 * 1_000_000_000);
+    /// clock.set_local_offset_seconds(7_200);
+    /// var.date_time_mut().set_to_now(&clock).unwrap();
+    /// assert_eq!(var.to_string(), "2024-06-16T01:30:00");
+    /// ```
+    pub fn set_to_now(&mut self, clock: &dyn Clock) -> Result<(), DateTimeError> {
+        self.set_nanoseconds(local_now(clock, self.time.spec().resolution().as_ref()))
+    }
+
+    /// Sets the date-time to the date and time of day in UTC of the instant of the given Unix
+    /// timestamp, counted in the given unit since 1970-01-01T00:00:00Z.
+    /// The date-time is left unchanged if the date is out of the date's range or the time is
+    /// finer than the time's resolution.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::accessors::temporal::unix_time::UnixTimeUnit;
+    /// use data::data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder;
+    /// use data::primitive_specs::date_time_spec::DateTimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DateTimeSpecBuilder::new()
+    ///     .set_date_time_type(DateTimeType::Local)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    /// let date_time_mut = var.date_time_mut();
+    ///
+    /// date_time_mut
+    ///     .set_from_unix_timestamp(1_718_454_896_789, UnixTimeUnit::Milliseconds)
+    ///     .unwrap();
+    /// assert_eq!(date_time_mut.to_string(), "2024-06-15T12:34:56.789");
+    /// assert_eq!(
+    ///     date_time_mut.unix_timestamp(UnixTimeUnit::Seconds).unwrap(),
+    ///     1_718_454_896
+    /// );
+    /// ```
+    pub fn set_from_unix_timestamp(
+        &mut self,
+        timestamp: i64,
+        unit: UnixTimeUnit,
+    ) -> Result<(), DateTimeError> {
+        self.set_nanoseconds(
+            unix_nanoseconds_from_timestamp(timestamp, unit) + NANOSECONDS_TO_UNIX_EPOCH,
+        )
+    }
+
+    /// Returns the Unix timestamp of the date-time taken as UTC, counted in the given unit since
+    /// 1970-01-01T00:00:00Z and rounded down to a whole unit.
+    /// Fails if the timestamp does not fit into an `i64`.
+    pub fn unix_timestamp(&self, unit: UnixTimeUnit) -> Result<i64, DateTimeError> {
+        timestamp_from_unix_nanoseconds(self.nanoseconds()? - NANOSECONDS_TO_UNIX_EPOCH, unit)
+            .map_err(|msg| DateError::TimestampOutOfRange(msg).into())
+    }
+
+    /// Sets the date-time to the date and time of day in UTC of the given system time.
+    /// The date-time is left unchanged if the date is out of the date's range or the time is
+    /// finer than the time's resolution.
+    pub fn set_from_system_time(&mut self, system_time: &SystemTime) -> Result<(), DateTimeError> {
+        self.set_nanoseconds(
+            unix_nanoseconds_from_system_time(system_time) + NANOSECONDS_TO_UNIX_EPOCH,
+        )
+    }
+
+    /// Returns the system time of the date-time taken as UTC.
+    /// Fails if the platform's system time cannot represent it.
+    pub fn system_time(&self) -> Result<SystemTime, DateTimeError> {
+        system_time_from_unix_nanoseconds(self.nanoseconds()? - NANOSECONDS_TO_UNIX_EPOCH)
+            .map_err(|msg| DateError::TimestampOutOfRange(msg).into())
+    }
+
+    /// Sets the date-time to the given signed number of nanoseconds from January 1, 0001
+    /// 00:00:00, leaving it unchanged if either the date or the time cannot be set.
+    pub(crate) fn set_nanoseconds(&mut self, nanoseconds: i128) -> Result<(), DateTimeError> {
+        let days = i64::try_from(nanoseconds.div_euclid(NANOSECONDS_PER_DAY)).map_err(|_| {
+            DateError::TimestampOutOfRange(format!(
+                "A date-time {} nanoseconds from January 1, 0001 is out of the range of a date.",
+                nanoseconds
+            ))
+        })?;
+        let previous = self.date.signed_days()?;
+        self.date.set_signed_days(days)?;
+        if let Err(error) = self
+            .time
+            .set_nanoseconds_of_day(nanoseconds.rem_euclid(NANOSECONDS_PER_DAY) as u64)
+        {
+            self.date.set_signed_days(previous)?;
+            return Err(error.into());
+        }
+        Ok(())
+    }
+
+    /// Returns the signed number of nanoseconds from January 1, 0001 00:00:00 to the date-time.
+    pub(crate) fn nanoseconds(&self) -> Result<i128, DateTimeError> {
+        Ok(self.date.signed_days()? as i128 * NANOSECONDS_PER_DAY
+            + self.time.nanoseconds_of_day()? as i128)
+    }
+
+    /// Sets the date-time from a string in an ISO 8601 format: (YYYY-MM-DDThh:mm:ss[.fffffffff]),
+    /// where the date and the time may be given in any of the forms that
+    /// [`Date::set_from_string`] and [`Time::set_from_string`] accept.
+    /// The date-time is left unchanged if the string is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder;
+    /// use data::primitive_specs::date_time_spec::DateTimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DateTimeSpecBuilder::new()
+    ///     .set_date_time_type(DateTimeType::Local)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    /// let date_time_mut = var.date_time_mut();
+    ///
+    /// date_time_mut.set_from_string("2024-06-15T12:34:56.789").unwrap();
+    /// assert_eq!(date_time_mut.date().date().unwrap(), (2024, 6, 15));
+    /// assert_eq!(date_time_mut.time().time().unwrap(), (12, 34, 56, 789, 0, 0));
+    /// assert_eq!(date_time_mut.to_string(), "2024-06-15T12:34:56.789");
+    /// ```
+    pub fn set_from_string(&mut self, date_time_str: &str) -> Result<(), DateTimeError> {
+        crate::accessors::temporal::set_from_strings::set_date_time_from_string::set_date_time_from_string(
+            self,
+            date_time_str,
+        )
+    }
+
+    /// Sets the date-time from a string in the layout of the given format pattern, which must
+    /// contain a date and a time as [`Date::set_from_format`] and [`Time::set_from_format`] require.
+    /// The date-time is left unchanged if the string does not match the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::accessors::temporal::format_pattern::FormatPattern;
+    /// use data::data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder;
+    /// use data::primitive_specs::date_time_spec::DateTimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DateTimeSpecBuilder::new()
+    ///     .set_date_time_type(DateTimeType::Local)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    /// let pattern = FormatPattern::new("%d/%m/%Y %H:%M").unwrap();
+    ///
+    /// var.date_time_mut().set_from_format("15/06/2024 12:34", &pattern).unwrap();
+    /// assert_eq!(var.to_string(), "2024-06-15T12:34:00");
+    /// assert_eq!(var.date_time().format(&pattern).unwrap(), "15/06/2024 12:34");
+    /// ```
+    pub fn set_from_format(
+        &mut self,
+        date_time_str: &str,
+        pattern: &FormatPattern,
+    ) -> Result<(), FormatError> {
+        let fields = pattern.parse(date_time_str)?;
+        let ((year, month, day), (hour, minute, second, millisecond, microsecond, nanosecond)) =
+            (fields.date()?, fields.time()?);
+        self.set_date_time(
+            // parsed years are from 0 to 9999
+            year as u32,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+        )?;
+        Ok(())
+    }
+
+    /// Formats the date-time with the given format pattern, which may not contain offset tokens.
+    pub fn format(&self, pattern: &FormatPattern) -> Result<String, FormatError> {
+        pattern.format(&PatternFields {
+            date: Some(self.date.proleptic_date()?),
+            time: Some(self.time.time()?),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the date-time as a tuple of (year, month, day) and a tuple of
+    /// (hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+    pub fn date_time(&self) -> Result<DateTimeFields, DateTimeError> {
+        Ok((self.date.date()?, self.time.time()?))
+    }
 }
 
+/// The fields of a date-time as a tuple of (year, month, day) and a tuple of
+/// (hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+pub type DateTimeFields = ((u32, u32, u32), (u8, u8, u8, u16, u16, u16));
+
 impl SetEqualTo for DateTime {
-    fn set_equal_to(&mut self, _other: &Self) -> Result<(), crate::set_equal_to::SetEqualToError> {
-        // Implement the logic to set this DateTime equal to another DateTime.
-        todo!("Implement set_equal_to for DateTime");
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.date.set_equal_to(&other.date)?;
+        self.time.set_equal_to(&other.time)?;
+        Ok(())
     }
 }
 
 impl Accessor for DateTime {}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+impl Debug for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DateTime {{ date: {:?}, time: {:?} }}",
+            self.date, self.time
+        )
+    }
+}
+
+impl PartialEq for DateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.date == other.date && self.time == other.time
+    }
+}
+
+impl Eq for DateTime {}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.date
+            .cmp(&other.date)
+            .then_with(|| self.time.cmp(&other.time))
+    }
+}
+
+impl Hash for DateTime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.date.hash(state);
+        self.time.hash(state);
+    }
+}
+
+/// An error that can occur when working with date-time values.
+#[derive(Debug, PartialEq)]
+pub enum DateTimeError {
+    /// A provider error.
+    ProviderError(ProviderError),
+    /// An error of the date component.
+    DateError(DateError),
+    /// An error of the time component.
+    TimeError(TimeError),
+    /// Indicates an invalid format error.
+    InvalidFormat(String),
+}
+
+impl Display for DateTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateTimeError::ProviderError(err) => write!(f, "Provider error: {}", err),
+            DateTimeError::DateError(err) => write!(f, "Date error: {}", err),
+            DateTimeError::TimeError(err) => write!(f, "Time error: {}", err),
+            DateTimeError::InvalidFormat(msg) => write!(f, "Invalid format: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DateTimeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DateTimeError::ProviderError(err) => Some(err),
+            DateTimeError::DateError(err) => Some(err),
+            DateTimeError::TimeError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProviderError> for DateTimeError {
+    fn from(error: ProviderError) -> Self {
+        DateTimeError::ProviderError(error)
+    }
+}
+
+impl From<DateError> for DateTimeError {
+    fn from(error: DateError) -> Self {
+        DateTimeError::DateError(error)
+    }
+}
+
+impl From<TimeError> for DateTimeError {
+    fn from(error: TimeError) -> Self {
+        DateTimeError::TimeError(error)
+    }
+}
+
+impl From<DateTimeError> for SetEqualToError {
+    fn from(error: DateTimeError) -> Self {
+        SetEqualToError::DateTimeError(error)
+    }
+}
@@ -1,14 +1,35 @@
-use std::rc::Rc;
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    rc::Rc,
+    time::Duration,
+};
 
 use crate::{
+    accessors::temporal::{
+        NANOSECONDS_PER_DAY, NANOSECONDS_PER_HOUR, NANOSECONDS_PER_MICROSECOND,
+        NANOSECONDS_PER_MILLISECOND, NANOSECONDS_PER_MINUTE, NANOSECONDS_PER_SECOND,
+    },
     adaptors::temporal_adaptors::day_to_second_duration_adaptor::DayToSecondDurationAdaptor,
     primitive_def::Accessor,
-    primitive_specs::duration_spec::DurationSpec,
+    primitive_specs::{
+        duration_spec::{DurationResolution, DurationSpec},
+        rounding_policy::RoundingPolicy,
+    },
     provider_error::ProviderError,
     set_equal_to::{SetEqualTo, SetEqualToError},
 };
 
+/// The fields of a day-second duration as a tuple of
+/// (days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+pub(crate) type DayToSecondFields = (i32, i32, i32, i32, i32, i32, i32);
+
 /// Accessor for day-second duration values.
+/// <p>
+/// A day-second duration is normalized: hours are less than 24, minutes and seconds less
+/// than 60, and the sub-second fields less than 1000. All of its fields carry the sign
+/// of the duration as a whole.
+/// </p>
 pub struct DayToSecondDuration {
     adaptor: Box<dyn DayToSecondDurationAdaptor>,
 }
@@ -23,21 +44,512 @@ impl DayToSecondDuration {
     pub fn spec(&self) -> &Rc<DurationSpec> {
         self.adaptor.spec()
     }
+
+    /// Sets the duration in days, hours, minutes, seconds, milliseconds, microseconds, and nanoseconds.
+    /// The fields need not be normalized and may have different signs.
+    /// Fails if the duration is not a whole number of units of the spec's resolution and the
+    /// spec's rounding policy is `Reject`, or if the duration's days would overflow.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_duration(
+        &mut self,
+        days: i32,
+        hours: i32,
+        minutes: i32,
+        seconds: i32,
+        milliseconds: i32,
+        microseconds: i32,
+        nanoseconds: i32,
+    ) -> Result<(), DayToSecondDurationError> {
+        self.set_duration_with_rounding(
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+            *self.spec().rounding_policy(),
+        )
+    }
+
+    /// Sets the duration in days, hours, minutes, seconds, milliseconds, microseconds, and
+    /// nanoseconds, handling a duration finer than the spec's resolution with the given rounding
+    /// policy instead of the spec's.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::duration_spec_builder::DurationSpecBuilder;
+    /// use data::primitive_specs::duration_spec::{DurationResolution, DurationType};
+    /// use data::primitive_specs::rounding_policy::RoundingPolicy;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DurationSpecBuilder::new()
+    ///     .set_type(DurationType::DayToSecond)
+    ///     .set_resolution(DurationResolution::Minute)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut var = Variable::new(&spec);
+    /// let duration_mut = var.day_to_second_duration_mut();
+    ///
+    /// duration_mut
+    ///     .set_duration_with_rounding(0, -1, -30, -30, 0, 0, 0, RoundingPolicy::RoundHalfUp)
+    ///     .unwrap();
+    /// assert_eq!(duration_mut.to_string(), "-PT1H31M");
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_duration_with_rounding(
+        &mut self,
+        days: i32,
+        hours: i32,
+        minutes: i32,
+        seconds: i32,
+        milliseconds: i32,
+        microseconds: i32,
+        nanoseconds: i32,
+        rounding_policy: RoundingPolicy,
+    ) -> Result<(), DayToSecondDurationError> {
+        let duration = (
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        );
+        if let Some(resolution) = self.spec().resolution() {
+            let total_nanoseconds = to_total_nanoseconds(duration);
+            // only the total must be a whole number of units, so that 120 minutes can be set
+            // with an Hour resolution
+            if rounding_policy == RoundingPolicy::Reject
+                && total_nanoseconds % nanoseconds_per_unit(resolution) != 0
+            {
+                let fields = [
+                    (hours, "hours", DurationResolution::Hour),
+                    (minutes, "minutes", DurationResolution::Minute),
+                    (seconds, "seconds", DurationResolution::Second),
+                    (
+                        milliseconds,
+                        "milliseconds",
+                        DurationResolution::Millisecond,
+                    ),
+                    (
+                        microseconds,
+                        "microseconds",
+                        DurationResolution::Microsecond,
+                    ),
+                    (nanoseconds, "nanoseconds", DurationResolution::Nanosecond),
+                ];
+                for (value, name, field_resolution) in fields {
+                    if value != 0 && field_resolution > *resolution {
+                        return Err(DayToSecondDurationError::ResolutionOutOfBounds(format!(
+                            "Cannot set {} with value {} on a day-to-second-duration with a {} resolution.",
+                            name, value, resolution
+                        )));
+                    }
+                }
+            }
+            return self.set_total_nanoseconds_with_rounding(total_nanoseconds, rounding_policy);
+        }
+        check_range(to_total_nanoseconds(duration))?;
+        self.adaptor.set_duration(
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        )
+    }
+
+    /// Sets the duration from an ISO 8601 duration string, either in the designator format
+    /// (PnDTnHnMnS) or (PnW), or in the alternative format (PYYYY-MM-DDThh:mm:ss) with zero
+    /// years and months. The string may be preceded by a sign, and the last component may
+    /// have a fraction after a '.' or ','.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::duration_spec_builder::DurationSpecBuilder;
+    /// use data::primitive_specs::duration_spec::DurationType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DurationSpecBuilder::new()
+    ///     .set_type(DurationType::DayToSecond)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut var = Variable::new(&spec);
+    /// let duration_mut = var.day_to_second_duration_mut();
+    ///
+    /// duration_mut.set_from_string("P3DT4H5M6.789S").unwrap();
+    /// assert_eq!(duration_mut.duration().unwrap(), (3, 4, 5, 6, 789, 0, 0));
+    /// assert_eq!(duration_mut.to_string(), "P3DT4H5M6.789S");
+    /// ```
+    pub fn set_from_string(&mut self, duration_str: &str) -> Result<(), DayToSecondDurationError> {
+        crate::accessors::temporal::set_from_strings::set_duration_from_string::set_day_to_second_duration_from_string(
+            self,
+            duration_str,
+        )
+    }
+
+    /// Returns the duration as a tuple of (days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+    pub fn duration(&self) -> Result<DayToSecondFields, DayToSecondDurationError> {
+        self.adaptor.duration()
+    }
+
+    /// Returns the number of days in the duration.
+    pub fn days(&self) -> Result<i32, DayToSecondDurationError> {
+        self.adaptor.days()
+    }
+
+    /// Returns the number of hours in the duration.
+    pub fn hours(&self) -> Result<i32, DayToSecondDurationError> {
+        self.adaptor.hours()
+    }
+
+    /// Returns the number of minutes in the duration.
+    pub fn minutes(&self) -> Result<i32, DayToSecondDurationError> {
+        self.adaptor.minutes()
+    }
+
+    /// Returns the number of seconds in the duration.
+    pub fn seconds(&self) -> Result<i32, DayToSecondDurationError> {
+        self.adaptor.seconds()
+    }
+
+    /// Returns the number of milliseconds in the duration.
+    pub fn milliseconds(&self) -> Result<i32, DayToSecondDurationError> {
+        self.adaptor.milliseconds()
+    }
+
+    /// Returns the number of microseconds in the duration.
+    pub fn microseconds(&self) -> Result<i32, DayToSecondDurationError> {
+        self.adaptor.microseconds()
+    }
+
+    /// Returns the number of nanoseconds in the duration.
+    pub fn nanoseconds(&self) -> Result<i32, DayToSecondDurationError> {
+        self.adaptor.nanoseconds()
+    }
+
+    /// Returns the duration in total nanoseconds.
+    pub fn total_nanoseconds(&self) -> Result<i128, DayToSecondDurationError> {
+        if self.adaptor.stores_duration_as_nanoseconds() {
+            self.adaptor.total_nanoseconds()
+        } else {
+            Ok(to_total_nanoseconds(self.duration()?))
+        }
+    }
+
+    /// Sets the duration in total nanoseconds.
+    /// Fails if the duration is finer than the spec's resolution and the spec's rounding policy
+    /// is `Reject`, or if its days would overflow.
+    pub fn set_total_nanoseconds(
+        &mut self,
+        nanoseconds: i128,
+    ) -> Result<(), DayToSecondDurationError> {
+        self.set_total_nanoseconds_with_rounding(nanoseconds, *self.spec().rounding_policy())
+    }
+
+    /// Sets the duration in total nanoseconds, handling a duration finer than the spec's
+    /// resolution with the given rounding policy instead of the spec's.
+    pub fn set_total_nanoseconds_with_rounding(
+        &mut self,
+        mut nanoseconds: i128,
+        rounding_policy: RoundingPolicy,
+    ) -> Result<(), DayToSecondDurationError> {
+        if let Some(resolution) = self.spec().resolution() {
+            nanoseconds = rounding_policy
+                .round(nanoseconds, nanoseconds_per_unit(resolution))
+                .ok_or_else(|| {
+                    DayToSecondDurationError::ResolutionOutOfBounds(format!(
+                        "Cannot set {} nanoseconds on a day-to-second-duration with a {} resolution.",
+                        nanoseconds, resolution
+                    ))
+                })?;
+        }
+        check_range(nanoseconds)?;
+        if self.adaptor.stores_duration_as_nanoseconds() {
+            self.adaptor.set_total_nanoseconds(nanoseconds)
+        } else {
+            let (days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds) =
+                from_total_nanoseconds(nanoseconds)?;
+            self.adaptor.set_duration(
+                days,
+                hours,
+                minutes,
+                seconds,
+                milliseconds,
+                microseconds,
+                nanoseconds,
+            )
+        }
+    }
+
+    /// Sets the duration from a `std::time::Duration`.
+    /// Fails if the duration is finer than the spec's resolution and the spec's rounding policy
+    /// is `Reject`, or if its days would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use data::data_spec_builders::duration_spec_builder::DurationSpecBuilder;
+    /// use data::primitive_specs::duration_spec::DurationType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DurationSpecBuilder::new()
+    ///     .set_type(DurationType::DayToSecond)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut var = Variable::new(&spec);
+    /// let duration_mut = var.day_to_second_duration_mut();
+    ///
+    /// duration_mut.set_from_std_duration(&Duration::from_millis(90_061_500)).unwrap();
+    /// assert_eq!(duration_mut.to_string(), "P1DT1H1M1.5S");
+    /// assert_eq!(duration_mut.std_duration().unwrap(), Duration::from_millis(90_061_500));
+    /// ```
+    pub fn set_from_std_duration(
+        &mut self,
+        duration: &Duration,
+    ) -> Result<(), DayToSecondDurationError> {
+        self.set_total_nanoseconds(duration.as_nanos() as i128)
+    }
+
+    /// Returns the duration as a `std::time::Duration`.
+    /// Fails if the duration is negative, which a `std::time::Duration` cannot represent.
+    pub fn std_duration(&self) -> Result<Duration, DayToSecondDurationError> {
+        let nanoseconds = self.total_nanoseconds()?;
+        if nanoseconds < 0 {
+            return Err(DayToSecondDurationError::Overflow(format!(
+                "A negative duration of {} nanoseconds cannot be converted to a std::time::Duration.",
+                nanoseconds
+            )));
+        }
+        Ok(Duration::new(
+            (nanoseconds / NANOSECONDS_PER_SECOND) as u64,
+            (nanoseconds % NANOSECONDS_PER_SECOND) as u32,
+        ))
+    }
+
+    /// Negates the duration.
+    pub fn negate(&mut self) -> Result<(), DayToSecondDurationError> {
+        let nanoseconds = self.total_nanoseconds()?;
+        self.set_total_nanoseconds(-nanoseconds)
+    }
+
+    /// Adds the other duration to this duration.
+    /// Fails if the sum is finer than the spec's resolution or if its days would overflow.
+    pub fn add(&mut self, other: &DayToSecondDuration) -> Result<(), DayToSecondDurationError> {
+        let nanoseconds = self.total_nanoseconds()? + other.total_nanoseconds()?;
+        self.set_total_nanoseconds(nanoseconds)
+    }
+
+    /// Subtracts the other duration from this duration.
+    /// Fails if the difference is finer than the spec's resolution or if its days would overflow.
+    pub fn subtract(
+        &mut self,
+        other: &DayToSecondDuration,
+    ) -> Result<(), DayToSecondDurationError> {
+        let nanoseconds = self.total_nanoseconds()? - other.total_nanoseconds()?;
+        self.set_total_nanoseconds(nanoseconds)
+    }
 }
 
-impl SetEqualTo for DayToSecondDuration {
-    fn set_equal_to(&mut self, _other: &Self) -> Result<(), SetEqualToError> {
-        // Implement the logic to set this DayToSecondDuration equal to another DayToSecondDuration.
-        todo!("Implement set_equal_to for DayToSecondDuration");
+/// Returns the total nanoseconds of a duration's fields.
+pub(crate) fn to_total_nanoseconds(duration: DayToSecondFields) -> i128 {
+    let (days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds) = duration;
+    days as i128 * NANOSECONDS_PER_DAY
+        + hours as i128 * NANOSECONDS_PER_HOUR
+        + minutes as i128 * NANOSECONDS_PER_MINUTE
+        + seconds as i128 * NANOSECONDS_PER_SECOND
+        + milliseconds as i128 * NANOSECONDS_PER_MILLISECOND
+        + microseconds as i128 * NANOSECONDS_PER_MICROSECOND
+        + nanoseconds as i128
+}
+
+/// Returns the normalized fields of a duration in total nanoseconds.
+pub(crate) fn from_total_nanoseconds(
+    nanoseconds: i128,
+) -> Result<DayToSecondFields, DayToSecondDurationError> {
+    check_range(nanoseconds)?;
+    Ok((
+        (nanoseconds / NANOSECONDS_PER_DAY) as i32,
+        (nanoseconds % NANOSECONDS_PER_DAY / NANOSECONDS_PER_HOUR) as i32,
+        (nanoseconds % NANOSECONDS_PER_HOUR / NANOSECONDS_PER_MINUTE) as i32,
+        (nanoseconds % NANOSECONDS_PER_MINUTE / NANOSECONDS_PER_SECOND) as i32,
+        (nanoseconds % NANOSECONDS_PER_SECOND / NANOSECONDS_PER_MILLISECOND) as i32,
+        (nanoseconds % NANOSECONDS_PER_MILLISECOND / NANOSECONDS_PER_MICROSECOND) as i32,
+        (nanoseconds % NANOSECONDS_PER_MICROSECOND) as i32,
+    ))
+}
+
+/// Checks that the days of a duration in total nanoseconds fit into an `i32`.
+fn check_range(nanoseconds: i128) -> Result<(), DayToSecondDurationError> {
+    if i32::try_from(nanoseconds / NANOSECONDS_PER_DAY).is_err() {
+        return Err(DayToSecondDurationError::Overflow(format!(
+            "A duration of {} nanoseconds exceeds the range of a day-to-second-duration.",
+            nanoseconds
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the number of nanoseconds in one unit of the resolution.
+fn nanoseconds_per_unit(resolution: &DurationResolution) -> i128 {
+    match resolution {
+        DurationResolution::Year | DurationResolution::Month | DurationResolution::Day => {
+            NANOSECONDS_PER_DAY
+        }
+        DurationResolution::Hour => NANOSECONDS_PER_HOUR,
+        DurationResolution::Minute => NANOSECONDS_PER_MINUTE,
+        DurationResolution::Second => NANOSECONDS_PER_SECOND,
+        DurationResolution::Millisecond => NANOSECONDS_PER_MILLISECOND,
+        DurationResolution::Microsecond => NANOSECONDS_PER_MICROSECOND,
+        DurationResolution::Nanosecond => 1,
     }
 }
 
 impl Accessor for DayToSecondDuration {}
 
-/// Errors that can occur when working with DayToSecondDuration accessors.
+impl Display for DayToSecondDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let total = self.total_nanoseconds().unwrap();
+        let (days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds) =
+            from_total_nanoseconds(total.abs()).unwrap();
+        let resolution = self
+            .spec()
+            .resolution()
+            .unwrap_or(DurationResolution::Nanosecond);
+        let mut text = String::new();
+        if days != 0 {
+            text.push_str(&format!("{}D", days));
+        }
+        let mut time_text = String::new();
+        if hours != 0 {
+            time_text.push_str(&format!("{}H", hours));
+        }
+        if minutes != 0 {
+            time_text.push_str(&format!("{}M", minutes));
+        }
+        let fraction = milliseconds as i128 * NANOSECONDS_PER_MILLISECOND
+            + microseconds as i128 * NANOSECONDS_PER_MICROSECOND
+            + nanoseconds as i128;
+        if seconds != 0 || fraction != 0 {
+            let fraction_text = match self.spec().resolution() {
+                Some(DurationResolution::Millisecond) => format!(".{:03}", fraction / 1_000_000),
+                Some(DurationResolution::Microsecond) => format!(".{:06}", fraction / 1_000),
+                Some(DurationResolution::Nanosecond) => format!(".{:09}", fraction),
+                _ => format!(".{:09}", fraction)
+                    .trim_end_matches('0')
+                    .to_string(),
+            };
+            let fraction_text = if fraction == 0 { "" } else { &fraction_text };
+            time_text.push_str(&format!("{}{}S", seconds, fraction_text));
+        }
+        if !time_text.is_empty() {
+            text.push('T');
+            text.push_str(&time_text);
+        }
+        if text.is_empty() {
+            text.push_str(match resolution {
+                DurationResolution::Hour => "T0H",
+                DurationResolution::Minute => "T0M",
+                DurationResolution::Year | DurationResolution::Month | DurationResolution::Day => {
+                    "0D"
+                }
+                _ => "T0S",
+            });
+        }
+        let sign = if total < 0 { "-" } else { "" };
+        write!(f, "{}P{}", sign, text)
+    }
+}
+
+impl Debug for DayToSecondDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds) =
+            self.duration().unwrap();
+        write!(
+            f,
+            "DayToSecondDuration {{ days: {}, hours: {}, minutes: {}, seconds: {}, milliseconds: {}, microseconds: {}, nanoseconds: {} }}",
+            days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds
+        )
+    }
+}
+
+impl SetEqualTo for DayToSecondDuration {
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        let nanoseconds = other.total_nanoseconds()?;
+        self.set_total_nanoseconds(nanoseconds)?;
+        Ok(())
+    }
+}
+
+impl PartialEq for DayToSecondDuration {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_nanoseconds().unwrap() == other.total_nanoseconds().unwrap()
+    }
+}
+
+impl Eq for DayToSecondDuration {}
+
+impl PartialOrd for DayToSecondDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DayToSecondDuration {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.total_nanoseconds()
+            .unwrap()
+            .cmp(&other.total_nanoseconds().unwrap())
+    }
+}
+
+impl Hash for DayToSecondDuration {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.total_nanoseconds().unwrap().hash(state);
+    }
+}
+
+/// Errors that can occur when working with day-second durations.
+#[derive(Debug, PartialEq)]
 pub enum DayToSecondDurationError {
     /// A provider error.
     ProviderError(ProviderError),
+    /// Indicates that the resolution is out of bounds.
+    ResolutionOutOfBounds(String),
+    /// Indicates that a duration exceeds the range of a day-to-second-duration.
+    Overflow(String),
+    /// Indicates an invalid format error.
+    InvalidFormat(String),
+}
+
+impl Display for DayToSecondDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayToSecondDurationError::ProviderError(err) => write!(f, "Provider error: {}", err),
+            DayToSecondDurationError::ResolutionOutOfBounds(msg) => {
+                write!(f, "Resolution out of bounds: {}", msg)
+            }
+            DayToSecondDurationError::Overflow(msg) => write!(f, "Overflow: {}", msg),
+            DayToSecondDurationError::InvalidFormat(s) => write!(f, "Invalid format: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for DayToSecondDurationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DayToSecondDurationError::ProviderError(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl From<ProviderError> for DayToSecondDurationError {
@@ -45,3 +557,9 @@ impl From<ProviderError> for DayToSecondDurationError {
         DayToSecondDurationError::ProviderError(error)
     }
 }
+
+impl From<DayToSecondDurationError> for SetEqualToError {
+    fn from(error: DayToSecondDurationError) -> Self {
+        SetEqualToError::DayToSecondDurationError(error)
+    }
+}
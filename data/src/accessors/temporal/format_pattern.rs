@@ -0,0 +1,719 @@
+use std::{
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+use crate::accessors::temporal::{
+    date::{DateError, DayOfWeek, day_of_week_from_signed_days, days_in_month, is_leap_year},
+    date_time::DateTimeError,
+    locale::Locale,
+    time::TimeError,
+    zoned_date_time::ZonedDateTimeError,
+    zoned_time::ZonedTimeError,
+};
+use crate::adaptors::temporal_adaptors::date_adaptor::{
+    is_proleptic_leap_year, signed_days_from_date,
+};
+
+/// The pattern of RFC 3339 date-times, such as `2024-06-15T12:34:56.789+02:00`.
+pub const RFC_3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
+
+/// The pattern of RFC 2822 date-times, such as `Sat, 15 Jun 2024 12:34:56 +0200`.
+pub const RFC_2822: &str = "%a, %d %b %Y %H:%M:%S %z";
+
+/// A pattern of strftime-like tokens and literal text for formatting and parsing dates, times,
+/// date-times and their zoned variants.
+/// <p>
+/// The supported tokens are:
+/// </p>
+///
+/// | Token | Meaning                                                                 |
+/// |-------|-------------------------------------------------------------------------|
+/// | `%Y`  | The year as four digits, such as `2024`, or with a sign and six digits, such as `-000043`, if it is not from 0 to 9999. |
+/// | `%m`  | The month as two digits, `01` to `12`.                                  |
+/// | `%d`  | The day of the month as two digits, `01` to `31`.                       |
+/// | `%j`  | The day of the year as three digits, `001` to `366`.                    |
+/// | `%A`  | The name of the day of the week, such as `Saturday`.                    |
+/// | `%a`  | The abbreviated name of the day of the week, such as `Sat`.             |
+/// | `%B`  | The name of the month, such as `June`.                                  |
+/// | `%b`  | The abbreviated name of the month, such as `Jun`.                       |
+/// | `%H`  | The hour as two digits, `00` to `23`.                                   |
+/// | `%M`  | The minute as two digits, `00` to `59`.                                 |
+/// | `%S`  | The second as two digits, `00` to `59`.                                 |
+/// | `%f`  | The fraction of the second as nine digits, such as `789000000`.         |
+/// | `%.f` | A `.` and the fraction of the second without trailing zeros, or nothing if the fraction is zero. |
+/// | `%z`  | The offset from UTC as `±hhmm`, such as `+0200`.                        |
+/// | `%:z` | The offset from UTC as `±hh:mm`, such as `+02:00`; `Z` is also parsed.  |
+/// | `%%`  | A literal `%`.                                                          |
+///
+/// <p>
+/// Any other text is literal and must match exactly when parsing. When parsing, numbers may
+/// have fewer digits than they are formatted with, years are parsed from 0 to 9999, names are
+/// matched regardless of case, and a day of the week must agree with the date. Positions in
+/// errors are byte offsets into the pattern or the parsed string.
+/// </p>
+/// <p>
+/// Names are those of the pattern's [`Locale`], which is English unless set with
+/// [`FormatPattern::with_locale`].
+/// </p>
+///
+/// # Examples
+///
+/// ```rust
+/// use data::accessors::temporal::format_pattern::FormatPattern;
+/// use data::accessors::temporal::locale::Locale;
+/// use data::data_spec_builders::date_spec_builder::DateSpecBuilder;
+/// use data::variable::Variable;
+///
+/// let pattern = FormatPattern::new("%a %d %b %Y").unwrap();
+/// let mut var = Variable::new(&DateSpecBuilder::new().build());
+///
+/// var.date_mut().set_from_format("sat 15 jun 2024", &pattern).unwrap();
+/// assert_eq!(var.date().date().unwrap(), (2024, 6, 15));
+/// assert_eq!(var.date().format(&pattern).unwrap(), "Sat 15 Jun 2024");
+///
+/// let pattern = FormatPattern::new("%A %d %B %Y")
+///     .unwrap()
+///     .with_locale(Locale::get("fr").unwrap());
+/// assert_eq!(var.date().format(&pattern).unwrap(), "samedi 15 juin 2024");
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct FormatPattern {
+    pattern: String,
+    items: Vec<(usize, Item)>,
+    locale: Rc<Locale>,
+}
+
+/// A token or literal text of a format pattern.
+#[derive(Clone, Debug, PartialEq)]
+enum Item {
+    Literal(String),
+    Year,
+    Month,
+    Day,
+    DayOfYear,
+    DayOfWeekName,
+    AbbreviatedDayOfWeekName,
+    MonthName,
+    AbbreviatedMonthName,
+    Hour,
+    Minute,
+    Second,
+    Nanosecond,
+    Fraction,
+    Offset,
+    OffsetWithColon,
+}
+
+impl Item {
+    /// Returns the kind of value that the item needs to be formatted, if any.
+    fn needs(&self) -> Option<&'static str> {
+        match self {
+            Item::Literal(_) => None,
+            Item::Year
+            | Item::Month
+            | Item::Day
+            | Item::DayOfYear
+            | Item::DayOfWeekName
+            | Item::AbbreviatedDayOfWeekName
+            | Item::MonthName
+            | Item::AbbreviatedMonthName => Some("date"),
+            Item::Hour | Item::Minute | Item::Second | Item::Nanosecond | Item::Fraction => {
+                Some("time")
+            }
+            Item::Offset | Item::OffsetWithColon => Some("UTC offset"),
+        }
+    }
+}
+
+impl FormatPattern {
+    /// Creates a format pattern from a string of tokens and literal text.
+    /// Fails with the position of the first token that is not supported.
+    pub fn new(pattern: &str) -> Result<FormatPattern, FormatError> {
+        let mut items: Vec<(usize, Item)> = Vec::new();
+        let mut chars = pattern.char_indices();
+        while let Some((position, c)) = chars.next() {
+            if c != '%' {
+                match items.last_mut() {
+                    Some((_, Item::Literal(literal))) => literal.push(c),
+                    _ => items.push((position, Item::Literal(c.to_string()))),
+                }
+                continue;
+            }
+            let mut token = String::from('%');
+            let mut next = || {
+                let (_, c) = chars.next()?;
+                token.push(c);
+                Some(c)
+            };
+            let item = match next() {
+                Some('Y') => Item::Year,
+                Some('m') => Item::Month,
+                Some('d') => Item::Day,
+                Some('j') => Item::DayOfYear,
+                Some('A') => Item::DayOfWeekName,
+                Some('a') => Item::AbbreviatedDayOfWeekName,
+                Some('B') => Item::MonthName,
+                Some('b') => Item::AbbreviatedMonthName,
+                Some('H') => Item::Hour,
+                Some('M') => Item::Minute,
+                Some('S') => Item::Second,
+                Some('f') => Item::Nanosecond,
+                Some('z') => Item::Offset,
+                Some('.') if next() == Some('f') => Item::Fraction,
+                Some(':') if next() == Some('z') => Item::OffsetWithColon,
+                Some('%') => match items.last_mut() {
+                    Some((_, Item::Literal(literal))) => {
+                        literal.push('%');
+                        continue;
+                    }
+                    _ => Item::Literal("%".to_string()),
+                },
+                _ => {
+                    return Err(FormatError::InvalidPattern(
+                        position,
+                        format!("The token '{}' is not supported.", token),
+                    ));
+                }
+            };
+            items.push((position, item));
+        }
+        Ok(FormatPattern {
+            pattern: pattern.to_string(),
+            items,
+            locale: Locale::english(),
+        })
+    }
+
+    /// Returns the format pattern with the names of months and days of the week of the given
+    /// locale.
+    pub fn with_locale(mut self, locale: Rc<Locale>) -> FormatPattern {
+        self.locale = locale;
+        self
+    }
+
+    /// Returns the RFC 3339 format pattern, [`RFC_3339`].
+    pub fn rfc_3339() -> FormatPattern {
+        Self::new(RFC_3339).unwrap()
+    }
+
+    /// Returns the RFC 2822 format pattern, [`RFC_2822`].
+    pub fn rfc_2822() -> FormatPattern {
+        Self::new(RFC_2822).unwrap()
+    }
+
+    /// Returns the pattern's string of tokens and literal text.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the locale of the names of months and days of the week.
+    pub fn locale(&self) -> &Rc<Locale> {
+        &self.locale
+    }
+
+    /// Formats the given fields. Fails with the position of the first token whose field is
+    /// not given.
+    pub(crate) fn format(&self, fields: &PatternFields) -> Result<String, FormatError> {
+        let mut result = String::new();
+        for (position, item) in &self.items {
+            let unsupported = || {
+                FormatError::UnsupportedToken(
+                    *position,
+                    format!(
+                        "The token needs a {}, which the value does not have.",
+                        item.needs().unwrap_or_default()
+                    ),
+                )
+            };
+            let date = || fields.date.ok_or_else(unsupported);
+            let time = || fields.time.ok_or_else(unsupported);
+            let offset = || fields.offset_seconds.ok_or_else(unsupported);
+            match item {
+                Item::Literal(literal) => result.push_str(literal),
+                Item::Year => {
+                    let year = date()?.0;
+                    if (0..=9999).contains(&year) {
+                        result.push_str(&format!("{:04}", year));
+                    } else {
+                        // the expanded ISO 8601 representation with a sign and six digits
+                        result.push_str(&format!("{:+07}", year));
+                    }
+                }
+                Item::Month => result.push_str(&format!("{:02}", date()?.1)),
+                Item::Day => result.push_str(&format!("{:02}", date()?.2)),
+                Item::DayOfYear => result.push_str(&format!("{:03}", day_of_year(date()?))),
+                Item::DayOfWeekName => {
+                    result.push_str(self.locale.day_of_week_name(day_of_week(date()?)));
+                }
+                Item::AbbreviatedDayOfWeekName => {
+                    let day_of_week = day_of_week(date()?);
+                    result.push_str(self.locale.abbreviated_day_of_week_name(day_of_week));
+                }
+                Item::MonthName => {
+                    let month = date()?.1;
+                    result.push_str(self.locale.month_name(month).unwrap_or_default());
+                }
+                Item::AbbreviatedMonthName => {
+                    let month = date()?.1;
+                    result.push_str(
+                        self.locale
+                            .abbreviated_month_name(month)
+                            .unwrap_or_default(),
+                    );
+                }
+                Item::Hour => result.push_str(&format!("{:02}", time()?.0)),
+                Item::Minute => result.push_str(&format!("{:02}", time()?.1)),
+                Item::Second => result.push_str(&format!("{:02}", time()?.2)),
+                Item::Nanosecond => result.push_str(&format!("{:09}", nanoseconds(time()?))),
+                Item::Fraction => {
+                    let nanoseconds = nanoseconds(time()?);
+                    if nanoseconds != 0 {
+                        let fraction = format!("{:09}", nanoseconds);
+                        result.push('.');
+                        result.push_str(fraction.trim_end_matches('0'));
+                    }
+                }
+                Item::Offset => result.push_str(&format_offset(offset()?, "")),
+                Item::OffsetWithColon => result.push_str(&format_offset(offset()?, ":")),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Parses the given string into fields. Fails with the position in the string at which it
+    /// does not match the pattern.
+    pub(crate) fn parse(&self, value: &str) -> Result<PatternFields, FormatError> {
+        let mut parser = Parser {
+            value,
+            position: 0,
+            locale: &self.locale,
+            parsed: Parsed::default(),
+        };
+        for (_, item) in &self.items {
+            parser.item(item)?;
+        }
+        if parser.position < value.len() {
+            return Err(parser.error(format!(
+                "Unexpected text '{}' after the end of the pattern.",
+                &value[parser.position..]
+            )));
+        }
+        parser.parsed.fields()
+    }
+}
+
+impl Display for FormatPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+impl Debug for FormatPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FormatPattern {{ pattern: {} }}", self.pattern)
+    }
+}
+
+/// The fields that a format pattern formats or parses: a date as (year, month, day) of the
+/// proleptic Gregorian calendar, a time as
+/// (hours, minutes, seconds, milliseconds, microseconds, nanoseconds) and an offset from UTC
+/// in seconds.
+#[derive(Default)]
+pub(crate) struct PatternFields {
+    pub(crate) date: Option<(i32, u32, u32)>,
+    pub(crate) time: Option<(u8, u8, u8, u16, u16, u16)>,
+    pub(crate) offset_seconds: Option<i32>,
+}
+
+impl PatternFields {
+    /// Returns the date, or an error if the pattern has no date.
+    pub(crate) fn date(&self) -> Result<(i32, u32, u32), FormatError> {
+        self.date
+            .ok_or_else(|| missing("a date (%Y with %m and %d, or with %j)"))
+    }
+
+    /// Returns the time, or an error if the pattern has no time.
+    pub(crate) fn time(&self) -> Result<(u8, u8, u8, u16, u16, u16), FormatError> {
+        self.time.ok_or_else(|| missing("a time (%H with %M)"))
+    }
+
+    /// Returns the offset from UTC in seconds, or an error if the pattern has no offset.
+    pub(crate) fn offset_seconds(&self) -> Result<i32, FormatError> {
+        self.offset_seconds
+            .ok_or_else(|| missing("a UTC offset (%z or %:z)"))
+    }
+}
+
+fn missing(field: &str) -> FormatError {
+    FormatError::MissingField(format!("The pattern does not contain {}.", field))
+}
+
+/// The fields parsed from a string, with the positions of those that are checked after parsing.
+#[derive(Default)]
+struct Parsed {
+    year: Option<u32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    day_of_year: Option<(usize, u32)>,
+    day_of_week: Option<(usize, DayOfWeek)>,
+    hour: Option<u32>,
+    minute: Option<u32>,
+    second: Option<u32>,
+    nanosecond: Option<u32>,
+    offset_seconds: Option<i32>,
+}
+
+impl Parsed {
+    fn fields(self) -> Result<PatternFields, FormatError> {
+        let date = match (self.year, self.month, self.day, self.day_of_year) {
+            (Some(year), Some(month), Some(day), _) => Some((year as i32, month, day)),
+            (Some(year), _, _, Some((position, day_of_year))) => {
+                let (year, month, day) =
+                    date_from_day_of_year(year, day_of_year).ok_or_else(|| {
+                        FormatError::ParseError(
+                            position,
+                            format!(
+                                "Day of the year {} is out of bounds for year {}.",
+                                day_of_year, year
+                            ),
+                        )
+                    })?;
+                Some((year as i32, month, day))
+            }
+            _ => None,
+        };
+        if let (Some(date), Some((position, expected))) = (date, self.day_of_week) {
+            let (year, month, day) = date;
+            if (1..=12).contains(&month)
+                && (1..=days_in_month(is_proleptic_leap_year(year as i64), month)).contains(&day)
+                && day_of_week(date) != expected
+            {
+                return Err(FormatError::ParseError(
+                    position,
+                    format!(
+                        "The day of the week {} does not match the date {:04}-{:02}-{:02}.",
+                        expected, year, month, day
+                    ),
+                ));
+            }
+        }
+        let time = match (self.hour, self.minute) {
+            (Some(hour), Some(minute)) => {
+                let nanosecond = self.nanosecond.unwrap_or(0);
+                Some((
+                    hour as u8,
+                    minute as u8,
+                    self.second.unwrap_or(0) as u8,
+                    (nanosecond / 1_000_000) as u16,
+                    (nanosecond / 1_000 % 1_000) as u16,
+                    (nanosecond % 1_000) as u16,
+                ))
+            }
+            _ => None,
+        };
+        Ok(PatternFields {
+            date,
+            time,
+            offset_seconds: self.offset_seconds,
+        })
+    }
+}
+
+/// A parser of a string according to the items of a format pattern.
+struct Parser<'a> {
+    value: &'a str,
+    position: usize,
+    locale: &'a Locale,
+    parsed: Parsed,
+}
+
+impl Parser<'_> {
+    fn item(&mut self, item: &Item) -> Result<(), FormatError> {
+        let position = self.position;
+        match item {
+            Item::Literal(literal) => {
+                if !self.value[self.position..].starts_with(literal.as_str()) {
+                    return Err(self.error(format!("Expected '{}'.", literal)));
+                }
+                self.position += literal.len();
+            }
+            Item::Year => self.parsed.year = Some(self.number(4, 4, "year")?),
+            Item::Month => self.parsed.month = Some(self.number(1, 2, "month")?),
+            Item::Day => self.parsed.day = Some(self.number(1, 2, "day")?),
+            Item::DayOfYear => {
+                self.parsed.day_of_year = Some((position, self.number(1, 3, "day of the year")?));
+            }
+            Item::DayOfWeekName => {
+                let names = self.locale.day_of_week_names();
+                let index = self.name(names, "name of the day of the week")?;
+                self.parsed.day_of_week = Some((position, DayOfWeek::from_index(index).unwrap()));
+            }
+            Item::AbbreviatedDayOfWeekName => {
+                let names = self.locale.abbreviated_day_of_week_names();
+                let index = self.name(names, "abbreviated name of the day of the week")?;
+                self.parsed.day_of_week = Some((position, DayOfWeek::from_index(index).unwrap()));
+            }
+            Item::MonthName => {
+                let names = self.locale.month_names();
+                self.parsed.month = Some(self.name(names, "name of the month")? as u32 + 1);
+            }
+            Item::AbbreviatedMonthName => {
+                let names = self.locale.abbreviated_month_names();
+                let index = self.name(names, "abbreviated name of the month")?;
+                self.parsed.month = Some(index as u32 + 1);
+            }
+            Item::Hour => self.parsed.hour = Some(self.number(1, 2, "hour")?),
+            Item::Minute => self.parsed.minute = Some(self.number(1, 2, "minute")?),
+            Item::Second => self.parsed.second = Some(self.number(1, 2, "second")?),
+            Item::Nanosecond => self.parsed.nanosecond = Some(self.fraction()?),
+            Item::Fraction => {
+                if self.value[self.position..].starts_with('.') {
+                    self.position += 1;
+                    self.parsed.nanosecond = Some(self.fraction()?);
+                }
+            }
+            Item::Offset => self.parsed.offset_seconds = Some(self.offset(false)?),
+            Item::OffsetWithColon => self.parsed.offset_seconds = Some(self.offset(true)?),
+        }
+        Ok(())
+    }
+
+    /// Parses a number of `min` to `max` digits.
+    fn number(&mut self, min: usize, max: usize, field: &str) -> Result<u32, FormatError> {
+        let digits = self.value[self.position..]
+            .bytes()
+            .take(max)
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if digits < min {
+            return Err(self.error(format!(
+                "Expected the {} as {} to {} digits.",
+                field, min, max
+            )));
+        }
+        let number = self.value[self.position..self.position + digits]
+            .parse()
+            .unwrap();
+        self.position += digits;
+        Ok(number)
+    }
+
+    /// Parses one to nine digits of a fraction of a second, in nanoseconds.
+    fn fraction(&mut self) -> Result<u32, FormatError> {
+        let start = self.position;
+        let fraction = self.number(1, 9, "fraction of the second")?;
+        Ok(fraction * 10u32.pow(9 - (self.position - start) as u32))
+    }
+
+    /// Parses the longest of the given names that the rest of the value starts with, regardless
+    /// of case, returning its index.
+    fn name(&mut self, names: &[String], field: &str) -> Result<usize, FormatError> {
+        let rest = &self.value[self.position..];
+        let Some((index, length)) = names
+            .iter()
+            .enumerate()
+            .filter_map(|(index, name)| Some((index, prefix_length(rest, name)?)))
+            .max_by_key(|(_, length)| *length)
+        else {
+            return Err(self.error(format!("Expected the {}.", field)));
+        };
+        self.position += length;
+        Ok(index)
+    }
+
+    /// Parses an offset from UTC as `±hhmm`, or as `±hh:mm` or `Z` if `with_colon`.
+    fn offset(&mut self, with_colon: bool) -> Result<i32, FormatError> {
+        let rest = &self.value[self.position..];
+        if with_colon && rest.starts_with(['Z', 'z']) {
+            self.position += 1;
+            return Ok(0);
+        }
+        let sign = match rest.chars().next() {
+            Some('+') => 1,
+            Some('-') => -1,
+            _ => return Err(self.error("Expected the sign of the UTC offset.".to_string())),
+        };
+        self.position += 1;
+        let hours = self.number(2, 2, "hours of the UTC offset")?;
+        if with_colon {
+            if !self.value[self.position..].starts_with(':') {
+                return Err(self.error("Expected ':'.".to_string()));
+            }
+            self.position += 1;
+        }
+        let position = self.position;
+        let minutes = self.number(2, 2, "minutes of the UTC offset")?;
+        if minutes >= 60 {
+            return Err(FormatError::ParseError(
+                position,
+                format!(
+                    "Minutes {} of the UTC offset are out of bounds (0 to 59).",
+                    minutes
+                ),
+            ));
+        }
+        Ok(sign * (hours * 3_600 + minutes * 60) as i32)
+    }
+
+    fn error(&self, message: String) -> FormatError {
+        FormatError::ParseError(self.position, message)
+    }
+}
+
+/// Returns the length in bytes of the prefix of the value that matches the name regardless of
+/// case, if any.
+fn prefix_length(value: &str, name: &str) -> Option<usize> {
+    let mut chars = value.char_indices();
+    for expected in name.chars() {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(value.len(), |(position, _)| position))
+}
+
+/// Returns the day of the week of the given (year, month, day).
+fn day_of_week(date: (i32, u32, u32)) -> DayOfWeek {
+    day_of_week_from_signed_days(signed_days_from_date(date.0 as i64, date.1, date.2))
+}
+
+/// Returns the day of the year of the given (year, month, day).
+fn day_of_year(date: (i32, u32, u32)) -> u32 {
+    let year = date.0 as i64;
+    (signed_days_from_date(year, date.1, date.2) - signed_days_from_date(year, 1, 1) + 1) as u32
+}
+
+/// Returns the (year, month, day) of the given day of the year, if it is in the year.
+fn date_from_day_of_year(year: u32, mut day_of_year: u32) -> Option<(u32, u32, u32)> {
+    let is_leap_year = is_leap_year(year);
+    for month in 1..=12 {
+        let days = days_in_month(is_leap_year, month);
+        if (1..=days).contains(&day_of_year) {
+            return Some((year, month, day_of_year));
+        }
+        day_of_year = day_of_year.checked_sub(days)?;
+    }
+    None
+}
+
+/// Returns the total nanoseconds of the fraction of a second of the given time.
+fn nanoseconds(time: (u8, u8, u8, u16, u16, u16)) -> u32 {
+    time.3 as u32 * 1_000_000 + time.4 as u32 * 1_000 + time.5 as u32
+}
+
+/// Formats an offset from UTC in seconds as `±hh<separator>mm`, followed by the seconds if any.
+fn format_offset(offset_seconds: i32, separator: &str) -> String {
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let offset = offset_seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (offset / 3_600, offset / 60 % 60, offset % 60);
+    let mut result = format!("{}{:02}{}{:02}", sign, hours, separator, minutes);
+    if seconds != 0 {
+        result.push_str(&format!("{}{:02}", separator, seconds));
+    }
+    result
+}
+
+/// An error that can occur when formatting or parsing with a format pattern.
+#[derive(Debug, PartialEq)]
+pub enum FormatError {
+    /// Indicates that the pattern has a token that is not supported, at the given position of
+    /// the pattern.
+    InvalidPattern(usize, String),
+    /// Indicates that a token of the pattern cannot be formatted from the value, at the given
+    /// position of the pattern.
+    UnsupportedToken(usize, String),
+    /// Indicates that a string does not match the pattern, at the given position of the string.
+    ParseError(usize, String),
+    /// Indicates that the pattern lacks a field that the value needs.
+    MissingField(String),
+    /// An error of a date.
+    DateError(DateError),
+    /// An error of a time.
+    TimeError(TimeError),
+    /// An error of a date-time.
+    DateTimeError(DateTimeError),
+    /// An error of a zoned time.
+    ZonedTimeError(ZonedTimeError),
+    /// An error of a zoned date-time.
+    ZonedDateTimeError(ZonedDateTimeError),
+}
+
+impl FormatError {
+    /// Returns the position in the pattern or the parsed string at which the error occurred,
+    /// if any.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            FormatError::InvalidPattern(position, _)
+            | FormatError::UnsupportedToken(position, _)
+            | FormatError::ParseError(position, _) => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatError::InvalidPattern(position, msg) => {
+                write!(f, "Invalid pattern at position {}: {}", position, msg)
+            }
+            FormatError::UnsupportedToken(position, msg) => {
+                write!(f, "Unsupported token at position {}: {}", position, msg)
+            }
+            FormatError::ParseError(position, msg) => {
+                write!(f, "Parse error at position {}: {}", position, msg)
+            }
+            FormatError::MissingField(msg) => write!(f, "Missing field: {}", msg),
+            FormatError::DateError(err) => write!(f, "Date error: {}", err),
+            FormatError::TimeError(err) => write!(f, "Time error: {}", err),
+            FormatError::DateTimeError(err) => write!(f, "Date-time error: {}", err),
+            FormatError::ZonedTimeError(err) => write!(f, "Zoned time error: {}", err),
+            FormatError::ZonedDateTimeError(err) => write!(f, "Zoned date-time error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FormatError::DateError(err) => Some(err),
+            FormatError::TimeError(err) => Some(err),
+            FormatError::DateTimeError(err) => Some(err),
+            FormatError::ZonedTimeError(err) => Some(err),
+            FormatError::ZonedDateTimeError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<DateError> for FormatError {
+    fn from(error: DateError) -> Self {
+        FormatError::DateError(error)
+    }
+}
+
+impl From<TimeError> for FormatError {
+    fn from(error: TimeError) -> Self {
+        FormatError::TimeError(error)
+    }
+}
+
+impl From<DateTimeError> for FormatError {
+    fn from(error: DateTimeError) -> Self {
+        FormatError::DateTimeError(error)
+    }
+}
+
+impl From<ZonedTimeError> for FormatError {
+    fn from(error: ZonedTimeError) -> Self {
+        FormatError::ZonedTimeError(error)
+    }
+}
+
+impl From<ZonedDateTimeError> for FormatError {
+    fn from(error: ZonedDateTimeError) -> Self {
+        FormatError::ZonedDateTimeError(error)
+    }
+}
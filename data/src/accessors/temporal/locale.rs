@@ -0,0 +1,445 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+use crate::accessors::temporal::date::DayOfWeek;
+
+thread_local! {
+    /// The built-in English locale, which format patterns use unless given another locale.
+    static ENGLISH: Rc<Locale> = Rc::new(english());
+    /// The locales registered on the current thread by their normalized tags, starting with the
+    /// built-in locales.
+    static LOCALE_REGISTRY: RefCell<HashMap<String, Rc<Locale>>> = RefCell::new(builtin_locales());
+}
+
+/// Returns the built-in English locale.
+fn english() -> Locale {
+    builtin(
+        "en",
+        [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ],
+        ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    )
+}
+
+/// Returns the built-in locales by their tags.
+fn builtin_locales() -> HashMap<String, Rc<Locale>> {
+    let locales = [
+        ENGLISH.with(Rc::clone),
+        Rc::new(builtin(
+            "de",
+            [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            [
+                "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ],
+            [
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+                "Sonntag",
+            ],
+            ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+        )),
+        Rc::new(builtin(
+            "fr",
+            [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+            [
+                "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+            ],
+            ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        )),
+        Rc::new(builtin(
+            "es",
+            [
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+            [
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ],
+            [
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+                "domingo",
+            ],
+            ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+        )),
+        Rc::new(builtin(
+            "nl",
+            [
+                "januari",
+                "februari",
+                "maart",
+                "april",
+                "mei",
+                "juni",
+                "juli",
+                "augustus",
+                "september",
+                "oktober",
+                "november",
+                "december",
+            ],
+            [
+                "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+            ],
+            [
+                "maandag",
+                "dinsdag",
+                "woensdag",
+                "donderdag",
+                "vrijdag",
+                "zaterdag",
+                "zondag",
+            ],
+            ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        )),
+        Rc::new(builtin(
+            "ja",
+            [
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ],
+            [
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ],
+            [
+                "月曜日",
+                "火曜日",
+                "水曜日",
+                "木曜日",
+                "金曜日",
+                "土曜日",
+                "日曜日",
+            ],
+            ["月", "火", "水", "木", "金", "土", "日"],
+        )),
+    ];
+    locales
+        .into_iter()
+        .map(|locale| (locale.tag.clone(), locale))
+        .collect()
+}
+
+/// A locale's full and abbreviated names of the months and the days of the week, which format
+/// patterns use to format and parse `%B`, `%b`, `%A` and `%a`.
+/// <p>
+/// The locales `en`, `de`, `fr`, `es`, `nl` and `ja` are built in, and applications can
+/// register further locales, or replace built-in ones, with [`Locale::register`]. Tags are
+/// matched regardless of case and of `-` or `_` separators, and a tag with a region, such as
+/// `de-AT`, falls back to its language, `de`, unless the region has been registered.
+/// </p>
+/// <p>
+/// Like the time zones that [`TimeZone::load`](super::time_zone::TimeZone::load) caches, locales
+/// are registered per thread: a locale registered on one thread is not visible on other threads,
+/// which start with the built-in locales. The registry is not locked, so looking up and
+/// registering locales never blocks and cannot fail because of another thread.
+/// </p>
+///
+/// # Examples
+///
+/// ```rust
+/// use data::accessors::temporal::date::DayOfWeek;
+/// use data::accessors::temporal::locale::Locale;
+///
+/// let german = Locale::get("de-DE").unwrap();
+/// assert_eq!(german.tag(), "de");
+/// assert_eq!(german.month_name(3), Some("März"));
+/// assert_eq!(DayOfWeek::Sunday.abbreviated_name(&german), "So");
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Locale {
+    tag: String,
+    month_names: [String; 12],
+    abbreviated_month_names: [String; 12],
+    day_of_week_names: [String; 7],
+    abbreviated_day_of_week_names: [String; 7],
+}
+
+impl Locale {
+    /// Creates a locale with the given tag, the names of the months from January and the names
+    /// of the days of the week from Monday.
+    /// Fails if the tag is not made of ASCII letters, digits, `-` and `_`, or if a name is empty
+    /// or the same as another name of its kind regardless of case, which would make parsing
+    /// ambiguous.
+    pub fn new(
+        tag: &str,
+        month_names: [&str; 12],
+        abbreviated_month_names: [&str; 12],
+        day_of_week_names: [&str; 7],
+        abbreviated_day_of_week_names: [&str; 7],
+    ) -> Result<Locale, LocaleError> {
+        let normalized_tag = normalize(tag);
+        if normalized_tag.is_empty()
+            || !normalized_tag
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            return Err(LocaleError::InvalidLocale(format!(
+                "The tag '{}' is not a locale tag.",
+                tag
+            )));
+        }
+        check_names(&month_names, "month")?;
+        check_names(&abbreviated_month_names, "abbreviated month")?;
+        check_names(&day_of_week_names, "day of the week")?;
+        check_names(
+            &abbreviated_day_of_week_names,
+            "abbreviated day of the week",
+        )?;
+        Ok(Locale {
+            tag: normalized_tag,
+            month_names: month_names.map(str::to_string),
+            abbreviated_month_names: abbreviated_month_names.map(str::to_string),
+            day_of_week_names: day_of_week_names.map(str::to_string),
+            abbreviated_day_of_week_names: abbreviated_day_of_week_names.map(str::to_string),
+        })
+    }
+
+    /// Returns the registered locale with the given tag, or with its language if the tag has a
+    /// region that is not registered.
+    pub fn get(tag: &str) -> Result<Rc<Locale>, LocaleError> {
+        let tag = normalize(tag);
+        LOCALE_REGISTRY
+            .with(|registry| {
+                let registry = registry.borrow();
+                registry
+                    .get(&tag)
+                    .or_else(|| {
+                        let (language, _) = tag.split_once('-')?;
+                        registry.get(language)
+                    })
+                    .cloned()
+            })
+            .ok_or(LocaleError::UnknownLocale(tag))
+    }
+
+    /// Registers the locale under its tag on the current thread, returning the locale that it
+    /// replaces, if any.
+    pub fn register(locale: Locale) -> Option<Rc<Locale>> {
+        LOCALE_REGISTRY.with(|registry| {
+            registry
+                .borrow_mut()
+                .insert(locale.tag.clone(), Rc::new(locale))
+        })
+    }
+
+    /// Returns the tags of the locales registered on the current thread in alphabetical order.
+    pub fn tags() -> Vec<String> {
+        let mut tags: Vec<String> =
+            LOCALE_REGISTRY.with(|registry| registry.borrow().keys().cloned().collect());
+        tags.sort();
+        tags
+    }
+
+    /// Returns the built-in English locale, which is not affected by registering another
+    /// locale with the tag `en`.
+    pub fn english() -> Rc<Locale> {
+        ENGLISH.with(Rc::clone)
+    }
+
+    /// Returns the locale's normalized tag, in lowercase with `-` separators.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Returns the name of the month for the given month number, if it is from 1 to 12.
+    pub fn month_name(&self, month: u32) -> Option<&str> {
+        let index = month.checked_sub(1)? as usize;
+        self.month_names.get(index).map(String::as_str)
+    }
+
+    /// Returns the abbreviated name of the month for the given month number, if it is from 1
+    /// to 12.
+    pub fn abbreviated_month_name(&self, month: u32) -> Option<&str> {
+        let index = month.checked_sub(1)? as usize;
+        self.abbreviated_month_names.get(index).map(String::as_str)
+    }
+
+    /// Returns the name of the day of the week.
+    pub fn day_of_week_name(&self, day_of_week: DayOfWeek) -> &str {
+        &self.day_of_week_names[day_of_week.index()]
+    }
+
+    /// Returns the abbreviated name of the day of the week.
+    pub fn abbreviated_day_of_week_name(&self, day_of_week: DayOfWeek) -> &str {
+        &self.abbreviated_day_of_week_names[day_of_week.index()]
+    }
+
+    /// Returns the names of the months from January.
+    pub(crate) fn month_names(&self) -> &[String; 12] {
+        &self.month_names
+    }
+
+    /// Returns the abbreviated names of the months from January.
+    pub(crate) fn abbreviated_month_names(&self) -> &[String; 12] {
+        &self.abbreviated_month_names
+    }
+
+    /// Returns the names of the days of the week from Monday.
+    pub(crate) fn day_of_week_names(&self) -> &[String; 7] {
+        &self.day_of_week_names
+    }
+
+    /// Returns the abbreviated names of the days of the week from Monday.
+    pub(crate) fn abbreviated_day_of_week_names(&self) -> &[String; 7] {
+        &self.abbreviated_day_of_week_names
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tag)
+    }
+}
+
+impl Debug for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Locale {{ tag: {} }}", self.tag)
+    }
+}
+
+/// Creates a built-in locale, whose names are known to be valid.
+fn builtin(
+    tag: &str,
+    month_names: [&str; 12],
+    abbreviated_month_names: [&str; 12],
+    day_of_week_names: [&str; 7],
+    abbreviated_day_of_week_names: [&str; 7],
+) -> Locale {
+    Locale::new(
+        tag,
+        month_names,
+        abbreviated_month_names,
+        day_of_week_names,
+        abbreviated_day_of_week_names,
+    )
+    .unwrap()
+}
+
+/// Returns the tag in lowercase with `-` separators.
+fn normalize(tag: &str) -> String {
+    tag.trim().to_ascii_lowercase().replace('_', "-")
+}
+
+/// Checks that the names are not empty and differ regardless of case.
+fn check_names(names: &[&str], kind: &str) -> Result<(), LocaleError> {
+    for (index, name) in names.iter().enumerate() {
+        if name.trim().is_empty() {
+            return Err(LocaleError::InvalidLocale(format!(
+                "The {} name {} is empty.",
+                kind,
+                index + 1
+            )));
+        }
+        if names[..index]
+            .iter()
+            .any(|other| other.to_lowercase() == name.to_lowercase())
+        {
+            return Err(LocaleError::InvalidLocale(format!(
+                "The {} name '{}' is not unique.",
+                kind, name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// An error that can occur when creating or looking up a locale.
+#[derive(Debug, PartialEq)]
+pub enum LocaleError {
+    /// Indicates that no locale is registered with the tag or its language.
+    UnknownLocale(String),
+    /// Indicates that the tag or the names of a locale are not valid.
+    InvalidLocale(String),
+}
+
+impl Display for LocaleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LocaleError::UnknownLocale(tag) => write!(f, "Unknown locale: {}", tag),
+            LocaleError::InvalidLocale(msg) => write!(f, "Invalid locale: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LocaleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
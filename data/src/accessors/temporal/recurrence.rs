@@ -0,0 +1,678 @@
+use std::{
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+use crate::{
+    accessors::{
+        sequence::{Sequence, SequenceError},
+        temporal::{
+            date::{DateError, DayOfWeek, day_of_week_from_signed_days, days_in_month},
+            time::TimeError,
+        },
+    },
+    adaptors::{
+        sequence_adaptor::SequenceGenerator,
+        temporal_adaptors::date_adaptor::{
+            date_from_signed_days, is_proleptic_leap_year, signed_days_from_date,
+        },
+    },
+    data_provider::{DataProvider, default_data_provider},
+    data_spec::{DataSpec, DataSpecLevel, DataSpecType},
+    primitive::Primitive,
+    primitive_def::PrimitiveDef,
+    primitive_specs::sequence_spec::SequenceSpec,
+    set_equal_to::SetEqualToError,
+    variable::Variable,
+};
+
+/// Frequency defines an enumeration of the periods in which a recurrence rule repeats.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Frequency {
+    /// Every day, or every `INTERVAL` days.
+    Daily,
+    /// Every week starting on Monday, or every `INTERVAL` weeks.
+    Weekly,
+    /// Every month, or every `INTERVAL` months.
+    Monthly,
+    /// Every year, or every `INTERVAL` years.
+    Yearly,
+}
+
+impl Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Daily => "DAILY",
+                Self::Weekly => "WEEKLY",
+                Self::Monthly => "MONTHLY",
+                Self::Yearly => "YEARLY",
+            }
+        )
+    }
+}
+
+/// A day of the week of a `BYDAY` part, optionally numbered within the month or year, so that
+/// `2MO` is the second Monday and `-1FR` the last Friday.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct WeekdayNum {
+    /// The number of the day of the week within the period, counted from its end if negative,
+    /// from 1 to 53 within a year or from 1 to 5 within a month.
+    pub ordinal: Option<i32>,
+    /// The day of the week.
+    pub day_of_week: DayOfWeek,
+}
+
+impl Display for WeekdayNum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(ordinal) = self.ordinal {
+            write!(f, "{}", ordinal)?;
+        }
+        write!(f, "{}", WEEKDAY_CODES[self.day_of_week.index()])
+    }
+}
+
+/// The end of a recurrence given by an `UNTIL` part, a date with an optional time of day.
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Until {
+    date: (u32, u32, u32),
+    time: Option<(u8, u8, u8)>,
+    utc: bool,
+}
+
+impl Display for Until {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = self.date;
+        write!(f, "{:04}{:02}{:02}", year, month, day)?;
+        if let Some((hour, minute, second)) = self.time {
+            write!(f, "T{:02}{:02}{:02}", hour, minute, second)?;
+        }
+        if self.utc {
+            write!(f, "Z")?;
+        }
+        Ok(())
+    }
+}
+
+/// A recurrence rule of RFC 5545, such as `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;COUNT=10`, which
+/// expands a `Date` or `DateTime` anchor into a sequence of occurrences.
+/// <p>
+/// The supported parts are `FREQ` (`DAILY`, `WEEKLY`, `MONTHLY` or `YEARLY`), `INTERVAL`,
+/// `BYDAY`, `BYMONTHDAY`, `COUNT` and `UNTIL`; others are rejected. Weeks start on Monday.
+/// The occurrences are the dates on or after the anchor that the rule matches, and they keep the
+/// time of day of a `DateTime` anchor. Without `BYDAY` or `BYMONTHDAY`, the anchor's day of the
+/// week, day of the month or month and day select the occurrences in each week, month or year,
+/// so that periods lacking the day, such as months without a 31st, are skipped.
+/// </p>
+/// <p>
+/// `UNTIL` is inclusive. Its time of day, if any, is compared with that of a `DateTime` anchor
+/// as a local time, and a trailing `Z` is kept but not converted.
+/// </p>
+///
+/// # Examples
+///
+/// ```rust
+/// use data::accessors::temporal::recurrence::RecurrenceRule;
+/// use data::data_spec_builders::date_spec_builder::DateSpecBuilder;
+/// use data::variable::Variable;
+///
+/// let rule = RecurrenceRule::new("FREQ=MONTHLY;BYDAY=-1FR;COUNT=3").unwrap();
+/// let mut anchor = Variable::new(&DateSpecBuilder::new().build());
+/// anchor.date_mut().set_date(2024, 1, 1).unwrap();
+///
+/// let occurrences = rule.occurrences(&anchor).unwrap();
+/// let dates: Vec<String> = occurrences
+///     .sequence()
+///     .iter()
+///     .map(|date| date.unwrap().to_string())
+///     .collect();
+/// assert_eq!(dates, ["2024-01-26", "2024-02-23", "2024-03-29"]);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecurrenceRule {
+    frequency: Frequency,
+    interval: u32,
+    by_day: Vec<WeekdayNum>,
+    by_month_day: Vec<i32>,
+    count: Option<u32>,
+    until: Option<Until>,
+}
+
+impl RecurrenceRule {
+    /// Parses a recurrence rule from the value of an `RRULE` property, which may keep its
+    /// `RRULE:` prefix. Part names and values are matched regardless of case.
+    pub fn new(rule: &str) -> Result<RecurrenceRule, RecurrenceError> {
+        let rule = rule.trim();
+        let rule = match rule.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &rule[6..],
+            _ => rule,
+        };
+        let mut frequency = None;
+        let mut interval = None;
+        let mut by_day = None;
+        let mut by_month_day = None;
+        let mut count = None;
+        let mut until = None;
+        for part in rule.split(';') {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| RecurrenceError::InvalidRule(format!("'{}' is not a part", part)))?;
+            let name = name.trim().to_ascii_uppercase();
+            let value = value.trim().to_ascii_uppercase();
+            let duplicate = match name.as_str() {
+                "FREQ" => frequency.replace(parse_frequency(&value)?).is_some(),
+                "INTERVAL" => match parse_number(&name, &value)? {
+                    0 => return Err(invalid_value(&name, &value)),
+                    n => interval.replace(n).is_some(),
+                },
+                "BYDAY" => by_day
+                    .replace(parse_list(&value, parse_weekday_num)?)
+                    .is_some(),
+                "BYMONTHDAY" => by_month_day
+                    .replace(parse_list(&value, parse_month_day)?)
+                    .is_some(),
+                "COUNT" => count.replace(parse_number(&name, &value)?).is_some(),
+                "UNTIL" => until.replace(parse_until(&value)?).is_some(),
+                _ => return Err(RecurrenceError::UnsupportedPart(name)),
+            };
+            if duplicate {
+                return Err(RecurrenceError::InvalidRule(format!(
+                    "{} is given more than once",
+                    name
+                )));
+            }
+        }
+        let frequency =
+            frequency.ok_or_else(|| RecurrenceError::InvalidRule("FREQ is missing".to_string()))?;
+        let by_day: Vec<WeekdayNum> = by_day.unwrap_or_default();
+        let by_month_day: Vec<i32> = by_month_day.unwrap_or_default();
+        if count.is_some() && until.is_some() {
+            return Err(RecurrenceError::InvalidRule(
+                "COUNT and UNTIL cannot both be given".to_string(),
+            ));
+        }
+        if matches!(frequency, Frequency::Daily | Frequency::Weekly)
+            && by_day.iter().any(|weekday| weekday.ordinal.is_some())
+        {
+            return Err(RecurrenceError::InvalidRule(format!(
+                "BYDAY cannot number days of the week with FREQ={}",
+                frequency
+            )));
+        }
+        // a month has at most five of each day of the week
+        if frequency == Frequency::Monthly
+            && by_day
+                .iter()
+                .any(|weekday| weekday.ordinal.is_some_and(|ordinal| ordinal.abs() > 5))
+        {
+            return Err(RecurrenceError::InvalidRule(
+                "BYDAY can only number days of the week from -5 to 5 with FREQ=MONTHLY".to_string(),
+            ));
+        }
+        if frequency == Frequency::Weekly && !by_month_day.is_empty() {
+            return Err(RecurrenceError::InvalidRule(
+                "BYMONTHDAY cannot be given with FREQ=WEEKLY".to_string(),
+            ));
+        }
+        Ok(RecurrenceRule {
+            frequency,
+            interval: interval.unwrap_or(1),
+            by_day,
+            by_month_day,
+            count,
+            until,
+        })
+    }
+
+    /// Returns the rule's frequency.
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Returns the number of periods between occurrences, which is 1 unless given.
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Returns the days of the week of the `BYDAY` part, which is empty unless given.
+    pub fn by_day(&self) -> &[WeekdayNum] {
+        &self.by_day
+    }
+
+    /// Returns the days of the month of the `BYMONTHDAY` part, which is empty unless given.
+    pub fn by_month_day(&self) -> &[i32] {
+        &self.by_month_day
+    }
+
+    /// Returns the number of occurrences, if limited by a `COUNT` part.
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Returns the occurrences of the rule at the given `Date` or `DateTime` anchor as a
+    /// read-only `Sequence` variable of values with the anchor's spec.
+    /// <p>
+    /// Occurrences are computed on demand as the sequence is iterated, and are kept once
+    /// computed. Rules without `COUNT` or `UNTIL` recur until the end of the range of the
+    /// anchor's date spec, so the sequence should not be copied or collected as a whole.
+    /// </p>
+    pub fn occurrences(&self, anchor: &Variable) -> Result<Variable, RecurrenceError> {
+        let (value_spec, date, time) = match anchor.data_spec().specification_type() {
+            DataSpecType::Primitive(Primitive::Date(Some(def))) => (
+                Primitive::Date(Some(PrimitiveDef::new(def.spec().clone(), None))),
+                anchor.date(),
+                None,
+            ),
+            DataSpecType::Primitive(Primitive::DateTime(Some(def))) => {
+                let (hour, minute, second, millisecond, microsecond, nanosecond) =
+                    anchor.date_time().time().time()?;
+                let is_after_second = millisecond != 0 || microsecond != 0 || nanosecond != 0;
+                (
+                    Primitive::DateTime(Some(PrimitiveDef::new(def.spec().clone(), None))),
+                    anchor.date_time().date(),
+                    Some((hour, minute, second, is_after_second)),
+                )
+            }
+            _ => {
+                return Err(RecurrenceError::UnsupportedAnchor(
+                    anchor.data_spec().to_string(),
+                ));
+            }
+        };
+        let anchor_days = date.signed_days()?;
+        let (_, anchor_month, anchor_day) = date_from_signed_days(anchor_days);
+        let mut last_days = signed_days_from_date(date.spec().range().max_year() as i64, 12, 31);
+        if let Some(until) = self.until {
+            let (year, month, day) = until.date;
+            let mut until_days = signed_days_from_date(year as i64, month, day);
+            if let (Some((hour, minute, second, is_after_second)), Some(until_time)) =
+                (time, until.time)
+            {
+                // an anchor later in the day than UNTIL cannot recur on UNTIL's date
+                if (hour, minute, second) > until_time
+                    || ((hour, minute, second) == until_time && is_after_second)
+                {
+                    until_days -= 1;
+                }
+            }
+            last_days = last_days.min(until_days);
+        }
+        let recurrence = Recurrence {
+            rule: self.clone(),
+            anchor: anchor.try_clone()?,
+            anchor_days,
+            anchor_month,
+            anchor_day,
+            last_days,
+        };
+
+        let value_spec = Rc::new(DataSpec::new_primitive(value_spec, DataSpecLevel::Access));
+        let spec = Rc::new(SequenceSpec::new(&Some(value_spec)));
+        let adaptor =
+            default_data_provider().generated_sequence_adaptor(&spec, Box::new(recurrence));
+        let def = Some(PrimitiveDef::new(spec, Some(Sequence::new(adaptor))));
+        Ok(Variable::new_primitive(Primitive::Sequence(def)))
+    }
+}
+
+impl Display for RecurrenceRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FREQ={}", self.frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            let by_day: Vec<String> = self.by_day.iter().map(|day| day.to_string()).collect();
+            write!(f, ";BYDAY={}", by_day.join(","))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let by_month_day: Vec<String> = self
+                .by_month_day
+                .iter()
+                .map(|day| day.to_string())
+                .collect();
+            write!(f, ";BYMONTHDAY={}", by_month_day.join(","))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until)?;
+        }
+        Ok(())
+    }
+}
+
+/// A recurrence rule expanded at an anchor, which finds its occurrences as signed days from
+/// January 1, 0001 and generates them as copies of the anchor.
+struct Recurrence {
+    rule: RecurrenceRule,
+    anchor: Variable,
+    anchor_days: i64,
+    anchor_month: u32,
+    anchor_day: u32,
+    last_days: i64,
+}
+
+impl SequenceGenerator for Recurrence {
+    fn next_value(
+        &self,
+        index: usize,
+        previous: Option<&Variable>,
+    ) -> Option<Result<Variable, SequenceError>> {
+        if self.rule.count.is_some_and(|count| index >= count as usize) {
+            return None;
+        }
+        let from = match previous {
+            Some(previous) => match self.days_of(previous) {
+                Ok(days) => days.checked_add(1)?,
+                Err(error) => return Some(Err(SetEqualToError::from(error).into())),
+            },
+            None => self.anchor_days,
+        };
+        let days = self.first_at_or_after(from)?;
+        let occurrence = || -> Result<Variable, SetEqualToError> {
+            let mut occurrence = self.anchor.try_clone()?;
+            match occurrence.data_spec().specification_type() {
+                DataSpecType::Primitive(Primitive::DateTime(..)) => occurrence
+                    .date_time_mut()
+                    .date_mut()
+                    .set_signed_days(days)?,
+                _ => occurrence.date_mut().set_signed_days(days)?,
+            }
+            Ok(occurrence)
+        };
+        Some(occurrence().map_err(SequenceError::from))
+    }
+}
+
+impl Recurrence {
+    /// Returns the days of the first occurrence on or after the given days, if any.
+    fn first_at_or_after(&self, days: i64) -> Option<i64> {
+        let interval = self.rule.interval as i64;
+        let days = days.max(self.anchor_days);
+        let mut period = self.period_of(days);
+        let offset = (period - self.period_of(self.anchor_days)).rem_euclid(interval);
+        if offset != 0 {
+            period += interval - offset;
+        }
+        loop {
+            let (start, end) = self.period_range(period);
+            if start > self.last_days {
+                return None;
+            }
+            let found =
+                (start.max(days)..=end.min(self.last_days)).find(|&d| self.matches(d, start, end));
+            if found.is_some() {
+                return found;
+            }
+            period += interval;
+        }
+    }
+
+    /// Returns the days of the date of an occurrence, a copy of the anchor.
+    fn days_of(&self, occurrence: &Variable) -> Result<i64, DateError> {
+        match occurrence.data_spec().specification_type() {
+            DataSpecType::Primitive(Primitive::DateTime(..)) => {
+                occurrence.date_time().date().signed_days()
+            }
+            _ => occurrence.date().signed_days(),
+        }
+    }
+
+    /// Returns the number of the period of the rule's frequency that contains the given days.
+    fn period_of(&self, days: i64) -> i64 {
+        match self.rule.frequency {
+            Frequency::Daily => days,
+            // January 1, 0001 was a Monday
+            Frequency::Weekly => days.div_euclid(7),
+            Frequency::Monthly => {
+                let (year, month, _) = date_from_signed_days(days);
+                year * 12 + month as i64 - 1
+            }
+            Frequency::Yearly => date_from_signed_days(days).0,
+        }
+    }
+
+    /// Returns the days of the first and the last day of the given period.
+    fn period_range(&self, period: i64) -> (i64, i64) {
+        match self.rule.frequency {
+            Frequency::Daily => (period, period),
+            Frequency::Weekly => (period * 7, period * 7 + 6),
+            Frequency::Monthly => {
+                let (year, month) = (period.div_euclid(12), period.rem_euclid(12) as u32 + 1);
+                let start = signed_days_from_date(year, month, 1);
+                let length = days_in_month(is_proleptic_leap_year(year), month);
+                (start, start + length as i64 - 1)
+            }
+            Frequency::Yearly => (
+                signed_days_from_date(period, 1, 1),
+                signed_days_from_date(period, 12, 31),
+            ),
+        }
+    }
+
+    /// Returns true if the rule matches the given days of the period from start to end; false
+    /// otherwise.
+    fn matches(&self, days: i64, start: i64, end: i64) -> bool {
+        let (year, month, day) = date_from_signed_days(days);
+        let weekday = day_of_week_from_signed_days(days);
+        let month_length = days_in_month(is_proleptic_leap_year(year), month) as i32;
+        let by_day = &self.rule.by_day;
+        let by_month_day = &self.rule.by_month_day;
+        if !by_month_day.is_empty()
+            && !by_month_day.iter().any(|&month_day| {
+                month_day == day as i32 || month_day == day as i32 - month_length - 1
+            })
+        {
+            return false;
+        }
+        if !by_day.is_empty()
+            && !by_day.iter().any(|weekday_num| {
+                weekday_num.day_of_week == weekday
+                    && match weekday_num.ordinal {
+                        None => true,
+                        Some(ordinal) if ordinal > 0 => (days - start) / 7 + 1 == ordinal as i64,
+                        Some(ordinal) => (end - days) / 7 + 1 == -ordinal as i64,
+                    }
+            })
+        {
+            return false;
+        }
+        if !by_day.is_empty() || !by_month_day.is_empty() {
+            return true;
+        }
+        // the anchor selects the occurrences of each period
+        match self.rule.frequency {
+            Frequency::Daily => true,
+            Frequency::Weekly => weekday == day_of_week_from_signed_days(self.anchor_days),
+            Frequency::Monthly => day == self.anchor_day,
+            Frequency::Yearly => (month, day) == (self.anchor_month, self.anchor_day),
+        }
+    }
+}
+
+/// The two-letter codes of the days of the week from Monday to Sunday.
+const WEEKDAY_CODES: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+/// Returns an error for an invalid value of the named part.
+fn invalid_value(name: &str, value: &str) -> RecurrenceError {
+    RecurrenceError::InvalidRule(format!("'{}' is not a valid {}", value, name))
+}
+
+fn parse_frequency(value: &str) -> Result<Frequency, RecurrenceError> {
+    match value {
+        "DAILY" => Ok(Frequency::Daily),
+        "WEEKLY" => Ok(Frequency::Weekly),
+        "MONTHLY" => Ok(Frequency::Monthly),
+        "YEARLY" => Ok(Frequency::Yearly),
+        "SECONDLY" | "MINUTELY" | "HOURLY" => {
+            Err(RecurrenceError::UnsupportedPart(format!("FREQ={}", value)))
+        }
+        _ => Err(invalid_value("FREQ", value)),
+    }
+}
+
+fn parse_number(name: &str, value: &str) -> Result<u32, RecurrenceError> {
+    if !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid_value(name, value));
+    }
+    value.parse().map_err(|_| invalid_value(name, value))
+}
+
+fn parse_list<T>(
+    value: &str,
+    parse: fn(&str) -> Result<T, RecurrenceError>,
+) -> Result<Vec<T>, RecurrenceError> {
+    value.split(',').map(|item| parse(item.trim())).collect()
+}
+
+/// Parses a signed number, such as `-1` or `+2`, whose magnitude lies in the given range.
+fn parse_ordinal(name: &str, value: &str, max: i32) -> Result<i32, RecurrenceError> {
+    let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let magnitude =
+        i32::try_from(parse_number(name, digits)?).map_err(|_| invalid_value(name, value))?;
+    if magnitude == 0 || magnitude > max {
+        return Err(invalid_value(name, value));
+    }
+    Ok(if value.starts_with('-') {
+        -magnitude
+    } else {
+        magnitude
+    })
+}
+
+fn parse_weekday_num(value: &str) -> Result<WeekdayNum, RecurrenceError> {
+    let split = value.len().saturating_sub(2);
+    let (ordinal, code) = (value.get(..split), value.get(split..));
+    let index = WEEKDAY_CODES
+        .iter()
+        .position(|&weekday_code| Some(weekday_code) == code)
+        .ok_or_else(|| invalid_value("BYDAY", value))?;
+    let ordinal = match ordinal {
+        Some("") | None => None,
+        Some(ordinal) => {
+            Some(parse_ordinal("BYDAY", ordinal, 53).map_err(|_| invalid_value("BYDAY", value))?)
+        }
+    };
+    Ok(WeekdayNum {
+        ordinal,
+        day_of_week: DayOfWeek::from_index(index).unwrap(),
+    })
+}
+
+fn parse_month_day(value: &str) -> Result<i32, RecurrenceError> {
+    parse_ordinal("BYMONTHDAY", value, 31)
+}
+
+/// Parses an `UNTIL` value of the form `YYYYMMDD`, `YYYYMMDDTHHMMSS` or `YYYYMMDDTHHMMSSZ`.
+fn parse_until(value: &str) -> Result<Until, RecurrenceError> {
+    let invalid = || invalid_value("UNTIL", value);
+    let (local, utc) = match value.strip_suffix('Z') {
+        Some(local) => (local, true),
+        None => (value, false),
+    };
+    let (date, time) = match local.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (local, None),
+    };
+    // the fields are sliced by byte, so a multi-byte character is rejected first
+    if date.len() != 8
+        || !date.is_ascii()
+        || time.is_some_and(|time| time.len() != 6 || !time.is_ascii())
+        || (utc && time.is_none())
+    {
+        return Err(invalid());
+    }
+    let field = |s: &str, range: std::ops::Range<usize>| -> Result<u32, RecurrenceError> {
+        parse_number("UNTIL", &s[range]).map_err(|_| invalid())
+    };
+    let (year, month, day) = (field(date, 0..4)?, field(date, 4..6)?, field(date, 6..8)?);
+    if year == 0
+        || !(1..=12).contains(&month)
+        || day == 0
+        || day > days_in_month(is_proleptic_leap_year(year as i64), month)
+    {
+        return Err(invalid());
+    }
+    let time = match time {
+        Some(time) => {
+            let (hour, minute, second) =
+                (field(time, 0..2)?, field(time, 2..4)?, field(time, 4..6)?);
+            if hour > 23 || minute > 59 || second > 59 {
+                return Err(invalid());
+            }
+            Some((hour as u8, minute as u8, second as u8))
+        }
+        None => None,
+    };
+    Ok(Until {
+        date: (year, month, day),
+        time,
+        utc,
+    })
+}
+
+/// Errors that can occur when working with recurrence rules.
+#[derive(Debug, PartialEq)]
+pub enum RecurrenceError {
+    /// Indicates that the rule is malformed or has an invalid part, with a description.
+    InvalidRule(String),
+    /// Indicates that the rule has a part or a frequency that is not supported.
+    UnsupportedPart(String),
+    /// Indicates that the anchor, given by its spec, is neither a `Date` nor a `DateTime`.
+    UnsupportedAnchor(String),
+    /// An error of the anchor's date.
+    DateError(DateError),
+    /// An error of the anchor's time.
+    TimeError(TimeError),
+    /// An error copying the anchor.
+    CopyError(Box<SetEqualToError>),
+}
+
+impl Display for RecurrenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecurrenceError::InvalidRule(msg) => write!(f, "Invalid recurrence rule: {}", msg),
+            RecurrenceError::UnsupportedPart(part) => {
+                write!(f, "Unsupported recurrence rule part: {}", part)
+            }
+            RecurrenceError::UnsupportedAnchor(spec) => {
+                write!(f, "Unsupported recurrence anchor: {}", spec)
+            }
+            RecurrenceError::DateError(err) => write!(f, "Date error: {}", err),
+            RecurrenceError::TimeError(err) => write!(f, "Time error: {}", err),
+            RecurrenceError::CopyError(err) => write!(f, "Copy error: {:?}", err),
+        }
+    }
+}
+
+impl std::error::Error for RecurrenceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RecurrenceError::DateError(err) => Some(err),
+            RecurrenceError::TimeError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<DateError> for RecurrenceError {
+    fn from(error: DateError) -> Self {
+        RecurrenceError::DateError(error)
+    }
+}
+
+impl From<TimeError> for RecurrenceError {
+    fn from(error: TimeError) -> Self {
+        RecurrenceError::TimeError(error)
+    }
+}
+
+impl From<SetEqualToError> for RecurrenceError {
+    fn from(error: SetEqualToError) -> Self {
+        RecurrenceError::CopyError(Box::new(error))
+    }
+}
@@ -1,8 +1,16 @@
 pub mod set_date_from_string;
+pub mod set_date_time_from_string;
+pub mod set_duration_from_string;
 pub mod set_time_from_string;
+pub mod set_zoned_date_time_from_string;
+pub mod set_zoned_time_from_string;
 
 #[cfg(test)]
 mod tests {
     mod set_date_from_string_test;
+    mod set_date_time_from_string_test;
+    mod set_duration_from_string_test;
     mod set_time_string_test;
+    mod set_zoned_date_time_from_string_test;
+    mod set_zoned_time_from_string_test;
 }
@@ -1,9 +1,21 @@
 use crate::accessors::temporal::date::{Date, DateError};
 
-/// Sets the date from a string in an ISO 8601 format (YYYY-MM-DD), or (YYYYMMDD).
+/// Sets the date from a string in an ISO 8601 calendar date format (YYYY-MM-DD), or (YYYYMMDD),
+/// week date format (YYYY-Www-D), or (YYYYWwwD), or ordinal date format (YYYY-DDD), or (YYYYDDD),
+/// or in the expanded calendar date format with a signed year (±YYYYYY-MM-DD).
 pub fn set_date_from_string(date: &mut Date, value: &str) -> Result<(), DateError> {
+    if value.starts_with(['+', '-']) {
+        return set_expanded_date_from_string(date, value);
+    }
+    if let Some((year_str, week_str)) = value.split_once('W') {
+        return set_week_date_from_string(date, value, year_str, week_str);
+    }
     let parts: Vec<&str> = value.split('-').collect();
-    if parts.len() == 3 {
+    if parts.len() == 2 && parts[1].len() == 3 {
+        set_ordinal_date_from_string(date, value, parts[0], parts[1])
+    } else if parts.len() == 1 && value.len() == 7 && value.is_ascii() {
+        set_ordinal_date_from_string(date, value, &value[0..4], &value[4..7])
+    } else if parts.len() == 3 {
         let year_result: Result<u32, _> = parts[0].parse();
         if year_result.is_err() {
             return Err(DateError::InvalidFormat(format!(
@@ -61,9 +73,107 @@ pub fn set_date_from_string(date: &mut Date, value: &str) -> Result<(), DateErro
             day_result.unwrap() as u32,
         )
     } else {
-        Err(DateError::InvalidFormat(format!(
-            "Date string '{}' is not in a valid ISO 8601 format  (YYYY-MM-DD), or (YYYYMMDD)",
-            value
-        )))
+        Err(invalid_format(value))
+    }
+}
+
+/// Sets the date from the year and the week and weekday of an ISO 8601 week date string, which
+/// are separated by dashes in the extended format (YYYY-Www-D) and not in the basic format
+/// (YYYYWwwD).
+fn set_week_date_from_string(
+    date: &mut Date,
+    value: &str,
+    year_str: &str,
+    week_str: &str,
+) -> Result<(), DateError> {
+    let (year_str, week_str, weekday_str) = match year_str.strip_suffix('-') {
+        Some(year_str) => match week_str.split_once('-') {
+            Some((week_str, weekday_str)) => (year_str, week_str, weekday_str),
+            None => return Err(invalid_format(value)),
+        },
+        None if week_str.len() == 3 && week_str.is_ascii() => {
+            (year_str, &week_str[0..2], &week_str[2..3])
+        }
+        None => return Err(invalid_format(value)),
+    };
+    let Ok(year) = year_str.parse::<u32>() else {
+        return Err(DateError::InvalidFormat(format!(
+            "Year '{}' is not a valid year number in date string '{}'",
+            year_str, value
+        )));
+    };
+    let Ok(week) = week_str.parse::<u32>() else {
+        return Err(DateError::InvalidFormat(format!(
+            "Week '{}' is not a valid week number in date string '{}'",
+            week_str, value
+        )));
+    };
+    let Ok(weekday) = weekday_str.parse::<u32>() else {
+        return Err(DateError::InvalidFormat(format!(
+            "Weekday '{}' is not a valid weekday number in date string '{}'",
+            weekday_str, value
+        )));
+    };
+    date.set_iso_week_date(year, week, weekday)
+}
+
+/// Sets the date from the year and the three-digit day of the year of an ISO 8601 ordinal date
+/// string.
+fn set_ordinal_date_from_string(
+    date: &mut Date,
+    value: &str,
+    year_str: &str,
+    day_str: &str,
+) -> Result<(), DateError> {
+    let Ok(year) = year_str.parse::<u32>() else {
+        return Err(DateError::InvalidFormat(format!(
+            "Year '{}' is not a valid year number in date string '{}'",
+            year_str, value
+        )));
+    };
+    let Ok(day_of_year) = day_str.parse::<u32>() else {
+        return Err(DateError::InvalidFormat(format!(
+            "Day of the year '{}' is not a valid day number in date string '{}'",
+            day_str, value
+        )));
+    };
+    date.set_ordinal_date(year, day_of_year)
+}
+
+/// Sets the date from an expanded ISO 8601 calendar date string, whose astronomically numbered
+/// year has a sign.
+fn set_expanded_date_from_string(date: &mut Date, value: &str) -> Result<(), DateError> {
+    let (sign, rest) = value.split_at(1);
+    let parts: Vec<&str> = rest.split('-').collect();
+    if parts.len() != 3 {
+        return Err(invalid_format(value));
     }
+    let Ok(year) = parts[0].parse::<u32>() else {
+        return Err(DateError::InvalidFormat(format!(
+            "Year '{}{}' is not a valid year number in date string '{}'",
+            sign, parts[0], value
+        )));
+    };
+    let Ok(month) = parts[1].parse::<u32>() else {
+        return Err(DateError::InvalidFormat(format!(
+            "Month '{}' is not a valid month number in date string '{}'",
+            parts[1], value
+        )));
+    };
+    let Ok(day) = parts[2].parse::<u32>() else {
+        return Err(DateError::InvalidFormat(format!(
+            "Day '{}' is not a valid day number in date string '{}'",
+            parts[2], value
+        )));
+    };
+    let year = i32::try_from(year).unwrap_or(i32::MAX);
+    let year = if sign == "-" { -year } else { year };
+    date.set_proleptic_date(year, month, day)
+}
+
+fn invalid_format(value: &str) -> DateError {
+    DateError::InvalidFormat(format!(
+        "Date string '{}' is not in a valid ISO 8601 format (YYYY-MM-DD), (YYYYMMDD), (YYYY-Www-D), (YYYYWwwD), (YYYY-DDD), (YYYYDDD) or (±YYYYYY-MM-DD)",
+        value
+    ))
 }
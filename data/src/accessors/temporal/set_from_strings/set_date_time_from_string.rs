@@ -0,0 +1,29 @@
+use crate::accessors::temporal::{
+    date_time::{DateTime, DateTimeError},
+    set_from_strings::{
+        set_date_from_string::set_date_from_string, set_time_from_string::set_time_from_string,
+    },
+};
+
+/// Sets the date-time from a string in an ISO 8601 format (YYYY-MM-DDThh:mm:ss[.fffffffff]),
+/// parsing the date and the time parts with the date and time parsers.
+pub fn set_date_time_from_string(
+    date_time: &mut DateTime,
+    value: &str,
+) -> Result<(), DateTimeError> {
+    let Some((date_str, time_str)) = value.split_once('T') else {
+        return Err(DateTimeError::InvalidFormat(format!(
+            "Date-time string '{}' is not in a valid ISO 8601 format (YYYY-MM-DDThh:mm:ss[.fffffffff])",
+            value
+        )));
+    };
+    let previous = date_time.date().date()?;
+    set_date_from_string(date_time.date_mut(), date_str)?;
+    if let Err(error) = set_time_from_string(date_time.time_mut(), time_str) {
+        date_time
+            .date_mut()
+            .set_date(previous.0, previous.1, previous.2)?;
+        return Err(error.into());
+    }
+    Ok(())
+}
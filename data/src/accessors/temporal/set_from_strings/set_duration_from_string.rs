@@ -0,0 +1,384 @@
+use crate::accessors::temporal::{
+    NANOSECONDS_PER_DAY, NANOSECONDS_PER_HOUR, NANOSECONDS_PER_MINUTE, NANOSECONDS_PER_SECOND,
+    day_to_second_duration::{DayToSecondDuration, DayToSecondDurationError},
+    year_to_month_duration::{YearToMonthDuration, YearToMonthDurationError},
+};
+
+const NANOSECONDS_PER_WEEK: i128 = 7 * NANOSECONDS_PER_DAY;
+
+/// The longest fraction, after trailing zeros are removed, that a duration component may have.
+const MAX_FRACTION_DIGITS: usize = 18;
+
+/// Sets a year-to-month-duration from an ISO 8601 duration string such as "P1Y2M" or "-P1.5Y".
+pub fn set_year_to_month_duration_from_string(
+    duration: &mut YearToMonthDuration,
+    value: &str,
+) -> Result<(), YearToMonthDurationError> {
+    let parsed = parse_duration(value).map_err(YearToMonthDurationError::InvalidFormat)?;
+    if parsed.has_day_to_second_components() {
+        return Err(YearToMonthDurationError::InvalidFormat(format!(
+            "Duration string '{}' has week, day or time components, which a year-to-month-duration cannot hold",
+            value
+        )));
+    }
+    let months = parsed
+        .total(&[(&parsed.years, 12), (&parsed.months, 1)])
+        .ok_or_else(|| {
+            YearToMonthDurationError::InvalidFormat(format!(
+                "Duration string '{}' is not a whole number of months",
+                value
+            ))
+        })?;
+    let months = i32::try_from(months).map_err(|_| {
+        YearToMonthDurationError::InvalidFormat(format!(
+            "Duration string '{}' is out of the range of a year-to-month-duration",
+            value
+        ))
+    })?;
+    duration.set_duration(months / 12, months % 12)
+}
+
+/// Sets a day-to-second-duration from an ISO 8601 duration string such as "P3DT4H5M6.789S" or "-P2W".
+pub fn set_day_to_second_duration_from_string(
+    duration: &mut DayToSecondDuration,
+    value: &str,
+) -> Result<(), DayToSecondDurationError> {
+    let parsed = parse_duration(value).map_err(DayToSecondDurationError::InvalidFormat)?;
+    if parsed.has_year_to_month_components() {
+        return Err(DayToSecondDurationError::InvalidFormat(format!(
+            "Duration string '{}' has year or month components, which a day-to-second-duration cannot hold",
+            value
+        )));
+    }
+    let nanoseconds = parsed
+        .total(&[
+            (&parsed.weeks, NANOSECONDS_PER_WEEK),
+            (&parsed.days, NANOSECONDS_PER_DAY),
+            (&parsed.hours, NANOSECONDS_PER_HOUR),
+            (&parsed.minutes, NANOSECONDS_PER_MINUTE),
+            (&parsed.seconds, NANOSECONDS_PER_SECOND),
+        ])
+        .ok_or_else(|| {
+            DayToSecondDurationError::InvalidFormat(format!(
+                "Duration string '{}' is not a whole number of nanoseconds",
+                value
+            ))
+        })?;
+    duration.set_total_nanoseconds(nanoseconds)
+}
+
+/// A component of a parsed duration: a whole number with an optional decimal fraction.
+struct Component {
+    whole: i128,
+    fraction: i128,
+    fraction_digits: u32,
+}
+
+impl Component {
+    fn is_zero(&self) -> bool {
+        self.whole == 0 && self.fraction == 0
+    }
+
+    /// Returns the component in a smaller unit, or `None` if it is not a whole number of those units.
+    fn in_units(&self, units: i128) -> Option<i128> {
+        let denominator = 10i128.pow(self.fraction_digits);
+        let fraction = self.fraction * units;
+        if fraction % denominator != 0 {
+            return None;
+        }
+        Some(self.whole * units + fraction / denominator)
+    }
+}
+
+/// A duration parsed from an ISO 8601 string.
+#[derive(Default)]
+struct ParsedDuration {
+    negative: bool,
+    years: Option<Component>,
+    months: Option<Component>,
+    weeks: Option<Component>,
+    days: Option<Component>,
+    hours: Option<Component>,
+    minutes: Option<Component>,
+    seconds: Option<Component>,
+}
+
+impl ParsedDuration {
+    /// Returns whether the duration has non-zero year or month components.
+    fn has_year_to_month_components(&self) -> bool {
+        [&self.years, &self.months]
+            .into_iter()
+            .any(|component| component.as_ref().is_some_and(|c| !c.is_zero()))
+    }
+
+    /// Returns whether the duration has non-zero week, day or time components.
+    fn has_day_to_second_components(&self) -> bool {
+        [
+            &self.weeks,
+            &self.days,
+            &self.hours,
+            &self.minutes,
+            &self.seconds,
+        ]
+        .into_iter()
+        .any(|component| component.as_ref().is_some_and(|c| !c.is_zero()))
+    }
+
+    /// Returns the signed sum of the components in a common unit, given each component
+    /// with the number of common units it holds, or `None` if the sum is not a whole number.
+    fn total(&self, components: &[(&Option<Component>, i128)]) -> Option<i128> {
+        let mut total: i128 = 0;
+        for (component, units) in components {
+            if let Some(component) = component {
+                total += component.in_units(*units)?;
+            }
+        }
+        Some(if self.negative { -total } else { total })
+    }
+}
+
+/// Parses an ISO 8601 duration string, either in the designator format, as in
+/// "P1Y2M3DT4H5M6.7S" or "P2W", or in the alternative format, as in "P0001-02-03T04:05:06.7"
+/// or "P00010203T040506.7". The string may be preceded by a sign.
+fn parse_duration(value: &str) -> Result<ParsedDuration, String> {
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let Some(body) = unsigned.strip_prefix('P') else {
+        return Err(format!(
+            "Duration string '{}' does not start with the designator 'P'",
+            value
+        ));
+    };
+    let (date_part, time_part) = match body.split_once('T') {
+        Some((date_part, time_part)) => (date_part, Some(time_part)),
+        None => (body, None),
+    };
+    if date_part.is_empty() && time_part.is_none_or(|time_part| time_part.is_empty()) {
+        return Err(format!("Duration string '{}' has no components", value));
+    }
+    let mut parsed = if body.contains(|c: char| c.is_ascii_alphabetic() && c != 'T') {
+        parse_designator_format(value, date_part, time_part)?
+    } else {
+        parse_alternative_format(value, date_part, time_part)?
+    };
+    parsed.negative = negative;
+    Ok(parsed)
+}
+
+/// Parses the parts of a duration string in the designator format.
+fn parse_designator_format(
+    value: &str,
+    date_part: &str,
+    time_part: Option<&str>,
+) -> Result<ParsedDuration, String> {
+    let mut parsed = ParsedDuration::default();
+    let mut components = parse_designated_components(value, date_part, &['Y', 'M', 'W', 'D'])?;
+    if let Some(time_part) = time_part {
+        if time_part.is_empty() {
+            return Err(format!(
+                "Duration string '{}' has a 'T' that is not followed by time components",
+                value
+            ));
+        }
+        let time_components = parse_designated_components(value, time_part, &['H', 'M', 'S'])?;
+        components.extend(
+            time_components
+                .into_iter()
+                .map(|(designator, component)| (designator.to_ascii_lowercase(), component)),
+        );
+    }
+    let last = components.len() - 1;
+    for (index, (designator, component)) in components.into_iter().enumerate() {
+        if component.fraction_digits > 0 && index != last {
+            return Err(format!(
+                "Only the last component may have a fraction in duration string '{}'",
+                value
+            ));
+        }
+        let slot = match designator {
+            'Y' => &mut parsed.years,
+            'M' => &mut parsed.months,
+            'W' => &mut parsed.weeks,
+            'D' => &mut parsed.days,
+            'h' => &mut parsed.hours,
+            'm' => &mut parsed.minutes,
+            _ => &mut parsed.seconds,
+        };
+        *slot = Some(component);
+    }
+    if parsed.weeks.is_some()
+        && (parsed.years.is_some()
+            || parsed.months.is_some()
+            || parsed.days.is_some()
+            || time_part.is_some())
+    {
+        return Err(format!(
+            "Weeks cannot be combined with other components in duration string '{}'",
+            value
+        ));
+    }
+    Ok(parsed)
+}
+
+/// Parses the numbers and their designators in a part of a duration string in the designator
+/// format. The designators must appear in the given order, each at most once.
+fn parse_designated_components(
+    value: &str,
+    part: &str,
+    designators: &[char],
+) -> Result<Vec<(char, Component)>, String> {
+    let mut components = Vec::new();
+    let mut next_designator = 0;
+    let mut number_start = 0;
+    for (index, c) in part.char_indices() {
+        if c.is_ascii_digit() || c == '.' || c == ',' {
+            continue;
+        }
+        let Some(position) = designators[next_designator..]
+            .iter()
+            .position(|designator| *designator == c)
+        else {
+            return Err(format!(
+                "Designator '{}' is unexpected in duration string '{}'",
+                c, value
+            ));
+        };
+        let component = parse_component(value, &part[number_start..index], c)?;
+        components.push((c, component));
+        next_designator += position + 1;
+        number_start = index + c.len_utf8();
+    }
+    if number_start != part.len() {
+        return Err(format!(
+            "Number '{}' has no designator in duration string '{}'",
+            &part[number_start..],
+            value
+        ));
+    }
+    Ok(components)
+}
+
+/// Parses the number of a duration component, which may have a fraction after a '.' or ','.
+fn parse_component(value: &str, number: &str, designator: char) -> Result<Component, String> {
+    let invalid = || {
+        format!(
+            "Number '{}' of designator '{}' is not valid in duration string '{}'",
+            number, designator, value
+        )
+    };
+    let (whole, fraction) = match number.split_once(['.', ',']) {
+        Some((whole, fraction)) => (whole, fraction.trim_end_matches('0')),
+        None => (number, ""),
+    };
+    if whole.is_empty()
+        || !whole.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+        || fraction.len() > MAX_FRACTION_DIGITS
+        || number.ends_with(['.', ','])
+    {
+        return Err(invalid());
+    }
+    Ok(Component {
+        whole: whole.parse::<i64>().map_err(|_| invalid())? as i128,
+        fraction: if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse().map_err(|_| invalid())?
+        },
+        fraction_digits: fraction.len() as u32,
+    })
+}
+
+/// Parses the parts of a duration string in the alternative format, with the date part as
+/// YYYY-MM-DD or YYYYMMDD and the time part as hh:mm:ss or hhmmss, the seconds optionally
+/// with a fraction.
+fn parse_alternative_format(
+    value: &str,
+    date_part: &str,
+    time_part: Option<&str>,
+) -> Result<ParsedDuration, String> {
+    let invalid = || {
+        format!(
+            "Duration string '{}' is not in a valid ISO 8601 format (PnYnMnDTnHnMnS), (PnW) or (PYYYY-MM-DDThh:mm:ss)",
+            value
+        )
+    };
+    let mut parsed = ParsedDuration::default();
+    if !date_part.is_empty() {
+        let fields: Vec<&str> = if date_part.contains('-') {
+            date_part.split('-').collect()
+        } else if date_part.len() == 8 && date_part.is_ascii() {
+            vec![&date_part[0..4], &date_part[4..6], &date_part[6..8]]
+        } else {
+            return Err(invalid());
+        };
+        let [years, months, days] = fields[..] else {
+            return Err(invalid());
+        };
+        parsed.years = Some(parse_alternative_field(value, years, 4, 9999, "Year")?);
+        parsed.months = Some(parse_alternative_field(value, months, 2, 12, "Month")?);
+        parsed.days = Some(parse_alternative_field(value, days, 2, 30, "Day")?);
+    }
+    if let Some(time_part) = time_part {
+        let (main, fraction) = match time_part.split_once(['.', ',']) {
+            Some((main, fraction)) => (main, Some(fraction)),
+            None => (time_part, None),
+        };
+        let fields: Vec<&str> = if main.contains(':') {
+            main.split(':').collect()
+        } else if main.len() == 6 && main.is_ascii() {
+            vec![&main[0..2], &main[2..4], &main[4..6]]
+        } else {
+            return Err(invalid());
+        };
+        let [hours, minutes, seconds] = fields[..] else {
+            return Err(invalid());
+        };
+        parsed.hours = Some(parse_alternative_field(value, hours, 2, 24, "Hour")?);
+        parsed.minutes = Some(parse_alternative_field(value, minutes, 2, 59, "Minute")?);
+        let seconds_number = match fraction {
+            Some(fraction) => format!("{}.{}", seconds, fraction),
+            None => seconds.to_string(),
+        };
+        let seconds_component = parse_component(value, &seconds_number, 'S')?;
+        if seconds.len() != 2 || seconds_component.whole > 59 {
+            return Err(format!(
+                "Second '{}' is not a valid second number in duration string '{}'",
+                seconds, value
+            ));
+        }
+        parsed.seconds = Some(seconds_component);
+    }
+    Ok(parsed)
+}
+
+/// Parses a fixed-width field of a duration string in the alternative format.
+fn parse_alternative_field(
+    value: &str,
+    field: &str,
+    width: usize,
+    max: i128,
+    name: &str,
+) -> Result<Component, String> {
+    let number = if field.len() == width && field.bytes().all(|b| b.is_ascii_digit()) {
+        field.parse::<i128>().ok().filter(|number| *number <= max)
+    } else {
+        None
+    };
+    match number {
+        Some(whole) => Ok(Component {
+            whole,
+            fraction: 0,
+            fraction_digits: 0,
+        }),
+        None => Err(format!(
+            "{} '{}' is not a valid {} number in duration string '{}'",
+            name,
+            field,
+            name.to_lowercase(),
+            value
+        )),
+    }
+}
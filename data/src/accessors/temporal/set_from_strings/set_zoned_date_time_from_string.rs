@@ -0,0 +1,86 @@
+use crate::{
+    accessors::temporal::{
+        set_from_strings::{
+            set_date_from_string::set_date_from_string, set_time_from_string::set_time_from_string,
+            set_zoned_time_from_string::set_zoned_time_from_string,
+        },
+        time_zone::{Disambiguation, TimeZone},
+        zoned_date_time::{ZonedDateTime, ZonedDateTimeError, unix_seconds},
+        zoned_time::format_offset,
+    },
+    primitive_specs::date_time_spec::ZoneType,
+};
+
+/// Sets the zoned date-time from a string in an ISO 8601 format
+/// (YYYY-MM-DDThh:mm:ss[.fffffffff]) followed by a UTC offset of (Z), (±hh:mm), (±hhmm) or (±hh),
+/// a time zone name in brackets, such as ([Europe/Berlin]), or both, parsing the date and the
+/// zoned time parts with the date and zoned time parsers.
+/// The zoned date-time is left unchanged if the string is invalid.
+pub fn set_zoned_date_time_from_string(
+    zoned_date_time: &mut ZonedDateTime,
+    value: &str,
+) -> Result<(), ZonedDateTimeError> {
+    let previous = zoned_date_time.state()?;
+    let result = set(zoned_date_time, value);
+    if result.is_err() {
+        zoned_date_time.restore(previous)?;
+    }
+    result
+}
+
+fn set(zoned_date_time: &mut ZonedDateTime, value: &str) -> Result<(), ZonedDateTimeError> {
+    let (date_time_str, time_zone_name) = match value.strip_suffix(']') {
+        Some(rest) => {
+            let Some((date_time_str, time_zone_name)) = rest.rsplit_once('[') else {
+                return Err(invalid_format(value));
+            };
+            (date_time_str, Some(time_zone_name))
+        }
+        None => (value, None),
+    };
+    let Some((date_str, time_str)) = date_time_str.split_once('T') else {
+        return Err(invalid_format(value));
+    };
+    zoned_date_time.check_zone_type(match time_zone_name {
+        Some(_) => ZoneType::Named,
+        None => ZoneType::Offset,
+    })?;
+    set_date_from_string(zoned_date_time.date_mut(), date_str)?;
+
+    let Some(time_zone_name) = time_zone_name else {
+        set_zoned_time_from_string(zoned_date_time.zoned_time_mut(), time_str)?;
+        zoned_date_time.set_time_zone(None);
+        return Ok(());
+    };
+    let time_zone = TimeZone::load(time_zone_name)?;
+    let has_offset = time_str.ends_with(['Z', 'z']) || time_str.contains(['+', '-']);
+    if !has_offset {
+        set_time_from_string(zoned_date_time.zoned_time_mut().time_mut(), time_str)?;
+        return zoned_date_time.resolve_in_time_zone(&time_zone, Disambiguation::Compatible);
+    }
+
+    // the offset fixes the instant, and must be the one the time zone uses at that instant
+    set_zoned_time_from_string(zoned_date_time.zoned_time_mut(), time_str)?;
+    let offset_seconds = zoned_date_time.zoned_time().offset_seconds()?;
+    let zone_offset_seconds = time_zone
+        .local_time_type(unix_seconds(zoned_date_time.utc_nanoseconds()?))
+        .offset_seconds();
+    if offset_seconds != zone_offset_seconds {
+        return Err(ZonedDateTimeError::InvalidFormat(format!(
+            "UTC offset '{}' does not match the offset '{}' of time zone '{}' in zoned date-time string '{}'",
+            format_offset(offset_seconds),
+            format_offset(zone_offset_seconds),
+            time_zone,
+            value
+        )));
+    }
+    zoned_date_time.set_time_zone(Some(time_zone));
+    Ok(())
+}
+
+fn invalid_format(value: &str) -> ZonedDateTimeError {
+    ZonedDateTimeError::InvalidFormat(format!(
+        "Zoned date-time string '{}' is not in a valid ISO 8601 format (YYYY-MM-DDThh:mm:ss[.fffffffff]±hh:mm)",
+        value
+    ))
+}
@@ -0,0 +1,76 @@
+use crate::accessors::temporal::{
+    set_from_strings::set_time_from_string::set_time_from_string,
+    zoned_time::{ZonedTime, ZonedTimeError, check_offset},
+};
+
+/// Sets the zoned time from a string in an ISO 8601 format (hh:mm:ss[.fffffffff]) followed by a
+/// UTC offset of (Z), (±hh:mm), (±hhmm) or (±hh), parsing the time part with the time parser.
+pub fn set_zoned_time_from_string(
+    zoned_time: &mut ZonedTime,
+    value: &str,
+) -> Result<(), ZonedTimeError> {
+    let (time_str, offset_seconds) = split_offset(value)?;
+    check_offset(offset_seconds)?;
+    let previous = zoned_time.time().time()?;
+    set_time_from_string(zoned_time.time_mut(), time_str)?;
+    if let Err(error) = zoned_time.set_offset_seconds(offset_seconds) {
+        zoned_time.time_mut().set_via_tuple(previous)?;
+        return Err(error);
+    }
+    Ok(())
+}
+
+/// Splits a zoned time string into its time part and its UTC offset in seconds.
+pub(crate) fn split_offset(value: &str) -> Result<(&str, i32), ZonedTimeError> {
+    if let Some(time_str) = value.strip_suffix(['Z', 'z']) {
+        return Ok((time_str, 0));
+    }
+    let Some(index) = value.rfind(['+', '-']) else {
+        return Err(ZonedTimeError::InvalidFormat(format!(
+            "Zoned time string '{}' has no UTC offset (Z, ±hh:mm, ±hhmm or ±hh)",
+            value
+        )));
+    };
+    let (time_str, offset_str) = value.split_at(index);
+    Ok((time_str, parse_offset(value, offset_str)?))
+}
+
+/// Parses a UTC offset of (±hh:mm[:ss]), (±hhmm[ss]) or (±hh) into seconds.
+fn parse_offset(value: &str, offset_str: &str) -> Result<i32, ZonedTimeError> {
+    let invalid = || {
+        ZonedTimeError::InvalidFormat(format!(
+            "UTC offset '{}' is not valid in zoned time string '{}'",
+            offset_str, value
+        ))
+    };
+    let sign = if offset_str.starts_with('-') { -1 } else { 1 };
+    let digits = &offset_str[1..];
+    let parts: Vec<&str> = if digits.contains(':') {
+        digits.split(':').collect()
+    } else if digits.len().is_multiple_of(2) && digits.is_ascii() {
+        (0..digits.len())
+            .step_by(2)
+            .map(|i| &digits[i..i + 2])
+            .collect()
+    } else {
+        return Err(invalid());
+    };
+    if parts.is_empty() || parts.len() > 3 {
+        return Err(invalid());
+    }
+    let mut seconds = 0;
+    for (index, part) in parts.iter().enumerate() {
+        if part.len() != 2 || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let number: i32 = part.parse().map_err(|_| invalid())?;
+        if index > 0 && number >= 60 {
+            return Err(invalid());
+        }
+        seconds = seconds * 60 + number;
+    }
+    for _ in parts.len()..3 {
+        seconds *= 60;
+    }
+    Ok(sign * seconds)
+}
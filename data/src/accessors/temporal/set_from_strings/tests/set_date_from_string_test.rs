@@ -1,6 +1,6 @@
 use crate::{
     accessors::temporal::date::DateError, data_spec_builders::date_spec_builder::DateSpecBuilder,
-    variable,
+    primitive_specs::date_spec::DateRange, variable,
 };
 
 #[test]
@@ -100,9 +100,132 @@ fn set_date_from_string_invalid_format() {
         DateError::InvalidFormat(mes) => {
             assert_eq!(
                 mes,
-                "Date string '2024/06/15' is not in a valid ISO 8601 format  (YYYY-MM-DD), or (YYYYMMDD)"
+                "Date string '2024/06/15' is not in a valid ISO 8601 format (YYYY-MM-DD), (YYYYMMDD), (YYYY-Www-D), (YYYYWwwD), (YYYY-DDD), (YYYYDDD) or (±YYYYYY-MM-DD)"
             );
         }
         _ => panic!("Expected InvalidFormat error"),
     }
 }
+
+#[test]
+fn set_date_from_week_date_string() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+
+    for date_str in ["2025-W07-3", "2025W073"] {
+        date_mut.set_from_string(date_str).unwrap();
+        assert_eq!(date_mut.date().unwrap(), (2025, 2, 12));
+    }
+
+    // week dates of the neighbouring year
+    date_mut.set_from_string("2020-W01-1").unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2019, 12, 30));
+    date_mut.set_from_string("2020-W53-7").unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2021, 1, 3));
+}
+
+#[test]
+fn invalid_week_date_string() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+    date_mut.set_date(2024, 6, 15).unwrap();
+
+    for (date_str, message) in [
+        (
+            "2025-WX7-3",
+            "Week 'X7' is not a valid week number in date string '2025-WX7-3'",
+        ),
+        (
+            "2025W07X",
+            "Weekday 'X' is not a valid weekday number in date string '2025W07X'",
+        ),
+        (
+            "2O25-W07-3",
+            "Year '2O25' is not a valid year number in date string '2O25-W07-3'",
+        ),
+        (
+            "2025-W073",
+            "Date string '2025-W073' is not in a valid ISO 8601 format (YYYY-MM-DD), (YYYYMMDD), (YYYY-Www-D), (YYYYWwwD), (YYYY-DDD), (YYYYDDD) or (±YYYYYY-MM-DD)",
+        ),
+    ] {
+        assert_eq!(
+            date_mut.set_from_string(date_str),
+            Err(DateError::InvalidFormat(message.to_string()))
+        );
+    }
+    assert_eq!(
+        date_mut.set_from_string("2025-W53-1"),
+        Err(DateError::WeekOutOfBounds(53, 2025, 52))
+    );
+    assert_eq!(
+        date_mut.set_from_string("2025-W07-8"),
+        Err(DateError::WeekdayOutOfBounds(8))
+    );
+    assert_eq!(date_mut.date().unwrap(), (2024, 6, 15));
+}
+
+#[test]
+fn set_date_from_ordinal_date_string() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+
+    for date_str in ["2025-045", "2025045"] {
+        date_mut.set_from_string(date_str).unwrap();
+        assert_eq!(date_mut.date().unwrap(), (2025, 2, 14));
+    }
+    date_mut.set_from_string("2024-366").unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 12, 31));
+
+    assert_eq!(
+        date_mut.set_from_string("2025-366"),
+        Err(DateError::DayOfYearOutOfBounds(366, 2025, 365))
+    );
+    assert_eq!(
+        date_mut.set_from_string("2025-04X"),
+        Err(DateError::InvalidFormat(
+            "Day of the year '04X' is not a valid day number in date string '2025-04X'".to_string()
+        ))
+    );
+    // a truncated calendar date is not an ordinal date
+    assert!(matches!(
+        date_mut.set_from_string("2025-04"),
+        Err(DateError::InvalidFormat(_))
+    ));
+    assert_eq!(date_mut.date().unwrap(), (2024, 12, 31));
+}
+
+#[test]
+fn set_date_from_expanded_date_string() {
+    let mut var = variable::Variable::new(
+        &DateSpecBuilder::new()
+            .set_range(DateRange::Extended)
+            .build(),
+    );
+    let date_mut = var.date_mut();
+
+    date_mut.set_from_string("-000043-03-13").unwrap();
+    assert_eq!(date_mut.proleptic_date().unwrap(), (-43, 3, 13));
+    date_mut.set_from_string("+012024-06-15").unwrap();
+    assert_eq!(date_mut.proleptic_date().unwrap(), (12_024, 6, 15));
+    date_mut.set_from_string("+0000-01-01").unwrap();
+    assert_eq!(date_mut.proleptic_date().unwrap(), (0, 1, 1));
+
+    assert_eq!(
+        date_mut.set_from_string("-00004X-03-13"),
+        Err(DateError::InvalidFormat(
+            "Year '-00004X' is not a valid year number in date string '-00004X-03-13'".to_string()
+        ))
+    );
+    assert_eq!(
+        date_mut.set_from_string("-1000000-01-01"),
+        Err(DateError::YearOutOfRange(-1_000_000, DateRange::Extended))
+    );
+    assert_eq!(date_mut.proleptic_date().unwrap(), (0, 1, 1));
+
+    // the standard range rejects years before 1
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    assert_eq!(
+        var.date_mut().set_from_string("-000043-03-13"),
+        Err(DateError::YearOutOfBounds(0))
+    );
+}
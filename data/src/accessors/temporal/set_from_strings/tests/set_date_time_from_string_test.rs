@@ -0,0 +1,70 @@
+use crate::{
+    accessors::temporal::{date::DateError, date_time::DateTimeError, time::TimeError},
+    data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder,
+    primitive_specs::date_time_spec::DateTimeType,
+    variable::Variable,
+};
+
+#[test]
+fn set_date_time_from_string() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut var = Variable::new(&spec);
+    let date_time_mut = var.date_time_mut();
+
+    for (date_time_str, expected_time) in [
+        ("2024-06-15T12:34:56", (12, 34, 56, 0, 0, 0)),
+        ("2024-6-15T12:34:56.5", (12, 34, 56, 500, 0, 0)),
+        ("20240615T123456", (12, 34, 56, 0, 0, 0)),
+        ("2024-06-15T12:34:56.123456789", (12, 34, 56, 123, 456, 789)),
+        ("2024-06-15T12:34:56,000000001", (12, 34, 56, 0, 0, 1)),
+    ] {
+        date_time_mut.set_from_string(date_time_str).unwrap();
+        assert_eq!(
+            date_time_mut.date_time().unwrap(),
+            ((2024, 6, 15), expected_time),
+            "{}",
+            date_time_str
+        );
+    }
+}
+
+#[test]
+fn missing_time_separator() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut var = Variable::new(&spec);
+    assert_eq!(
+        var.date_time_mut().set_from_string("2024-06-15 12:34:56"),
+        Err(DateTimeError::InvalidFormat(
+            "Date-time string '2024-06-15 12:34:56' is not in a valid ISO 8601 format (YYYY-MM-DDThh:mm:ss[.fffffffff])"
+                .to_string()
+        ))
+    );
+}
+
+#[test]
+fn invalid_date_or_time_leaves_date_time_unchanged() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut var = Variable::new(&spec);
+    let date_time_mut = var.date_time_mut();
+    date_time_mut
+        .set_from_string("2024-06-15T12:34:56")
+        .unwrap();
+
+    assert_eq!(
+        date_time_mut.set_from_string("2I24-06-15T12:34:56"),
+        Err(DateTimeError::DateError(DateError::InvalidFormat(
+            "Year '2I24' is not a valid year number in date string '2I24-06-15'".to_string()
+        )))
+    );
+    assert!(matches!(
+        date_time_mut.set_from_string("2025-01-01T12:3X:56"),
+        Err(DateTimeError::TimeError(TimeError::InvalidFormat(_)))
+    ));
+    assert_eq!(date_time_mut.to_string(), "2024-06-15T12:34:56");
+}
@@ -0,0 +1,320 @@
+use crate::{
+    accessors::temporal::{
+        day_to_second_duration::DayToSecondDurationError,
+        year_to_month_duration::YearToMonthDurationError,
+    },
+    data_spec_builders::duration_spec_builder::DurationSpecBuilder,
+    primitive_specs::duration_spec::{DurationResolution, DurationType},
+    variable::Variable,
+};
+
+#[test]
+fn set_year_to_month_duration_from_string() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::YearToMonth)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let ymd = var.year_to_month_duration_mut();
+
+    for (duration_str, expected) in [
+        ("P1Y2M", (1, 2)),
+        ("P1Y", (1, 0)),
+        ("P14M", (1, 2)),
+        ("-P1Y2M", (-1, -2)),
+        ("+P2M", (0, 2)),
+        ("P1.5Y", (1, 6)),
+        ("P1,25Y", (1, 3)),
+        ("P0001-02-00", (1, 2)),
+        ("P00010200", (1, 2)),
+    ] {
+        ymd.set_from_string(duration_str).unwrap();
+        assert_eq!(ymd.duration().unwrap(), expected, "{}", duration_str);
+    }
+}
+
+#[test]
+fn invalid_year_to_month_duration_strings() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::YearToMonth)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let ymd = var.year_to_month_duration_mut();
+
+    for (duration_str, message) in [
+        (
+            "1Y2M",
+            "Duration string '1Y2M' does not start with the designator 'P'",
+        ),
+        ("P", "Duration string 'P' has no components"),
+        (
+            "P2M1Y",
+            "Designator 'Y' is unexpected in duration string 'P2M1Y'",
+        ),
+        (
+            "P1.5Y2M",
+            "Only the last component may have a fraction in duration string 'P1.5Y2M'",
+        ),
+        (
+            "P1Y2",
+            "Number '2' has no designator in duration string 'P1Y2'",
+        ),
+        (
+            "P.5Y",
+            "Number '.5' of designator 'Y' is not valid in duration string 'P.5Y'",
+        ),
+        (
+            "P1YT",
+            "Duration string 'P1YT' has a 'T' that is not followed by time components",
+        ),
+        (
+            "P1Y2D",
+            "Duration string 'P1Y2D' has week, day or time components, which a year-to-month-duration cannot hold",
+        ),
+        (
+            "P1W",
+            "Duration string 'P1W' has week, day or time components, which a year-to-month-duration cannot hold",
+        ),
+        (
+            "P0.5M",
+            "Duration string 'P0.5M' is not a whole number of months",
+        ),
+        (
+            "P9999999999M",
+            "Duration string 'P9999999999M' is out of the range of a year-to-month-duration",
+        ),
+        (
+            "P0001-13-00",
+            "Month '13' is not a valid month number in duration string 'P0001-13-00'",
+        ),
+        (
+            "P0001é02",
+            "Duration string 'P0001é02' is not in a valid ISO 8601 format (PnYnMnDTnHnMnS), (PnW) or (PYYYY-MM-DDThh:mm:ss)",
+        ),
+    ] {
+        assert_eq!(
+            ymd.set_from_string(duration_str),
+            Err(YearToMonthDurationError::InvalidFormat(message.to_string()))
+        );
+    }
+}
+
+#[test]
+fn year_to_month_duration_from_string_respects_resolution() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::YearToMonth)
+        .set_resolution(DurationResolution::Year)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let ymd = var.year_to_month_duration_mut();
+    ymd.set_from_string("P24M").unwrap();
+    assert_eq!(ymd.duration().unwrap(), (2, 0));
+    assert!(matches!(
+        ymd.set_from_string("P1Y2M"),
+        Err(YearToMonthDurationError::ResolutionOutOfBounds(_))
+    ));
+}
+
+#[test]
+fn year_to_month_duration_display_round_trips() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::YearToMonth)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let ymd = var.year_to_month_duration_mut();
+
+    for duration_str in ["P1Y2M", "P1Y", "P2M", "-P1Y2M", "-P2M", "P0M"] {
+        ymd.set_from_string(duration_str).unwrap();
+        assert_eq!(ymd.to_string(), duration_str);
+    }
+
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::YearToMonth)
+        .set_resolution(DurationResolution::Year)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let ymd = var.year_to_month_duration_mut();
+    ymd.set_duration(0, 0).unwrap();
+    assert_eq!(ymd.to_string(), "P0Y");
+    ymd.set_duration(-3, 0).unwrap();
+    assert_eq!(ymd.to_string(), "-P3Y");
+}
+
+#[test]
+fn set_day_to_second_duration_from_string() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+
+    for (duration_str, expected) in [
+        ("P3DT4H5M6.789S", (3, 4, 5, 6, 789, 0, 0)),
+        ("P3D", (3, 0, 0, 0, 0, 0, 0)),
+        ("PT36H", (1, 12, 0, 0, 0, 0, 0)),
+        ("PT0.000000001S", (0, 0, 0, 0, 0, 0, 1)),
+        ("PT1,5M", (0, 0, 1, 30, 0, 0, 0)),
+        ("PT0.5H", (0, 0, 30, 0, 0, 0, 0)),
+        ("P1.5D", (1, 12, 0, 0, 0, 0, 0)),
+        ("P2W", (14, 0, 0, 0, 0, 0, 0)),
+        ("-P1DT1S", (-1, 0, 0, -1, 0, 0, 0)),
+        ("-PT0.5S", (0, 0, 0, 0, -500, 0, 0)),
+        ("P0000-00-03T04:05:06.789", (3, 4, 5, 6, 789, 0, 0)),
+        ("P00000003T040506,789", (3, 4, 5, 6, 789, 0, 0)),
+        ("PT04:05:06", (0, 4, 5, 6, 0, 0, 0)),
+    ] {
+        dsd.set_from_string(duration_str).unwrap();
+        assert_eq!(dsd.duration().unwrap(), expected, "{}", duration_str);
+    }
+}
+
+#[test]
+fn invalid_day_to_second_duration_strings() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+
+    for (duration_str, message) in [
+        (
+            "P1Y2D",
+            "Duration string 'P1Y2D' has year or month components, which a day-to-second-duration cannot hold",
+        ),
+        (
+            "P1M",
+            "Duration string 'P1M' has year or month components, which a day-to-second-duration cannot hold",
+        ),
+        (
+            "PT1S2M",
+            "Designator 'M' is unexpected in duration string 'PT1S2M'",
+        ),
+        (
+            "P1DT1D",
+            "Designator 'D' is unexpected in duration string 'P1DT1D'",
+        ),
+        (
+            "PT1H1H",
+            "Designator 'H' is unexpected in duration string 'PT1H1H'",
+        ),
+        (
+            "P1W2D",
+            "Weeks cannot be combined with other components in duration string 'P1W2D'",
+        ),
+        (
+            "PT0.0000000001S",
+            "Duration string 'PT0.0000000001S' is not a whole number of nanoseconds",
+        ),
+        (
+            "PT1.S",
+            "Number '1.' of designator 'S' is not valid in duration string 'PT1.S'",
+        ),
+        (
+            "P1DT2H3",
+            "Number '3' has no designator in duration string 'P1DT2H3'",
+        ),
+        (
+            "PT25:00:00",
+            "Hour '25' is not a valid hour number in duration string 'PT25:00:00'",
+        ),
+        (
+            "PT01:00:60",
+            "Second '60' is not a valid second number in duration string 'PT01:00:60'",
+        ),
+        (
+            "P1-2",
+            "Duration string 'P1-2' is not in a valid ISO 8601 format (PnYnMnDTnHnMnS), (PnW) or (PYYYY-MM-DDThh:mm:ss)",
+        ),
+        // the fixed-width fields are not split within a multi-byte character
+        (
+            "P123é456",
+            "Duration string 'P123é456' is not in a valid ISO 8601 format (PnYnMnDTnHnMnS), (PnW) or (PYYYY-MM-DDThh:mm:ss)",
+        ),
+        (
+            "PT1é345",
+            "Duration string 'PT1é345' is not in a valid ISO 8601 format (PnYnMnDTnHnMnS), (PnW) or (PYYYY-MM-DDThh:mm:ss)",
+        ),
+    ] {
+        assert_eq!(
+            dsd.set_from_string(duration_str),
+            Err(DayToSecondDurationError::InvalidFormat(message.to_string()))
+        );
+    }
+    assert!(matches!(
+        dsd.set_from_string("P9999999999D"),
+        Err(DayToSecondDurationError::Overflow(_))
+    ));
+}
+
+#[test]
+fn day_to_second_duration_from_string_respects_resolution() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .set_resolution(DurationResolution::Minute)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_from_string("PT1.5H").unwrap();
+    assert_eq!(dsd.duration().unwrap(), (0, 1, 30, 0, 0, 0, 0));
+    assert!(matches!(
+        dsd.set_from_string("PT1H30M1S"),
+        Err(DayToSecondDurationError::ResolutionOutOfBounds(_))
+    ));
+}
+
+#[test]
+fn day_to_second_duration_display_round_trips() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+
+    for duration_str in [
+        "P3DT4H5M6.789S",
+        "P3D",
+        "PT4H",
+        "PT5M",
+        "PT0.000000001S",
+        "P1DT1S",
+        "-P1DT12H",
+        "-PT0.5S",
+        "PT0S",
+    ] {
+        dsd.set_from_string(duration_str).unwrap();
+        assert_eq!(dsd.to_string(), duration_str);
+    }
+}
+
+#[test]
+fn day_to_second_duration_display_respects_resolution() {
+    for (resolution, zero, value) in [
+        (DurationResolution::Day, "P0D", "P2D"),
+        (DurationResolution::Hour, "PT0H", "P2DT3H"),
+        (DurationResolution::Minute, "PT0M", "PT3H4M"),
+        (DurationResolution::Second, "PT0S", "PT4M5S"),
+        (DurationResolution::Millisecond, "PT0S", "PT5.100S"),
+        (DurationResolution::Microsecond, "PT0S", "PT5.100000S"),
+        (DurationResolution::Nanosecond, "PT0S", "PT5.100000000S"),
+    ] {
+        let spec = DurationSpecBuilder::new()
+            .set_type(DurationType::DayToSecond)
+            .set_resolution(resolution)
+            .build()
+            .unwrap();
+        let mut var = Variable::new(&spec);
+        let dsd = var.day_to_second_duration_mut();
+        assert_eq!(dsd.to_string(), zero);
+        dsd.set_from_string(value).unwrap();
+        assert_eq!(dsd.to_string(), value);
+        assert_eq!(var.to_string(), value);
+    }
+}
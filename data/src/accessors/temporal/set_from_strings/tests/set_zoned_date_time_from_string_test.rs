@@ -0,0 +1,83 @@
+use crate::{
+    accessors::temporal::{
+        date::DateError, zoned_date_time::ZonedDateTimeError, zoned_time::ZonedTimeError,
+    },
+    data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder,
+    primitive_specs::date_time_spec::DateTimeType,
+    variable::Variable,
+};
+
+#[test]
+fn set_zoned_date_time_from_string() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_date_time_mut = var.zoned_date_time_mut();
+
+    for (zoned_date_time_str, expected_time, expected_offset) in [
+        ("2024-06-15T12:34:56Z", (12, 34, 56, 0, 0, 0), 0),
+        ("2024-06-15T12:34:56+02:00", (12, 34, 56, 0, 0, 0), 7_200),
+        ("20240615T123456-0800", (12, 34, 56, 0, 0, 0), -28_800),
+        (
+            "2024-06-15T12:34:56.123456789+05:30",
+            (12, 34, 56, 123, 456, 789),
+            19_800,
+        ),
+    ] {
+        zoned_date_time_mut
+            .set_from_string(zoned_date_time_str)
+            .unwrap();
+        assert_eq!(
+            zoned_date_time_mut.zoned_date_time().unwrap(),
+            ((2024, 6, 15), expected_time, expected_offset),
+            "{}",
+            zoned_date_time_str
+        );
+    }
+}
+
+#[test]
+fn missing_time_separator() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    assert_eq!(
+        var.zoned_date_time_mut()
+            .set_from_string("2024-06-15 12:34:56Z"),
+        Err(ZonedDateTimeError::InvalidFormat(
+            "Zoned date-time string '2024-06-15 12:34:56Z' is not in a valid ISO 8601 format (YYYY-MM-DDThh:mm:ss[.fffffffff]±hh:mm)"
+                .to_string()
+        ))
+    );
+}
+
+#[test]
+fn invalid_date_or_zoned_time_leaves_zoned_date_time_unchanged() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_date_time_mut = var.zoned_date_time_mut();
+    zoned_date_time_mut
+        .set_from_string("2024-06-15T12:34:56+02:00")
+        .unwrap();
+
+    assert!(matches!(
+        zoned_date_time_mut.set_from_string("2024-13-15T12:34:56Z"),
+        Err(ZonedDateTimeError::DateError(DateError::MonthOutOfBounds(
+            13
+        )))
+    ));
+    assert_eq!(
+        zoned_date_time_mut.set_from_string("2025-01-01T12:34:56"),
+        Err(ZonedDateTimeError::ZonedTimeError(
+            ZonedTimeError::InvalidFormat(
+                "Zoned time string '12:34:56' has no UTC offset (Z, ±hh:mm, ±hhmm or ±hh)"
+                    .to_string()
+            )
+        ))
+    );
+    assert_eq!(zoned_date_time_mut.to_string(), "2024-06-15T12:34:56+02:00");
+}
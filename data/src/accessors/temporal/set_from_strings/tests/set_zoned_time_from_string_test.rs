@@ -0,0 +1,103 @@
+use crate::{
+    accessors::temporal::{
+        time::TimeError,
+        zoned_time::{MAX_OFFSET_SECONDS, ZonedTimeError},
+    },
+    data_spec_builders::time_spec_builder::TimeSpecBuilder,
+    primitive_specs::time_spec::TimeType,
+    variable::Variable,
+};
+
+#[test]
+fn set_zoned_time_from_string() {
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_time_mut = var.zoned_time_mut();
+
+    for (zoned_time_str, expected) in [
+        ("12:34:56Z", ((12, 34, 56, 0, 0, 0), 0)),
+        ("12:34:56z", ((12, 34, 56, 0, 0, 0), 0)),
+        ("12:34:56+02:00", ((12, 34, 56, 0, 0, 0), 7_200)),
+        ("12:34:56-05:30", ((12, 34, 56, 0, 0, 0), -19_800)),
+        ("12:34:56+0545", ((12, 34, 56, 0, 0, 0), 20_700)),
+        ("12:34:56-03", ((12, 34, 56, 0, 0, 0), -10_800)),
+        ("12:34:56+00:19:32", ((12, 34, 56, 0, 0, 0), 1_172)),
+        ("12:34:56.789+01:00", ((12, 34, 56, 789, 0, 0), 3_600)),
+        ("123456,5-01:00", ((12, 34, 56, 500, 0, 0), -3_600)),
+        (
+            "12:34:56+18:00",
+            ((12, 34, 56, 0, 0, 0), MAX_OFFSET_SECONDS),
+        ),
+    ] {
+        zoned_time_mut.set_from_string(zoned_time_str).unwrap();
+        assert_eq!(
+            zoned_time_mut.zoned_time().unwrap(),
+            expected,
+            "{}",
+            zoned_time_str
+        );
+    }
+}
+
+#[test]
+fn invalid_zoned_time_strings() {
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_time_mut = var.zoned_time_mut();
+
+    for (zoned_time_str, message) in [
+        (
+            "12:34:56",
+            "Zoned time string '12:34:56' has no UTC offset (Z, ±hh:mm, ±hhmm or ±hh)",
+        ),
+        (
+            "12:34:56+2:00",
+            "UTC offset '+2:00' is not valid in zoned time string '12:34:56+2:00'",
+        ),
+        (
+            "12:34:56+020",
+            "UTC offset '+020' is not valid in zoned time string '12:34:56+020'",
+        ),
+        (
+            "12:34:56+02:60",
+            "UTC offset '+02:60' is not valid in zoned time string '12:34:56+02:60'",
+        ),
+        (
+            "12:34:56-",
+            "UTC offset '-' is not valid in zoned time string '12:34:56-'",
+        ),
+        (
+            "12:34:56+01:02:03:04",
+            "UTC offset '+01:02:03:04' is not valid in zoned time string '12:34:56+01:02:03:04'",
+        ),
+    ] {
+        assert_eq!(
+            zoned_time_mut.set_from_string(zoned_time_str),
+            Err(ZonedTimeError::InvalidFormat(message.to_string()))
+        );
+    }
+}
+
+#[test]
+fn invalid_time_or_offset_leaves_zoned_time_unchanged() {
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_time_mut = var.zoned_time_mut();
+    zoned_time_mut.set_from_string("12:34:56+02:00").unwrap();
+
+    assert_eq!(
+        zoned_time_mut.set_from_string("12:34:56+18:01"),
+        Err(ZonedTimeError::OffsetOutOfBounds(64_860))
+    );
+    assert_eq!(
+        zoned_time_mut.set_from_string("24:00:00Z"),
+        Err(ZonedTimeError::TimeError(TimeError::HourOutOfBounds(24)))
+    );
+    assert_eq!(zoned_time_mut.to_string(), "12:34:56+02:00");
+}
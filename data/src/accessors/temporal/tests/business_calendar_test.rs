@@ -0,0 +1,227 @@
+use crate::{
+    accessors::temporal::{
+        business_calendar::{BusinessCalendar, BusinessCalendarError, RollConvention},
+        date::{DateError, DayOfWeek},
+    },
+    data_spec_builders::date_spec_builder::DateSpecBuilder,
+    variable::Variable,
+};
+
+const CALENDAR: &str = "\
+# Holidays of the test exchange
+name: TEST
+weekend: Sat, sunday
+
+2025-01-01 New Year's Day
+2025-04-18 Good Friday
+2025-04-21 Easter Monday: observed
+2025-12-25
+2025-12-26
+2025-12-27 Saturday
+";
+
+#[test]
+fn business_calendar_from_text() {
+    let calendar = BusinessCalendar::from_text("unnamed", CALENDAR).unwrap();
+    assert_eq!(calendar.name(), "TEST");
+    assert_eq!(calendar.to_string(), "TEST");
+    assert_eq!(calendar.weekend(), [DayOfWeek::Saturday, DayOfWeek::Sunday]);
+    assert!(calendar.is_weekend(DayOfWeek::Sunday));
+    assert!(!calendar.is_weekend(DayOfWeek::Friday));
+    assert_eq!(calendar.holidays().len(), 6);
+    let spec = DateSpecBuilder::new().build();
+    let mut var = Variable::new(&spec);
+    var.date_mut().set_date(2025, 4, 21).unwrap();
+    assert!(calendar.holidays().contains(&var).unwrap());
+
+    let calendar = BusinessCalendar::from_text("gulf", "weekend: Fri, Sat").unwrap();
+    assert_eq!(calendar.name(), "gulf");
+    assert_eq!(calendar.weekend(), [DayOfWeek::Friday, DayOfWeek::Saturday]);
+    assert!(calendar.holidays().is_empty());
+}
+
+#[test]
+fn business_calendar_from_text_errors() {
+    assert_eq!(
+        BusinessCalendar::from_text("test", "weekend: Sat, Someday").unwrap_err(),
+        BusinessCalendarError::InvalidData("line 1: 'Someday' is not a day".to_string())
+    );
+    assert!(matches!(
+        BusinessCalendar::from_text("test", "# holidays\n2025-02-30"),
+        Err(BusinessCalendarError::InvalidData(msg)) if msg.starts_with("line 2: '2025-02-30'")
+    ));
+    assert_eq!(
+        BusinessCalendar::from_text("test", "region: EU").unwrap_err(),
+        BusinessCalendarError::InvalidData("line 1: unknown entry 'region'".to_string())
+    );
+}
+
+#[test]
+fn business_calendar_load() {
+    let directory = std::env::temp_dir().join(format!(
+        "epiphany-business-calendars-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&directory).unwrap();
+    let path = directory.join("target.txt");
+    std::fs::write(&path, "2025-05-01\n").unwrap();
+
+    let calendar = BusinessCalendar::load(&path).unwrap();
+    assert_eq!(calendar.name(), "target");
+    assert_eq!(calendar.holidays().len(), 1);
+    assert!(matches!(
+        BusinessCalendar::load(directory.join("missing.txt")),
+        Err(BusinessCalendarError::Io(..))
+    ));
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn is_business_day() {
+    let mut calendar = BusinessCalendar::from_text("unnamed", CALENDAR).unwrap();
+    let spec = DateSpecBuilder::new().build();
+    let mut var = Variable::new(&spec);
+    var.date_mut().set_date(2025, 4, 17).unwrap();
+    assert!(calendar.is_business_day(var.date()).unwrap());
+    var.date_mut().set_date(2025, 4, 18).unwrap();
+    assert!(!calendar.is_business_day(var.date()).unwrap());
+    var.date_mut().set_date(2025, 4, 19).unwrap();
+    assert!(!calendar.is_business_day(var.date()).unwrap());
+
+    var.date_mut().set_date(2025, 4, 17).unwrap();
+    assert!(calendar.add_holiday(var.date()).unwrap());
+    assert!(!calendar.add_holiday(var.date()).unwrap());
+    assert!(!calendar.is_business_day(var.date()).unwrap());
+    assert!(calendar.holidays_mut().remove(&var).unwrap());
+    assert!(calendar.is_business_day(var.date()).unwrap());
+}
+
+#[test]
+fn roll() {
+    let calendar = BusinessCalendar::from_text("unnamed", CALENDAR).unwrap();
+    let spec = DateSpecBuilder::new().build();
+    let mut var = Variable::new(&spec);
+    var.date_mut().set_from_string("2025-04-18").unwrap();
+    let date = var.date_mut();
+    calendar.roll(date, RollConvention::Following).unwrap();
+    assert_eq!(date.to_string(), "2025-04-22");
+
+    // business days are left unchanged
+    calendar.roll(date, RollConvention::Preceding).unwrap();
+    assert_eq!(date.to_string(), "2025-04-22");
+
+    date.set_date(2025, 4, 21).unwrap();
+    calendar.roll(date, RollConvention::Preceding).unwrap();
+    assert_eq!(date.to_string(), "2025-04-17");
+
+    // the following business day is in the same month
+    date.set_date(2025, 4, 19).unwrap();
+    calendar
+        .roll(date, RollConvention::ModifiedFollowing)
+        .unwrap();
+    assert_eq!(date.to_string(), "2025-04-22");
+
+    // the following business day is in the next month
+    date.set_date(2025, 8, 30).unwrap();
+    calendar
+        .roll(date, RollConvention::ModifiedFollowing)
+        .unwrap();
+    assert_eq!(date.to_string(), "2025-08-29");
+    date.set_date(2025, 8, 30).unwrap();
+    calendar.roll(date, RollConvention::Following).unwrap();
+    assert_eq!(date.to_string(), "2025-09-01");
+}
+
+#[test]
+fn add_business_days() {
+    let calendar = BusinessCalendar::from_text("unnamed", CALENDAR).unwrap();
+    let spec = DateSpecBuilder::new().build();
+    let mut var = Variable::new(&spec);
+    var.date_mut().set_from_string("2025-04-16").unwrap();
+    let date = var.date_mut();
+    calendar.add_business_days(date, 2).unwrap();
+    assert_eq!(date.to_string(), "2025-04-22");
+    calendar.add_business_days(date, -2).unwrap();
+    assert_eq!(date.to_string(), "2025-04-16");
+    calendar.add_business_days(date, 0).unwrap();
+    assert_eq!(date.to_string(), "2025-04-16");
+
+    // from a day that is not a business day
+    date.set_date(2025, 12, 25).unwrap();
+    calendar.add_business_days(date, 1).unwrap();
+    assert_eq!(date.to_string(), "2025-12-29");
+    date.set_date(2025, 12, 25).unwrap();
+    calendar.add_business_days(date, -1).unwrap();
+    assert_eq!(date.to_string(), "2025-12-24");
+
+    // out of bounds
+    date.set_date(9999, 12, 30).unwrap();
+    assert_eq!(
+        calendar.add_business_days(date, 2),
+        Err(BusinessCalendarError::DateError(
+            DateError::YearOutOfBounds(10000)
+        ))
+    );
+    assert_eq!(date.to_string(), "9999-12-30");
+}
+
+#[test]
+fn business_days_between() {
+    let calendar = BusinessCalendar::from_text("unnamed", CALENDAR).unwrap();
+    let spec = DateSpecBuilder::new().build();
+    let mut start = Variable::new(&spec);
+    start.date_mut().set_from_string("2025-04-14").unwrap();
+    let mut end = Variable::new(&spec);
+    end.date_mut().set_from_string("2025-04-28").unwrap();
+    // ten weekdays less Good Friday and Easter Monday
+    assert_eq!(
+        calendar
+            .business_days_between(start.date(), end.date())
+            .unwrap(),
+        8
+    );
+    assert_eq!(
+        calendar
+            .business_days_between(end.date(), start.date())
+            .unwrap(),
+        -8
+    );
+    assert_eq!(
+        calendar
+            .business_days_between(start.date(), start.date())
+            .unwrap(),
+        0
+    );
+    // ten weekdays less December 25 and 26, as the holiday on a Saturday is not counted twice
+    start.date_mut().set_from_string("2025-12-22").unwrap();
+    end.date_mut().set_from_string("2026-01-05").unwrap();
+    assert_eq!(
+        calendar
+            .business_days_between(start.date(), end.date())
+            .unwrap(),
+        8
+    );
+}
+
+#[test]
+fn calendar_without_business_days() {
+    let calendar = BusinessCalendar::new(
+        "never",
+        &[
+            DayOfWeek::Monday,
+            DayOfWeek::Tuesday,
+            DayOfWeek::Wednesday,
+            DayOfWeek::Thursday,
+            DayOfWeek::Friday,
+            DayOfWeek::Saturday,
+            DayOfWeek::Sunday,
+        ],
+    );
+    let spec = DateSpecBuilder::new().build();
+    let mut var = Variable::new(&spec);
+    var.date_mut().set_from_string("2025-01-01").unwrap();
+    assert_eq!(
+        calendar.add_business_days(var.date_mut(), 1),
+        Err(BusinessCalendarError::NoBusinessDays("never".to_string()))
+    );
+}
@@ -0,0 +1,235 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::temporal::{
+        clock::{Clock, FixedClock, SystemClock},
+        date::DateError,
+        tests::time_zone_test::central_european_tzif,
+        time_zone::{Disambiguation, TimeZone},
+        zoned_date_time::ZonedDateTimeError,
+        zoned_time::ZonedTimeError,
+    },
+    data_spec_builders::{
+        date_spec_builder::DateSpecBuilder, date_time_spec_builder::DateTimeSpecBuilder,
+        time_spec_builder::TimeSpecBuilder,
+    },
+    primitive_specs::{
+        date_time_spec::{DateTimeType, ZoneType},
+        time_spec::{TimeResolution, TimeType},
+    },
+    variable::Variable,
+};
+
+/// 2024-06-15T12:34:56.789612345Z in nanoseconds since the Unix epoch.
+const JUNE_15_2024: i128 = 1_718_454_896_789_612_345;
+
+#[test]
+fn fixed_clock() {
+    let clock = FixedClock::new(JUNE_15_2024);
+    assert_eq!(clock.unix_nanoseconds(), JUNE_15_2024);
+    assert_eq!(clock.local_offset_seconds(0), 0);
+
+    clock.advance(1_000);
+    assert_eq!(clock.unix_nanoseconds(), JUNE_15_2024 + 1_000);
+    clock.advance(-2_000);
+    assert_eq!(clock.unix_nanoseconds(), JUNE_15_2024 - 1_000);
+    clock.set_unix_nanoseconds(0);
+    assert_eq!(clock.unix_nanoseconds(), 0);
+    clock.set_local_offset_seconds(-3_600);
+    assert_eq!(clock.local_offset_seconds(0), -3_600);
+}
+
+#[test]
+fn system_clock() {
+    let clock = SystemClock::new();
+    // later than 2024-01-01T00:00:00Z
+    assert!(clock.unix_nanoseconds() > 1_704_067_200 * 1_000_000_000);
+    assert_eq!(clock.local_offset_seconds(0), 0);
+    assert!(clock.time_zone().is_none());
+
+    let time_zone = Rc::new(TimeZone::from_tzif("Europe/Test", &central_european_tzif()).unwrap());
+    let clock = SystemClock::with_time_zone(time_zone.clone());
+    assert_eq!(clock.time_zone(), Some(&time_zone));
+    // 2024-01-01T00:00:00Z and 2024-06-15T00:00:00Z
+    assert_eq!(clock.local_offset_seconds(1_704_067_200), 3_600);
+    assert_eq!(clock.local_offset_seconds(1_718_409_600), 7_200);
+}
+
+#[test]
+fn time_set_to_now() {
+    let clock = FixedClock::new(JUNE_15_2024);
+    for (resolution, expected) in [
+        (None, (12, 34, 56, 789, 612, 345)),
+        (
+            Some(TimeResolution::Nanosecond),
+            (12, 34, 56, 789, 612, 345),
+        ),
+        (Some(TimeResolution::Microsecond), (12, 34, 56, 789, 612, 0)),
+        (
+            Some(TimeResolution::Microsecond100),
+            (12, 34, 56, 789, 600, 0),
+        ),
+        (Some(TimeResolution::Millisecond), (12, 34, 56, 789, 0, 0)),
+        (Some(TimeResolution::Second), (12, 34, 56, 0, 0, 0)),
+    ] {
+        let mut builder = TimeSpecBuilder::new();
+        builder.set_time_type(TimeType::Local);
+        if let Some(resolution) = resolution {
+            builder.set_resolution(resolution);
+        }
+        let mut var = Variable::new(&builder.build());
+        var.time_mut().set_to_now(&clock).unwrap();
+        assert_eq!(var.time().time().unwrap(), expected);
+    }
+
+    // the local time wraps around midnight
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Local)
+        .set_resolution(TimeResolution::Second)
+        .build();
+    let mut var = Variable::new(&spec);
+    clock.set_local_offset_seconds(12 * 3_600);
+    var.time_mut().set_to_now(&clock).unwrap();
+    assert_eq!(var.time().time().unwrap(), (0, 34, 56, 0, 0, 0));
+}
+
+#[test]
+fn time_set_to_now_with_system_clock() {
+    // the system clock's fraction of a second does not exceed a second resolution
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Local)
+        .set_resolution(TimeResolution::Second)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.time_mut().set_to_now(&SystemClock::new()).unwrap();
+    assert_eq!(var.time().time().unwrap().3, 0);
+    assert_eq!(var.time().time().unwrap().4, 0);
+    assert_eq!(var.time().time().unwrap().5, 0);
+
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Local)
+        .set_resolution(TimeResolution::Microsecond100)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.time_mut().set_to_now(&SystemClock::new()).unwrap();
+    assert_eq!(var.time().time().unwrap().4 % 100, 0);
+    assert_eq!(var.time().time().unwrap().5, 0);
+}
+
+#[test]
+fn date_set_to_now() {
+    let clock = FixedClock::new(JUNE_15_2024);
+    let mut var = Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+    date_mut.set_to_now(&clock).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 6, 15));
+
+    clock.set_local_offset_seconds(12 * 3_600);
+    date_mut.set_to_now(&clock).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 6, 16));
+
+    // before the Unix epoch
+    clock.set_unix_nanoseconds(-1);
+    clock.set_local_offset_seconds(0);
+    date_mut.set_to_now(&clock).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (1969, 12, 31));
+
+    // out of the date's range
+    clock.set_unix_nanoseconds(-62_135_596_800 * 1_000_000_000 - 1);
+    assert_eq!(
+        date_mut.set_to_now(&clock),
+        Err(DateError::YearOutOfBounds(0))
+    );
+    assert_eq!(date_mut.date().unwrap(), (1969, 12, 31));
+}
+
+#[test]
+fn date_time_set_to_now() {
+    let clock = FixedClock::new(JUNE_15_2024);
+    let mut var = Variable::new(
+        &DateTimeSpecBuilder::new()
+            .set_date_time_type(DateTimeType::Local)
+            .build(),
+    );
+    let date_time_mut = var.date_time_mut();
+    date_time_mut.set_to_now(&clock).unwrap();
+    assert_eq!(
+        date_time_mut.date_time().unwrap(),
+        ((2024, 6, 15), (12, 34, 56, 789, 612, 345))
+    );
+
+    // the date and the time are taken from the same reading
+    clock.set_local_offset_seconds(-13 * 3_600);
+    date_time_mut.set_to_now(&clock).unwrap();
+    assert_eq!(date_time_mut.to_string(), "2024-06-14T23:34:56.789612345");
+}
+
+#[test]
+fn zoned_time_set_to_now() {
+    let clock = FixedClock::new(JUNE_15_2024);
+    clock.set_local_offset_seconds(-5 * 3_600);
+    let mut var = Variable::new(
+        &TimeSpecBuilder::new()
+            .set_time_type(TimeType::Zoned)
+            .set_resolution(TimeResolution::Second)
+            .build(),
+    );
+    let zoned_time_mut = var.zoned_time_mut();
+    zoned_time_mut.set_to_now(&clock).unwrap();
+    assert_eq!(zoned_time_mut.to_string(), "07:34:56-05:00");
+
+    // the offset is out of bounds
+    clock.set_local_offset_seconds(19 * 3_600);
+    assert_eq!(
+        zoned_time_mut.set_to_now(&clock),
+        Err(ZonedTimeError::OffsetOutOfBounds(19 * 3_600))
+    );
+    assert_eq!(zoned_time_mut.to_string(), "07:34:56-05:00");
+}
+
+#[test]
+fn zoned_date_time_set_to_now() {
+    let clock = FixedClock::new(JUNE_15_2024);
+    clock.set_local_offset_seconds(3_600);
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_date_time_mut = var.zoned_date_time_mut();
+    zoned_date_time_mut.set_to_now(&clock).unwrap();
+    assert_eq!(
+        zoned_date_time_mut.to_string(),
+        "2024-06-15T13:34:56.789612345+01:00"
+    );
+
+    // a named time zone takes precedence over the clock's local offset
+    let time_zone = Rc::new(TimeZone::from_tzif("Europe/Test", &central_european_tzif()).unwrap());
+    zoned_date_time_mut
+        .set_in_time_zone(
+            (2024, 1, 1),
+            (0, 0, 0, 0, 0, 0),
+            &time_zone,
+            Disambiguation::Compatible,
+        )
+        .unwrap();
+    zoned_date_time_mut.set_to_now(&clock).unwrap();
+    assert_eq!(
+        zoned_date_time_mut.to_string(),
+        "2024-06-15T14:34:56.789612345+02:00[Europe/Test]"
+    );
+}
+
+#[test]
+fn zoned_date_time_set_to_now_requires_time_zone() {
+    let clock = FixedClock::new(JUNE_15_2024);
+    let mut var = Variable::new(
+        &DateTimeSpecBuilder::new()
+            .set_date_time_type(DateTimeType::Zoned)
+            .set_zone_type(ZoneType::Named)
+            .build(),
+    );
+    assert!(matches!(
+        var.zoned_date_time_mut().set_to_now(&clock),
+        Err(ZonedDateTimeError::ZoneTypeMismatch(_))
+    ));
+}
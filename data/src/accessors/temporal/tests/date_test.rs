@@ -4,8 +4,13 @@ use crate::{
     accessors::temporal::date::{DateError, DayOfWeek, days_in_month, is_leap_year, month_name},
     adaptor::Adaptor,
     adaptors::temporal_adaptors::date_adaptor::DateAdaptor,
-    data_spec_builders::date_spec_builder::DateSpecBuilder,
-    primitive_specs::date_spec::DateSpec,
+    data_spec_builders::{
+        date_spec_builder::DateSpecBuilder, duration_spec_builder::DurationSpecBuilder,
+    },
+    primitive_specs::{
+        date_spec::{DateRange, DateSpec},
+        duration_spec::{DurationResolution, DurationType},
+    },
     set_equal_to::SetEqualTo,
     variable,
 };
@@ -154,6 +159,17 @@ fn day_of_week_display() {
     assert_eq!(format!("{}", DayOfWeek::Sunday), "Sunday");
 }
 
+#[test]
+fn day_of_week_index() {
+    assert_eq!(DayOfWeek::Monday.index(), 0);
+    assert_eq!(DayOfWeek::Sunday.index(), 6);
+    for index in 0..7 {
+        assert_eq!(DayOfWeek::from_index(index).unwrap().index(), index);
+    }
+    assert_eq!(DayOfWeek::from_index(3), Some(DayOfWeek::Thursday));
+    assert_eq!(DayOfWeek::from_index(7), None);
+}
+
 #[test]
 fn year_out_of_bounds_low() {
     let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
@@ -468,7 +484,7 @@ impl DateAdaptor for CustomDateAdaptor {
 
 #[test]
 fn custom_date_adaptor() {
-    let date_spec = Rc::new(DateSpec::new());
+    let date_spec = Rc::new(DateSpec::new(DateRange::Standard));
     let custom_date = CustomDateAdaptor::new(date_spec.clone());
     let mut date_accessor = crate::accessors::temporal::date::Date::new(Box::new(custom_date));
     date_accessor.set_date(2020, 3, 14).unwrap();
@@ -479,7 +495,7 @@ fn custom_date_adaptor() {
 
 #[test]
 fn custom_date_adaptor_set_equal_to() {
-    let date_spec = Rc::new(DateSpec::new());
+    let date_spec = Rc::new(DateSpec::new(DateRange::Standard));
     let custom_date1 = CustomDateAdaptor::new(date_spec.clone());
     let mut date_accessor1 = crate::accessors::temporal::date::Date::new(Box::new(custom_date1));
     date_accessor1.set_date(2021, 6, 18).unwrap();
@@ -500,7 +516,7 @@ fn custom_date_adaptor_partial_eq_and_hash() {
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
 
-    let date_spec = Rc::new(DateSpec::new());
+    let date_spec = Rc::new(DateSpec::new(DateRange::Standard));
 
     let custom_date1 = CustomDateAdaptor::new(date_spec.clone());
     let mut date_accessor1 = crate::accessors::temporal::date::Date::new(Box::new(custom_date1));
@@ -539,7 +555,7 @@ fn custom_date_adaptor_partial_eq_and_hash() {
 
 #[test]
 fn custom_date_adaptor_ord() {
-    let date_spec = Rc::new(DateSpec::new());
+    let date_spec = Rc::new(DateSpec::new(DateRange::Standard));
 
     let custom_date1 = CustomDateAdaptor::new(date_spec.clone());
     let mut date_accessor1 = crate::accessors::temporal::date::Date::new(Box::new(custom_date1));
@@ -563,3 +579,553 @@ fn custom_date_adaptor_ord() {
         std::cmp::Ordering::Equal
     );
 }
+
+#[test]
+fn date_days() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+    for (date, days) in [
+        ((1, 1, 1), 0),
+        ((1, 12, 31), 364),
+        ((2000, 3, 1), 730_179),
+        ((2000, 12, 31), 730_484),
+        ((9999, 12, 31), 3_652_058),
+    ] {
+        date_mut.set_date(date.0, date.1, date.2).unwrap();
+        assert_eq!(date_mut.days().unwrap(), days);
+        date_mut.set_days(0).unwrap();
+        date_mut.set_days(days).unwrap();
+        assert_eq!(date_mut.date().unwrap(), date);
+    }
+    assert_eq!(
+        date_mut.set_days(3_652_059),
+        Err(DateError::YearOutOfBounds(10_000))
+    );
+}
+
+#[test]
+fn custom_date_adaptor_days() {
+    let date_spec = Rc::new(DateSpec::new(DateRange::Standard));
+    let custom_date = CustomDateAdaptor::new(date_spec.clone());
+    let mut date_accessor = crate::accessors::temporal::date::Date::new(Box::new(custom_date));
+    date_accessor.set_days(738_944).unwrap();
+    assert_eq!(date_accessor.date().unwrap(), (2024, 2, 29));
+    assert_eq!(date_accessor.days().unwrap(), 738_944);
+}
+
+#[test]
+fn date_add_year_to_month_duration() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let mut duration = variable::Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::YearToMonth)
+            .build()
+            .unwrap(),
+    );
+    let date_mut = var.date_mut();
+    for (date, (years, months), expected) in [
+        ((2024, 6, 15), (1, 2), (2025, 8, 15)),
+        ((2024, 6, 15), (0, 7), (2025, 1, 15)),
+        ((2024, 6, 15), (-1, -6), (2022, 12, 15)),
+        // the day is clamped to the end of the month
+        ((2024, 1, 31), (0, 1), (2024, 2, 29)),
+        ((2023, 1, 31), (0, 1), (2023, 2, 28)),
+        ((2024, 2, 29), (1, 0), (2025, 2, 28)),
+        ((2024, 3, 31), (0, -1), (2024, 2, 29)),
+        ((2024, 5, 31), (0, 1), (2024, 6, 30)),
+    ] {
+        date_mut.set_date(date.0, date.1, date.2).unwrap();
+        duration
+            .year_to_month_duration_mut()
+            .set_duration(years, months)
+            .unwrap();
+        date_mut
+            .add_year_to_month_duration(duration.year_to_month_duration())
+            .unwrap();
+        assert_eq!(date_mut.date().unwrap(), expected);
+
+        // subtracting the negated duration gives the same date
+        date_mut.set_date(date.0, date.1, date.2).unwrap();
+        duration
+            .year_to_month_duration_mut()
+            .set_duration(-years, -months)
+            .unwrap();
+        date_mut
+            .subtract_year_to_month_duration(duration.year_to_month_duration())
+            .unwrap();
+        assert_eq!(date_mut.date().unwrap(), expected);
+    }
+}
+
+#[test]
+fn date_year_to_month_duration_out_of_bounds() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let mut duration = variable::Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::YearToMonth)
+            .build()
+            .unwrap(),
+    );
+    let date_mut = var.date_mut();
+    date_mut.set_date(9999, 12, 15).unwrap();
+    duration
+        .year_to_month_duration_mut()
+        .set_duration(0, 1)
+        .unwrap();
+    assert_eq!(
+        date_mut.add_year_to_month_duration(duration.year_to_month_duration()),
+        Err(DateError::YearOutOfBounds(10_000))
+    );
+    date_mut.set_date(1, 1, 15).unwrap();
+    assert_eq!(
+        date_mut.subtract_year_to_month_duration(duration.year_to_month_duration()),
+        Err(DateError::YearOutOfBounds(0))
+    );
+    assert_eq!(date_mut.date().unwrap(), (1, 1, 15));
+}
+
+#[test]
+fn date_add_day_to_second_duration() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let mut duration = variable::Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::DayToSecond)
+            .build()
+            .unwrap(),
+    );
+    let date_mut = var.date_mut();
+    date_mut.set_date(2024, 2, 28).unwrap();
+    duration
+        .day_to_second_duration_mut()
+        .set_duration(2, 0, 0, 0, 0, 0, 0)
+        .unwrap();
+    date_mut
+        .add_day_to_second_duration(duration.day_to_second_duration())
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 3, 1));
+    date_mut
+        .subtract_day_to_second_duration(duration.day_to_second_duration())
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 2, 28));
+
+    // only whole days are added
+    duration
+        .day_to_second_duration_mut()
+        .set_duration(366, 23, 59, 0, 0, 0, 0)
+        .unwrap();
+    date_mut
+        .add_day_to_second_duration(duration.day_to_second_duration())
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2025, 2, 28));
+    duration
+        .day_to_second_duration_mut()
+        .set_duration(0, -23, 0, 0, 0, 0, 0)
+        .unwrap();
+    date_mut
+        .add_day_to_second_duration(duration.day_to_second_duration())
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2025, 2, 28));
+
+    // the date is left unchanged when out of bounds
+    duration
+        .day_to_second_duration_mut()
+        .set_duration(1_000_000_000, 0, 0, 0, 0, 0, 0)
+        .unwrap();
+    assert!(matches!(
+        date_mut.add_day_to_second_duration(duration.day_to_second_duration()),
+        Err(DateError::YearOutOfBounds(_))
+    ));
+    assert_eq!(
+        date_mut.subtract_day_to_second_duration(duration.day_to_second_duration()),
+        Err(DateError::YearOutOfBounds(0))
+    );
+    assert_eq!(date_mut.date().unwrap(), (2025, 2, 28));
+}
+
+#[test]
+fn date_days_until() {
+    let mut start = variable::Variable::new(&DateSpecBuilder::new().build());
+    let mut end = variable::Variable::new(&DateSpecBuilder::new().build());
+    let mut duration = variable::Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::DayToSecond)
+            .build()
+            .unwrap(),
+    );
+    start.date_mut().set_date(2024, 1, 1).unwrap();
+    end.date_mut().set_date(2025, 1, 1).unwrap();
+
+    start
+        .date()
+        .days_until(end.date(), duration.day_to_second_duration_mut())
+        .unwrap();
+    assert_eq!(duration.day_to_second_duration().days().unwrap(), 366);
+    end.date()
+        .days_until(start.date(), duration.day_to_second_duration_mut())
+        .unwrap();
+    assert_eq!(duration.day_to_second_duration().days().unwrap(), -366);
+    assert_eq!(duration.to_string(), "-P366D");
+}
+
+#[test]
+fn date_months_until() {
+    let mut start = variable::Variable::new(&DateSpecBuilder::new().build());
+    let mut end = variable::Variable::new(&DateSpecBuilder::new().build());
+    let mut duration = variable::Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::YearToMonth)
+            .build()
+            .unwrap(),
+    );
+    for (from, to, expected) in [
+        ((2024, 6, 15), (2024, 6, 15), (0, 0)),
+        ((2024, 6, 15), (2025, 8, 15), (1, 2)),
+        ((2024, 6, 15), (2025, 8, 14), (1, 1)),
+        ((2024, 6, 15), (2024, 7, 14), (0, 0)),
+        ((2025, 8, 15), (2024, 6, 15), (-1, -2)),
+        ((2025, 8, 14), (2024, 6, 15), (-1, -1)),
+        // end-of-month clamping counts as a whole month
+        ((2024, 1, 31), (2024, 2, 29), (0, 1)),
+        ((2023, 1, 31), (2023, 2, 28), (0, 1)),
+        ((2024, 1, 31), (2024, 2, 28), (0, 0)),
+        ((2024, 3, 31), (2024, 2, 29), (0, -1)),
+        ((2024, 3, 30), (2024, 2, 28), (0, -1)),
+    ] {
+        start.date_mut().set_date(from.0, from.1, from.2).unwrap();
+        end.date_mut().set_date(to.0, to.1, to.2).unwrap();
+        start
+            .date()
+            .months_until(end.date(), duration.year_to_month_duration_mut())
+            .unwrap();
+        assert_eq!(
+            duration.year_to_month_duration().duration().unwrap(),
+            expected,
+            "from {:?} to {:?}",
+            from,
+            to
+        );
+    }
+
+    // the duration's resolution is checked
+    let mut years = variable::Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::YearToMonth)
+            .set_resolution(DurationResolution::Year)
+            .build()
+            .unwrap(),
+    );
+    start.date_mut().set_date(2024, 1, 1).unwrap();
+    end.date_mut().set_date(2024, 3, 1).unwrap();
+    assert!(matches!(
+        start
+            .date()
+            .months_until(end.date(), years.year_to_month_duration_mut()),
+        Err(DateError::YearToMonthDurationError(_))
+    ));
+}
+
+#[test]
+fn iso_week_date() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+
+    for ((year, month, day), (week_year, week, weekday)) in [
+        ((2025, 2, 12), (2025, 7, 3)),
+        // early January in the last week of the previous year
+        ((2021, 1, 3), (2020, 53, 7)),
+        ((2022, 1, 1), (2021, 52, 6)),
+        // late December in week 1 of the next year
+        ((2024, 12, 30), (2025, 1, 1)),
+        ((2026, 1, 1), (2026, 1, 4)),
+        ((1, 1, 1), (1, 1, 1)),
+        ((9999, 12, 31), (9999, 52, 5)),
+    ] {
+        date_mut.set_date(year, month, day).unwrap();
+        assert_eq!(
+            date_mut.iso_week_date().unwrap(),
+            (week_year, week, weekday)
+        );
+        assert_eq!(date_mut.iso_week().unwrap(), week);
+        assert_eq!(date_mut.iso_week_year().unwrap(), week_year);
+
+        date_mut.set_date(1, 1, 1).unwrap();
+        date_mut
+            .set_iso_week_date(week_year, week, weekday)
+            .unwrap();
+        assert_eq!(date_mut.date().unwrap(), (year, month, day));
+    }
+
+    date_mut.set_date(2025, 2, 12).unwrap();
+    assert_eq!(date_mut.to_iso_week_date_string().unwrap(), "2025-W07-3");
+    date_mut.set_date(2021, 1, 3).unwrap();
+    assert_eq!(date_mut.to_iso_week_date_string().unwrap(), "2020-W53-7");
+}
+
+#[test]
+fn iso_week_date_out_of_bounds() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+    date_mut.set_date(2024, 6, 15).unwrap();
+
+    assert_eq!(
+        date_mut.set_iso_week_date(2025, 53, 1),
+        Err(DateError::WeekOutOfBounds(53, 2025, 52))
+    );
+    assert_eq!(
+        date_mut.set_iso_week_date(2025, 0, 1),
+        Err(DateError::WeekOutOfBounds(0, 2025, 52))
+    );
+    assert_eq!(
+        date_mut.set_iso_week_date(2025, 1, 0),
+        Err(DateError::WeekdayOutOfBounds(0))
+    );
+    assert_eq!(
+        date_mut.set_iso_week_date(0, 1, 1),
+        Err(DateError::YearOutOfBounds(0))
+    );
+    // the last days of week 52 of 9999 fall in 10000
+    assert_eq!(
+        date_mut.set_iso_week_date(9999, 52, 6),
+        Err(DateError::YearOutOfBounds(10000))
+    );
+    assert_eq!(date_mut.date().unwrap(), (2024, 6, 15));
+
+    assert_eq!(
+        DateError::WeekOutOfBounds(53, 2025, 52).to_string(),
+        "Week value 53 is out of bounds for ISO week-numbering year 2025. Valid range is 1 to 52."
+    );
+    assert_eq!(
+        DateError::WeekdayOutOfBounds(8).to_string(),
+        "Weekday value 8 is out of bounds (1 to 7)."
+    );
+}
+
+#[test]
+fn ordinal_date() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+
+    date_mut.set_ordinal_date(2025, 45).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2025, 2, 14));
+    assert_eq!(date_mut.to_ordinal_date_string().unwrap(), "2025-045");
+    date_mut.set_ordinal_date(2024, 366).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 12, 31));
+    assert_eq!(date_mut.to_ordinal_date_string().unwrap(), "2024-366");
+
+    assert_eq!(
+        date_mut.set_ordinal_date(2025, 366),
+        Err(DateError::DayOfYearOutOfBounds(366, 2025, 365))
+    );
+    assert_eq!(
+        date_mut.set_ordinal_date(2025, 0),
+        Err(DateError::DayOfYearOutOfBounds(0, 2025, 365))
+    );
+    assert_eq!(
+        DateError::DayOfYearOutOfBounds(366, 2025, 365).to_string(),
+        "Day of the year value 366 is out of bounds for year 2025. Valid range is 1 to 365."
+    );
+    assert_eq!(date_mut.date().unwrap(), (2024, 12, 31));
+}
+
+#[test]
+fn proleptic_date() {
+    let mut var = variable::Variable::new(
+        &DateSpecBuilder::new()
+            .set_range(DateRange::Extended)
+            .build(),
+    );
+    let date_mut = var.date_mut();
+
+    // December 31, 1 B.C. is the day before January 1, 0001
+    date_mut.set_proleptic_date(0, 12, 31).unwrap();
+    assert_eq!(date_mut.signed_days().unwrap(), -1);
+    assert_eq!(date_mut.day_of_week().unwrap(), DayOfWeek::Sunday);
+    assert_eq!(date_mut.day_of_year().unwrap(), 366);
+    assert_eq!(date_mut.to_string(), "0000-12-31");
+    assert_eq!(
+        format!("{:?}", date_mut),
+        "Date { year: 0, month: 12, day: 31 }"
+    );
+
+    // year 0 is a leap year, as is every 400th year before it
+    date_mut.set_proleptic_date(-400, 2, 29).unwrap();
+    assert_eq!(date_mut.proleptic_date().unwrap(), (-400, 2, 29));
+    assert_eq!(date_mut.to_string(), "-000400-02-29");
+    assert_eq!(
+        date_mut.set_proleptic_date(-100, 2, 29),
+        Err(DateError::DayOutOfBounds(29, "February".to_string(), 28))
+    );
+
+    date_mut.set_signed_days(-366).unwrap();
+    assert_eq!(date_mut.proleptic_date().unwrap(), (0, 1, 1));
+    date_mut.set_proleptic_date(999_999, 12, 31).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (999_999, 12, 31));
+    assert_eq!(date_mut.to_string(), "+999999-12-31");
+    date_mut.set_date(10_000, 1, 1).unwrap();
+    assert_eq!(date_mut.to_string(), "+010000-01-01");
+
+    // the unsigned accessors reject dates before January 1, 0001
+    date_mut.set_proleptic_date(-43, 3, 13).unwrap();
+    assert_eq!(date_mut.date(), Err(DateError::DateBeforeYearOne(-43)));
+    assert_eq!(date_mut.days(), Err(DateError::DateBeforeYearOne(-43)));
+    assert_eq!(
+        DateError::DateBeforeYearOne(-43).to_string(),
+        "The date in year -43 lies before January 1, 0001; use the proleptic date or signed days."
+    );
+
+    assert_eq!(
+        date_mut.set_proleptic_date(-1_000_000, 1, 1),
+        Err(DateError::YearOutOfRange(-1_000_000, DateRange::Extended))
+    );
+    assert_eq!(
+        date_mut.set_date(1_000_000, 1, 1),
+        Err(DateError::YearOutOfRange(1_000_000, DateRange::Extended))
+    );
+    assert_eq!(
+        DateError::YearOutOfRange(1_000_000, DateRange::Extended).to_string(),
+        "Year value 1000000 is out of the Extended date range (-999999 to 999999)."
+    );
+    assert_eq!(date_mut.proleptic_date().unwrap(), (-43, 3, 13));
+}
+
+#[test]
+fn proleptic_date_standard_range() {
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+    date_mut.set_date(2024, 6, 15).unwrap();
+
+    assert_eq!(
+        date_mut.set_proleptic_date(0, 12, 31),
+        Err(DateError::YearOutOfBounds(0))
+    );
+    assert_eq!(
+        date_mut.set_signed_days(-1),
+        Err(DateError::YearOutOfBounds(0))
+    );
+    assert_eq!(date_mut.proleptic_date().unwrap(), (2024, 6, 15));
+    assert_eq!(
+        date_mut.signed_days().unwrap(),
+        date_mut.days().unwrap() as i64
+    );
+
+    // an extended date before year 1 cannot be copied to a standard date
+    let mut extended = variable::Variable::new(
+        &DateSpecBuilder::new()
+            .set_range(DateRange::Extended)
+            .build(),
+    );
+    extended.date_mut().set_proleptic_date(-43, 3, 13).unwrap();
+    assert!(date_mut.set_equal_to(extended.date()).is_err());
+    extended
+        .date_mut()
+        .set_proleptic_date(1066, 10, 14)
+        .unwrap();
+    date_mut.set_equal_to(extended.date()).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (1066, 10, 14));
+    assert!(*date_mut == *extended.date());
+}
+
+#[test]
+fn proleptic_date_arithmetic() {
+    let spec = DateSpecBuilder::new()
+        .set_range(DateRange::Extended)
+        .build();
+    let mut var = variable::Variable::new(&spec);
+    let mut start = variable::Variable::new(&spec);
+    let mut days = variable::Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::DayToSecond)
+            .build()
+            .unwrap(),
+    );
+    let mut months = variable::Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::YearToMonth)
+            .build()
+            .unwrap(),
+    );
+    let date_mut = var.date_mut();
+
+    date_mut.set_proleptic_date(1, 1, 1).unwrap();
+    days.day_to_second_duration_mut()
+        .set_duration(1, 0, 0, 0, 0, 0, 0)
+        .unwrap();
+    date_mut
+        .subtract_day_to_second_duration(days.day_to_second_duration())
+        .unwrap();
+    assert_eq!(date_mut.proleptic_date().unwrap(), (0, 12, 31));
+
+    months
+        .year_to_month_duration_mut()
+        .set_duration(1, 2)
+        .unwrap();
+    date_mut
+        .subtract_year_to_month_duration(months.year_to_month_duration())
+        .unwrap();
+    assert_eq!(date_mut.proleptic_date().unwrap(), (-1, 10, 31));
+
+    start.date_mut().set_proleptic_date(1, 1, 1).unwrap();
+    start
+        .date()
+        .days_until(date_mut, days.day_to_second_duration_mut())
+        .unwrap();
+    assert_eq!(days.day_to_second_duration().days().unwrap(), -428);
+    start
+        .date()
+        .months_until(date_mut, months.year_to_month_duration_mut())
+        .unwrap();
+    assert_eq!(
+        months.year_to_month_duration().duration().unwrap(),
+        (-1, -2)
+    );
+}
+
+#[test]
+fn julian_date() {
+    let mut var = variable::Variable::new(
+        &DateSpecBuilder::new()
+            .set_range(DateRange::Extended)
+            .build(),
+    );
+    let date_mut = var.date_mut();
+
+    // the Gregorian calendar followed October 4, 1582 of the Julian calendar with October 15
+    date_mut.set_date(1582, 10, 15).unwrap();
+    assert_eq!(date_mut.julian_date().unwrap(), (1582, 10, 5));
+    date_mut.set_julian_date(1582, 10, 4).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (1582, 10, 14));
+
+    // the calendars agree from March 1, 200 to February 28, 300
+    date_mut.set_julian_date(200, 3, 1).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (200, 3, 1));
+    date_mut.set_julian_date(1, 1, 1).unwrap();
+    assert_eq!(date_mut.proleptic_date().unwrap(), (0, 12, 30));
+    date_mut.set_date(2024, 6, 15).unwrap();
+    assert_eq!(date_mut.julian_date().unwrap(), (2024, 6, 2));
+
+    // 1900 is a leap year of the Julian calendar only
+    date_mut.set_julian_date(1900, 2, 29).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (1900, 3, 13));
+    assert_eq!(date_mut.julian_date().unwrap(), (1900, 2, 29));
+    // the epoch of the Julian day numbers, January 1, 4713 B.C. of the Julian calendar
+    date_mut.set_julian_date(-4712, 1, 1).unwrap();
+    assert_eq!(date_mut.proleptic_date().unwrap(), (-4713, 11, 24));
+    assert_eq!(date_mut.julian_date().unwrap(), (-4712, 1, 1));
+
+    assert_eq!(
+        date_mut.set_julian_date(1901, 2, 29),
+        Err(DateError::DayOutOfBounds(29, "February".to_string(), 28))
+    );
+    assert_eq!(
+        date_mut.set_julian_date(1901, 13, 1),
+        Err(DateError::MonthOutOfBounds(13))
+    );
+    assert_eq!(date_mut.julian_date().unwrap(), (-4712, 1, 1));
+
+    // a Julian date in year 1 lies before the standard range
+    let mut var = variable::Variable::new(&DateSpecBuilder::new().build());
+    assert_eq!(
+        var.date_mut().set_julian_date(1, 1, 2),
+        Err(DateError::YearOutOfBounds(0))
+    );
+}
@@ -0,0 +1,183 @@
+use crate::{
+    accessors::temporal::{date::DateError, date_time::DateTimeError, time::TimeError},
+    data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder,
+    primitive_specs::date_time_spec::DateTimeType,
+    set_equal_to::SetEqualTo,
+    variable::Variable,
+};
+
+#[test]
+fn default_date_time() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let var = Variable::new(&spec);
+    let date_time = var.date_time();
+    assert_eq!(date_time.time().time().unwrap(), (0, 0, 0, 0, 0, 0));
+    assert_eq!(
+        date_time.date_time().unwrap(),
+        (date_time.date().date().unwrap(), (0, 0, 0, 0, 0, 0))
+    );
+}
+
+#[test]
+fn set_date_time() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut var = Variable::new(&spec);
+    let date_time = var.date_time_mut();
+    date_time
+        .set_date_time(2024, 2, 29, 23, 59, 58, 1, 2, 3)
+        .unwrap();
+    assert_eq!(
+        date_time.date_time().unwrap(),
+        ((2024, 2, 29), (23, 59, 58, 1, 2, 3))
+    );
+    assert_eq!(date_time.date().day_of_year().unwrap(), 60);
+    assert_eq!(date_time.time().nanosecond().unwrap(), 3);
+}
+
+#[test]
+fn set_date_time_is_unchanged_on_error() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut var = Variable::new(&spec);
+    let date_time = var.date_time_mut();
+    date_time
+        .set_date_time(2024, 6, 15, 12, 0, 0, 0, 0, 0)
+        .unwrap();
+
+    assert_eq!(
+        date_time.set_date_time(2023, 2, 29, 12, 0, 0, 0, 0, 0),
+        Err(DateTimeError::DateError(DateError::DayOutOfBounds(
+            29,
+            "February".to_string(),
+            28
+        )))
+    );
+    assert_eq!(
+        date_time.set_date_time(2025, 1, 1, 24, 0, 0, 0, 0, 0),
+        Err(DateTimeError::TimeError(TimeError::HourOutOfBounds(24)))
+    );
+    assert_eq!(
+        date_time.date_time().unwrap(),
+        ((2024, 6, 15), (12, 0, 0, 0, 0, 0))
+    );
+}
+
+#[test]
+fn set_components() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut var = Variable::new(&spec);
+    let date_time = var.date_time_mut();
+    date_time.date_mut().set_date(2024, 6, 15).unwrap();
+    date_time.time_mut().set_time(8, 30, 0, 0, 0, 0).unwrap();
+    assert_eq!(date_time.to_string(), "2024-06-15T08:30:00");
+}
+
+#[test]
+fn date_time_display_and_debug() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.date_time_mut()
+        .set_from_string("2024-06-15T12:34:56.000789")
+        .unwrap();
+    assert_eq!(var.date_time().to_string(), "2024-06-15T12:34:56.000789");
+    assert_eq!(var.to_string(), "2024-06-15T12:34:56.000789");
+    assert_eq!(
+        format!("{:?}", var.date_time()),
+        "DateTime { date: Date { year: 2024, month: 6, day: 15 }, time: Time { hour: 12, minute: 34, second: 56, millisecond: 000, microsecond: 789, nanosecond: 000 } }"
+    );
+}
+
+#[test]
+fn date_time_set_equal_to() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    var1.date_time_mut()
+        .set_from_string("2024-06-15T12:34:56")
+        .unwrap();
+
+    // test set equal to via accessor
+    let mut var2 = Variable::new(&spec);
+    var2.date_time_mut().set_equal_to(var1.date_time()).unwrap();
+    assert_eq!(var2.date_time(), var1.date_time());
+
+    // test set equal to via variable
+    let mut var3 = Variable::new(&spec);
+    var3.set_equal_to(&var1).unwrap();
+    assert_eq!(var3.to_string(), "2024-06-15T12:34:56");
+
+    // test clone
+    let var4 = var1.clone();
+    assert_eq!(var4, var1);
+}
+
+#[test]
+fn date_time_partial_eq_and_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    let mut var2 = Variable::new(&spec);
+    let mut var3 = Variable::new(&spec);
+    var1.date_time_mut()
+        .set_from_string("2024-06-15T12:34:56")
+        .unwrap();
+    var2.date_time_mut()
+        .set_from_string("20240615T123456")
+        .unwrap();
+    var3.date_time_mut()
+        .set_from_string("2024-06-16T12:34:56")
+        .unwrap();
+
+    assert_eq!(var1.date_time(), var2.date_time());
+    assert_ne!(var1.date_time(), var3.date_time());
+    assert_eq!(var1, var2);
+    assert_ne!(var1, var3);
+
+    let hash = |var: &Variable| {
+        let mut hasher = DefaultHasher::new();
+        var.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&var1), hash(&var2));
+    assert_ne!(hash(&var1), hash(&var3));
+}
+
+#[test]
+fn date_time_ord() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    let mut var2 = Variable::new(&spec);
+    let mut var3 = Variable::new(&spec);
+    var1.date_time_mut()
+        .set_from_string("2024-06-15T23:59:59.999999999")
+        .unwrap();
+    var2.date_time_mut()
+        .set_from_string("2024-06-16T00:00:00")
+        .unwrap();
+    var3.date_time_mut()
+        .set_from_string("2024-06-16T00:00:00.000000001")
+        .unwrap();
+
+    assert!(var1.date_time() < var2.date_time());
+    assert!(var2.date_time() < var3.date_time());
+    assert!(var3.date_time() > var1.date_time());
+    assert!(var1 < var2);
+    assert!(var2 < var3);
+    assert!(var2 <= var2.clone());
+}
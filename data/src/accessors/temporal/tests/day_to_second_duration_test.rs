@@ -0,0 +1,560 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::temporal::day_to_second_duration::{DayToSecondDuration, DayToSecondDurationError},
+    adaptor::Adaptor,
+    adaptors::temporal_adaptors::day_to_second_duration_adaptor::DayToSecondDurationAdaptor,
+    data_spec_builders::duration_spec_builder::DurationSpecBuilder,
+    primitive_specs::{
+        duration_spec::{DurationResolution, DurationSpec, DurationType},
+        rounding_policy::RoundingPolicy,
+    },
+    provider_error::ProviderError,
+    set_equal_to::SetEqualTo,
+    variable::Variable,
+};
+
+#[test]
+fn default_day_to_second_duration() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration();
+    assert_eq!(dsd.duration().unwrap(), (0, 0, 0, 0, 0, 0, 0));
+    assert_eq!(dsd.total_nanoseconds().unwrap(), 0);
+}
+
+#[test]
+fn positive_day_to_second_duration() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_duration(1, 2, 3, 4, 5, 6, 7).unwrap();
+    assert_eq!(dsd.duration().unwrap(), (1, 2, 3, 4, 5, 6, 7));
+    assert_eq!(dsd.days().unwrap(), 1);
+    assert_eq!(dsd.hours().unwrap(), 2);
+    assert_eq!(dsd.minutes().unwrap(), 3);
+    assert_eq!(dsd.seconds().unwrap(), 4);
+    assert_eq!(dsd.milliseconds().unwrap(), 5);
+    assert_eq!(dsd.microseconds().unwrap(), 6);
+    assert_eq!(dsd.nanoseconds().unwrap(), 7);
+    assert_eq!(dsd.total_nanoseconds().unwrap(), 93_784_005_006_007);
+}
+
+#[test]
+fn negative_day_to_second_duration() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_duration(-1, -2, -3, -4, -5, -6, -7).unwrap();
+    assert_eq!(dsd.duration().unwrap(), (-1, -2, -3, -4, -5, -6, -7));
+    assert_eq!(dsd.total_nanoseconds().unwrap(), -93_784_005_006_007);
+}
+
+#[test]
+fn normalized_overflowing_fields() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_duration(0, 25, 61, 61, 1001, 1001, 1001).unwrap();
+    assert_eq!(dsd.duration().unwrap(), (1, 2, 2, 2, 2, 2, 1));
+}
+
+#[test]
+fn normalized_mixed_signs() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_duration(1, -1, 0, 0, 0, 0, 0).unwrap();
+    assert_eq!(dsd.duration().unwrap(), (0, 23, 0, 0, 0, 0, 0));
+    dsd.set_duration(0, 0, 0, -1, 500, 0, 0).unwrap();
+    assert_eq!(dsd.duration().unwrap(), (0, 0, 0, 0, -500, 0, 0));
+}
+
+#[test]
+fn total_nanoseconds() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_total_nanoseconds(-90_061_001_000_001).unwrap();
+    assert_eq!(dsd.duration().unwrap(), (-1, -1, -1, -1, -1, 0, -1));
+}
+
+#[test]
+fn resolution_out_of_bounds_error() {
+    let minute_spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .set_resolution(DurationResolution::Minute)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&minute_spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_duration(1, 2, 3, 0, 0, 0, 0).unwrap();
+    assert_eq!(
+        dsd.set_duration(0, 0, 0, 4, 0, 0, 0),
+        Err(DayToSecondDurationError::ResolutionOutOfBounds(
+            "Cannot set seconds with value 4 on a day-to-second-duration with a Minute resolution."
+                .to_string()
+        ))
+    );
+    assert_eq!(
+        dsd.set_total_nanoseconds(1_000_000_000),
+        Err(DayToSecondDurationError::ResolutionOutOfBounds(
+            "Cannot set 1000000000 nanoseconds on a day-to-second-duration with a Minute resolution."
+                .to_string()
+        ))
+    );
+    assert_eq!(dsd.duration().unwrap(), (1, 2, 3, 0, 0, 0, 0));
+}
+
+#[test]
+fn resolution_applies_to_total() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .set_resolution(DurationResolution::Hour)
+        .set_rounding_policy(RoundingPolicy::Reject)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+
+    // finer fields are accepted when they add up to whole hours, as with total nanoseconds
+    dsd.set_duration(0, 0, 120, 0, 0, 0, 0).unwrap();
+    assert_eq!(dsd.duration().unwrap(), (0, 2, 0, 0, 0, 0, 0));
+    dsd.set_duration(1, -1, 30, 1_800, 0, 0, 0).unwrap();
+    assert_eq!(dsd.duration().unwrap(), (1, 0, 0, 0, 0, 0, 0));
+    assert_eq!(
+        dsd.set_duration(0, 0, 90, 0, 0, 0, 0),
+        Err(DayToSecondDurationError::ResolutionOutOfBounds(
+            "Cannot set minutes with value 90 on a day-to-second-duration with a Hour resolution."
+                .to_string()
+        ))
+    );
+    assert_eq!(dsd.duration().unwrap(), (1, 0, 0, 0, 0, 0, 0));
+}
+
+#[test]
+fn overflow_error() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_duration(i32::MAX, 23, 0, 0, 0, 0, 0).unwrap();
+    assert!(matches!(
+        dsd.set_duration(i32::MAX, 24, 0, 0, 0, 0, 0),
+        Err(DayToSecondDurationError::Overflow(_))
+    ));
+    assert_eq!(dsd.days().unwrap(), i32::MAX);
+}
+
+#[test]
+fn negate() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_duration(1, 2, 0, 0, 0, 0, 0).unwrap();
+    dsd.negate().unwrap();
+    assert_eq!(dsd.duration().unwrap(), (-1, -2, 0, 0, 0, 0, 0));
+    dsd.negate().unwrap();
+    assert_eq!(dsd.duration().unwrap(), (1, 2, 0, 0, 0, 0, 0));
+}
+
+#[test]
+fn add_and_subtract() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var1 = Variable::new(&spec);
+    let mut var2 = Variable::new(&spec);
+    var2.day_to_second_duration_mut()
+        .set_duration(0, 12, 30, 0, 0, 0, 0)
+        .unwrap();
+
+    let dsd1 = var1.day_to_second_duration_mut();
+    dsd1.set_duration(0, 12, 45, 0, 0, 0, 0).unwrap();
+    dsd1.add(var2.day_to_second_duration()).unwrap();
+    assert_eq!(dsd1.duration().unwrap(), (1, 1, 15, 0, 0, 0, 0));
+    dsd1.subtract(var2.day_to_second_duration()).unwrap();
+    dsd1.subtract(var2.day_to_second_duration()).unwrap();
+    assert_eq!(dsd1.duration().unwrap(), (0, 0, 15, 0, 0, 0, 0));
+    dsd1.subtract(var2.day_to_second_duration()).unwrap();
+    assert_eq!(dsd1.duration().unwrap(), (0, -12, -15, 0, 0, 0, 0));
+}
+
+#[test]
+fn add_finer_resolution_error() {
+    let second_spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .set_resolution(DurationResolution::Second)
+        .build()
+        .unwrap();
+    let mut var1 = Variable::new(&second_spec);
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var2 = Variable::new(&spec);
+    var2.day_to_second_duration_mut()
+        .set_duration(0, 0, 0, 0, 1, 0, 0)
+        .unwrap();
+    let dsd1 = var1.day_to_second_duration_mut();
+    assert!(matches!(
+        dsd1.add(var2.day_to_second_duration()),
+        Err(DayToSecondDurationError::ResolutionOutOfBounds(_))
+    ));
+}
+
+#[test]
+fn dsd_debug() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_duration(1, 2, 3, 4, 5, 6, 7).unwrap();
+    assert_eq!(
+        format!("{:?}", dsd),
+        "DayToSecondDuration { days: 1, hours: 2, minutes: 3, seconds: 4, milliseconds: 5, microseconds: 6, nanoseconds: 7 }"
+    );
+}
+
+#[test]
+fn dsd_set_equal_to() {
+    // set value to be copied
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var1_mut = Variable::new(&spec);
+    let dsd1_mut = var1_mut.day_to_second_duration_mut();
+    dsd1_mut.set_duration(1, 2, 3, 4, 5, 6, 7).unwrap();
+
+    // test set equal to via accessor
+    let mut var2_mut = Variable::new(&spec);
+    let dsd2_mut = var2_mut.day_to_second_duration_mut();
+    dsd2_mut.set_equal_to(dsd1_mut).unwrap();
+    assert_eq!(dsd2_mut.duration().unwrap(), (1, 2, 3, 4, 5, 6, 7));
+
+    // test set equal to via variable
+    let mut var3_mut = Variable::new(&spec);
+    var3_mut.set_equal_to(&var1_mut).unwrap();
+    assert_eq!(
+        var3_mut.day_to_second_duration().duration().unwrap(),
+        (1, 2, 3, 4, 5, 6, 7)
+    );
+}
+
+#[test]
+fn dsd_partial_eq_and_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var1_mut = Variable::new(&spec);
+    let mut var2_mut = Variable::new(&spec);
+    let mut var3_mut = Variable::new(&spec);
+
+    var1_mut
+        .day_to_second_duration_mut()
+        .set_duration(1, 0, 0, 0, 0, 0, 0)
+        .unwrap();
+    var2_mut
+        .day_to_second_duration_mut()
+        .set_duration(0, 24, 0, 0, 0, 0, 0)
+        .unwrap();
+    var3_mut
+        .day_to_second_duration_mut()
+        .set_duration(0, 23, 0, 0, 0, 0, 0)
+        .unwrap();
+
+    // test accessor equality
+    assert_eq!(
+        var1_mut.day_to_second_duration(),
+        var2_mut.day_to_second_duration()
+    );
+    assert_ne!(
+        var1_mut.day_to_second_duration(),
+        var3_mut.day_to_second_duration()
+    );
+
+    // test variable equality
+    assert_eq!(var1_mut, var2_mut);
+    assert_ne!(var1_mut, var3_mut);
+
+    // test variable hashing
+    let hash = |var: &Variable| {
+        let mut hasher = DefaultHasher::new();
+        var.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&var1_mut), hash(&var2_mut));
+    assert_ne!(hash(&var1_mut), hash(&var3_mut));
+}
+
+#[test]
+fn dsd_ord() {
+    let spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var1_mut = Variable::new(&spec);
+    let mut var2_mut = Variable::new(&spec);
+    let mut var3_mut = Variable::new(&spec);
+
+    var1_mut
+        .day_to_second_duration_mut()
+        .set_duration(0, -1, 0, 0, 0, 0, 0)
+        .unwrap();
+    var2_mut
+        .day_to_second_duration_mut()
+        .set_duration(0, 0, 0, 0, 0, 0, 1)
+        .unwrap();
+    var3_mut
+        .day_to_second_duration_mut()
+        .set_duration(0, 0, -60, 0, 0, 0, 0)
+        .unwrap();
+
+    // test accessor ordering
+    let dsd1 = var1_mut.day_to_second_duration();
+    let dsd2 = var2_mut.day_to_second_duration();
+    let dsd3 = var3_mut.day_to_second_duration();
+    assert!(dsd1 < dsd2);
+    assert!(dsd2 > dsd1);
+    assert!(dsd1 <= dsd3);
+    assert!(dsd1 >= dsd3);
+
+    // test variable ordering
+    assert!(var1_mut < var2_mut);
+    assert!(var2_mut > var1_mut);
+    assert!(var1_mut <= var3_mut);
+    assert!(var1_mut >= var3_mut);
+}
+
+struct CustomDsdAdaptor {
+    spec: Rc<DurationSpec>,
+    duration: (i32, i32, i32, i32, i32, i32, i32),
+}
+
+impl CustomDsdAdaptor {
+    pub fn new(spec: Rc<DurationSpec>) -> Self {
+        Self {
+            spec,
+            duration: (0, 0, 0, 0, 0, 0, 0),
+        }
+    }
+}
+
+impl Adaptor for CustomDsdAdaptor {}
+
+impl DayToSecondDurationAdaptor for CustomDsdAdaptor {
+    fn spec(&self) -> &Rc<DurationSpec> {
+        &self.spec
+    }
+
+    fn stores_duration_as_nanoseconds(&self) -> bool {
+        false
+    }
+
+    fn set_duration(
+        &mut self,
+        days: i32,
+        hours: i32,
+        minutes: i32,
+        seconds: i32,
+        milliseconds: i32,
+        microseconds: i32,
+        nanoseconds: i32,
+    ) -> Result<(), DayToSecondDurationError> {
+        self.duration = (
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        );
+        Ok(())
+    }
+
+    fn duration(&self) -> Result<(i32, i32, i32, i32, i32, i32, i32), DayToSecondDurationError> {
+        Ok(self.duration)
+    }
+}
+
+#[test]
+fn custom_dsd_adaptor() {
+    let spec = Rc::new(DurationSpec::new(Some(DurationType::DayToSecond), None));
+    let mut custom_accessor = DayToSecondDuration::new(Box::new(CustomDsdAdaptor::new(spec)));
+    custom_accessor.set_duration(1, 2, 3, 4, 5, 6, 7).unwrap();
+    assert_eq!(custom_accessor.duration().unwrap(), (1, 2, 3, 4, 5, 6, 7));
+    assert_eq!(
+        custom_accessor.total_nanoseconds().unwrap(),
+        93_784_005_006_007
+    );
+    custom_accessor.negate().unwrap();
+    assert_eq!(
+        custom_accessor.duration().unwrap(),
+        (-1, -2, -3, -4, -5, -6, -7)
+    );
+}
+
+#[test]
+fn custom_dsd_adaptor_set_equal_to() {
+    let spec = Rc::new(DurationSpec::new(Some(DurationType::DayToSecond), None));
+
+    let mut custom_accessor1 =
+        DayToSecondDuration::new(Box::new(CustomDsdAdaptor::new(spec.clone())));
+    custom_accessor1.set_duration(0, 0, 90, 0, 0, 0, 0).unwrap();
+
+    let variable_spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&variable_spec);
+    let dsd = var.day_to_second_duration_mut();
+    dsd.set_equal_to(&custom_accessor1).unwrap();
+    assert_eq!(dsd.duration().unwrap(), (0, 1, 30, 0, 0, 0, 0));
+    assert_eq!(*dsd, custom_accessor1);
+}
+
+struct UnimplementedDsdAdaptor {
+    spec: Rc<DurationSpec>,
+    stores_duration_as_nanoseconds: bool,
+}
+
+impl Adaptor for UnimplementedDsdAdaptor {}
+
+impl DayToSecondDurationAdaptor for UnimplementedDsdAdaptor {
+    fn spec(&self) -> &Rc<DurationSpec> {
+        &self.spec
+    }
+
+    fn stores_duration_as_nanoseconds(&self) -> bool {
+        self.stores_duration_as_nanoseconds
+    }
+}
+
+#[test]
+fn unimplemented_dsd_adaptor() {
+    // an adaptor without the methods for how it stores the duration fails instead of panicking
+    for (stores_duration_as_nanoseconds, method) in
+        [(true, "total_nanoseconds"), (false, "duration")]
+    {
+        let spec = Rc::new(DurationSpec::new(Some(DurationType::DayToSecond), None));
+        let mut accessor = DayToSecondDuration::new(Box::new(UnimplementedDsdAdaptor {
+            spec,
+            stores_duration_as_nanoseconds,
+        }));
+        assert_eq!(
+            accessor.duration(),
+            Err(DayToSecondDurationError::ProviderError(
+                ProviderError::General(format!(
+                    "The day-to-second-duration adaptor does not implement '{}'.",
+                    method
+                ))
+            ))
+        );
+        assert!(matches!(
+            accessor.set_duration(1, 2, 3, 4, 5, 6, 7),
+            Err(DayToSecondDurationError::ProviderError(..))
+        ));
+    }
+}
+
+#[test]
+fn day_to_second_duration_rounding_policies() {
+    for (rounding_policy, expected) in [
+        (RoundingPolicy::Truncate, [90, -90, 91, -91]),
+        (RoundingPolicy::RoundHalfEven, [90, -90, 92, -92]),
+        (RoundingPolicy::RoundHalfUp, [91, -91, 92, -92]),
+    ] {
+        let mut builder = DurationSpecBuilder::new();
+        builder
+            .set_type(DurationType::DayToSecond)
+            .set_resolution(DurationResolution::Second)
+            .set_rounding_policy(rounding_policy);
+        let mut var = Variable::new(&builder.build().unwrap());
+        let duration_mut = var.day_to_second_duration_mut();
+        for ((minutes, seconds, milliseconds), expected) in
+            [(1, 30, 500), (-1, -30, -500), (1, 31, 500), (-1, -31, -500)]
+                .into_iter()
+                .zip(expected)
+        {
+            duration_mut
+                .set_duration(0, 0, minutes, seconds, milliseconds, 0, 0)
+                .unwrap();
+            assert_eq!(
+                duration_mut.total_nanoseconds().unwrap(),
+                expected * 1_000_000_000
+            );
+        }
+        // durations are rounded as a whole
+        duration_mut
+            .set_total_nanoseconds(86_399_999_999_999)
+            .unwrap();
+        let expected = match rounding_policy {
+            RoundingPolicy::Truncate => (0, 23, 59, 59, 0, 0, 0),
+            _ => (1, 0, 0, 0, 0, 0, 0),
+        };
+        assert_eq!(duration_mut.duration().unwrap(), expected);
+    }
+}
+
+#[test]
+fn day_to_second_duration_with_rounding() {
+    let millisecond_spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .set_resolution(DurationResolution::Millisecond)
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&millisecond_spec);
+    let duration_mut = var.day_to_second_duration_mut();
+    assert!(matches!(
+        duration_mut.set_duration(0, 0, 0, 1, 2, 500, 0),
+        Err(DayToSecondDurationError::ResolutionOutOfBounds(_))
+    ));
+    duration_mut
+        .set_duration_with_rounding(0, 0, 0, 1, 2, 500, 0, RoundingPolicy::RoundHalfUp)
+        .unwrap();
+    assert_eq!(duration_mut.duration().unwrap(), (0, 0, 0, 1, 3, 0, 0));
+    duration_mut
+        .set_total_nanoseconds_with_rounding(2_500_000, RoundingPolicy::RoundHalfEven)
+        .unwrap();
+    assert_eq!(duration_mut.duration().unwrap(), (0, 0, 0, 0, 2, 0, 0));
+    assert_eq!(
+        duration_mut.set_total_nanoseconds_with_rounding(2_500_000, RoundingPolicy::Reject),
+        Err(DayToSecondDurationError::ResolutionOutOfBounds(
+            "Cannot set 2500000 nanoseconds on a day-to-second-duration with a Millisecond resolution."
+                .to_string()
+        ))
+    );
+    assert_eq!(duration_mut.duration().unwrap(), (0, 0, 0, 0, 2, 0, 0));
+}
@@ -0,0 +1,582 @@
+use crate::{
+    accessors::temporal::{
+        date::DateError,
+        format_pattern::{FormatError, FormatPattern, RFC_2822, RFC_3339},
+        locale::Locale,
+        time::TimeError,
+        zoned_date_time::ZonedDateTimeError,
+        zoned_time::ZonedTimeError,
+    },
+    data_spec_builders::{
+        date_spec_builder::DateSpecBuilder, date_time_spec_builder::DateTimeSpecBuilder,
+        time_spec_builder::TimeSpecBuilder,
+    },
+    primitive_specs::{
+        date_spec::DateRange,
+        date_time_spec::DateTimeType,
+        time_spec::{TimeResolution, TimeType},
+    },
+    variable::Variable,
+};
+
+#[test]
+fn format_pattern_new() {
+    let rfc_3339 = FormatPattern::rfc_3339();
+    assert_eq!(rfc_3339.pattern(), RFC_3339);
+    assert_eq!(rfc_3339.to_string(), "%Y-%m-%dT%H:%M:%S%.f%:z");
+    assert_eq!(
+        format!("{:?}", FormatPattern::rfc_2822()),
+        "FormatPattern { pattern: %a, %d %b %Y %H:%M:%S %z }"
+    );
+    assert_eq!(
+        FormatPattern::rfc_2822(),
+        FormatPattern::new(RFC_2822).unwrap()
+    );
+
+    assert_eq!(
+        FormatPattern::new("%Y-%q"),
+        Err(FormatError::InvalidPattern(
+            3,
+            "The token '%q' is not supported.".to_string()
+        ))
+    );
+    assert_eq!(
+        FormatPattern::new("%H:%.x").unwrap_err().position(),
+        Some(3)
+    );
+    assert_eq!(FormatPattern::new("%:Z").unwrap_err().position(), Some(0));
+    assert_eq!(
+        FormatPattern::new("100%").unwrap_err().to_string(),
+        "Invalid pattern at position 3: The token '%' is not supported."
+    );
+}
+
+#[test]
+fn format_date() {
+    let mut var = Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+    date_mut.set_date(2024, 3, 1).unwrap();
+    assert_eq!(
+        date_mut
+            .format(&FormatPattern::new("%Y-%m-%d").unwrap())
+            .unwrap(),
+        "2024-03-01"
+    );
+    assert_eq!(
+        date_mut
+            .format(&FormatPattern::new("%a %d %b %Y, day %j").unwrap())
+            .unwrap(),
+        "Fri 01 Mar 2024, day 061"
+    );
+    assert_eq!(
+        date_mut
+            .format(&FormatPattern::new("100%% of %Y").unwrap())
+            .unwrap(),
+        "100% of 2024"
+    );
+    date_mut.set_date(7, 12, 31).unwrap();
+    assert_eq!(
+        date_mut
+            .format(&FormatPattern::new("%a %Y/%j").unwrap())
+            .unwrap(),
+        "Mon 0007/365"
+    );
+
+    // a date has no time or offset
+    assert_eq!(
+        date_mut.format(&FormatPattern::new("%Y-%m-%d %H:%M").unwrap()),
+        Err(FormatError::UnsupportedToken(
+            9,
+            "The token needs a time, which the value does not have.".to_string()
+        ))
+    );
+    assert_eq!(
+        date_mut
+            .format(&FormatPattern::new("%Y%z").unwrap())
+            .unwrap_err()
+            .position(),
+        Some(2)
+    );
+}
+
+#[test]
+fn format_and_parse_extended_date() {
+    let mut var = Variable::new(
+        &DateSpecBuilder::new()
+            .set_range(DateRange::Extended)
+            .build(),
+    );
+    let date_mut = var.date_mut();
+
+    // years outside 0 to 9999 are formatted as in ISO 8601 with a sign and six digits
+    date_mut.set_proleptic_date(-43, 3, 13).unwrap();
+    assert_eq!(
+        date_mut.format(&FormatPattern::new("%Y-%m-%d, %A, day %j").unwrap()),
+        Ok("-000043-03-13, Wednesday, day 072".to_string())
+    );
+    date_mut.set_proleptic_date(12024, 6, 15).unwrap();
+    assert_eq!(
+        date_mut.format(&FormatPattern::new("%Y-%m-%d").unwrap()),
+        Ok("+012024-06-15".to_string())
+    );
+
+    // the year 0 of an extended date can be parsed
+    date_mut
+        .set_from_format("0000-02-29", &FormatPattern::new("%Y-%m-%d").unwrap())
+        .unwrap();
+    assert_eq!(date_mut.proleptic_date(), Ok((0, 2, 29)));
+    assert_eq!(
+        date_mut.format(&FormatPattern::new("%d %b %Y").unwrap()),
+        Ok("29 Feb 0000".to_string())
+    );
+}
+
+#[test]
+fn parse_date() {
+    let mut var = Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+    date_mut
+        .set_from_format("15.06.2024", &FormatPattern::new("%d.%m.%Y").unwrap())
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 6, 15));
+
+    // fewer digits and names in any case
+    date_mut
+        .set_from_format("1/2/2023", &FormatPattern::new("%d/%m/%Y").unwrap())
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2023, 2, 1));
+    date_mut
+        .set_from_format(
+            "SAT, 15 JUN 2024",
+            &FormatPattern::new("%a, %d %b %Y").unwrap(),
+        )
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 6, 15));
+
+    // the day of the year
+    date_mut
+        .set_from_format("2024-060", &FormatPattern::new("%Y-%j").unwrap())
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 2, 29));
+    date_mut
+        .set_from_format("2023-365", &FormatPattern::new("%Y-%j").unwrap())
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2023, 12, 31));
+    assert_eq!(
+        date_mut.set_from_format("2023-366", &FormatPattern::new("%Y-%j").unwrap()),
+        Err(FormatError::ParseError(
+            5,
+            "Day of the year 366 is out of bounds for year 2023.".to_string()
+        ))
+    );
+    assert_eq!(date_mut.date().unwrap(), (2023, 12, 31));
+}
+
+#[test]
+fn parse_date_errors() {
+    let mut var = Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+    date_mut.set_date(2024, 6, 15).unwrap();
+
+    assert_eq!(
+        date_mut.set_from_format("2024/06/15", &FormatPattern::new("%Y-%m-%d").unwrap()),
+        Err(FormatError::ParseError(4, "Expected '-'.".to_string()))
+    );
+    assert_eq!(
+        date_mut.set_from_format("24-06-15", &FormatPattern::new("%Y-%m-%d").unwrap()),
+        Err(FormatError::ParseError(
+            0,
+            "Expected the year as 4 to 4 digits.".to_string()
+        ))
+    );
+    assert_eq!(
+        date_mut.set_from_format("2024-06-15T", &FormatPattern::new("%Y-%m-%d").unwrap()),
+        Err(FormatError::ParseError(
+            10,
+            "Unexpected text 'T' after the end of the pattern.".to_string()
+        ))
+    );
+    assert_eq!(
+        date_mut.set_from_format(
+            "Sun 15 Jun 2024",
+            &FormatPattern::new("%a %d %b %Y").unwrap()
+        ),
+        Err(FormatError::ParseError(
+            0,
+            "The day of the week Sunday does not match the date 2024-06-15.".to_string()
+        ))
+    );
+    assert_eq!(
+        date_mut
+            .set_from_format("15 Jux 2024", &FormatPattern::new("%d %b %Y").unwrap())
+            .unwrap_err()
+            .to_string(),
+        "Parse error at position 3: Expected the abbreviated name of the month."
+    );
+    assert_eq!(
+        date_mut.set_from_format("2024-06", &FormatPattern::new("%Y-%m").unwrap()),
+        Err(FormatError::MissingField(
+            "The pattern does not contain a date (%Y with %m and %d, or with %j).".to_string()
+        ))
+    );
+    assert_eq!(
+        date_mut.set_from_format("2024-02-30", &FormatPattern::new("%Y-%m-%d").unwrap()),
+        Err(FormatError::DateError(DateError::DayOutOfBounds(
+            30,
+            "February".to_string(),
+            29
+        )))
+    );
+    assert_eq!(date_mut.date().unwrap(), (2024, 6, 15));
+}
+
+#[test]
+fn format_and_parse_time() {
+    let mut var = Variable::new(
+        &TimeSpecBuilder::new()
+            .set_time_type(TimeType::Local)
+            .build(),
+    );
+    let time_mut = var.time_mut();
+    time_mut
+        .set_from_format("7:05", &FormatPattern::new("%H:%M").unwrap())
+        .unwrap();
+    assert_eq!(time_mut.time().unwrap(), (7, 5, 0, 0, 0, 0));
+    assert_eq!(
+        time_mut
+            .format(&FormatPattern::new("%H:%M:%S%.f").unwrap())
+            .unwrap(),
+        "07:05:00"
+    );
+    assert_eq!(
+        time_mut
+            .format(&FormatPattern::new("%H%M%S.%f").unwrap())
+            .unwrap(),
+        "070500.000000000"
+    );
+
+    time_mut
+        .set_from_format("23:59:58.0012", &FormatPattern::new("%H:%M:%S%.f").unwrap())
+        .unwrap();
+    assert_eq!(time_mut.time().unwrap(), (23, 59, 58, 1, 200, 0));
+    assert_eq!(
+        time_mut
+            .format(&FormatPattern::new("%H:%M:%S%.f").unwrap())
+            .unwrap(),
+        "23:59:58.0012"
+    );
+    assert_eq!(
+        time_mut
+            .format(&FormatPattern::new("%S,%f").unwrap())
+            .unwrap(),
+        "58,001200000"
+    );
+    time_mut
+        .set_from_format(
+            "12:34:56,123456789",
+            &FormatPattern::new("%H:%M:%S,%f").unwrap(),
+        )
+        .unwrap();
+    assert_eq!(time_mut.time().unwrap(), (12, 34, 56, 123, 456, 789));
+
+    // failures leave the time unchanged
+    assert_eq!(
+        time_mut.set_from_format("12", &FormatPattern::new("%H").unwrap()),
+        Err(FormatError::MissingField(
+            "The pattern does not contain a time (%H with %M).".to_string()
+        ))
+    );
+    assert_eq!(
+        time_mut.set_from_format("24:00", &FormatPattern::new("%H:%M").unwrap()),
+        Err(FormatError::TimeError(TimeError::HourOutOfBounds(24)))
+    );
+    assert_eq!(
+        time_mut
+            .format(&FormatPattern::new("%Y %H").unwrap())
+            .unwrap_err()
+            .position(),
+        Some(0)
+    );
+    assert_eq!(time_mut.time().unwrap(), (12, 34, 56, 123, 456, 789));
+}
+
+#[test]
+fn parse_time_resolution() {
+    let mut var = Variable::new(
+        &TimeSpecBuilder::new()
+            .set_time_type(TimeType::Local)
+            .set_resolution(TimeResolution::Second)
+            .build(),
+    );
+    let time_mut = var.time_mut();
+    time_mut
+        .set_from_format("12:34:56", &FormatPattern::new("%H:%M:%S%.f").unwrap())
+        .unwrap();
+    assert!(matches!(
+        time_mut.set_from_format("12:34:56.5", &FormatPattern::new("%H:%M:%S%.f").unwrap()),
+        Err(FormatError::TimeError(TimeError::ResolutionOutOfBounds(_)))
+    ));
+    assert_eq!(time_mut.time().unwrap(), (12, 34, 56, 0, 0, 0));
+}
+
+#[test]
+fn format_and_parse_date_time() {
+    let mut var = Variable::new(
+        &DateTimeSpecBuilder::new()
+            .set_date_time_type(DateTimeType::Local)
+            .build(),
+    );
+    let date_time_mut = var.date_time_mut();
+    date_time_mut
+        .set_from_format(
+            "06/15/2024 08:30:15",
+            &FormatPattern::new("%m/%d/%Y %H:%M:%S").unwrap(),
+        )
+        .unwrap();
+    assert_eq!(date_time_mut.to_string(), "2024-06-15T08:30:15");
+    assert_eq!(
+        date_time_mut
+            .format(&FormatPattern::new("%a %d %b %Y %H:%M").unwrap())
+            .unwrap(),
+        "Sat 15 Jun 2024 08:30"
+    );
+    assert_eq!(
+        date_time_mut.format(&FormatPattern::rfc_3339()),
+        Err(FormatError::UnsupportedToken(
+            20,
+            "The token needs a UTC offset, which the value does not have.".to_string()
+        ))
+    );
+    assert!(matches!(
+        date_time_mut.set_from_format("2024-06-15", &FormatPattern::new("%Y-%m-%d").unwrap()),
+        Err(FormatError::MissingField(_))
+    ));
+    assert_eq!(date_time_mut.to_string(), "2024-06-15T08:30:15");
+}
+
+#[test]
+fn format_and_parse_localized_names() {
+    let mut var = Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+    date_mut.set_date(2024, 3, 3).unwrap();
+    assert_eq!(
+        date_mut
+            .format(&FormatPattern::new("%A, %d %B %Y").unwrap())
+            .unwrap(),
+        "Sunday, 03 March 2024"
+    );
+    assert_eq!(FormatPattern::new("%A").unwrap().locale().tag(), "en");
+
+    for (tag, formatted) in [
+        ("de", "Sonntag, 03 März 2024 (So, Mär)"),
+        ("fr", "dimanche, 03 mars 2024 (dim., mars)"),
+        ("es", "domingo, 03 marzo 2024 (dom, mar)"),
+        ("nl", "zondag, 03 maart 2024 (zo, mrt)"),
+        ("ja", "日曜日, 03 3月 2024 (日, 3月)"),
+    ] {
+        let localized = FormatPattern::new("%A, %d %B %Y (%a, %b)")
+            .unwrap()
+            .with_locale(Locale::get(tag).unwrap());
+        assert_eq!(date_mut.format(&localized).unwrap(), formatted, "{}", tag);
+        date_mut.set_date(2000, 1, 1).unwrap();
+        date_mut.set_from_format(formatted, &localized).unwrap();
+        assert_eq!(date_mut.date().unwrap(), (2024, 3, 3), "{}", tag);
+    }
+
+    // names are matched regardless of case, and the longest name wins
+    let german = FormatPattern::new("%a %d %b %Y")
+        .unwrap()
+        .with_locale(Locale::get("de").unwrap());
+    date_mut.set_from_format("MI 05 MÄR 2025", &german).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2025, 3, 5));
+    let japanese = FormatPattern::new("%Y年%b%d日")
+        .unwrap()
+        .with_locale(Locale::get("ja").unwrap());
+    date_mut
+        .set_from_format("2024年11月30日", &japanese)
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 11, 30));
+    assert_eq!(
+        date_mut.set_from_format(
+            "samedi 30 November 2024",
+            &FormatPattern::new("%A %d %B %Y").unwrap()
+        ),
+        Err(FormatError::ParseError(
+            0,
+            "Expected the name of the day of the week.".to_string()
+        ))
+    );
+    assert_ne!(german, FormatPattern::new("%a %d %b %Y").unwrap());
+
+    let mut var = Variable::new(
+        &DateTimeSpecBuilder::new()
+            .set_date_time_type(DateTimeType::Local)
+            .build(),
+    );
+    let date_time_mut = var.date_time_mut();
+    let french = FormatPattern::new("%A %d %B %Y %H:%M")
+        .unwrap()
+        .with_locale(Locale::get("fr-FR").unwrap());
+    date_time_mut
+        .set_from_format("jeudi 15 août 2024 18:05", &french)
+        .unwrap();
+    assert_eq!(date_time_mut.to_string(), "2024-08-15T18:05:00");
+    assert_eq!(
+        date_time_mut.format(&french).unwrap(),
+        "jeudi 15 août 2024 18:05"
+    );
+}
+
+#[test]
+fn format_and_parse_zoned_time() {
+    let mut var = Variable::new(
+        &TimeSpecBuilder::new()
+            .set_time_type(TimeType::Zoned)
+            .build(),
+    );
+    let zoned_time_mut = var.zoned_time_mut();
+    zoned_time_mut
+        .set_from_format("10:00 +0530", &FormatPattern::new("%H:%M %z").unwrap())
+        .unwrap();
+    assert_eq!(
+        zoned_time_mut.zoned_time().unwrap(),
+        ((10, 0, 0, 0, 0, 0), 19_800)
+    );
+    assert_eq!(
+        zoned_time_mut
+            .format(&FormatPattern::new("%H:%M%:z").unwrap())
+            .unwrap(),
+        "10:00+05:30"
+    );
+    zoned_time_mut
+        .set_from_format("10:00Z", &FormatPattern::new("%H:%M%:z").unwrap())
+        .unwrap();
+    assert_eq!(
+        zoned_time_mut
+            .format(&FormatPattern::new("%H:%M %z").unwrap())
+            .unwrap(),
+        "10:00 +0000"
+    );
+    zoned_time_mut
+        .set_from_format("10:00-03:30", &FormatPattern::new("%H:%M%:z").unwrap())
+        .unwrap();
+    assert_eq!(zoned_time_mut.offset_seconds().unwrap(), -12_600);
+
+    // failures leave the zoned time unchanged
+    assert_eq!(
+        zoned_time_mut.set_from_format("10:00", &FormatPattern::new("%H:%M").unwrap()),
+        Err(FormatError::MissingField(
+            "The pattern does not contain a UTC offset (%z or %:z).".to_string()
+        ))
+    );
+    assert_eq!(
+        zoned_time_mut.set_from_format("10:00 0200", &FormatPattern::new("%H:%M %z").unwrap()),
+        Err(FormatError::ParseError(
+            6,
+            "Expected the sign of the UTC offset.".to_string()
+        ))
+    );
+    assert_eq!(
+        zoned_time_mut.set_from_format("10:00+0260", &FormatPattern::new("%H:%M%z").unwrap()),
+        Err(FormatError::ParseError(
+            8,
+            "Minutes 60 of the UTC offset are out of bounds (0 to 59).".to_string()
+        ))
+    );
+    assert_eq!(
+        zoned_time_mut.set_from_format("10:00+02:00", &FormatPattern::new("%H:%M%z").unwrap()),
+        Err(FormatError::ParseError(
+            8,
+            "Expected the minutes of the UTC offset as 2 to 2 digits.".to_string()
+        ))
+    );
+    assert_eq!(
+        zoned_time_mut.set_from_format("10:00+19:00", &FormatPattern::new("%H:%M%:z").unwrap()),
+        Err(FormatError::ZonedTimeError(
+            ZonedTimeError::OffsetOutOfBounds(68_400)
+        ))
+    );
+    assert_eq!(zoned_time_mut.to_string(), "10:00:00-03:30");
+}
+
+#[test]
+fn format_and_parse_zoned_date_time() {
+    let mut var = Variable::new(
+        &DateTimeSpecBuilder::new()
+            .set_date_time_type(DateTimeType::Zoned)
+            .build(),
+    );
+    let zoned_date_time_mut = var.zoned_date_time_mut();
+    let rfc_3339 = FormatPattern::rfc_3339();
+    let rfc_2822 = FormatPattern::rfc_2822();
+
+    zoned_date_time_mut
+        .set_from_format("2024-06-15T12:34:56.789+02:00", &rfc_3339)
+        .unwrap();
+    assert_eq!(
+        zoned_date_time_mut.zoned_date_time().unwrap(),
+        ((2024, 6, 15), (12, 34, 56, 789, 0, 0), 7_200)
+    );
+    assert_eq!(
+        zoned_date_time_mut.format(&rfc_2822).unwrap(),
+        "Sat, 15 Jun 2024 12:34:56 +0200"
+    );
+    assert_eq!(
+        zoned_date_time_mut.format(&rfc_3339).unwrap(),
+        "2024-06-15T12:34:56.789+02:00"
+    );
+
+    zoned_date_time_mut
+        .set_from_format("Mon, 1 Jan 2024 00:00:00 -0800", &rfc_2822)
+        .unwrap();
+    assert_eq!(
+        zoned_date_time_mut.format(&rfc_3339).unwrap(),
+        "2024-01-01T00:00:00-08:00"
+    );
+    zoned_date_time_mut
+        .set_from_format("2024-01-01T08:00:00Z", &rfc_3339)
+        .unwrap();
+    assert_eq!(
+        zoned_date_time_mut.format(&rfc_2822).unwrap(),
+        "Mon, 01 Jan 2024 08:00:00 +0000"
+    );
+
+    // failures leave the zoned date-time unchanged
+    assert_eq!(
+        zoned_date_time_mut
+            .set_from_format("Tue, 01 Jan 2024 08:00:00 +0000", &rfc_2822)
+            .unwrap_err()
+            .position(),
+        Some(0)
+    );
+    assert_eq!(
+        zoned_date_time_mut.set_from_format("2024-06-31T12:00:00Z", &rfc_3339),
+        Err(FormatError::ZonedDateTimeError(
+            ZonedDateTimeError::DateError(DateError::DayOutOfBounds(31, "June".to_string(), 30))
+        ))
+    );
+    assert_eq!(zoned_date_time_mut.to_string(), "2024-01-01T08:00:00Z");
+}
+
+#[test]
+fn format_error() {
+    let error = FormatError::ParseError(4, "Expected '-'.".to_string());
+    assert_eq!(error.position(), Some(4));
+    assert_eq!(
+        error.to_string(),
+        "Parse error at position 4: Expected '-'."
+    );
+    let error = FormatError::MissingField("The pattern does not contain a date.".to_string());
+    assert_eq!(error.position(), None);
+    assert_eq!(
+        error.to_string(),
+        "Missing field: The pattern does not contain a date."
+    );
+    let error = FormatError::TimeError(TimeError::HourOutOfBounds(24));
+    assert_eq!(error.position(), None);
+    assert!(std::error::Error::source(&error).is_some());
+    assert_eq!(
+        error.to_string(),
+        "Time error: Hour value 24 is out of bounds (0-23)."
+    );
+}
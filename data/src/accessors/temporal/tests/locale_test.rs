@@ -0,0 +1,146 @@
+use crate::accessors::temporal::{
+    date::{DayOfWeek, month_name},
+    locale::{Locale, LocaleError},
+};
+
+const MONTHS: [&str; 12] = [
+    "Lunar 1", "Lunar 2", "Lunar 3", "Lunar 4", "Lunar 5", "Lunar 6", "Lunar 7", "Lunar 8",
+    "Lunar 9", "Lunar 10", "Lunar 11", "Lunar 12",
+];
+const ABBREVIATED_MONTHS: [&str; 12] = [
+    "L1", "L2", "L3", "L4", "L5", "L6", "L7", "L8", "L9", "L10", "L11", "L12",
+];
+const DAYS: [&str; 7] = [
+    "Day 1", "Day 2", "Day 3", "Day 4", "Day 5", "Day 6", "Day 7",
+];
+const ABBREVIATED_DAYS: [&str; 7] = ["D1", "D2", "D3", "D4", "D5", "D6", "D7"];
+
+#[test]
+fn builtin_locales() {
+    for tag in ["de", "en", "es", "fr", "ja", "nl"] {
+        assert!(Locale::tags().contains(&tag.to_string()), "{}", tag);
+    }
+    let english = Locale::english();
+    assert_eq!(english.tag(), "en");
+    for month in 1..=12 {
+        assert_eq!(english.month_name(month), Some(month_name(month)));
+    }
+    assert_eq!(english.month_name(0), None);
+    assert_eq!(english.abbreviated_month_name(13), None);
+
+    let spanish = Locale::get("es").unwrap();
+    assert_eq!(spanish.month_name(9), Some("septiembre"));
+    assert_eq!(spanish.abbreviated_month_name(9), Some("sept"));
+    assert_eq!(spanish.day_of_week_name(DayOfWeek::Wednesday), "miércoles");
+    let japanese = Locale::get("ja").unwrap();
+    assert_eq!(japanese.month_name(11), Some("11月"));
+    assert_eq!(DayOfWeek::Monday.name(&japanese), "月曜日");
+    assert_eq!(DayOfWeek::Monday.abbreviated_name(&japanese), "月");
+    let dutch = Locale::get("nl").unwrap();
+    assert_eq!(dutch.abbreviated_month_name(3), Some("mrt"));
+    assert_eq!(DayOfWeek::Sunday.name(&dutch), "zondag");
+    assert_eq!(dutch.to_string(), "nl");
+    assert_eq!(format!("{:?}", dutch), "Locale { tag: nl }");
+}
+
+#[test]
+fn get_locale() {
+    assert_eq!(Locale::get("FR").unwrap().tag(), "fr");
+    assert_eq!(Locale::get("fr_CA").unwrap().tag(), "fr");
+    assert_eq!(Locale::get(" de-AT ").unwrap().tag(), "de");
+    assert_eq!(
+        Locale::get("pt-BR"),
+        Err(LocaleError::UnknownLocale("pt-br".to_string()))
+    );
+    assert_eq!(Locale::get("").unwrap_err().to_string(), "Unknown locale: ");
+}
+
+#[test]
+fn register_locale() {
+    let locale = Locale::new(
+        "x-Lunar_TEST",
+        MONTHS,
+        ABBREVIATED_MONTHS,
+        DAYS,
+        ABBREVIATED_DAYS,
+    )
+    .unwrap();
+    assert_eq!(locale.tag(), "x-lunar-test");
+    assert!(Locale::register(locale.clone()).is_none());
+    let registered = Locale::get("X-LUNAR-TEST").unwrap();
+    assert_eq!(*registered, locale);
+    assert_eq!(registered.day_of_week_name(DayOfWeek::Sunday), "Day 7");
+    assert!(Locale::tags().contains(&"x-lunar-test".to_string()));
+
+    // registering the tag again replaces the locale
+    let mut days = DAYS;
+    days[0] = "First day";
+    let replacement = Locale::new(
+        "x-lunar-test",
+        MONTHS,
+        ABBREVIATED_MONTHS,
+        days,
+        ABBREVIATED_DAYS,
+    )
+    .unwrap();
+    assert_eq!(*Locale::register(replacement).unwrap(), locale);
+    assert_eq!(
+        DayOfWeek::Monday.name(&Locale::get("x-lunar-test").unwrap()),
+        "First day"
+    );
+}
+
+#[test]
+fn invalid_locale() {
+    assert_eq!(
+        Locale::new("en US", MONTHS, ABBREVIATED_MONTHS, DAYS, ABBREVIATED_DAYS),
+        Err(LocaleError::InvalidLocale(
+            "The tag 'en US' is not a locale tag.".to_string()
+        ))
+    );
+    let mut months = MONTHS;
+    months[4] = " ";
+    assert_eq!(
+        Locale::new("x-test", months, ABBREVIATED_MONTHS, DAYS, ABBREVIATED_DAYS),
+        Err(LocaleError::InvalidLocale(
+            "The month name 5 is empty.".to_string()
+        ))
+    );
+    let mut abbreviated_days = ABBREVIATED_DAYS;
+    abbreviated_days[6] = "d1";
+    assert_eq!(
+        Locale::new("x-test", MONTHS, ABBREVIATED_MONTHS, DAYS, abbreviated_days)
+            .unwrap_err()
+            .to_string(),
+        "Invalid locale: The abbreviated day of the week name 'd1' is not unique."
+    );
+}
+
+#[test]
+fn register_locale_per_thread() {
+    std::thread::spawn(|| {
+        let locale = Locale::new(
+            "x-thread-test",
+            MONTHS,
+            ABBREVIATED_MONTHS,
+            DAYS,
+            ABBREVIATED_DAYS,
+        )
+        .unwrap();
+        assert!(Locale::register(locale).is_none());
+        assert!(Locale::get("x-thread-test").is_ok());
+    })
+    .join()
+    .unwrap();
+
+    // the locale is registered only on the thread that registered it
+    assert_eq!(
+        Locale::get("x-thread-test"),
+        Err(LocaleError::UnknownLocale("x-thread-test".to_string()))
+    );
+    assert!(
+        std::thread::spawn(|| Locale::get("de").is_ok())
+            .join()
+            .unwrap()
+    );
+}
@@ -0,0 +1,337 @@
+use crate::{
+    accessors::temporal::{
+        date::DayOfWeek,
+        recurrence::{Frequency, RecurrenceError, RecurrenceRule, WeekdayNum},
+    },
+    data_spec_builders::{
+        date_spec_builder::DateSpecBuilder, date_time_spec_builder::DateTimeSpecBuilder,
+    },
+    primitive_specs::date_time_spec::DateTimeType,
+    variable::Variable,
+};
+
+#[test]
+fn parse_rule() {
+    let rule = RecurrenceRule::new("RRULE:freq=monthly;interval=2;byday=1mo,-1FR;count=4").unwrap();
+    assert_eq!(rule.frequency(), Frequency::Monthly);
+    assert_eq!(rule.interval(), 2);
+    assert_eq!(
+        rule.by_day(),
+        [
+            WeekdayNum {
+                ordinal: Some(1),
+                day_of_week: DayOfWeek::Monday
+            },
+            WeekdayNum {
+                ordinal: Some(-1),
+                day_of_week: DayOfWeek::Friday
+            }
+        ]
+    );
+    assert!(rule.by_month_day().is_empty());
+    assert_eq!(rule.count(), Some(4));
+    assert_eq!(
+        rule.to_string(),
+        "FREQ=MONTHLY;INTERVAL=2;BYDAY=1MO,-1FR;COUNT=4"
+    );
+
+    let rule = RecurrenceRule::new("FREQ=YEARLY;BYMONTHDAY=+1,-1;UNTIL=20301231T235959Z").unwrap();
+    assert_eq!(rule.interval(), 1);
+    assert_eq!(rule.by_month_day(), [1, -1]);
+    assert_eq!(rule.count(), None);
+    assert_eq!(
+        rule.to_string(),
+        "FREQ=YEARLY;BYMONTHDAY=1,-1;UNTIL=20301231T235959Z"
+    );
+}
+
+#[test]
+fn parse_rule_errors() {
+    for rule in [
+        "",
+        "INTERVAL=2",
+        "FREQ=DAILY;FREQ=WEEKLY",
+        "FREQ=FORTNIGHTLY",
+        "FREQ=DAILY;INTERVAL=0",
+        "FREQ=DAILY;INTERVAL=-1",
+        "FREQ=DAILY;COUNT=2;UNTIL=20250101",
+        "FREQ=WEEKLY;BYDAY=2MO",
+        "FREQ=WEEKLY;BYMONTHDAY=1",
+        "FREQ=MONTHLY;BYDAY=XX",
+        "FREQ=MONTHLY;BYDAY=54MO",
+        "FREQ=MONTHLY;BYDAY=6MO",
+        "FREQ=MONTHLY;BYDAY=1MO,-6FR",
+        "FREQ=MONTHLY;BYMONTHDAY=0",
+        "FREQ=MONTHLY;BYMONTHDAY=32",
+        "FREQ=MONTHLY;BYMONTHDAY=4294967295",
+        "FREQ=MONTHLY;BYDAY=4294967295MO",
+        "FREQ=DAILY;UNTIL=20250230",
+        "FREQ=DAILY;UNTIL=20250101Z",
+        "FREQ=DAILY;UNTIL=20250101T246000",
+        "FREQ=DAILY;UNTIL=202é401",
+        "FREQ=DAILY;UNTIL=20240101T1é234",
+    ] {
+        assert!(
+            matches!(
+                RecurrenceRule::new(rule),
+                Err(RecurrenceError::InvalidRule(..))
+            ),
+            "{}",
+            rule
+        );
+    }
+    assert_eq!(
+        RecurrenceRule::new("FREQ=MONTHLY;BYDAY=6MO"),
+        Err(RecurrenceError::InvalidRule(
+            "BYDAY can only number days of the week from -5 to 5 with FREQ=MONTHLY".to_string()
+        ))
+    );
+    assert_eq!(
+        RecurrenceRule::new("FREQ=HOURLY"),
+        Err(RecurrenceError::UnsupportedPart("FREQ=HOURLY".to_string()))
+    );
+    assert_eq!(
+        RecurrenceRule::new("FREQ=MONTHLY;BYSETPOS=-1"),
+        Err(RecurrenceError::UnsupportedPart("BYSETPOS".to_string()))
+    );
+}
+
+#[test]
+fn unsupported_anchor() {
+    let rule = RecurrenceRule::new("FREQ=DAILY").unwrap();
+    let anchor = Variable::try_from(42i64).unwrap();
+    assert!(matches!(
+        rule.occurrences(&anchor),
+        Err(RecurrenceError::UnsupportedAnchor(..))
+    ));
+}
+
+#[test]
+fn daily_and_weekly() {
+    let spec = DateSpecBuilder::new().build();
+    let mut anchor = Variable::new(&spec);
+    anchor.date_mut().set_from_string("2025-01-30").unwrap();
+    let values: Vec<String> = RecurrenceRule::new("FREQ=DAILY;INTERVAL=2;COUNT=3")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["2025-01-30", "2025-02-01", "2025-02-03"]);
+    // a Thursday, so the first week only has the Friday
+    let values: Vec<String> = RecurrenceRule::new("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;COUNT=5")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(
+        values,
+        [
+            "2025-01-31",
+            "2025-02-10",
+            "2025-02-14",
+            "2025-02-24",
+            "2025-02-28"
+        ]
+    );
+    let values: Vec<String> = RecurrenceRule::new("FREQ=WEEKLY;COUNT=3")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["2025-01-30", "2025-02-06", "2025-02-13"]);
+}
+
+#[test]
+fn monthly() {
+    let spec = DateSpecBuilder::new().build();
+    let mut anchor = Variable::new(&spec);
+    anchor.date_mut().set_from_string("2024-01-31").unwrap();
+    // months without a 31st are skipped
+    let values: Vec<String> = RecurrenceRule::new("FREQ=MONTHLY;COUNT=3")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["2024-01-31", "2024-03-31", "2024-05-31"]);
+    let values: Vec<String> = RecurrenceRule::new("FREQ=MONTHLY;BYMONTHDAY=-1;COUNT=3")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["2024-01-31", "2024-02-29", "2024-03-31"]);
+    anchor.date_mut().set_from_string("2024-01-01").unwrap();
+    let values: Vec<String> = RecurrenceRule::new("FREQ=MONTHLY;BYDAY=-1FR,1MO;COUNT=4")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(
+        values,
+        ["2024-01-01", "2024-01-26", "2024-02-05", "2024-02-23"]
+    );
+    // months without a fifth Friday are skipped
+    let values: Vec<String> = RecurrenceRule::new("FREQ=MONTHLY;BYDAY=5FR;COUNT=2")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["2024-03-29", "2024-05-31"]);
+}
+
+#[test]
+fn yearly() {
+    let spec = DateSpecBuilder::new().build();
+    let mut anchor = Variable::new(&spec);
+    anchor.date_mut().set_from_string("2024-02-29").unwrap();
+    let values: Vec<String> = RecurrenceRule::new("FREQ=YEARLY;COUNT=3")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["2024-02-29", "2028-02-29", "2032-02-29"]);
+    anchor.date_mut().set_from_string("2025-01-01").unwrap();
+    let values: Vec<String> = RecurrenceRule::new("FREQ=YEARLY;BYDAY=20MO;COUNT=2")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["2025-05-19", "2026-05-18"]);
+}
+
+#[test]
+fn until_is_inclusive() {
+    let date_spec = DateSpecBuilder::new().build();
+    let mut anchor = Variable::new(&date_spec);
+    anchor.date_mut().set_from_string("2025-01-06").unwrap();
+    let values: Vec<String> = RecurrenceRule::new("FREQ=WEEKLY;UNTIL=20250120")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["2025-01-06", "2025-01-13", "2025-01-20"]);
+    let date_time_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let mut anchor = Variable::new(&date_time_spec);
+    anchor
+        .date_time_mut()
+        .set_from_string("2025-01-06T09:30:00")
+        .unwrap();
+    let values: Vec<String> = RecurrenceRule::new("FREQ=DAILY;UNTIL=20250108T093000")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(
+        values,
+        [
+            "2025-01-06T09:30:00",
+            "2025-01-07T09:30:00",
+            "2025-01-08T09:30:00"
+        ]
+    );
+    let values: Vec<String> = RecurrenceRule::new("FREQ=DAILY;UNTIL=20250108T090000Z")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["2025-01-06T09:30:00", "2025-01-07T09:30:00"]);
+    let values: Vec<String> = RecurrenceRule::new("FREQ=DAILY;UNTIL=20250101")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert!(values.is_empty());
+}
+
+#[test]
+fn unbounded_rule_is_lazy() {
+    let spec = DateSpecBuilder::new().build();
+    let mut anchor = Variable::new(&spec);
+    anchor.date_mut().set_from_string("2025-01-01").unwrap();
+    let rule = RecurrenceRule::new("FREQ=DAILY").unwrap();
+    let occurrences = rule.occurrences(&anchor).unwrap();
+    let sequence = occurrences.sequence();
+    let first: Vec<String> = sequence
+        .iter()
+        .take(2)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(first, ["2025-01-01", "2025-01-02"]);
+    // iterating again returns the same occurrences, then goes on
+    let again: Vec<String> = sequence
+        .iter()
+        .take(3)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(again, ["2025-01-01", "2025-01-02", "2025-01-03"]);
+}
+
+#[test]
+fn recurrence_ends_with_the_date_range() {
+    let spec = DateSpecBuilder::new().build();
+    let mut anchor = Variable::new(&spec);
+    anchor.date_mut().set_from_string("9998-12-31").unwrap();
+    let values: Vec<String> = RecurrenceRule::new("FREQ=YEARLY")
+        .unwrap()
+        .occurrences(&anchor)
+        .unwrap()
+        .sequence()
+        .iter()
+        .take(10)
+        .map(|value| value.unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["9998-12-31", "9999-12-31"]);
+}
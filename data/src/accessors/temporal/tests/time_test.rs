@@ -4,8 +4,14 @@ use crate::{
     accessors::temporal::time::{Time, TimeError},
     adaptor::Adaptor,
     adaptors::temporal_adaptors::time_adaptor::TimeAdaptor,
-    data_spec_builders::time_spec_builder::TimeSpecBuilder,
-    primitive_specs::time_spec::{TimeResolution, TimeSpec, TimeType},
+    data_spec_builders::{
+        duration_spec_builder::DurationSpecBuilder, time_spec_builder::TimeSpecBuilder,
+    },
+    primitive_specs::{
+        duration_spec::DurationType,
+        rounding_policy::RoundingPolicy,
+        time_spec::{TimeResolution, TimeSpec, TimeType},
+    },
     set_equal_to::SetEqualTo,
     variable,
 };
@@ -669,3 +675,239 @@ fn custom_time_adaptor_partial_equal_and_hash() {
     assert_eq!(hash1, hash2);
     assert_ne!(hash1, hash3);
 }
+
+#[test]
+fn time_add_day_to_second_duration() {
+    let mut var = variable::Variable::new(
+        &TimeSpecBuilder::new()
+            .set_time_type(TimeType::Local)
+            .build(),
+    );
+    let mut duration = variable::Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::DayToSecond)
+            .build()
+            .unwrap(),
+    );
+    let time_mut = var.time_mut();
+    time_mut.set_time(10, 20, 30, 400, 500, 600).unwrap();
+
+    // within the day
+    duration
+        .day_to_second_duration_mut()
+        .set_duration(0, 1, 2, 3, 4, 5, 6)
+        .unwrap();
+    let days = time_mut
+        .add_day_to_second_duration(duration.day_to_second_duration())
+        .unwrap();
+    assert_eq!(days, 0);
+    assert_eq!(time_mut.time().unwrap(), (11, 22, 33, 404, 505, 606));
+    let days = time_mut
+        .subtract_day_to_second_duration(duration.day_to_second_duration())
+        .unwrap();
+    assert_eq!(days, 0);
+    assert_eq!(time_mut.time().unwrap(), (10, 20, 30, 400, 500, 600));
+
+    // wrapping forward past midnight
+    duration
+        .day_to_second_duration_mut()
+        .set_duration(2, 14, 0, 0, 0, 0, 0)
+        .unwrap();
+    let days = time_mut
+        .add_day_to_second_duration(duration.day_to_second_duration())
+        .unwrap();
+    assert_eq!(days, 3);
+    assert_eq!(time_mut.time().unwrap(), (0, 20, 30, 400, 500, 600));
+
+    // wrapping backward past midnight
+    duration
+        .day_to_second_duration_mut()
+        .set_duration(0, 0, 21, 0, 0, 0, 0)
+        .unwrap();
+    let days = time_mut
+        .subtract_day_to_second_duration(duration.day_to_second_duration())
+        .unwrap();
+    assert_eq!(days, -1);
+    assert_eq!(time_mut.time().unwrap(), (23, 59, 30, 400, 500, 600));
+    duration
+        .day_to_second_duration_mut()
+        .set_duration(0, 0, 0, 29, 600, 0, 0)
+        .unwrap();
+    let days = time_mut
+        .add_day_to_second_duration(duration.day_to_second_duration())
+        .unwrap();
+    assert_eq!(days, 1);
+    assert_eq!(time_mut.time().unwrap(), (0, 0, 0, 0, 500, 600));
+}
+
+#[test]
+fn time_add_day_to_second_duration_resolution() {
+    let mut var = variable::Variable::new(
+        &TimeSpecBuilder::new()
+            .set_time_type(TimeType::Local)
+            .set_resolution(TimeResolution::Second)
+            .build(),
+    );
+    let mut duration = variable::Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::DayToSecond)
+            .build()
+            .unwrap(),
+    );
+    let time_mut = var.time_mut();
+    time_mut.set_time(10, 20, 30, 0, 0, 0).unwrap();
+    duration
+        .day_to_second_duration_mut()
+        .set_duration(0, 0, 0, 0, 1, 0, 0)
+        .unwrap();
+    assert!(matches!(
+        time_mut.add_day_to_second_duration(duration.day_to_second_duration()),
+        Err(TimeError::ResolutionOutOfBounds(_))
+    ));
+    assert_eq!(time_mut.time().unwrap(), (10, 20, 30, 0, 0, 0));
+}
+
+#[test]
+fn time_rounding_policies() {
+    // the millisecond resolution is stored in 100 microsecond ticks and the microsecond
+    // resolution in nanoseconds
+    for (resolution, time, expected) in [
+        (
+            TimeResolution::Millisecond,
+            (12, 34, 56, 789, 500, 0),
+            [
+                (12, 34, 56, 789, 0, 0),
+                (12, 34, 56, 790, 0, 0),
+                (12, 34, 56, 790, 0, 0),
+            ],
+        ),
+        (
+            TimeResolution::Millisecond,
+            (12, 34, 56, 788, 500, 0),
+            [
+                (12, 34, 56, 788, 0, 0),
+                (12, 34, 56, 788, 0, 0),
+                (12, 34, 56, 789, 0, 0),
+            ],
+        ),
+        (
+            TimeResolution::Second,
+            (12, 59, 59, 999, 999, 999),
+            [
+                (12, 59, 59, 0, 0, 0),
+                (13, 0, 0, 0, 0, 0),
+                (13, 0, 0, 0, 0, 0),
+            ],
+        ),
+        (
+            TimeResolution::Microsecond,
+            (12, 34, 56, 789, 12, 501),
+            [
+                (12, 34, 56, 789, 12, 0),
+                (12, 34, 56, 789, 13, 0),
+                (12, 34, 56, 789, 13, 0),
+            ],
+        ),
+        (
+            TimeResolution::Microsecond,
+            (12, 34, 56, 789, 12, 500),
+            [
+                (12, 34, 56, 789, 12, 0),
+                (12, 34, 56, 789, 12, 0),
+                (12, 34, 56, 789, 13, 0),
+            ],
+        ),
+    ] {
+        for (rounding_policy, expected) in [
+            RoundingPolicy::Truncate,
+            RoundingPolicy::RoundHalfEven,
+            RoundingPolicy::RoundHalfUp,
+        ]
+        .into_iter()
+        .zip(expected)
+        {
+            let mut var = variable::Variable::new(
+                &TimeSpecBuilder::new()
+                    .set_time_type(TimeType::Local)
+                    .set_resolution(resolution)
+                    .set_rounding_policy(rounding_policy)
+                    .build(),
+            );
+            var.time_mut().set_via_tuple(time).unwrap();
+            assert_eq!(var.time().time().unwrap(), expected);
+        }
+        let mut var = variable::Variable::new(
+            &TimeSpecBuilder::new()
+                .set_time_type(TimeType::Local)
+                .set_resolution(resolution)
+                .set_rounding_policy(RoundingPolicy::Reject)
+                .build(),
+        );
+        assert!(matches!(
+            var.time_mut().set_via_tuple(time),
+            Err(TimeError::ResolutionOutOfBounds(_))
+        ));
+    }
+}
+
+#[test]
+fn time_rounding_past_midnight() {
+    let mut var = variable::Variable::new(
+        &TimeSpecBuilder::new()
+            .set_time_type(TimeType::Local)
+            .set_resolution(TimeResolution::Second)
+            .set_rounding_policy(RoundingPolicy::RoundHalfUp)
+            .build(),
+    );
+    let time_mut = var.time_mut();
+    time_mut.set_time(1, 2, 3, 0, 0, 0).unwrap();
+    assert_eq!(
+        time_mut.set_time(23, 59, 59, 500, 0, 0),
+        Err(TimeError::ResolutionOutOfBounds(
+            "Cannot round time 23:59:59.500000000 to Second resolution without passing midnight."
+                .to_string()
+        ))
+    );
+    assert_eq!(time_mut.time().unwrap(), (1, 2, 3, 0, 0, 0));
+    time_mut.set_time(23, 59, 59, 499, 0, 0).unwrap();
+    assert_eq!(time_mut.time().unwrap(), (23, 59, 59, 0, 0, 0));
+}
+
+#[test]
+fn time_set_time_with_rounding() {
+    let mut var = variable::Variable::new(
+        &TimeSpecBuilder::new()
+            .set_time_type(TimeType::Local)
+            .set_resolution(TimeResolution::Millisecond)
+            .set_rounding_policy(RoundingPolicy::Reject)
+            .build(),
+    );
+    let time_mut = var.time_mut();
+    time_mut
+        .set_time_with_rounding(0, 0, 0, 1, 600, 0, RoundingPolicy::Truncate)
+        .unwrap();
+    assert_eq!(time_mut.time().unwrap(), (0, 0, 0, 1, 0, 0));
+    time_mut
+        .set_time_with_rounding(0, 0, 0, 1, 600, 0, RoundingPolicy::RoundHalfEven)
+        .unwrap();
+    assert_eq!(time_mut.time().unwrap(), (0, 0, 0, 2, 0, 0));
+    // the spec's policy still applies to the other setters
+    assert!(time_mut.set_time(0, 0, 0, 1, 600, 0).is_err());
+    // the rounding policy does not apply to out of bounds fields
+    assert_eq!(
+        time_mut.set_time_with_rounding(0, 0, 0, 1_000, 0, 0, RoundingPolicy::Truncate),
+        Err(TimeError::MillisecondOutOfBounds(1_000))
+    );
+
+    // a rounding policy has no effect without a resolution
+    let mut var = variable::Variable::new(
+        &TimeSpecBuilder::new()
+            .set_time_type(TimeType::Local)
+            .build(),
+    );
+    let time_mut = var.time_mut();
+    time_mut
+        .set_time_with_rounding(0, 0, 0, 1, 2, 3, RoundingPolicy::Truncate)
+        .unwrap();
+    assert_eq!(time_mut.time().unwrap(), (0, 0, 0, 1, 2, 3));
+}
@@ -0,0 +1,313 @@
+use std::path::PathBuf;
+
+use crate::accessors::temporal::time_zone::{
+    Disambiguation, LocalTimeResolution, TimeZone, TimeZoneError,
+};
+
+/// 2023-03-26T01:00:00Z, when clocks were set forward from CET to CEST.
+const SPRING_2023: i64 = 1_679_792_400;
+/// 2023-10-29T01:00:00Z, when clocks were set back from CEST to CET.
+const AUTUMN_2023: i64 = 1_698_541_200;
+
+/// Returns the contents of a version 2 TZif file for a central European time zone with the
+/// transitions of 2023 and a footer rule for later years.
+pub(crate) fn central_european_tzif() -> Vec<u8> {
+    tzif(
+        &[(SPRING_2023, 1), (AUTUMN_2023, 0)],
+        &[(3_600, false, 0), (7_200, true, 4)],
+        b"CET\0CEST\0",
+        "CET-1CEST,M3.5.0,M10.5.0/3",
+    )
+}
+
+/// Returns the contents of a version 2 TZif file with the given transitions, local time types
+/// and abbreviations, and a version 1 data block without transitions.
+fn tzif(
+    transitions: &[(i64, u8)],
+    types: &[(i32, bool, u8)],
+    abbreviations: &[u8],
+    footer: &str,
+) -> Vec<u8> {
+    let header = |transition_count: usize| {
+        let mut header = b"TZif2".to_vec();
+        header.extend([0; 15]);
+        for count in [0, 0, 0, transition_count, types.len(), abbreviations.len()] {
+            header.extend((count as u32).to_be_bytes());
+        }
+        header
+    };
+    let local_time_types = types.iter().flat_map(|(offset, is_dst, index)| {
+        let mut local_time_type = offset.to_be_bytes().to_vec();
+        local_time_type.extend([*is_dst as u8, *index]);
+        local_time_type
+    });
+
+    let mut data = header(0);
+    data.extend(local_time_types.clone());
+    data.extend(abbreviations);
+    data.extend(header(transitions.len()));
+    for (time, _) in transitions {
+        data.extend(time.to_be_bytes());
+    }
+    data.extend(transitions.iter().map(|(_, index)| *index));
+    data.extend(local_time_types);
+    data.extend(abbreviations);
+    data.extend(format!("\n{}\n", footer).into_bytes());
+    data
+}
+
+/// Writes the central European time zone as `Europe/Test` to a directory of its own for the
+/// given test, and returns the directory.
+pub(crate) fn time_zone_directory(test: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!(
+        "epiphany-time-zones-{}-{}",
+        std::process::id(),
+        test
+    ));
+    std::fs::create_dir_all(directory.join("Europe")).unwrap();
+    std::fs::write(directory.join("Europe/Test"), central_european_tzif()).unwrap();
+    directory
+}
+
+#[test]
+fn time_zone_local_time_type() {
+    let time_zone = TimeZone::from_tzif("Europe/Test", &central_european_tzif()).unwrap();
+    assert_eq!(time_zone.name(), "Europe/Test");
+    assert_eq!(time_zone.to_string(), "Europe/Test");
+    assert_eq!(format!("{:?}", time_zone), "TimeZone { name: Europe/Test }");
+
+    // before the first transition
+    let local_time_type = time_zone.local_time_type(0);
+    assert_eq!(local_time_type.offset_seconds(), 3_600);
+    assert!(!local_time_type.is_dst());
+    assert_eq!(local_time_type.abbreviation(), "CET");
+
+    // at and between the transitions
+    let local_time_type = time_zone.local_time_type(SPRING_2023 - 1);
+    assert_eq!(local_time_type.abbreviation(), "CET");
+    let local_time_type = time_zone.local_time_type(SPRING_2023);
+    assert_eq!(local_time_type.offset_seconds(), 7_200);
+    assert!(local_time_type.is_dst());
+    assert_eq!(local_time_type.abbreviation(), "CEST");
+    let local_time_type = time_zone.local_time_type(AUTUMN_2023);
+    assert_eq!(local_time_type.offset_seconds(), 3_600);
+
+    // after the last transition, from the footer rule
+    // 2024-03-31T00:59:59Z and 2024-03-31T01:00:00Z
+    assert_eq!(
+        time_zone.local_time_type(1_711_846_799).offset_seconds(),
+        3_600
+    );
+    assert_eq!(
+        time_zone.local_time_type(1_711_846_800).offset_seconds(),
+        7_200
+    );
+    // 2024-06-15T10:00:00Z
+    let local_time_type = time_zone.local_time_type(1_718_445_600);
+    assert_eq!(local_time_type.offset_seconds(), 7_200);
+    assert!(local_time_type.is_dst());
+    assert_eq!(local_time_type.abbreviation(), "CEST");
+    // 2024-10-27T00:59:59Z and 2024-10-27T01:00:00Z
+    assert_eq!(
+        time_zone.local_time_type(1_729_990_799).offset_seconds(),
+        7_200
+    );
+    assert_eq!(
+        time_zone.local_time_type(1_729_990_800).offset_seconds(),
+        3_600
+    );
+}
+
+#[test]
+fn time_zone_fixed_offset_rule() {
+    let time_zone = TimeZone::from_tzif(
+        "Etc/Test",
+        &tzif(&[], &[(-18_000, false, 0)], b"EST\0", "EST5"),
+    )
+    .unwrap();
+    let local_time_type = time_zone.local_time_type(1_718_445_600);
+    assert_eq!(local_time_type.offset_seconds(), -18_000);
+    assert_eq!(local_time_type.abbreviation(), "EST");
+    assert!(matches!(
+        time_zone.resolve_local(1_718_445_600),
+        LocalTimeResolution::Unique(_)
+    ));
+}
+
+#[test]
+fn time_zone_resolve_local() {
+    let time_zone = TimeZone::from_tzif("Europe/Test", &central_european_tzif()).unwrap();
+
+    // 2023-06-15T12:00:00 local time
+    let LocalTimeResolution::Unique(local_time_type) = time_zone.resolve_local(1_686_830_400)
+    else {
+        panic!("expected a unique local time");
+    };
+    assert_eq!(local_time_type.abbreviation(), "CEST");
+
+    // 2023-03-26T02:30:00 local time is skipped
+    let LocalTimeResolution::Skipped(before, after) = time_zone.resolve_local(SPRING_2023 + 5_400)
+    else {
+        panic!("expected a skipped local time");
+    };
+    assert_eq!(before.abbreviation(), "CET");
+    assert_eq!(after.abbreviation(), "CEST");
+
+    // 2023-10-29T02:30:00 local time occurs twice
+    let LocalTimeResolution::Ambiguous(earlier, later) =
+        time_zone.resolve_local(AUTUMN_2023 + 5_400)
+    else {
+        panic!("expected an ambiguous local time");
+    };
+    assert_eq!(earlier.abbreviation(), "CEST");
+    assert_eq!(later.abbreviation(), "CET");
+
+    // the same transitions of 2024 from the footer rule
+    assert!(matches!(
+        time_zone.resolve_local(1_711_852_200),
+        LocalTimeResolution::Skipped(..)
+    ));
+    assert!(matches!(
+        time_zone.resolve_local(1_729_996_200),
+        LocalTimeResolution::Ambiguous(..)
+    ));
+}
+
+#[test]
+fn time_zone_disambiguation() {
+    let time_zone = TimeZone::from_tzif("Europe/Test", &central_european_tzif()).unwrap();
+    let skipped = SPRING_2023 + 5_400;
+    let ambiguous = AUTUMN_2023 + 5_400;
+
+    // a skipped local time moves forward with the offset before the transition, or backward
+    // with the offset after it
+    assert_eq!(
+        time_zone.to_unix_seconds(skipped, Disambiguation::Compatible),
+        Ok(SPRING_2023 + 1_800)
+    );
+    assert_eq!(
+        time_zone.to_unix_seconds(skipped, Disambiguation::Later),
+        Ok(SPRING_2023 + 1_800)
+    );
+    assert_eq!(
+        time_zone.to_unix_seconds(skipped, Disambiguation::Earlier),
+        Ok(SPRING_2023 - 1_800)
+    );
+    assert!(matches!(
+        time_zone.to_unix_seconds(skipped, Disambiguation::Reject),
+        Err(TimeZoneError::SkippedLocalTime(_))
+    ));
+
+    // an ambiguous local time resolves to its earlier or later instant
+    assert_eq!(
+        time_zone.to_unix_seconds(ambiguous, Disambiguation::Compatible),
+        Ok(AUTUMN_2023 - 1_800)
+    );
+    assert_eq!(
+        time_zone.to_unix_seconds(ambiguous, Disambiguation::Earlier),
+        Ok(AUTUMN_2023 - 1_800)
+    );
+    assert_eq!(
+        time_zone.to_unix_seconds(ambiguous, Disambiguation::Later),
+        Ok(AUTUMN_2023 + 1_800)
+    );
+    assert_eq!(
+        time_zone.to_unix_seconds(ambiguous, Disambiguation::Reject),
+        Err(TimeZoneError::AmbiguousLocalTime(
+            "The local time occurs twice in time zone 'Europe/Test'.".to_string()
+        ))
+    );
+
+    // a unique local time ignores the disambiguation
+    assert_eq!(
+        time_zone.to_unix_seconds(SPRING_2023 + 86_400, Disambiguation::Reject),
+        Ok(SPRING_2023 + 86_400 - 7_200)
+    );
+}
+
+#[test]
+fn time_zone_load() {
+    let directory = time_zone_directory("load");
+    let time_zone = TimeZone::load_from_directory(&directory, "Europe/Test").unwrap();
+    assert_eq!(time_zone.name(), "Europe/Test");
+    assert!(std::rc::Rc::ptr_eq(
+        &time_zone,
+        &TimeZone::load_from_directory(&directory, "Europe/Test").unwrap()
+    ));
+
+    TimeZone::set_directory(Some(directory.clone()));
+    assert_eq!(TimeZone::directory(), directory);
+    assert_eq!(TimeZone::load("Europe/Test").unwrap(), time_zone);
+    assert_eq!(
+        TimeZone::load("Europe/Missing"),
+        Err(TimeZoneError::NotFound("Europe/Missing".to_string()))
+    );
+    TimeZone::set_directory(None);
+    assert_ne!(TimeZone::directory(), directory);
+}
+
+#[test]
+fn time_zone_invalid_name() {
+    let directory = time_zone_directory("invalid_name");
+    for name in [
+        "",
+        "../Europe/Test",
+        "Europe/../Test",
+        "/etc/passwd",
+        "Europe Test",
+    ] {
+        assert_eq!(
+            TimeZone::load_from_directory(&directory, name),
+            Err(TimeZoneError::InvalidName(name.to_string()))
+        );
+    }
+}
+
+#[test]
+fn time_zone_invalid_data() {
+    let data = central_european_tzif();
+    assert!(matches!(
+        TimeZone::from_tzif("Europe/Test", b"not a time zone"),
+        Err(TimeZoneError::InvalidData(_))
+    ));
+    assert!(matches!(
+        TimeZone::from_tzif("Europe/Test", &data[..data.len() - 10]),
+        Err(TimeZoneError::InvalidData(_))
+    ));
+    assert!(matches!(
+        TimeZone::from_tzif(
+            "Europe/Test",
+            &tzif(&[], &[(3_600, false, 0)], b"CET\0", "not a rule")
+        ),
+        Err(TimeZoneError::InvalidData(_))
+    ));
+    assert!(matches!(
+        TimeZone::from_tzif(
+            "Europe/Test",
+            &tzif(&[(0, 1)], &[(3_600, false, 0)], b"CET\0", "CET-1")
+        ),
+        Err(TimeZoneError::InvalidData(_))
+    ));
+    assert_eq!(
+        TimeZone::from_tzif("Europe/Test", b"not a time zone")
+            .unwrap_err()
+            .to_string(),
+        "Invalid TZif data: the data does not start with 'TZif'"
+    );
+
+    // counts that exceed the data are rejected before space is reserved for them
+    for counts in [[0, 0, 0, u32::MAX, 1, 0], [0, 0, 0, 0, u32::MAX, 0]] {
+        let mut data = b"TZif".to_vec();
+        data.extend([0; 16]);
+        for count in counts {
+            data.extend(count.to_be_bytes());
+        }
+        data.extend([0; 6]);
+        assert_eq!(
+            TimeZone::from_tzif("Europe/Test", &data),
+            Err(TimeZoneError::InvalidData(
+                "the data is truncated".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    rc::Rc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    accessors::temporal::{
+        date::DateError,
+        date_time::DateTimeError,
+        day_to_second_duration::DayToSecondDurationError,
+        tests::time_zone_test::central_european_tzif,
+        time_zone::{Disambiguation, TimeZone},
+        unix_time::UnixTimeUnit,
+        zoned_date_time::ZonedDateTimeError,
+    },
+    data_spec_builders::{
+        date_spec_builder::DateSpecBuilder, date_time_spec_builder::DateTimeSpecBuilder,
+        duration_spec_builder::DurationSpecBuilder,
+    },
+    primitive_specs::{
+        date_spec::DateRange,
+        date_time_spec::DateTimeType,
+        duration_spec::{DurationResolution, DurationType},
+    },
+    variable::Variable,
+};
+
+/// 2024-06-15T12:34:56.789612345Z in nanoseconds since the Unix epoch.
+const JUNE_15_2024: i64 = 1_718_454_896_789_612_345;
+
+#[test]
+fn unix_time_unit() {
+    assert_eq!(UnixTimeUnit::Seconds.nanoseconds(), 1_000_000_000);
+    assert_eq!(UnixTimeUnit::Milliseconds.nanoseconds(), 1_000_000);
+    assert_eq!(UnixTimeUnit::Microseconds.nanoseconds(), 1_000);
+    assert_eq!(UnixTimeUnit::Nanoseconds.nanoseconds(), 1);
+    assert_eq!(UnixTimeUnit::Milliseconds.to_string(), "milliseconds");
+}
+
+#[test]
+fn date_unix_timestamp() {
+    let mut var = Variable::new(&DateSpecBuilder::new().build());
+    let date_mut = var.date_mut();
+    date_mut
+        .set_from_unix_timestamp(JUNE_15_2024, UnixTimeUnit::Nanoseconds)
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 6, 15));
+    assert_eq!(
+        date_mut.unix_timestamp(UnixTimeUnit::Seconds).unwrap(),
+        1_718_409_600
+    );
+    assert_eq!(
+        date_mut.unix_timestamp(UnixTimeUnit::Milliseconds).unwrap(),
+        1_718_409_600_000
+    );
+
+    // an instant before the Unix epoch falls on the previous day
+    date_mut
+        .set_from_unix_timestamp(-1, UnixTimeUnit::Seconds)
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (1969, 12, 31));
+    assert_eq!(
+        date_mut.unix_timestamp(UnixTimeUnit::Seconds).unwrap(),
+        -86_400
+    );
+
+    // out of the date's range, the date is left unchanged
+    assert_eq!(
+        date_mut.set_from_unix_timestamp(-62_135_596_801, UnixTimeUnit::Seconds),
+        Err(DateError::YearOutOfBounds(0))
+    );
+    assert_eq!(
+        date_mut.set_from_unix_timestamp(i64::MAX, UnixTimeUnit::Seconds),
+        Err(DateError::YearOutOfBounds(u32::MAX))
+    );
+    assert_eq!(date_mut.date().unwrap(), (1969, 12, 31));
+
+    // 9999-12-31 cannot be counted in nanoseconds by an i64
+    date_mut.set_date(9999, 12, 31).unwrap();
+    assert!(matches!(
+        date_mut.unix_timestamp(UnixTimeUnit::Nanoseconds),
+        Err(DateError::TimestampOutOfRange(_))
+    ));
+    assert_eq!(
+        date_mut.unix_timestamp(UnixTimeUnit::Seconds).unwrap(),
+        253_402_214_400
+    );
+}
+
+#[test]
+fn date_system_time() {
+    let mut var = Variable::new(
+        &DateSpecBuilder::new()
+            .set_range(DateRange::Extended)
+            .build(),
+    );
+    let date_mut = var.date_mut();
+    let system_time = UNIX_EPOCH + Duration::from_secs(1_718_454_896);
+    date_mut.set_from_system_time(&system_time).unwrap();
+    assert_eq!(date_mut.date().unwrap(), (2024, 6, 15));
+    assert_eq!(
+        date_mut.system_time().unwrap(),
+        UNIX_EPOCH + Duration::from_secs(1_718_409_600)
+    );
+
+    date_mut
+        .set_from_system_time(&(UNIX_EPOCH - Duration::from_secs(1)))
+        .unwrap();
+    assert_eq!(date_mut.date().unwrap(), (1969, 12, 31));
+    assert_eq!(
+        date_mut.system_time().unwrap(),
+        UNIX_EPOCH - Duration::from_secs(86_400)
+    );
+
+    date_mut.set_proleptic_date(-9999, 1, 1).unwrap();
+    let system_time = date_mut.system_time().unwrap();
+    date_mut.set_date(2024, 6, 15).unwrap();
+    date_mut.set_from_system_time(&system_time).unwrap();
+    assert_eq!(date_mut.proleptic_date().unwrap(), (-9999, 1, 1));
+}
+
+#[test]
+fn date_time_unix_timestamp() {
+    let mut var = Variable::new(
+        &DateTimeSpecBuilder::new()
+            .set_date_time_type(DateTimeType::Local)
+            .build(),
+    );
+    let date_time_mut = var.date_time_mut();
+    date_time_mut
+        .set_from_unix_timestamp(JUNE_15_2024, UnixTimeUnit::Nanoseconds)
+        .unwrap();
+    assert_eq!(date_time_mut.to_string(), "2024-06-15T12:34:56.789612345");
+    for (unit, expected) in [
+        (UnixTimeUnit::Seconds, 1_718_454_896),
+        (UnixTimeUnit::Milliseconds, 1_718_454_896_789),
+        (UnixTimeUnit::Microseconds, 1_718_454_896_789_612),
+        (UnixTimeUnit::Nanoseconds, JUNE_15_2024),
+    ] {
+        assert_eq!(date_time_mut.unix_timestamp(unit).unwrap(), expected);
+    }
+
+    // timestamps are rounded down to a whole unit
+    date_time_mut
+        .set_from_unix_timestamp(-1_500, UnixTimeUnit::Milliseconds)
+        .unwrap();
+    assert_eq!(date_time_mut.to_string(), "1969-12-31T23:59:58.5");
+    assert_eq!(
+        date_time_mut.unix_timestamp(UnixTimeUnit::Seconds).unwrap(),
+        -2
+    );
+}
+
+#[test]
+fn date_time_unix_timestamp_errors() {
+    let mut var = Variable::new(
+        &DateTimeSpecBuilder::new()
+            .set_date_time_type(DateTimeType::Local)
+            .build(),
+    );
+    let date_time_mut = var.date_time_mut();
+    date_time_mut
+        .set_from_unix_timestamp(1_718_454_896, UnixTimeUnit::Seconds)
+        .unwrap();
+
+    // out of the date's range, the date-time is left unchanged
+    assert_eq!(
+        date_time_mut.set_from_unix_timestamp(-62_135_596_801, UnixTimeUnit::Seconds),
+        Err(DateTimeError::DateError(DateError::YearOutOfBounds(0)))
+    );
+    assert_eq!(date_time_mut.to_string(), "2024-06-15T12:34:56");
+
+    date_time_mut
+        .set_date_time(2300, 1, 1, 0, 0, 0, 0, 0, 0)
+        .unwrap();
+    assert!(matches!(
+        date_time_mut.unix_timestamp(UnixTimeUnit::Nanoseconds),
+        Err(DateTimeError::DateError(DateError::TimestampOutOfRange(_)))
+    ));
+}
+
+#[test]
+fn date_time_system_time() {
+    let mut var = Variable::new(
+        &DateTimeSpecBuilder::new()
+            .set_date_time_type(DateTimeType::Local)
+            .build(),
+    );
+    let date_time_mut = var.date_time_mut();
+    let system_time = UNIX_EPOCH + Duration::from_nanos(JUNE_15_2024 as u64);
+    date_time_mut.set_from_system_time(&system_time).unwrap();
+    assert_eq!(date_time_mut.to_string(), "2024-06-15T12:34:56.789612345");
+    assert_eq!(date_time_mut.system_time().unwrap(), system_time);
+
+    let system_time = UNIX_EPOCH - Duration::new(1, 250_000_000);
+    date_time_mut.set_from_system_time(&system_time).unwrap();
+    assert_eq!(date_time_mut.to_string(), "1969-12-31T23:59:58.75");
+    assert_eq!(date_time_mut.system_time().unwrap(), system_time);
+
+    // the current system time
+    let system_time = SystemTime::now();
+    date_time_mut.set_from_system_time(&system_time).unwrap();
+    assert_eq!(date_time_mut.system_time().unwrap(), system_time);
+}
+
+#[test]
+fn zoned_date_time_unix_timestamp() {
+    let mut var = Variable::new(
+        &DateTimeSpecBuilder::new()
+            .set_date_time_type(DateTimeType::Zoned)
+            .build(),
+    );
+    let zoned_date_time_mut = var.zoned_date_time_mut();
+    zoned_date_time_mut
+        .set_from_unix_timestamp(JUNE_15_2024, UnixTimeUnit::Nanoseconds)
+        .unwrap();
+    assert_eq!(
+        zoned_date_time_mut.to_string(),
+        "2024-06-15T12:34:56.789612345Z"
+    );
+
+    // the current offset is kept
+    zoned_date_time_mut.convert_to_offset(-5 * 3_600).unwrap();
+    zoned_date_time_mut
+        .set_from_unix_timestamp(0, UnixTimeUnit::Seconds)
+        .unwrap();
+    assert_eq!(zoned_date_time_mut.to_string(), "1969-12-31T19:00:00-05:00");
+    assert_eq!(
+        zoned_date_time_mut
+            .unix_timestamp(UnixTimeUnit::Seconds)
+            .unwrap(),
+        0
+    );
+
+    // the named time zone is kept, with its offset at the instant
+    let time_zone = Rc::new(TimeZone::from_tzif("Europe/Test", &central_european_tzif()).unwrap());
+    zoned_date_time_mut
+        .set_in_time_zone(
+            (2024, 1, 1),
+            (0, 0, 0, 0, 0, 0),
+            &time_zone,
+            Disambiguation::Compatible,
+        )
+        .unwrap();
+    zoned_date_time_mut
+        .set_from_unix_timestamp(1_718_454_896_789, UnixTimeUnit::Milliseconds)
+        .unwrap();
+    assert_eq!(
+        zoned_date_time_mut.to_string(),
+        "2024-06-15T14:34:56.789+02:00[Europe/Test]"
+    );
+    assert_eq!(
+        zoned_date_time_mut
+            .unix_timestamp(UnixTimeUnit::Milliseconds)
+            .unwrap(),
+        1_718_454_896_789
+    );
+
+    // out of the date's range, the zoned date-time is left unchanged
+    assert!(matches!(
+        zoned_date_time_mut.set_from_unix_timestamp(i64::MAX, UnixTimeUnit::Seconds),
+        Err(ZonedDateTimeError::DateError(
+            DateError::TimestampOutOfRange(_)
+        ))
+    ));
+    assert_eq!(
+        zoned_date_time_mut.set_from_unix_timestamp(-62_135_596_801, UnixTimeUnit::Seconds),
+        Err(ZonedDateTimeError::DateError(DateError::YearOutOfBounds(0)))
+    );
+    assert_eq!(
+        zoned_date_time_mut.to_string(),
+        "2024-06-15T14:34:56.789+02:00[Europe/Test]"
+    );
+}
+
+#[test]
+fn zoned_date_time_system_time() {
+    let mut var = Variable::new(
+        &DateTimeSpecBuilder::new()
+            .set_date_time_type(DateTimeType::Zoned)
+            .build(),
+    );
+    let zoned_date_time_mut = var.zoned_date_time_mut();
+    zoned_date_time_mut
+        .set_from_string("2024-01-01T00:00:00+05:30")
+        .unwrap();
+    let system_time = UNIX_EPOCH + Duration::from_nanos(JUNE_15_2024 as u64);
+    zoned_date_time_mut
+        .set_from_system_time(&system_time)
+        .unwrap();
+    assert_eq!(
+        zoned_date_time_mut.to_string(),
+        "2024-06-15T18:04:56.789612345+05:30"
+    );
+    assert_eq!(zoned_date_time_mut.system_time().unwrap(), system_time);
+}
+
+#[test]
+fn day_to_second_duration_std_duration() {
+    let mut var = Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::DayToSecond)
+            .build()
+            .unwrap(),
+    );
+    let duration_mut = var.day_to_second_duration_mut();
+    duration_mut
+        .set_from_std_duration(&Duration::new(90_061, 123_456_789))
+        .unwrap();
+    assert_eq!(
+        duration_mut.duration().unwrap(),
+        (1, 1, 1, 1, 123, 456, 789)
+    );
+    assert_eq!(
+        duration_mut.std_duration().unwrap(),
+        Duration::new(90_061, 123_456_789)
+    );
+
+    // a negative duration cannot be converted
+    duration_mut.negate().unwrap();
+    assert!(matches!(
+        duration_mut.std_duration(),
+        Err(DayToSecondDurationError::Overflow(_))
+    ));
+
+    // the days would overflow
+    assert!(matches!(
+        duration_mut.set_from_std_duration(&Duration::MAX),
+        Err(DayToSecondDurationError::Overflow(_))
+    ));
+}
+
+#[test]
+fn day_to_second_duration_std_duration_resolution() {
+    let mut var = Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::DayToSecond)
+            .set_resolution(DurationResolution::Second)
+            .build()
+            .unwrap(),
+    );
+    let duration_mut = var.day_to_second_duration_mut();
+    assert!(matches!(
+        duration_mut.set_from_std_duration(&Duration::from_millis(1_500)),
+        Err(DayToSecondDurationError::ResolutionOutOfBounds(_))
+    ));
+    duration_mut
+        .set_from_std_duration(&Duration::from_secs(90))
+        .unwrap();
+    assert_eq!(duration_mut.to_string(), "PT1M30S");
+}
@@ -5,7 +5,10 @@ use crate::{
     adaptor::Adaptor,
     adaptors::temporal_adaptors::year_to_month_duration_adaptor::YearToMonthDurationAdaptor,
     data_spec_builders::duration_spec_builder::DurationSpecBuilder,
-    primitive_specs::duration_spec::{DurationResolution, DurationSpec, DurationType},
+    primitive_specs::{
+        duration_spec::{DurationResolution, DurationSpec, DurationType},
+        rounding_policy::RoundingPolicy,
+    },
     set_equal_to::SetEqualTo,
     variable::Variable,
 };
@@ -458,3 +461,59 @@ fn custom_ymd_adaptor_ord() {
         std::cmp::Ordering::Equal
     );
 }
+
+#[test]
+fn year_to_month_duration_rounding_policies() {
+    for (rounding_policy, expected) in [
+        (RoundingPolicy::Truncate, [(1, 0), (-1, 0), (2, 0), (0, 0)]),
+        (
+            RoundingPolicy::RoundHalfEven,
+            [(2, 0), (-2, 0), (2, 0), (1, 0)],
+        ),
+        (
+            RoundingPolicy::RoundHalfUp,
+            [(2, 0), (-2, 0), (3, 0), (1, 0)],
+        ),
+    ] {
+        let mut var = Variable::new(
+            &DurationSpecBuilder::new()
+                .set_type(DurationType::YearToMonth)
+                .set_resolution(DurationResolution::Year)
+                .set_rounding_policy(rounding_policy)
+                .build()
+                .unwrap(),
+        );
+        let duration_mut = var.year_to_month_duration_mut();
+        for ((years, months), expected) in
+            [(1, 6), (-1, -6), (2, 6), (0, 7)].into_iter().zip(expected)
+        {
+            duration_mut.set_duration(years, months).unwrap();
+            assert_eq!(duration_mut.duration().unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn year_to_month_duration_with_rounding() {
+    let mut var = Variable::new(
+        &DurationSpecBuilder::new()
+            .set_type(DurationType::YearToMonth)
+            .set_resolution(DurationResolution::Year)
+            .build()
+            .unwrap(),
+    );
+    let duration_mut = var.year_to_month_duration_mut();
+    duration_mut
+        .set_duration_with_rounding(1, 11, RoundingPolicy::RoundHalfUp)
+        .unwrap();
+    assert_eq!(duration_mut.duration().unwrap(), (2, 0));
+    assert!(matches!(
+        duration_mut.set_duration(1, 11),
+        Err(YearToMonthDurationError::ResolutionOutOfBounds(_))
+    ));
+    assert!(matches!(
+        duration_mut.set_duration_with_rounding(i32::MAX, 11, RoundingPolicy::RoundHalfUp),
+        Err(YearToMonthDurationError::ResolutionOutOfBounds(_))
+    ));
+    assert_eq!(duration_mut.duration().unwrap(), (2, 0));
+}
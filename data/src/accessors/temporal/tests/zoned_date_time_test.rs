@@ -0,0 +1,518 @@
+use crate::{
+    accessors::temporal::{
+        date::DateError,
+        tests::time_zone_test::time_zone_directory,
+        time_zone::{Disambiguation, TimeZone, TimeZoneError},
+        zoned_date_time::ZonedDateTimeError,
+        zoned_time::ZonedTimeError,
+    },
+    data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder,
+    primitive_specs::date_time_spec::{DateTimeType, ZoneType},
+    set_equal_to::SetEqualTo,
+    variable::Variable,
+};
+
+#[test]
+fn set_zoned_date_time() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_date_time = var.zoned_date_time_mut();
+    zoned_date_time
+        .set_zoned_date_time((2024, 2, 29), (23, 59, 58, 1, 2, 3), 3_600)
+        .unwrap();
+    assert_eq!(
+        zoned_date_time.zoned_date_time().unwrap(),
+        ((2024, 2, 29), (23, 59, 58, 1, 2, 3), 3_600)
+    );
+    assert_eq!(zoned_date_time.date().days().unwrap(), 738_944);
+    assert_eq!(
+        zoned_date_time.to_string(),
+        "2024-02-29T23:59:58.001002003+01:00"
+    );
+}
+
+#[test]
+fn set_zoned_date_time_is_unchanged_on_error() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_date_time = var.zoned_date_time_mut();
+    zoned_date_time
+        .set_from_string("2024-06-15T12:00:00+01:00")
+        .unwrap();
+
+    assert_eq!(
+        zoned_date_time.set_zoned_date_time((2023, 2, 29), (12, 0, 0, 0, 0, 0), 0),
+        Err(ZonedDateTimeError::DateError(DateError::DayOutOfBounds(
+            29,
+            "February".to_string(),
+            28
+        )))
+    );
+    assert_eq!(
+        zoned_date_time.set_zoned_date_time((2025, 1, 1), (12, 0, 0, 0, 0, 0), 65_000),
+        Err(ZonedDateTimeError::ZonedTimeError(
+            ZonedTimeError::OffsetOutOfBounds(65_000)
+        ))
+    );
+    assert_eq!(zoned_date_time.to_string(), "2024-06-15T12:00:00+01:00");
+}
+
+#[test]
+fn zoned_date_time_to_utc_and_convert_to_offset() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_date_time = var.zoned_date_time_mut();
+    zoned_date_time
+        .set_from_string("2024-03-01T01:30:00+02:00")
+        .unwrap();
+    zoned_date_time.to_utc().unwrap();
+    assert_eq!(zoned_date_time.to_string(), "2024-02-29T23:30:00Z");
+
+    zoned_date_time.convert_to_offset(-7_200).unwrap();
+    assert_eq!(zoned_date_time.to_string(), "2024-02-29T21:30:00-02:00");
+
+    zoned_date_time.convert_to_offset(9_000).unwrap();
+    assert_eq!(zoned_date_time.to_string(), "2024-03-01T02:00:00+02:30");
+
+    zoned_date_time
+        .set_from_string("9999-12-31T23:00:00Z")
+        .unwrap();
+    assert_eq!(
+        zoned_date_time.convert_to_offset(7_200),
+        Err(ZonedDateTimeError::DateError(DateError::YearOutOfBounds(
+            10_000
+        )))
+    );
+    zoned_date_time
+        .set_from_string("0001-01-01T01:00:00Z")
+        .unwrap();
+    assert_eq!(
+        zoned_date_time.convert_to_offset(-7_200),
+        Err(ZonedDateTimeError::DateError(DateError::YearOutOfBounds(0)))
+    );
+    assert_eq!(zoned_date_time.to_string(), "0001-01-01T01:00:00Z");
+}
+
+#[test]
+fn zoned_date_time_display_and_debug() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.zoned_date_time_mut()
+        .set_from_string("2024-06-15T12:34:56.789-05:00")
+        .unwrap();
+    assert_eq!(
+        var.zoned_date_time().to_string(),
+        "2024-06-15T12:34:56.789-05:00"
+    );
+    assert_eq!(var.to_string(), "2024-06-15T12:34:56.789-05:00");
+    assert_eq!(
+        format!("{:?}", var.zoned_date_time()),
+        "ZonedDateTime { date: Date { year: 2024, month: 6, day: 15 }, zoned_time: ZonedTime { time: Time { hour: 12, minute: 34, second: 56, millisecond: 789, microsecond: 000, nanosecond: 000 }, offset: -05:00 }, time_zone: None }"
+    );
+}
+
+#[test]
+fn zoned_date_time_set_equal_to() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    var1.zoned_date_time_mut()
+        .set_from_string("2024-06-15T12:34:56+02:00")
+        .unwrap();
+
+    // test set equal to via accessor
+    let mut var2 = Variable::new(&spec);
+    var2.zoned_date_time_mut()
+        .set_equal_to(var1.zoned_date_time())
+        .unwrap();
+    assert_eq!(var2.to_string(), "2024-06-15T12:34:56+02:00");
+
+    // test set equal to via variable
+    let mut var3 = Variable::new(&spec);
+    var3.set_equal_to(&var1).unwrap();
+    assert_eq!(var3.to_string(), "2024-06-15T12:34:56+02:00");
+
+    // test clone
+    let var4 = var1.clone();
+    assert_eq!(var4, var1);
+}
+
+#[test]
+fn zoned_date_time_partial_eq_and_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    let mut var2 = Variable::new(&spec);
+    let mut var3 = Variable::new(&spec);
+    var1.zoned_date_time_mut()
+        .set_from_string("2024-06-15T01:00:00+02:00")
+        .unwrap();
+    var2.zoned_date_time_mut()
+        .set_from_string("2024-06-14T23:00:00Z")
+        .unwrap();
+    var3.zoned_date_time_mut()
+        .set_from_string("2024-06-15T23:00:00Z")
+        .unwrap();
+
+    assert_eq!(var1.zoned_date_time(), var2.zoned_date_time());
+    assert_ne!(var1.zoned_date_time(), var3.zoned_date_time());
+    assert_eq!(var1, var2);
+    assert_ne!(var1, var3);
+
+    let hash = |var: &Variable| {
+        let mut hasher = DefaultHasher::new();
+        var.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&var1), hash(&var2));
+    assert_ne!(hash(&var1), hash(&var3));
+}
+
+#[test]
+fn zoned_date_time_ord() {
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    let mut var2 = Variable::new(&spec);
+    let mut var3 = Variable::new(&spec);
+    var1.zoned_date_time_mut()
+        .set_from_string("2024-06-15T08:00:00+09:00")
+        .unwrap();
+    var2.zoned_date_time_mut()
+        .set_from_string("2024-06-15T00:00:00Z")
+        .unwrap();
+    var3.zoned_date_time_mut()
+        .set_from_string("2024-06-14T20:00:01-04:00")
+        .unwrap();
+
+    assert!(var1.zoned_date_time() < var2.zoned_date_time());
+    assert!(var2.zoned_date_time() < var3.zoned_date_time());
+    assert!(var1 < var3);
+    assert!(var2 <= var2.clone());
+}
+
+#[test]
+fn zoned_date_time_set_in_time_zone() {
+    let directory = time_zone_directory("set_in_time_zone");
+    let time_zone = TimeZone::load_from_directory(&directory, "Europe/Test").unwrap();
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_date_time = var.zoned_date_time_mut();
+
+    zoned_date_time
+        .set_in_time_zone(
+            (2024, 6, 15),
+            (12, 0, 0, 0, 0, 0),
+            &time_zone,
+            Disambiguation::Reject,
+        )
+        .unwrap();
+    assert_eq!(
+        zoned_date_time.zoned_date_time().unwrap(),
+        ((2024, 6, 15), (12, 0, 0, 0, 0, 0), 7_200)
+    );
+    assert_eq!(zoned_date_time.time_zone(), Some(&time_zone));
+    assert_eq!(
+        zoned_date_time.to_string(),
+        "2024-06-15T12:00:00+02:00[Europe/Test]"
+    );
+    assert_eq!(
+        format!("{:?}", zoned_date_time),
+        "ZonedDateTime { date: Date { year: 2024, month: 6, day: 15 }, zoned_time: ZonedTime { time: Time { hour: 12, minute: 00, second: 00, millisecond: 000, microsecond: 000, nanosecond: 000 }, offset: +02:00 }, time_zone: Europe/Test }"
+    );
+
+    // 02:30 is skipped when clocks are set forward
+    let skipped = ((2024, 3, 31), (2, 30, 0, 0, 0, 0));
+    zoned_date_time
+        .set_in_time_zone(skipped.0, skipped.1, &time_zone, Disambiguation::Compatible)
+        .unwrap();
+    assert_eq!(
+        zoned_date_time.to_string(),
+        "2024-03-31T03:30:00+02:00[Europe/Test]"
+    );
+    zoned_date_time
+        .set_in_time_zone(skipped.0, skipped.1, &time_zone, Disambiguation::Earlier)
+        .unwrap();
+    assert_eq!(
+        zoned_date_time.to_string(),
+        "2024-03-31T01:30:00+01:00[Europe/Test]"
+    );
+
+    // 02:30 occurs twice when clocks are set back
+    let ambiguous = ((2024, 10, 27), (2, 30, 0, 0, 0, 0));
+    zoned_date_time
+        .set_in_time_zone(
+            ambiguous.0,
+            ambiguous.1,
+            &time_zone,
+            Disambiguation::Compatible,
+        )
+        .unwrap();
+    assert_eq!(
+        zoned_date_time.to_string(),
+        "2024-10-27T02:30:00+02:00[Europe/Test]"
+    );
+    zoned_date_time
+        .set_in_time_zone(ambiguous.0, ambiguous.1, &time_zone, Disambiguation::Later)
+        .unwrap();
+    assert_eq!(
+        zoned_date_time.to_string(),
+        "2024-10-27T02:30:00+01:00[Europe/Test]"
+    );
+
+    // failures leave the zoned date-time unchanged
+    assert!(matches!(
+        zoned_date_time.set_in_time_zone(
+            ambiguous.0,
+            ambiguous.1,
+            &time_zone,
+            Disambiguation::Reject
+        ),
+        Err(ZonedDateTimeError::TimeZoneError(
+            TimeZoneError::AmbiguousLocalTime(_)
+        ))
+    ));
+    assert!(matches!(
+        zoned_date_time.set_in_time_zone(skipped.0, skipped.1, &time_zone, Disambiguation::Reject),
+        Err(ZonedDateTimeError::TimeZoneError(
+            TimeZoneError::SkippedLocalTime(_)
+        ))
+    ));
+    assert!(matches!(
+        zoned_date_time.set_in_time_zone(
+            (2024, 2, 30),
+            (12, 0, 0, 0, 0, 0),
+            &time_zone,
+            Disambiguation::Compatible
+        ),
+        Err(ZonedDateTimeError::DateError(_))
+    ));
+    assert_eq!(
+        zoned_date_time.to_string(),
+        "2024-10-27T02:30:00+01:00[Europe/Test]"
+    );
+}
+
+#[test]
+fn zoned_date_time_convert_to_time_zone() {
+    let directory = time_zone_directory("convert_to_time_zone");
+    let time_zone = TimeZone::load_from_directory(&directory, "Europe/Test").unwrap();
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_date_time = var.zoned_date_time_mut();
+    zoned_date_time
+        .set_from_string("2024-06-15T23:30:00Z")
+        .unwrap();
+    let utc = zoned_date_time.zoned_date_time().unwrap();
+
+    zoned_date_time.convert_to_time_zone(&time_zone).unwrap();
+    assert_eq!(
+        zoned_date_time.to_string(),
+        "2024-06-16T01:30:00+02:00[Europe/Test]"
+    );
+    var.zoned_date_time_mut()
+        .set_from_string("2024-12-15T23:30:00Z")
+        .unwrap();
+    var.zoned_date_time_mut()
+        .convert_to_time_zone(&time_zone)
+        .unwrap();
+    assert_eq!(var.to_string(), "2024-12-16T00:30:00+01:00[Europe/Test]");
+
+    // converting to an offset drops the time zone
+    var.zoned_date_time_mut().to_utc().unwrap();
+    assert_eq!(var.zoned_date_time().time_zone(), None);
+    assert_eq!(var.to_string(), "2024-12-15T23:30:00Z");
+    assert_ne!(var.zoned_date_time().zoned_date_time().unwrap(), utc);
+}
+
+#[test]
+fn zoned_date_time_set_from_string_with_time_zone() {
+    TimeZone::set_directory(Some(time_zone_directory("set_from_string")));
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_date_time = var.zoned_date_time_mut();
+
+    zoned_date_time
+        .set_from_string("2024-06-15T12:00:00[Europe/Test]")
+        .unwrap();
+    assert_eq!(
+        zoned_date_time.to_string(),
+        "2024-06-15T12:00:00+02:00[Europe/Test]"
+    );
+    assert_eq!(zoned_date_time.time_zone().unwrap().name(), "Europe/Test");
+
+    // the offset selects the instant of an ambiguous local time
+    zoned_date_time
+        .set_from_string("2024-10-27T02:30:00+01:00[Europe/Test]")
+        .unwrap();
+    assert_eq!(
+        zoned_date_time.zoned_date_time().unwrap(),
+        ((2024, 10, 27), (2, 30, 0, 0, 0, 0), 3_600)
+    );
+    let mut other = Variable::new(&spec);
+    other
+        .zoned_date_time_mut()
+        .set_from_string("2024-10-27T01:30:00Z")
+        .unwrap();
+    assert_eq!(var, other);
+    var.zoned_date_time_mut()
+        .set_from_string("2024-10-27T02:30:00+02:00[Europe/Test]")
+        .unwrap();
+    other
+        .zoned_date_time_mut()
+        .set_from_string("2024-10-27T00:30:00Z")
+        .unwrap();
+    assert_eq!(var, other);
+
+    // a string without a time zone drops it
+    var.zoned_date_time_mut()
+        .set_from_string("2024-06-15T12:00:00+02:00")
+        .unwrap();
+    assert_eq!(var.zoned_date_time().time_zone(), None);
+
+    // invalid strings leave the zoned date-time unchanged
+    let zoned_date_time = var.zoned_date_time_mut();
+    assert_eq!(
+        zoned_date_time.set_from_string("2024-06-15T12:00:00+01:00[Europe/Test]"),
+        Err(ZonedDateTimeError::InvalidFormat(
+            "UTC offset '+01:00' does not match the offset '+02:00' of time zone 'Europe/Test' in zoned date-time string '2024-06-15T12:00:00+01:00[Europe/Test]'".to_string()
+        ))
+    );
+    assert_eq!(
+        zoned_date_time.set_from_string("2024-06-15T12:00:00[Europe/Missing]"),
+        Err(ZonedDateTimeError::TimeZoneError(TimeZoneError::NotFound(
+            "Europe/Missing".to_string()
+        )))
+    );
+    assert_eq!(
+        zoned_date_time.set_from_string("2024-06-15T12:00:00[../Test]"),
+        Err(ZonedDateTimeError::TimeZoneError(
+            TimeZoneError::InvalidName("../Test".to_string())
+        ))
+    );
+    assert!(matches!(
+        zoned_date_time.set_from_string("2024-06-15T12:00:00Europe/Test]"),
+        Err(ZonedDateTimeError::InvalidFormat(_))
+    ));
+    assert!(matches!(
+        zoned_date_time.set_from_string("2024-06-15T12:00:00"),
+        Err(ZonedDateTimeError::ZonedTimeError(
+            ZonedTimeError::InvalidFormat(_)
+        ))
+    ));
+    assert_eq!(zoned_date_time.to_string(), "2024-06-15T12:00:00+02:00");
+    TimeZone::set_directory(None);
+}
+
+#[test]
+fn zoned_date_time_zone_type() {
+    let directory = time_zone_directory("zone_type");
+    let time_zone = TimeZone::load_from_directory(&directory, "Europe/Test").unwrap();
+    TimeZone::set_directory(Some(directory));
+    let named_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .set_zone_type(ZoneType::Named)
+        .build();
+    let mut named = Variable::new(&named_spec);
+    let offset_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .set_zone_type(ZoneType::Offset)
+        .build();
+    let mut offset = Variable::new(&offset_spec);
+
+    // a zoned date-time that requires a named zone rejects fixed offsets
+    let mismatch = |zone_type: &str, required: &str| {
+        Err(ZonedDateTimeError::ZoneTypeMismatch(format!(
+            "Cannot set a zone of type {} on a zoned date-time that requires a zone of type {}.",
+            zone_type, required
+        )))
+    };
+    assert_eq!(
+        named
+            .zoned_date_time_mut()
+            .set_zoned_date_time((2024, 6, 15), (12, 0, 0, 0, 0, 0), 0),
+        mismatch("Offset", "Named")
+    );
+    assert_eq!(
+        named
+            .zoned_date_time_mut()
+            .set_from_string("2024-06-15T12:00:00Z"),
+        mismatch("Offset", "Named")
+    );
+    assert_eq!(
+        named.zoned_date_time_mut().to_utc(),
+        mismatch("Offset", "Named")
+    );
+    named
+        .zoned_date_time_mut()
+        .set_from_string("2024-06-15T12:00:00[Europe/Test]")
+        .unwrap();
+
+    // a zoned date-time that requires a fixed offset rejects named zones
+    assert_eq!(
+        offset.zoned_date_time_mut().set_in_time_zone(
+            (2024, 6, 15),
+            (12, 0, 0, 0, 0, 0),
+            &time_zone,
+            Disambiguation::Compatible
+        ),
+        mismatch("Named", "Offset")
+    );
+    assert_eq!(
+        offset
+            .zoned_date_time_mut()
+            .set_from_string("2024-06-15T12:00:00[Europe/Test]"),
+        mismatch("Named", "Offset")
+    );
+    assert_eq!(
+        offset
+            .zoned_date_time_mut()
+            .convert_to_time_zone(&time_zone),
+        mismatch("Named", "Offset")
+    );
+    assert!(
+        offset
+            .zoned_date_time_mut()
+            .set_equal_to(named.zoned_date_time())
+            .is_err()
+    );
+    offset
+        .zoned_date_time_mut()
+        .set_from_string("2024-06-15T12:00:00+02:00")
+        .unwrap();
+
+    // a zoned date-time without a required zone type accepts both
+    let spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Zoned)
+        .build();
+    let mut any = Variable::new(&spec);
+    any.zoned_date_time_mut()
+        .set_equal_to(named.zoned_date_time())
+        .unwrap();
+    assert_eq!(any.to_string(), "2024-06-15T12:00:00+02:00[Europe/Test]");
+    any.zoned_date_time_mut()
+        .set_equal_to(offset.zoned_date_time())
+        .unwrap();
+    assert_eq!(any.to_string(), "2024-06-15T12:00:00+02:00");
+    TimeZone::set_directory(None);
+}
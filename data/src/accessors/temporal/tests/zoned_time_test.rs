@@ -0,0 +1,193 @@
+use crate::{
+    accessors::temporal::{time::TimeError, zoned_time::ZonedTimeError},
+    data_spec_builders::time_spec_builder::TimeSpecBuilder,
+    primitive_specs::time_spec::TimeType,
+    set_equal_to::SetEqualTo,
+    variable::Variable,
+};
+
+#[test]
+fn default_zoned_time() {
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let var = Variable::new(&spec);
+    assert_eq!(
+        var.zoned_time().zoned_time().unwrap(),
+        ((0, 0, 0, 0, 0, 0), 0)
+    );
+    assert_eq!(var.to_string(), "00:00:00Z");
+}
+
+#[test]
+fn set_zoned_time() {
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_time = var.zoned_time_mut();
+    zoned_time
+        .set_zoned_time((23, 59, 58, 1, 2, 3), -3_600)
+        .unwrap();
+    assert_eq!(
+        zoned_time.zoned_time().unwrap(),
+        ((23, 59, 58, 1, 2, 3), -3_600)
+    );
+    assert_eq!(zoned_time.zone().hours().unwrap(), -1);
+
+    zoned_time.set_offset_seconds(19_800).unwrap();
+    assert_eq!(zoned_time.to_string(), "23:59:58.001002003+05:30");
+}
+
+#[test]
+fn set_zoned_time_is_unchanged_on_error() {
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_time = var.zoned_time_mut();
+    zoned_time.set_from_string("12:00:00+01:00").unwrap();
+
+    assert_eq!(
+        zoned_time.set_zoned_time((12, 0, 0, 0, 0, 0), -64_801),
+        Err(ZonedTimeError::OffsetOutOfBounds(-64_801))
+    );
+    assert_eq!(
+        zoned_time.set_zoned_time((12, 60, 0, 0, 0, 0), 0),
+        Err(ZonedTimeError::TimeError(TimeError::MinuteOutOfBounds(60)))
+    );
+    assert_eq!(
+        zoned_time.set_offset_seconds(64_801),
+        Err(ZonedTimeError::OffsetOutOfBounds(64_801))
+    );
+    assert_eq!(zoned_time.to_string(), "12:00:00+01:00");
+}
+
+#[test]
+fn zoned_time_to_utc_and_convert_to_offset() {
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    let zoned_time = var.zoned_time_mut();
+    zoned_time.set_from_string("10:00:00.5+02:00").unwrap();
+    zoned_time.to_utc().unwrap();
+    assert_eq!(zoned_time.to_string(), "08:00:00.5Z");
+
+    zoned_time.convert_to_offset(-36_000).unwrap();
+    assert_eq!(zoned_time.to_string(), "22:00:00.5-10:00");
+
+    zoned_time.convert_to_offset(50_400).unwrap();
+    assert_eq!(zoned_time.to_string(), "22:00:00.5+14:00");
+
+    assert_eq!(
+        zoned_time.convert_to_offset(70_000),
+        Err(ZonedTimeError::OffsetOutOfBounds(70_000))
+    );
+    assert_eq!(zoned_time.to_string(), "22:00:00.5+14:00");
+}
+
+#[test]
+fn zoned_time_display_and_debug() {
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.zoned_time_mut()
+        .set_from_string("12:34:56-00:19:32")
+        .unwrap();
+    assert_eq!(var.zoned_time().to_string(), "12:34:56-00:19:32");
+    assert_eq!(var.to_string(), "12:34:56-00:19:32");
+    assert_eq!(
+        format!("{:?}", var.zoned_time()),
+        "ZonedTime { time: Time { hour: 12, minute: 34, second: 56, millisecond: 000, microsecond: 000, nanosecond: 000 }, offset: -00:19:32 }"
+    );
+}
+
+#[test]
+fn zoned_time_set_equal_to() {
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    var1.zoned_time_mut()
+        .set_from_string("12:34:56+02:00")
+        .unwrap();
+
+    // test set equal to via accessor
+    let mut var2 = Variable::new(&spec);
+    var2.zoned_time_mut()
+        .set_equal_to(var1.zoned_time())
+        .unwrap();
+    assert_eq!(var2.to_string(), "12:34:56+02:00");
+
+    // test set equal to via variable
+    let mut var3 = Variable::new(&spec);
+    var3.set_equal_to(&var1).unwrap();
+    assert_eq!(var3.to_string(), "12:34:56+02:00");
+
+    // test clone
+    let var4 = var1.clone();
+    assert_eq!(var4.to_string(), "12:34:56+02:00");
+    assert_eq!(var4, var1);
+}
+
+#[test]
+fn zoned_time_partial_eq_and_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    let mut var2 = Variable::new(&spec);
+    let mut var3 = Variable::new(&spec);
+    let mut var4 = Variable::new(&spec);
+    var1.zoned_time_mut()
+        .set_from_string("10:00:00+02:00")
+        .unwrap();
+    var2.zoned_time_mut().set_from_string("08:00:00Z").unwrap();
+    var3.zoned_time_mut().set_from_string("10:00:00Z").unwrap();
+    var4.zoned_time_mut()
+        .set_from_string("23:00:00-09:00")
+        .unwrap();
+
+    assert_eq!(var1.zoned_time(), var2.zoned_time());
+    assert_ne!(var1.zoned_time(), var3.zoned_time());
+    assert_eq!(var1, var2);
+    assert_ne!(var1, var3);
+    // 23:00-09:00 is 08:00Z on the next day
+    assert_eq!(var4, var2);
+
+    let hash = |var: &Variable| {
+        let mut hasher = DefaultHasher::new();
+        var.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&var1), hash(&var2));
+    assert_eq!(hash(&var1), hash(&var4));
+    assert_ne!(hash(&var1), hash(&var3));
+}
+
+#[test]
+fn zoned_time_ord() {
+    let spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Zoned)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    let mut var2 = Variable::new(&spec);
+    let mut var3 = Variable::new(&spec);
+    var1.zoned_time_mut()
+        .set_from_string("12:00:00+05:00")
+        .unwrap();
+    var2.zoned_time_mut().set_from_string("08:00:00Z").unwrap();
+    var3.zoned_time_mut()
+        .set_from_string("05:00:00-04:00")
+        .unwrap();
+
+    assert!(var1.zoned_time() < var2.zoned_time());
+    assert!(var2.zoned_time() < var3.zoned_time());
+    assert!(var1 < var3);
+    assert!(var2 <= var2.clone());
+}
@@ -4,9 +4,18 @@ use std::{
 };
 
 use crate::{
+    accessors::temporal::{
+        NANOSECONDS_PER_DAY,
+        clock::Clock,
+        day_to_second_duration::{DayToSecondDuration, DayToSecondDurationError},
+        format_pattern::{FormatError, FormatPattern, PatternFields},
+    },
     adaptors::temporal_adaptors::time_adaptor::TimeAdaptor,
     primitive_def::Accessor,
-    primitive_specs::time_spec::{TimeResolution, TimeSpec},
+    primitive_specs::{
+        rounding_policy::RoundingPolicy,
+        time_spec::{TimeResolution, TimeSpec},
+    },
     provider_error::ProviderError,
     set_equal_to::{SetEqualTo, SetEqualToError},
 };
@@ -33,6 +42,8 @@ impl Time {
     }
 
     /// Sets the time to the specified hours, minutes, seconds, milliseconds, and microseconds.
+    /// A time finer than the resolution of the time's spec is handled with the spec's rounding
+    /// policy.
     pub fn set_time(
         &mut self,
         hour: u8,
@@ -41,6 +52,54 @@ impl Time {
         millisecond: u16,
         microsecond: u16,
         nanosecond: u16,
+    ) -> Result<(), TimeError> {
+        self.set_time_with_rounding(
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+            *self.spec().rounding_policy(),
+        )
+    }
+
+    /// Sets the time to the specified hours, minutes, seconds, milliseconds, and microseconds,
+    /// handling a time finer than the resolution of the time's spec with the given rounding
+    /// policy instead of the spec's. A time that rounds up to midnight of the next day is
+    /// rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::time_spec_builder::TimeSpecBuilder;
+    /// use data::primitive_specs::rounding_policy::RoundingPolicy;
+    /// use data::primitive_specs::time_spec::{TimeResolution, TimeType};
+    /// use data::variable::Variable;
+    ///
+    /// let spec = TimeSpecBuilder::new()
+    ///     .set_time_type(TimeType::Local)
+    ///     .set_resolution(TimeResolution::Millisecond)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    /// let time_mut = var.time_mut();
+    ///
+    /// assert!(time_mut.set_time(12, 0, 0, 0, 999, 999).is_err());
+    /// time_mut
+    ///     .set_time_with_rounding(12, 0, 0, 0, 999, 999, RoundingPolicy::RoundHalfUp)
+    ///     .unwrap();
+    /// assert_eq!(time_mut.time().unwrap(), (12, 0, 0, 1, 0, 0));
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_time_with_rounding(
+        &mut self,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        millisecond: u16,
+        microsecond: u16,
+        nanosecond: u16,
+        rounding_policy: RoundingPolicy,
     ) -> Result<(), TimeError> {
         if hour >= 24 {
             return Err(TimeError::HourOutOfBounds(hour));
@@ -60,6 +119,34 @@ impl Time {
         if nanosecond >= 1_000 {
             return Err(TimeError::NanosecondOutOfBounds(nanosecond));
         }
+        if let Some(resolution) = self.spec().resolution()
+            && rounding_policy != RoundingPolicy::Reject
+        {
+            let nanoseconds = ((hour as u64 * 60 + minute as u64) * 60 + second as u64)
+                * 1_000_000_000
+                + millisecond as u64 * 1_000_000
+                + microsecond as u64 * 1_000
+                + nanosecond as u64;
+            let rounded = rounding_policy
+                .round(nanoseconds as i128, resolution.nanoseconds() as i128)
+                .unwrap_or(nanoseconds as i128);
+            if rounded >= NANOSECONDS_PER_DAY {
+                return Err(TimeError::ResolutionOutOfBounds(format!(
+                    "Cannot round time {:02}:{:02}:{:02}.{:03}{:03}{:03} to {} resolution without passing midnight.",
+                    hour, minute, second, millisecond, microsecond, nanosecond, *resolution
+                )));
+            }
+            let (hour, minute, second, millisecond, microsecond, nanosecond) =
+                time_from_nanoseconds_of_day(rounded as u64);
+            return self.adaptor.set_time(
+                hour,
+                minute,
+                second,
+                millisecond,
+                microsecond,
+                nanosecond,
+            );
+        }
         if let Some(resolution) = self.spec().resolution() {
             if millisecond > 0 && *resolution < TimeResolution::Millisecond {
                 return Err(TimeError::ResolutionOutOfBounds(format!(
@@ -110,6 +197,33 @@ impl Time {
         )
     }
 
+    /// Sets the time to the current local time of the given clock, truncated to the resolution of
+    /// the time's spec, so that a time with `Second` resolution is set to the current whole
+    /// second.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::accessors::temporal::clock::FixedClock;
+    /// use data::data_spec_builders::time_spec_builder::TimeSpecBuilder;
+    /// use data::primitive_specs::time_spec::{TimeResolution, TimeType};
+    /// use data::variable::Variable;
+    ///
+    /// let spec = TimeSpecBuilder::new()
+    ///     .set_time_type(TimeType::Local)
+    ///     .set_resolution(TimeResolution::Millisecond)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    ///
+    /// // 2024-06-15T12:34:56.789012345Z
+    /// let clock = FixedClock::new(1_718_454_896_789_012_345);
+    /// var.time_mut().set_to_now(&clock).unwrap();
+    /// assert_eq!(var.to_string(), "12:34:56.789");
+    /// ```
+    pub fn set_to_now(&mut self, clock: &dyn Clock) -> Result<(), TimeError> {
+        self.adaptor.set_to_now(clock)
+    }
+
     /// Sets the time from a string in the in an ISO 8601 format "HH:MM:SS.mmmuuuNNN".
     pub fn set_from_string(&mut self, time_str: &str) -> Result<(), TimeError> {
         crate::accessors::temporal::set_from_strings::set_time_from_string::set_time_from_string(
@@ -117,6 +231,27 @@ impl Time {
         )
     }
 
+    /// Sets the time from a string in the layout of the given format pattern, which must contain
+    /// the hour and minute; the second and its fraction are zero unless the pattern has them.
+    /// The time is left unchanged if the string does not match the pattern.
+    pub fn set_from_format(
+        &mut self,
+        time_str: &str,
+        pattern: &FormatPattern,
+    ) -> Result<(), FormatError> {
+        self.set_via_tuple(pattern.parse(time_str)?.time()?)?;
+        Ok(())
+    }
+
+    /// Formats the time with the given format pattern, which may not contain date or offset
+    /// tokens.
+    pub fn format(&self, pattern: &FormatPattern) -> Result<String, FormatError> {
+        pattern.format(&PatternFields {
+            time: Some(self.time()?),
+            ..Default::default()
+        })
+    }
+
     /// Returns the current time as a tuple of (hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
     pub fn time(&self) -> Result<(u8, u8, u8, u16, u16, u16), TimeError> {
         self.adaptor.time()
@@ -151,6 +286,91 @@ impl Time {
     pub fn nanosecond(&self) -> Result<u16, TimeError> {
         self.adaptor.nanosecond()
     }
+
+    /// Returns the number of nanoseconds since midnight.
+    pub(crate) fn nanoseconds_of_day(&self) -> Result<u64, TimeError> {
+        if self.adaptor.can_return_time_as_nanos() {
+            return self.adaptor.nanos();
+        }
+        let (hours, minutes, seconds, milliseconds, microseconds, nanoseconds) = self.time()?;
+        Ok(
+            ((hours as u64 * 60 + minutes as u64) * 60 + seconds as u64) * 1_000_000_000
+                + milliseconds as u64 * 1_000_000
+                + microseconds as u64 * 1_000
+                + nanoseconds as u64,
+        )
+    }
+
+    /// Sets the time to the given number of nanoseconds since midnight.
+    pub(crate) fn set_nanoseconds_of_day(&mut self, nanoseconds: u64) -> Result<(), TimeError> {
+        self.set_via_tuple(time_from_nanoseconds_of_day(nanoseconds))
+    }
+
+    /// Adds the day-to-second duration to the time, wrapping around midnight, and returns the
+    /// number of days that the time moved forward, or backward if negative, when it wrapped.
+    /// The time is left unchanged if the result is finer than the time's resolution.
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::duration_spec_builder::DurationSpecBuilder;
+    /// use data::data_spec_builders::time_spec_builder::TimeSpecBuilder;
+    /// use data::primitive_specs::duration_spec::DurationType;
+    /// use data::primitive_specs::time_spec::TimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let mut time = Variable::new(&TimeSpecBuilder::new().set_time_type(TimeType::Local).build());
+    /// let mut duration = Variable::new(
+    ///     &DurationSpecBuilder::new()
+    ///         .set_type(DurationType::DayToSecond)
+    ///         .build()
+    ///         .unwrap(),
+    /// );
+    /// time.time_mut().set_time(22, 30, 0, 0, 0, 0).unwrap();
+    /// duration.day_to_second_duration_mut().set_duration(0, 3, 0, 0, 0, 0, 0).unwrap();
+    ///
+    /// let days = time.time_mut()
+    ///     .add_day_to_second_duration(duration.day_to_second_duration())
+    ///     .unwrap();
+    /// assert_eq!(days, 1);
+    /// assert_eq!(time.time().time().unwrap(), (1, 30, 0, 0, 0, 0));
+    /// ```
+    pub fn add_day_to_second_duration(
+        &mut self,
+        duration: &DayToSecondDuration,
+    ) -> Result<i64, TimeError> {
+        self.add_nanoseconds(duration.total_nanoseconds()?)
+    }
+
+    /// Subtracts the day-to-second duration from the time, wrapping around midnight, and returns
+    /// the number of days that the time moved forward, or backward if negative, when it wrapped.
+    /// The time is left unchanged if the result is finer than the time's resolution.
+    pub fn subtract_day_to_second_duration(
+        &mut self,
+        duration: &DayToSecondDuration,
+    ) -> Result<i64, TimeError> {
+        self.add_nanoseconds(-duration.total_nanoseconds()?)
+    }
+
+    /// Adds the given number of nanoseconds to the time, returning the number of wrapped days.
+    fn add_nanoseconds(&mut self, nanoseconds: i128) -> Result<i64, TimeError> {
+        let nanoseconds = self.nanoseconds_of_day()? as i128 + nanoseconds;
+        self.set_nanoseconds_of_day(nanoseconds.rem_euclid(NANOSECONDS_PER_DAY) as u64)?;
+        Ok(nanoseconds.div_euclid(NANOSECONDS_PER_DAY) as i64)
+    }
+}
+
+/// Returns the time of day as a tuple of (hours, minutes, seconds, milliseconds, microseconds,
+/// nanoseconds) of the given number of nanoseconds since midnight.
+pub(crate) fn time_from_nanoseconds_of_day(nanoseconds: u64) -> (u8, u8, u8, u16, u16, u16) {
+    let seconds = nanoseconds / 1_000_000_000;
+    let fraction = nanoseconds % 1_000_000_000;
+    (
+        (seconds / 3_600) as u8,
+        (seconds / 60 % 60) as u8,
+        (seconds % 60) as u8,
+        (fraction / 1_000_000) as u16,
+        (fraction / 1_000 % 1_000) as u16,
+        (fraction % 1_000) as u16,
+    )
 }
 
 impl Accessor for Time {}
@@ -277,6 +497,8 @@ pub enum TimeError {
     ResolutionOutOfBounds(String),
     /// Indicates an invalid format error.
     InvalidFormat(String),
+    /// An error of a day-to-second duration used in time arithmetic.
+    DayToSecondDurationError(DayToSecondDurationError),
 }
 
 impl Display for TimeError {
@@ -303,6 +525,9 @@ impl Display for TimeError {
             }
             TimeError::ResolutionOutOfBounds(msg) => write!(f, "Resolution out of bounds: {}", msg),
             TimeError::InvalidFormat(msg) => write!(f, "Invalid format: {}", msg),
+            TimeError::DayToSecondDurationError(err) => {
+                write!(f, "Day-to-second duration error: {}", err)
+            }
         }
     }
 }
@@ -311,11 +536,18 @@ impl std::error::Error for TimeError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             TimeError::ProviderError(err) => Some(err),
+            TimeError::DayToSecondDurationError(err) => Some(err),
             _ => None,
         }
     }
 }
 
+impl From<DayToSecondDurationError> for TimeError {
+    fn from(error: DayToSecondDurationError) -> Self {
+        TimeError::DayToSecondDurationError(error)
+    }
+}
+
 impl From<TimeError> for SetEqualToError {
     fn from(error: TimeError) -> Self {
         SetEqualToError::TimeError(error)
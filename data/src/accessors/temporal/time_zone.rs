@@ -0,0 +1,759 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{Debug, Display},
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+/// The directory from which time zones are loaded when neither a directory has been set for the
+/// current thread nor the `TZDIR` environment variable is set.
+pub const DEFAULT_TIME_ZONE_DIRECTORY: &str = "/usr/share/zoneinfo";
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+thread_local! {
+    static DIRECTORY: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+    static CACHE: RefCell<HashMap<(PathBuf, String), Rc<TimeZone>>> = RefCell::new(HashMap::new());
+}
+
+/// A named time zone of the IANA time zone database, such as `Europe/Berlin`.
+/// <p>
+/// Time zones are read from TZif files (RFC 8536), versions 1 to 4. Instants after the last
+/// transition of the file are resolved with the POSIX TZ rule of the file's footer, so that
+/// daylight saving time keeps being observed for future dates. Leap seconds are ignored.
+/// </p>
+pub struct TimeZone {
+    name: String,
+    transitions: Vec<i64>,
+    transition_types: Vec<usize>,
+    local_time_types: Vec<LocalTimeType>,
+    rule: Option<PosixRule>,
+}
+
+impl TimeZone {
+    /// Loads the named time zone from the time zone directory.
+    /// <p>
+    /// The directory is the one set with [`TimeZone::set_directory`] for the current thread, if any,
+    /// else the one named by the `TZDIR` environment variable, if set, else
+    /// [`DEFAULT_TIME_ZONE_DIRECTORY`]. Loaded time zones are cached per thread.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use data::accessors::temporal::time_zone::TimeZone;
+    ///
+    /// let berlin = TimeZone::load("Europe/Berlin").unwrap();
+    /// // 2024-07-01T00:00:00Z falls in central European summer time.
+    /// let local_time_type = berlin.local_time_type(1_719_792_000);
+    /// assert_eq!(local_time_type.offset_seconds(), 7_200);
+    /// assert_eq!(local_time_type.abbreviation(), "CEST");
+    /// ```
+    pub fn load(name: &str) -> Result<Rc<TimeZone>, TimeZoneError> {
+        Self::load_from_directory(Self::directory(), name)
+    }
+
+    /// Loads the named time zone from the given directory.
+    pub fn load_from_directory(
+        directory: impl AsRef<Path>,
+        name: &str,
+    ) -> Result<Rc<TimeZone>, TimeZoneError> {
+        check_name(name)?;
+        let key = (directory.as_ref().to_path_buf(), name.to_string());
+        if let Some(time_zone) = CACHE.with(|cache| cache.borrow().get(&key).cloned()) {
+            return Ok(time_zone);
+        }
+        let path = directory.as_ref().join(name);
+        let data = std::fs::read(&path).map_err(|error| match error.kind() {
+            std::io::ErrorKind::NotFound => TimeZoneError::NotFound(name.to_string()),
+            _ => TimeZoneError::Io(format!("Cannot read '{}': {}", path.display(), error)),
+        })?;
+        let time_zone = Rc::new(Self::from_tzif(name, &data)?);
+        CACHE.with(|cache| cache.borrow_mut().insert(key, time_zone.clone()));
+        Ok(time_zone)
+    }
+
+    /// Sets the directory from which [`TimeZone::load`] loads time zones on the current thread,
+    /// or restores the default directory if `None`.
+    pub fn set_directory(directory: Option<PathBuf>) {
+        DIRECTORY.with(|current| *current.borrow_mut() = directory);
+    }
+
+    /// Returns the directory from which [`TimeZone::load`] loads time zones on the current thread.
+    pub fn directory() -> PathBuf {
+        DIRECTORY
+            .with(|current| current.borrow().clone())
+            .or_else(|| std::env::var_os("TZDIR").map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_TIME_ZONE_DIRECTORY))
+    }
+
+    /// Creates a time zone with the given name from the contents of a TZif file.
+    pub fn from_tzif(name: &str, data: &[u8]) -> Result<TimeZone, TimeZoneError> {
+        let mut reader = Reader { data, position: 0 };
+        let mut header = reader.header()?;
+        let mut time_size = 4;
+        if header.version >= b'2' {
+            reader.skip(header.block_length(time_size))?;
+            header = reader.header()?;
+            time_size = 8;
+        }
+
+        reader.check_records(header.transition_count, time_size + 1)?;
+        reader.check_records(header.type_count, 6)?;
+        let mut transitions = Vec::with_capacity(header.transition_count);
+        for _ in 0..header.transition_count {
+            transitions.push(reader.time(time_size)?);
+        }
+        if transitions.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(invalid_data("transition times are not in ascending order"));
+        }
+        let mut transition_types = Vec::with_capacity(header.transition_count);
+        for _ in 0..header.transition_count {
+            let index = reader.take(1)?[0] as usize;
+            if index >= header.type_count {
+                return Err(invalid_data(
+                    "a transition refers to an unknown local time type",
+                ));
+            }
+            transition_types.push(index);
+        }
+        let mut types = Vec::with_capacity(header.type_count);
+        for _ in 0..header.type_count {
+            let offset_seconds = reader.time(4)?;
+            let is_dst = reader.take(1)?[0] != 0;
+            let abbreviation_index = reader.take(1)?[0] as usize;
+            if offset_seconds == i32::MIN as i64 {
+                return Err(invalid_data("a local time type has an invalid offset"));
+            }
+            types.push((offset_seconds as i32, is_dst, abbreviation_index));
+        }
+        let abbreviations = reader.take(header.abbreviation_length)?;
+        reader.skip(
+            header.leap_second_count * (time_size + 4)
+                + header.standard_indicator_count
+                + header.ut_indicator_count,
+        )?;
+
+        let mut local_time_types = Vec::with_capacity(types.len());
+        for (offset_seconds, is_dst, abbreviation_index) in types {
+            let Some(rest) = abbreviations.get(abbreviation_index..) else {
+                return Err(invalid_data(
+                    "a local time type has an invalid abbreviation",
+                ));
+            };
+            let end = rest.iter().position(|&b| b == 0).unwrap_or(rest.len());
+            local_time_types.push(LocalTimeType {
+                offset_seconds,
+                is_dst,
+                abbreviation: String::from_utf8_lossy(&rest[..end]).into_owned(),
+            });
+        }
+
+        let mut rule = None;
+        if header.version >= b'2' {
+            let footer = reader.footer()?;
+            if !footer.is_empty() {
+                rule = Some(PosixRule::parse(footer).ok_or_else(|| {
+                    invalid_data(&format!("the footer '{}' is not a valid TZ string", footer))
+                })?);
+            }
+        }
+
+        Ok(TimeZone {
+            name: name.to_string(),
+            transitions,
+            transition_types,
+            local_time_types,
+            rule,
+        })
+    }
+
+    /// Returns the time zone's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the local time type in effect at the given instant, in seconds from
+    /// 1970-01-01T00:00:00Z.
+    pub fn local_time_type(&self, unix_seconds: i64) -> LocalTimeType {
+        let count = self.transitions.partition_point(|&t| t <= unix_seconds);
+        if count == self.transitions.len()
+            && let Some(rule) = &self.rule
+        {
+            return rule.local_time_type(unix_seconds).clone();
+        }
+        match count {
+            0 => self.local_time_types[0].clone(),
+            _ => self.local_time_types[self.transition_types[count - 1]].clone(),
+        }
+    }
+
+    /// Resolves a local date-time, in seconds from 1970-01-01T00:00:00 local time, to the local
+    /// time types that can be in effect at it.
+    pub fn resolve_local(&self, local_seconds: i64) -> LocalTimeResolution {
+        // Offsets never change more than once within a day, so the local time types in effect a day
+        // before and a day after are the only candidates.
+        let before = self.local_time_type(local_seconds - SECONDS_PER_DAY);
+        let after = self.local_time_type(local_seconds + SECONDS_PER_DAY);
+        let valid = |candidate: &LocalTimeType| {
+            let actual = self.local_time_type(local_seconds - candidate.offset_seconds as i64);
+            (actual.offset_seconds == candidate.offset_seconds).then_some(actual)
+        };
+        match (valid(&before), valid(&after)) {
+            (Some(earlier), Some(later)) if earlier.offset_seconds != later.offset_seconds => {
+                LocalTimeResolution::Ambiguous(earlier, later)
+            }
+            (Some(local_time_type), _) | (None, Some(local_time_type)) => {
+                LocalTimeResolution::Unique(local_time_type)
+            }
+            (None, None) => LocalTimeResolution::Skipped(before, after),
+        }
+    }
+
+    /// Returns the instant, in seconds from 1970-01-01T00:00:00Z, at which the local date-time,
+    /// in seconds from 1970-01-01T00:00:00 local time, occurs, applying the given disambiguation
+    /// if the local date-time is ambiguous or skipped.
+    pub fn to_unix_seconds(
+        &self,
+        local_seconds: i64,
+        disambiguation: Disambiguation,
+    ) -> Result<i64, TimeZoneError> {
+        let offset = match (self.resolve_local(local_seconds), disambiguation) {
+            (LocalTimeResolution::Unique(local_time_type), _) => local_time_type.offset_seconds,
+            (
+                LocalTimeResolution::Ambiguous(earlier, _),
+                Disambiguation::Compatible | Disambiguation::Earlier,
+            ) => earlier.offset_seconds,
+            (LocalTimeResolution::Ambiguous(_, later), Disambiguation::Later) => {
+                later.offset_seconds
+            }
+            (
+                LocalTimeResolution::Skipped(before, _),
+                Disambiguation::Compatible | Disambiguation::Later,
+            ) => before.offset_seconds,
+            (LocalTimeResolution::Skipped(_, after), Disambiguation::Earlier) => {
+                after.offset_seconds
+            }
+            (LocalTimeResolution::Ambiguous(..), Disambiguation::Reject) => {
+                return Err(TimeZoneError::AmbiguousLocalTime(format!(
+                    "The local time occurs twice in time zone '{}'.",
+                    self.name
+                )));
+            }
+            (LocalTimeResolution::Skipped(..), Disambiguation::Reject) => {
+                return Err(TimeZoneError::SkippedLocalTime(format!(
+                    "The local time does not occur in time zone '{}'.",
+                    self.name
+                )));
+            }
+        };
+        Ok(local_seconds - offset as i64)
+    }
+}
+
+impl PartialEq for TimeZone {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Display for TimeZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Debug for TimeZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TimeZone {{ name: {} }}", self.name)
+    }
+}
+
+/// A local time type of a time zone: an offset from UTC, whether it is daylight saving time
+/// and its abbreviation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalTimeType {
+    offset_seconds: i32,
+    is_dst: bool,
+    abbreviation: String,
+}
+
+impl LocalTimeType {
+    /// Returns the offset from UTC in seconds.
+    pub fn offset_seconds(&self) -> i32 {
+        self.offset_seconds
+    }
+
+    /// Returns true if the local time type is daylight saving time; false otherwise.
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    /// Returns the local time type's abbreviation, such as `CEST`.
+    pub fn abbreviation(&self) -> &str {
+        &self.abbreviation
+    }
+}
+
+/// The local time types that can be in effect at a local date-time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LocalTimeResolution {
+    /// The local date-time occurs once, with the given local time type.
+    Unique(LocalTimeType),
+    /// The local date-time occurs twice, as clocks are set back: first with the earlier local
+    /// time type and then with the later one.
+    Ambiguous(LocalTimeType, LocalTimeType),
+    /// The local date-time does not occur, as clocks are set forward: the local time types are
+    /// those in effect before and after the gap.
+    Skipped(LocalTimeType, LocalTimeType),
+}
+
+/// Disambiguation defines how a local date-time that occurs twice or not at all in a time zone
+/// is resolved to an instant.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Disambiguation {
+    /// The earlier instant of an ambiguous local time; a skipped local time is moved forward by
+    /// the length of the gap.
+    #[default]
+    Compatible,
+    /// The earlier of the two possible instants.
+    Earlier,
+    /// The later of the two possible instants.
+    Later,
+    /// Ambiguous and skipped local times are rejected with an error.
+    Reject,
+}
+
+/// Checks that a time zone name is a relative path of name components.
+fn check_name(name: &str) -> Result<(), TimeZoneError> {
+    let is_valid = !name.is_empty()
+        && name.split('/').all(|component| {
+            !component.is_empty()
+                && !component.starts_with('.')
+                && component
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+' | '.'))
+        });
+    if !is_valid {
+        return Err(TimeZoneError::InvalidName(name.to_string()));
+    }
+    Ok(())
+}
+
+fn invalid_data(message: &str) -> TimeZoneError {
+    TimeZoneError::InvalidData(message.to_string())
+}
+
+/// The counts of a TZif header.
+struct Header {
+    version: u8,
+    ut_indicator_count: usize,
+    standard_indicator_count: usize,
+    leap_second_count: usize,
+    transition_count: usize,
+    type_count: usize,
+    abbreviation_length: usize,
+}
+
+impl Header {
+    /// Returns the length of the data block that follows the header.
+    fn block_length(&self, time_size: usize) -> usize {
+        self.transition_count * (time_size + 1)
+            + self.type_count * 6
+            + self.abbreviation_length
+            + self.leap_second_count * (time_size + 4)
+            + self.standard_indicator_count
+            + self.ut_indicator_count
+    }
+}
+
+/// A reader of the big-endian contents of a TZif file.
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], TimeZoneError> {
+        let bytes = self
+            .position
+            .checked_add(length)
+            .and_then(|end| self.data.get(self.position..end))
+            .ok_or_else(|| invalid_data("the data is truncated"))?;
+        self.position += length;
+        Ok(bytes)
+    }
+
+    /// Checks that the rest of the data can hold the given number of records of the given size, so
+    /// that space is reserved for them only after they are known to be present.
+    fn check_records(&self, count: usize, record_size: usize) -> Result<(), TimeZoneError> {
+        match count.checked_mul(record_size) {
+            Some(length) if length <= self.data.len() - self.position => Ok(()),
+            _ => Err(invalid_data("the data is truncated")),
+        }
+    }
+
+    fn skip(&mut self, length: usize) -> Result<(), TimeZoneError> {
+        self.take(length).map(|_| ())
+    }
+
+    fn count(&mut self) -> Result<usize, TimeZoneError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+    }
+
+    fn time(&mut self, size: usize) -> Result<i64, TimeZoneError> {
+        let bytes = self.take(size)?;
+        Ok(match size {
+            4 => i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as i64,
+            _ => i64::from_be_bytes(bytes.try_into().unwrap()),
+        })
+    }
+
+    fn header(&mut self) -> Result<Header, TimeZoneError> {
+        if self.take(4)? != b"TZif" {
+            return Err(invalid_data("the data does not start with 'TZif'"));
+        }
+        let version = self.take(1)?[0];
+        if !matches!(version, 0 | b'2' | b'3' | b'4') {
+            return Err(invalid_data("the version is not supported"));
+        }
+        self.skip(15)?;
+        let header = Header {
+            version,
+            ut_indicator_count: self.count()?,
+            standard_indicator_count: self.count()?,
+            leap_second_count: self.count()?,
+            transition_count: self.count()?,
+            type_count: self.count()?,
+            abbreviation_length: self.count()?,
+        };
+        if header.type_count == 0 {
+            return Err(invalid_data("there are no local time types"));
+        }
+        Ok(header)
+    }
+
+    fn footer(&mut self) -> Result<&'a str, TimeZoneError> {
+        let footer = self.data[self.position..]
+            .strip_prefix(b"\n")
+            .and_then(|rest| Some(&rest[..rest.iter().position(|&b| b == b'\n')?]))
+            .ok_or_else(|| invalid_data("the footer is missing"))?;
+        std::str::from_utf8(footer).map_err(|_| invalid_data("the footer is not valid UTF-8"))
+    }
+}
+
+/// A POSIX TZ rule, such as `CET-1CEST,M3.5.0,M10.5.0/3`.
+struct PosixRule {
+    standard: LocalTimeType,
+    daylight: Option<DaylightRule>,
+}
+
+/// The daylight saving time part of a POSIX TZ rule.
+struct DaylightRule {
+    local_time_type: LocalTimeType,
+    start: (RuleDate, i64),
+    end: (RuleDate, i64),
+}
+
+/// The day of a year on which a daylight saving time transition occurs.
+enum RuleDate {
+    /// Day 1 to 365 of the year, where February 29 is never counted (Jn).
+    JulianWithoutLeapDay(i64),
+    /// Day 0 to 365 of the year, where February 29 is counted (n).
+    Julian(i64),
+    /// Day d (0 is Sunday) of week w (5 is the last) of month m (Mm.w.d).
+    MonthWeekDay(i64, i64, i64),
+}
+
+impl PosixRule {
+    fn parse(rule: &str) -> Option<PosixRule> {
+        let mut parser = RuleParser {
+            rule: rule.as_bytes(),
+            position: 0,
+        };
+        let standard_abbreviation = parser.abbreviation()?;
+        let standard_offset = -parser.time(24)?;
+        let standard = LocalTimeType {
+            offset_seconds: standard_offset as i32,
+            is_dst: false,
+            abbreviation: standard_abbreviation,
+        };
+        if parser.is_done() {
+            return Some(PosixRule {
+                standard,
+                daylight: None,
+            });
+        }
+        let daylight_abbreviation = parser.abbreviation()?;
+        let daylight_offset = if parser.is_done() || parser.peek() == Some(b',') {
+            standard_offset + 3_600
+        } else {
+            -parser.time(24)?
+        };
+        let (start, end) = if parser.is_done() {
+            // the rules of the United States are the conventional default
+            (
+                (RuleDate::MonthWeekDay(3, 2, 0), 7_200),
+                (RuleDate::MonthWeekDay(11, 1, 0), 7_200),
+            )
+        } else {
+            parser.expect(b',')?;
+            let start = parser.transition()?;
+            parser.expect(b',')?;
+            (start, parser.transition()?)
+        };
+        if !parser.is_done() {
+            return None;
+        }
+        Some(PosixRule {
+            standard,
+            daylight: Some(DaylightRule {
+                local_time_type: LocalTimeType {
+                    offset_seconds: daylight_offset as i32,
+                    is_dst: true,
+                    abbreviation: daylight_abbreviation,
+                },
+                start,
+                end,
+            }),
+        })
+    }
+
+    fn local_time_type(&self, unix_seconds: i64) -> &LocalTimeType {
+        let Some(daylight) = &self.daylight else {
+            return &self.standard;
+        };
+        let standard_offset = self.standard.offset_seconds as i64;
+        let daylight_offset = daylight.local_time_type.offset_seconds as i64;
+        let (year, _, _) =
+            civil_from_days((unix_seconds + standard_offset).div_euclid(SECONDS_PER_DAY));
+        let start =
+            daylight.start.0.day(year) * SECONDS_PER_DAY + daylight.start.1 - standard_offset;
+        let end = daylight.end.0.day(year) * SECONDS_PER_DAY + daylight.end.1 - daylight_offset;
+        let is_dst = if start < end {
+            start <= unix_seconds && unix_seconds < end
+        } else {
+            !(end <= unix_seconds && unix_seconds < start)
+        };
+        if is_dst {
+            &daylight.local_time_type
+        } else {
+            &self.standard
+        }
+    }
+}
+
+impl RuleDate {
+    /// Returns the day, in days from 1970-01-01, on which the rule date falls in the given year.
+    fn day(&self, year: i64) -> i64 {
+        let first_of_year = days_from_civil(year, 1, 1);
+        match *self {
+            RuleDate::JulianWithoutLeapDay(day) => {
+                let leap_day = if day >= 60 && is_leap_year(year) {
+                    1
+                } else {
+                    0
+                };
+                first_of_year + day - 1 + leap_day
+            }
+            RuleDate::Julian(day) => first_of_year + day,
+            RuleDate::MonthWeekDay(month, week, weekday) => {
+                let first = days_from_civil(year, month, 1);
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first + (weekday - first_weekday).rem_euclid(7) + (week - 1) * 7;
+                let (next_year, next_month) = if month == 12 {
+                    (year + 1, 1)
+                } else {
+                    (year, month + 1)
+                };
+                while day >= days_from_civil(next_year, next_month, 1) {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+/// A parser of the parts of a POSIX TZ rule.
+struct RuleParser<'a> {
+    rule: &'a [u8],
+    position: usize,
+}
+
+impl RuleParser<'_> {
+    fn is_done(&self) -> bool {
+        self.position >= self.rule.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.rule.get(self.position).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.peek() != Some(byte) {
+            return None;
+        }
+        self.position += 1;
+        Some(())
+    }
+
+    fn abbreviation(&mut self) -> Option<String> {
+        let start = self.position;
+        let abbreviation = if self.peek() == Some(b'<') {
+            self.position += 1;
+            while self.peek()? != b'>' {
+                self.position += 1;
+            }
+            self.position += 1;
+            &self.rule[start + 1..self.position - 1]
+        } else {
+            while self.peek().is_some_and(|b| b.is_ascii_alphabetic()) {
+                self.position += 1;
+            }
+            &self.rule[start..self.position]
+        };
+        if abbreviation.len() < 3 {
+            return None;
+        }
+        Some(String::from_utf8_lossy(abbreviation).into_owned())
+    }
+
+    fn number(&mut self, max: i64) -> Option<i64> {
+        let start = self.position;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.position += 1;
+        }
+        let number = std::str::from_utf8(&self.rule[start..self.position])
+            .ok()?
+            .parse()
+            .ok()?;
+        (number <= max).then_some(number)
+    }
+
+    /// Parses a signed time of [+-]hh[:mm[:ss]] into seconds, where hours are at most `max_hours`.
+    fn time(&mut self, max_hours: i64) -> Option<i64> {
+        let sign = match self.peek() {
+            Some(b'-') => {
+                self.position += 1;
+                -1
+            }
+            Some(b'+') => {
+                self.position += 1;
+                1
+            }
+            _ => 1,
+        };
+        let mut seconds = self.number(max_hours)? * 3_600;
+        if self.peek() == Some(b':') {
+            self.position += 1;
+            seconds += self.number(59)? * 60;
+            if self.peek() == Some(b':') {
+                self.position += 1;
+                seconds += self.number(59)?;
+            }
+        }
+        Some(sign * seconds)
+    }
+
+    fn transition(&mut self) -> Option<(RuleDate, i64)> {
+        let date = match self.peek()? {
+            b'J' => {
+                self.position += 1;
+                let day = self.number(365)?;
+                if day < 1 {
+                    return None;
+                }
+                RuleDate::JulianWithoutLeapDay(day)
+            }
+            b'M' => {
+                self.position += 1;
+                let month = self.number(12)?;
+                self.expect(b'.')?;
+                let week = self.number(5)?;
+                self.expect(b'.')?;
+                let weekday = self.number(6)?;
+                if month < 1 || week < 1 {
+                    return None;
+                }
+                RuleDate::MonthWeekDay(month, week, weekday)
+            }
+            _ => RuleDate::Julian(self.number(365)?),
+        };
+        let time = if self.peek() == Some(b'/') {
+            self.position += 1;
+            self.time(167)?
+        } else {
+            7_200
+        };
+        Some((date, time))
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0) && ((year % 100 != 0) || (year % 400 == 0))
+}
+
+/// Returns the number of days from 1970-01-01 to the given date of the proleptic Gregorian calendar.
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the (year, month, day) of the proleptic Gregorian calendar that lies the given number of
+/// days from 1970-01-01.
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// An error that can occur when working with time zones.
+#[derive(Debug, PartialEq)]
+pub enum TimeZoneError {
+    /// Indicates that a time zone name is not valid.
+    InvalidName(String),
+    /// Indicates that no time zone with the given name exists.
+    NotFound(String),
+    /// Indicates that a time zone could not be read.
+    Io(String),
+    /// Indicates that the contents of a TZif file are not valid.
+    InvalidData(String),
+    /// Indicates that a local time occurs twice in a time zone.
+    AmbiguousLocalTime(String),
+    /// Indicates that a local time does not occur in a time zone.
+    SkippedLocalTime(String),
+}
+
+impl Display for TimeZoneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeZoneError::InvalidName(name) => write!(f, "Invalid time zone name: {}", name),
+            TimeZoneError::NotFound(name) => write!(f, "Time zone not found: {}", name),
+            TimeZoneError::Io(msg) => write!(f, "I/O error: {}", msg),
+            TimeZoneError::InvalidData(msg) => write!(f, "Invalid TZif data: {}", msg),
+            TimeZoneError::AmbiguousLocalTime(msg) => write!(f, "Ambiguous local time: {}", msg),
+            TimeZoneError::SkippedLocalTime(msg) => write!(f, "Skipped local time: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for TimeZoneError {}
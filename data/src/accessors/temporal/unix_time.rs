@@ -0,0 +1,108 @@
+use std::{
+    fmt::Display,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::accessors::temporal::{DAYS_TO_UNIX_EPOCH, NANOSECONDS_PER_DAY};
+
+/// UnixTimeUnit defines an enumeration of the units that a Unix timestamp, the signed number of
+/// units since 1970-01-01T00:00:00Z, is counted in.
+/// <p>
+/// Temporal values are converted to timestamps by rounding down to a whole unit, so that
+/// 1969-12-31T23:59:59.5Z is -1 in seconds.
+/// </p>
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum UnixTimeUnit {
+    /// Whole seconds.
+    Seconds,
+    /// Milliseconds.
+    Milliseconds,
+    /// Microseconds.
+    Microseconds,
+    /// Nanoseconds.
+    Nanoseconds,
+}
+
+impl UnixTimeUnit {
+    /// Returns the number of nanoseconds in one unit.
+    pub fn nanoseconds(&self) -> i128 {
+        match self {
+            Self::Seconds => 1_000_000_000,
+            Self::Milliseconds => 1_000_000,
+            Self::Microseconds => 1_000,
+            Self::Nanoseconds => 1,
+        }
+    }
+}
+
+impl Display for UnixTimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Seconds => "seconds",
+                Self::Milliseconds => "milliseconds",
+                Self::Microseconds => "microseconds",
+                Self::Nanoseconds => "nanoseconds",
+            }
+        )
+    }
+}
+
+/// The number of nanoseconds from January 1, 0001 00:00:00 to the Unix epoch.
+pub(crate) const NANOSECONDS_TO_UNIX_EPOCH: i128 = DAYS_TO_UNIX_EPOCH * NANOSECONDS_PER_DAY;
+
+/// Returns the nanoseconds since the Unix epoch of a timestamp in the given unit.
+pub(crate) fn unix_nanoseconds_from_timestamp(timestamp: i64, unit: UnixTimeUnit) -> i128 {
+    timestamp as i128 * unit.nanoseconds()
+}
+
+/// Returns the timestamp in the given unit of the nanoseconds since the Unix epoch, rounded down
+/// to a whole unit, or an error message if the timestamp does not fit into an `i64`.
+pub(crate) fn timestamp_from_unix_nanoseconds(
+    unix_nanoseconds: i128,
+    unit: UnixTimeUnit,
+) -> Result<i64, String> {
+    i64::try_from(unix_nanoseconds.div_euclid(unit.nanoseconds())).map_err(|_| {
+        format!(
+            "An instant {} nanoseconds from the Unix epoch cannot be counted in {} by an i64.",
+            unix_nanoseconds, unit
+        )
+    })
+}
+
+/// Returns the nanoseconds since the Unix epoch of the system time, which are negative for
+/// earlier system times.
+pub(crate) fn unix_nanoseconds_from_system_time(system_time: &SystemTime) -> i128 {
+    match system_time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_nanos() as i128,
+        Err(error) => -(error.duration().as_nanos() as i128),
+    }
+}
+
+/// Returns the system time at the nanoseconds since the Unix epoch, or an error message if the
+/// platform's system time cannot represent it.
+pub(crate) fn system_time_from_unix_nanoseconds(
+    unix_nanoseconds: i128,
+) -> Result<SystemTime, String> {
+    let duration = u64::try_from(unix_nanoseconds.unsigned_abs() / 1_000_000_000)
+        .ok()
+        .map(|seconds| {
+            Duration::new(
+                seconds,
+                (unix_nanoseconds.unsigned_abs() % 1_000_000_000) as u32,
+            )
+        });
+    match duration {
+        Some(duration) if unix_nanoseconds >= 0 => UNIX_EPOCH.checked_add(duration),
+        Some(duration) => UNIX_EPOCH.checked_sub(duration),
+        None => None,
+    }
+    .ok_or_else(|| {
+        format!(
+            "An instant {} nanoseconds from the Unix epoch is out of the range of a system time.",
+            unix_nanoseconds
+        )
+    })
+}
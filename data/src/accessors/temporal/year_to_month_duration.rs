@@ -6,7 +6,10 @@ use std::{
 use crate::{
     adaptors::temporal_adaptors::year_to_month_duration_adaptor::YearToMonthDurationAdaptor,
     primitive_def::Accessor,
-    primitive_specs::duration_spec::{DurationResolution, DurationSpec},
+    primitive_specs::{
+        duration_spec::{DurationResolution, DurationSpec},
+        rounding_policy::RoundingPolicy,
+    },
     provider_error::ProviderError,
     set_equal_to::{SetEqualTo, SetEqualToError},
 };
@@ -27,12 +30,39 @@ impl YearToMonthDuration {
         self.adaptor.spec()
     }
 
-    /// Sets the duration in years and months.
+    /// Sets the duration in years and months. A duration finer than the resolution of the
+    /// duration's spec is handled with the spec's rounding policy.
     pub fn set_duration(
         &mut self,
         years: i32,
         months: i32,
     ) -> Result<(), YearToMonthDurationError> {
+        self.set_duration_with_rounding(years, months, *self.spec().rounding_policy())
+    }
+
+    /// Sets the duration in years and months, handling a duration finer than the resolution of
+    /// the duration's spec with the given rounding policy instead of the spec's.
+    pub fn set_duration_with_rounding(
+        &mut self,
+        years: i32,
+        months: i32,
+        rounding_policy: RoundingPolicy,
+    ) -> Result<(), YearToMonthDurationError> {
+        if *self.spec().resolution() == Some(DurationResolution::Year)
+            && rounding_policy != RoundingPolicy::Reject
+        {
+            let total_months = years as i128 * 12 + months as i128;
+            let rounded = rounding_policy
+                .round(total_months, 12)
+                .unwrap_or(total_months);
+            let years = i32::try_from(rounded / 12).map_err(|_| {
+                YearToMonthDurationError::ResolutionOutOfBounds(format!(
+                    "Cannot round {} years and {} months to whole years without exceeding the range of the years.",
+                    years, months
+                ))
+            })?;
+            return self.adaptor.set_duration(years, 0);
+        }
         if let Some(resolution) = self.spec().resolution() {
             if months != 0 && *resolution == DurationResolution::Year {
                 return Err(YearToMonthDurationError::ResolutionOutOfBounds(format!(
@@ -46,6 +76,36 @@ impl YearToMonthDuration {
             .map_err(YearToMonthDurationError::from)
     }
 
+    /// Sets the duration from an ISO 8601 duration string, either in the designator format
+    /// (PnYnM) or in the alternative format (PYYYY-MM-DD) with zero days. The string may be
+    /// preceded by a sign, and the last component may have a fraction after a '.' or ','
+    /// as long as the duration is a whole number of months.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::duration_spec_builder::DurationSpecBuilder;
+    /// use data::primitive_specs::duration_spec::DurationType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DurationSpecBuilder::new()
+    ///     .set_type(DurationType::YearToMonth)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut var = Variable::new(&spec);
+    /// let duration_mut = var.year_to_month_duration_mut();
+    ///
+    /// duration_mut.set_from_string("-P1.5Y").unwrap();
+    /// assert_eq!(duration_mut.duration().unwrap(), (-1, -6));
+    /// assert_eq!(duration_mut.to_string(), "-P1Y6M");
+    /// ```
+    pub fn set_from_string(&mut self, duration_str: &str) -> Result<(), YearToMonthDurationError> {
+        crate::accessors::temporal::set_from_strings::set_duration_from_string::set_year_to_month_duration_from_string(
+            self,
+            duration_str,
+        )
+    }
+
     /// Returns the duration as a tuple of (years, months).
     pub fn duration(&self) -> Result<(i32, i32), YearToMonthDurationError> {
         self.adaptor.duration()
@@ -67,7 +127,17 @@ impl Accessor for YearToMonthDuration {}
 impl Display for YearToMonthDuration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let (years, months) = self.duration().unwrap();
-        write!(f, "P{}Y{}M", years, months)
+        let sign = if years < 0 || months < 0 { "-" } else { "" };
+        let (years, months) = (years.unsigned_abs(), months.unsigned_abs());
+        if *self.spec().resolution() == Some(DurationResolution::Year) {
+            write!(f, "{}P{}Y", sign, years)
+        } else if years == 0 {
+            write!(f, "{}P{}M", sign, months)
+        } else if months == 0 {
+            write!(f, "{}P{}Y", sign, years)
+        } else {
+            write!(f, "{}P{}Y{}M", sign, years, months)
+        }
     }
 }
 
@@ -1,47 +1,671 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    rc::Rc,
+    time::SystemTime,
+};
+
 use crate::{
-    accessors::temporal::{date::Date, zoned_time::ZonedTime},
+    accessors::temporal::{
+        DAYS_TO_UNIX_EPOCH, NANOSECONDS_PER_DAY, NANOSECONDS_PER_SECOND,
+        clock::{Clock, now},
+        date::{Date, DateError},
+        format_pattern::{FormatError, FormatPattern, PatternFields},
+        time::TimeError,
+        time_zone::{Disambiguation, TimeZone, TimeZoneError},
+        unix_time::{
+            NANOSECONDS_TO_UNIX_EPOCH, UnixTimeUnit, system_time_from_unix_nanoseconds,
+            timestamp_from_unix_nanoseconds, unix_nanoseconds_from_system_time,
+            unix_nanoseconds_from_timestamp,
+        },
+        zoned_time::{ZonedTime, ZonedTimeError, check_offset},
+    },
     adaptors::temporal_adaptors::{
         date_adaptor::DateAdaptor, day_to_second_duration_adaptor::DayToSecondDurationAdaptor,
         time_adaptor::TimeAdaptor,
     },
     primitive_def::Accessor,
-    set_equal_to::SetEqualTo,
+    primitive_specs::date_time_spec::{DateTimeSpec, ZoneType},
+    provider_error::ProviderError,
+    set_equal_to::{SetEqualTo, SetEqualToError},
 };
 
-/// Accessor for date-time values.
+/// Accessor for zoned date-time values.
+/// <p>
+/// A zoned date-time is a date combined with a zoned time. Zoned date-times are compared by
+/// the instant they represent, so that 2024-06-15T01:00:00+02:00 is equal to
+/// 2024-06-14T23:00:00Z.
+/// </p>
+/// <p>
+/// A zoned date-time holds either a fixed offset from UTC or a named time zone, such as
+/// Europe/Berlin, in which case the offset follows the zone's daylight saving time rules.
+/// </p>
 pub struct ZonedDateTime {
+    spec: Rc<DateTimeSpec>,
     date: Date,
     zoned_time: ZonedTime,
+    time_zone: Option<Rc<TimeZone>>,
 }
 impl ZonedDateTime {
     /// Creates a new ZonedDateTime accessor.
     pub fn new(
+        spec: Rc<DateTimeSpec>,
         date_adaptor: Box<dyn DateAdaptor>,
         time_adaptor: Box<dyn TimeAdaptor>,
         zone_adaptor: Box<dyn DayToSecondDurationAdaptor>,
     ) -> Self {
         Self {
+            spec,
             date: Date::new(date_adaptor),
             zoned_time: ZonedTime::new(time_adaptor, zone_adaptor),
+            time_zone: None,
         }
     }
 
+    /// Returns the zoned-date-time's specification.
+    pub fn spec(&self) -> &Rc<DateTimeSpec> {
+        &self.spec
+    }
+
     /// Returns the zoned-date-time's date component.
     pub fn date(&self) -> &Date {
         &self.date
     }
 
+    /// Returns the zoned-date-time's date component as mutable.
+    pub fn date_mut(&mut self) -> &mut Date {
+        &mut self.date
+    }
+
     /// Returns the zoned-date-time's zoned-time component.
     pub fn zoned_time(&self) -> &ZonedTime {
         &self.zoned_time
     }
+
+    /// Returns the zoned-date-time's zoned-time component as mutable.
+    pub fn zoned_time_mut(&mut self) -> &mut ZonedTime {
+        &mut self.zoned_time
+    }
+
+    /// Returns the zoned-date-time's named time zone, if it holds one.
+    pub fn time_zone(&self) -> Option<&Rc<TimeZone>> {
+        self.time_zone.as_ref()
+    }
+
+    /// Sets the zoned-date-time to the given date, as a tuple of (year, month, day), time of day,
+    /// as a tuple of (hours, minutes, seconds, milliseconds, microseconds, nanoseconds), and
+    /// offset from UTC in seconds, dropping any named time zone.
+    /// The zoned-date-time is left unchanged if the date, the time or the offset is invalid.
+    pub fn set_zoned_date_time(
+        &mut self,
+        date: (u32, u32, u32),
+        time: (u8, u8, u8, u16, u16, u16),
+        offset_seconds: i32,
+    ) -> Result<(), ZonedDateTimeError> {
+        self.check_zone_type(ZoneType::Offset)?;
+        let previous = self.date.days()?;
+        self.date.set_date(date.0, date.1, date.2)?;
+        if let Err(error) = self.zoned_time.set_zoned_time(time, offset_seconds) {
+            self.date.set_days(previous)?;
+            return Err(error.into());
+        }
+        self.time_zone = None;
+        Ok(())
+    }
+
+    /// Sets the zoned-date-time to the given local date, as a tuple of (year, month, day), and
+    /// time of day, as a tuple of (hours, minutes, seconds, milliseconds, microseconds,
+    /// nanoseconds), in the given time zone. A local date-time that occurs twice or not at all
+    /// in the time zone is resolved with the given disambiguation.
+    /// The zoned-date-time is left unchanged if the date-time is invalid or cannot be resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use data::accessors::temporal::time_zone::{Disambiguation, TimeZone};
+    /// use data::data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder;
+    /// use data::primitive_specs::date_time_spec::DateTimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DateTimeSpecBuilder::new()
+    ///     .set_date_time_type(DateTimeType::Zoned)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    /// let berlin = TimeZone::load("Europe/Berlin").unwrap();
+    ///
+    /// // 02:30 is skipped when clocks are set forward on 2024-03-31.
+    /// var.zoned_date_time_mut()
+    ///     .set_in_time_zone((2024, 3, 31), (2, 30, 0, 0, 0, 0), &berlin, Disambiguation::Compatible)
+    ///     .unwrap();
+    /// assert_eq!(var.to_string(), "2024-03-31T03:30:00+02:00[Europe/Berlin]");
+    /// ```
+    pub fn set_in_time_zone(
+        &mut self,
+        date: (u32, u32, u32),
+        time: (u8, u8, u8, u16, u16, u16),
+        time_zone: &Rc<TimeZone>,
+        disambiguation: Disambiguation,
+    ) -> Result<(), ZonedDateTimeError> {
+        self.check_zone_type(ZoneType::Named)?;
+        let previous = self.state()?;
+        let result = self
+            .date
+            .set_date(date.0, date.1, date.2)
+            .map_err(ZonedDateTimeError::from)
+            .and_then(|_| Ok(self.zoned_time.time_mut().set_via_tuple(time)?))
+            .and_then(|_| self.resolve_in_time_zone(time_zone, disambiguation));
+        if result.is_err() {
+            self.restore(previous)?;
+        }
+        result
+    }
+
+    /// Re-expresses the zoned-date-time in the given time zone, keeping the instant it represents.
+    pub fn convert_to_time_zone(
+        &mut self,
+        time_zone: &Rc<TimeZone>,
+    ) -> Result<(), ZonedDateTimeError> {
+        self.check_zone_type(ZoneType::Named)?;
+        let utc_nanoseconds = self.utc_nanoseconds()?;
+        let offset_seconds = time_zone
+            .local_time_type(unix_seconds(utc_nanoseconds))
+            .offset_seconds();
+        self.set_instant(utc_nanoseconds, offset_seconds)?;
+        self.time_zone = Some(time_zone.clone());
+        Ok(())
+    }
+
+    /// Sets the zoned-date-time to the current instant of the given clock, with the time truncated
+    /// to the resolution of the time's spec. The instant is expressed in the zoned-date-time's
+    /// named time zone if it holds one, or with the clock's local offset from UTC otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::accessors::temporal::clock::FixedClock;
+    /// use data::data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder;
+    /// use data::primitive_specs::date_time_spec::DateTimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DateTimeSpecBuilder::new()
+    ///     .set_date_time_type(DateTimeType::Zoned)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    ///
+    /// // 2024-06-15T23:30:00Z, with the local time five hours behind UTC
+    /// let clock = FixedClock::new(1_718_494_200 * 1_000_000_000);
+    /// clock.set_local_offset_seconds(-18_000);
+    /// var.zoned_date_time_mut().set_to_now(&clock).unwrap();
+    /// assert_eq!(var.to_string(), "2024-06-15T18:30:00-05:00");
+    /// ```
+    pub fn set_to_now(&mut self, clock: &dyn Clock) -> Result<(), ZonedDateTimeError> {
+        let (utc_nanoseconds, offset_seconds) =
+            now(clock, self.zoned_time.time().spec().resolution().as_ref());
+        self.set_instant_in_zone(utc_nanoseconds, offset_seconds)
+    }
+
+    /// Sets the zoned-date-time to the instant of the given Unix timestamp, counted in the given
+    /// unit since 1970-01-01T00:00:00Z. The instant is expressed in the zoned-date-time's named
+    /// time zone if it holds one, or with its current offset from UTC otherwise.
+    /// The zoned-date-time is left unchanged if the date is out of the date's range or the time
+    /// is finer than the time's resolution.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::accessors::temporal::unix_time::UnixTimeUnit;
+    /// use data::data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder;
+    /// use data::primitive_specs::date_time_spec::DateTimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DateTimeSpecBuilder::new()
+    ///     .set_date_time_type(DateTimeType::Zoned)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    /// let zoned_date_time_mut = var.zoned_date_time_mut();
+    ///
+    /// zoned_date_time_mut.set_from_string("2024-01-01T00:00:00+02:00").unwrap();
+    /// zoned_date_time_mut
+    ///     .set_from_unix_timestamp(1_718_454_896, UnixTimeUnit::Seconds)
+    ///     .unwrap();
+    /// assert_eq!(zoned_date_time_mut.to_string(), "2024-06-15T14:34:56+02:00");
+    /// ```
+    pub fn set_from_unix_timestamp(
+        &mut self,
+        timestamp: i64,
+        unit: UnixTimeUnit,
+    ) -> Result<(), ZonedDateTimeError> {
+        self.set_from_unix_nanoseconds(unix_nanoseconds_from_timestamp(timestamp, unit))
+    }
+
+    /// Returns the Unix timestamp of the instant that the zoned-date-time represents, counted in
+    /// the given unit since 1970-01-01T00:00:00Z and rounded down to a whole unit.
+    /// Fails if the timestamp does not fit into an `i64`.
+    pub fn unix_timestamp(&self, unit: UnixTimeUnit) -> Result<i64, ZonedDateTimeError> {
+        timestamp_from_unix_nanoseconds(self.utc_nanoseconds()? - NANOSECONDS_TO_UNIX_EPOCH, unit)
+            .map_err(|msg| DateError::TimestampOutOfRange(msg).into())
+    }
+
+    /// Sets the zoned-date-time to the instant of the given system time, expressed in its named
+    /// time zone if it holds one, or with its current offset from UTC otherwise.
+    /// The zoned-date-time is left unchanged if the date is out of the date's range or the time
+    /// is finer than the time's resolution.
+    pub fn set_from_system_time(
+        &mut self,
+        system_time: &SystemTime,
+    ) -> Result<(), ZonedDateTimeError> {
+        self.set_from_unix_nanoseconds(unix_nanoseconds_from_system_time(system_time))
+    }
+
+    /// Returns the system time of the instant that the zoned-date-time represents.
+    /// Fails if the platform's system time cannot represent it.
+    pub fn system_time(&self) -> Result<SystemTime, ZonedDateTimeError> {
+        system_time_from_unix_nanoseconds(self.utc_nanoseconds()? - NANOSECONDS_TO_UNIX_EPOCH)
+            .map_err(|msg| DateError::TimestampOutOfRange(msg).into())
+    }
+
+    /// Sets the zoned-date-time to the instant in nanoseconds since the Unix epoch, keeping its
+    /// named time zone or offset, and leaving it unchanged on failure.
+    fn set_from_unix_nanoseconds(
+        &mut self,
+        unix_nanoseconds: i128,
+    ) -> Result<(), ZonedDateTimeError> {
+        let previous = self.state()?;
+        let result = self.set_instant_in_zone(
+            unix_nanoseconds + NANOSECONDS_TO_UNIX_EPOCH,
+            self.zoned_time.offset_seconds()?,
+        );
+        if result.is_err() {
+            self.restore(previous)?;
+        }
+        result
+    }
+
+    /// Sets the zoned-date-time to the given instant, in nanoseconds from January 1, 0001
+    /// 00:00:00Z, expressed in its named time zone if it holds one, or with the given offset from
+    /// UTC in seconds otherwise.
+    fn set_instant_in_zone(
+        &mut self,
+        utc_nanoseconds: i128,
+        offset_seconds: i32,
+    ) -> Result<(), ZonedDateTimeError> {
+        match self.time_zone.clone() {
+            Some(time_zone) => {
+                // the instant must lie in the range of a date before the zone's rules apply to it
+                days_of(utc_nanoseconds)?;
+                let offset_seconds = time_zone
+                    .local_time_type(unix_seconds(utc_nanoseconds))
+                    .offset_seconds();
+                self.set_instant(utc_nanoseconds, offset_seconds)
+            }
+            None => {
+                self.check_zone_type(ZoneType::Offset)?;
+                self.set_instant(utc_nanoseconds, offset_seconds)
+            }
+        }
+    }
+
+    /// Sets the zoned-date-time from a string in an ISO 8601 format:
+    /// (YYYY-MM-DDThh:mm:ss[.fffffffff]) followed by a UTC offset of (Z), (±hh:mm), (±hhmm)
+    /// or (±hh), where the date and the zoned time may be given in any of the forms that
+    /// [`Date::set_from_string`] and [`ZonedTime::set_from_string`] accept.
+    /// The string may end with a time zone name in brackets, such as (\[Europe/Berlin\]), in which
+    /// case the offset may be left out and the local date-time is resolved in the time zone with
+    /// [`Disambiguation::Compatible`], or, if given, must be the time zone's offset at that instant.
+    /// The zoned-date-time is left unchanged if the string is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder;
+    /// use data::primitive_specs::date_time_spec::DateTimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DateTimeSpecBuilder::new()
+    ///     .set_date_time_type(DateTimeType::Zoned)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    /// let zoned_date_time_mut = var.zoned_date_time_mut();
+    ///
+    /// zoned_date_time_mut.set_from_string("2024-06-15T01:30:00+02:00").unwrap();
+    /// zoned_date_time_mut.to_utc().unwrap();
+    /// assert_eq!(zoned_date_time_mut.to_string(), "2024-06-14T23:30:00Z");
+    /// ```
+    pub fn set_from_string(&mut self, zoned_date_time_str: &str) -> Result<(), ZonedDateTimeError> {
+        crate::accessors::temporal::set_from_strings::set_zoned_date_time_from_string::set_zoned_date_time_from_string(
+            self,
+            zoned_date_time_str,
+        )
+    }
+
+    /// Sets the zoned-date-time from a string in the layout of the given format pattern, which
+    /// must contain a date, a time and a UTC offset, dropping any named time zone.
+    /// The zoned-date-time is left unchanged if the string does not match the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::accessors::temporal::format_pattern::FormatPattern;
+    /// use data::data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder;
+    /// use data::primitive_specs::date_time_spec::DateTimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = DateTimeSpecBuilder::new()
+    ///     .set_date_time_type(DateTimeType::Zoned)
+    ///     .build();
+    /// let mut var = Variable::new(&spec);
+    ///
+    /// var.zoned_date_time_mut()
+    ///     .set_from_format("Sat, 15 Jun 2024 12:34:56 +0200", &FormatPattern::rfc_2822())
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     var.zoned_date_time().format(&FormatPattern::rfc_3339()).unwrap(),
+    ///     "2024-06-15T12:34:56+02:00"
+    /// );
+    /// ```
+    pub fn set_from_format(
+        &mut self,
+        zoned_date_time_str: &str,
+        pattern: &FormatPattern,
+    ) -> Result<(), FormatError> {
+        let fields = pattern.parse(zoned_date_time_str)?;
+        // parsed years are from 0 to 9999
+        let (year, month, day) = fields.date()?;
+        self.set_zoned_date_time(
+            (year as u32, month, day),
+            fields.time()?,
+            fields.offset_seconds()?,
+        )?;
+        Ok(())
+    }
+
+    /// Formats the zoned-date-time with the given format pattern.
+    pub fn format(&self, pattern: &FormatPattern) -> Result<String, FormatError> {
+        let (time, offset_seconds) = self.zoned_time.zoned_time()?;
+        pattern.format(&PatternFields {
+            date: Some(self.date.proleptic_date()?),
+            time: Some(time),
+            offset_seconds: Some(offset_seconds),
+        })
+    }
+
+    /// Returns the zoned-date-time as a tuple of (year, month, day), a tuple of (hours, minutes,
+    /// seconds, milliseconds, microseconds, nanoseconds) and the offset from UTC in seconds.
+    pub fn zoned_date_time(&self) -> Result<ZonedDateTimeFields, ZonedDateTimeError> {
+        let (time, offset_seconds) = self.zoned_time.zoned_time()?;
+        Ok((self.date.date()?, time, offset_seconds))
+    }
+
+    /// Re-expresses the zoned-date-time in UTC, keeping the instant it represents.
+    pub fn to_utc(&mut self) -> Result<(), ZonedDateTimeError> {
+        self.convert_to_offset(0)
+    }
+
+    /// Re-expresses the zoned-date-time with the given offset from UTC in seconds, keeping the
+    /// instant it represents and dropping any named time zone. The date rolls over when the time
+    /// of day crosses midnight.
+    pub fn convert_to_offset(&mut self, offset_seconds: i32) -> Result<(), ZonedDateTimeError> {
+        self.check_zone_type(ZoneType::Offset)?;
+        self.set_instant(self.utc_nanoseconds()?, offset_seconds)?;
+        self.time_zone = None;
+        Ok(())
+    }
+
+    /// Sets the zoned-date-time to the given instant, in nanoseconds from January 1, 0001
+    /// 00:00:00Z, expressed with the given offset from UTC in seconds.
+    fn set_instant(
+        &mut self,
+        utc_nanoseconds: i128,
+        offset_seconds: i32,
+    ) -> Result<(), ZonedDateTimeError> {
+        check_offset(offset_seconds)?;
+        let local = utc_nanoseconds + offset_seconds as i128 * NANOSECONDS_PER_SECOND;
+        self.date.set_days(days_of(local)?)?;
+        self.zoned_time
+            .time_mut()
+            .set_nanoseconds_of_day(local.rem_euclid(NANOSECONDS_PER_DAY) as u64)?;
+        self.zoned_time.set_offset_seconds(offset_seconds)?;
+        Ok(())
+    }
+
+    /// Takes the zoned-date-time's date and time of day as a local date-time in the given time
+    /// zone and sets its offset, and the time zone, accordingly.
+    pub(crate) fn resolve_in_time_zone(
+        &mut self,
+        time_zone: &Rc<TimeZone>,
+        disambiguation: Disambiguation,
+    ) -> Result<(), ZonedDateTimeError> {
+        let local_nanoseconds = self.date.days()? as i128 * NANOSECONDS_PER_DAY
+            + self.zoned_time.time().nanoseconds_of_day()? as i128;
+        let local_seconds = unix_seconds(local_nanoseconds);
+        let utc_seconds = time_zone.to_unix_seconds(local_seconds, disambiguation)?;
+        let utc_nanoseconds =
+            local_nanoseconds - (local_seconds - utc_seconds) as i128 * NANOSECONDS_PER_SECOND;
+        let offset_seconds = time_zone.local_time_type(utc_seconds).offset_seconds();
+        self.set_instant(utc_nanoseconds, offset_seconds)?;
+        self.time_zone = Some(time_zone.clone());
+        Ok(())
+    }
+
+    /// Sets the zoned-date-time's time zone without changing its date, time or offset.
+    pub(crate) fn set_time_zone(&mut self, time_zone: Option<Rc<TimeZone>>) {
+        self.time_zone = time_zone;
+    }
+
+    /// Checks that the zoned-date-time's specification allows it to hold a zone of the given type.
+    pub(crate) fn check_zone_type(&self, zone_type: ZoneType) -> Result<(), ZonedDateTimeError> {
+        if let Some(required) = self.spec.zone_type()
+            && *required != zone_type
+        {
+            return Err(ZonedDateTimeError::ZoneTypeMismatch(format!(
+                "Cannot set a zone of type {} on a zoned date-time that requires a zone of type {}.",
+                zone_type, required
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the zoned-date-time's state, so that it can be restored after a failed update.
+    pub(crate) fn state(&self) -> Result<ZonedDateTimeState, ZonedDateTimeError> {
+        Ok((
+            self.date.days()?,
+            self.zoned_time.time().nanoseconds_of_day()?,
+            self.zoned_time.offset_seconds()?,
+            self.time_zone.clone(),
+        ))
+    }
+
+    /// Restores a state returned by [`ZonedDateTime::state`].
+    pub(crate) fn restore(&mut self, state: ZonedDateTimeState) -> Result<(), ZonedDateTimeError> {
+        let (days, nanoseconds, offset_seconds, time_zone) = state;
+        self.date.set_days(days)?;
+        self.zoned_time
+            .time_mut()
+            .set_nanoseconds_of_day(nanoseconds)?;
+        self.zoned_time.set_offset_seconds(offset_seconds)?;
+        self.time_zone = time_zone;
+        Ok(())
+    }
+
+    /// Returns the number of nanoseconds from January 1, 0001 00:00:00Z to the instant that the
+    /// zoned-date-time represents.
+    pub(crate) fn utc_nanoseconds(&self) -> Result<i128, ZonedDateTimeError> {
+        Ok(self.date.days()? as i128 * NANOSECONDS_PER_DAY
+            + self.zoned_time.time().nanoseconds_of_day()? as i128
+            - self.zoned_time.offset_nanoseconds()? as i128)
+    }
+}
+
+/// The state of a zoned date-time: its days, time of day in nanoseconds, offset and time zone.
+pub(crate) type ZonedDateTimeState = (u32, u64, i32, Option<Rc<TimeZone>>);
+
+/// Returns the whole days from January 1, 0001 of the given nanoseconds from
+/// 0001-01-01T00:00:00, or an error if they are out of the range of a date.
+fn days_of(nanoseconds: i128) -> Result<u32, DateError> {
+    let days = nanoseconds.div_euclid(NANOSECONDS_PER_DAY);
+    if days < 0 {
+        return Err(DateError::YearOutOfBounds(0));
+    }
+    u32::try_from(days).map_err(|_| {
+        DateError::TimestampOutOfRange(format!(
+            "An instant {} nanoseconds from January 1, 0001 is out of the range of a date.",
+            nanoseconds
+        ))
+    })
 }
 
+/// Returns the whole seconds from 1970-01-01T00:00:00 of the given nanoseconds from
+/// 0001-01-01T00:00:00.
+pub(crate) fn unix_seconds(nanoseconds: i128) -> i64 {
+    (nanoseconds.div_euclid(NANOSECONDS_PER_SECOND) - DAYS_TO_UNIX_EPOCH * 86_400) as i64
+}
+
+/// The fields of a zoned date-time as a tuple of (year, month, day), a tuple of (hours, minutes,
+/// seconds, milliseconds, microseconds, nanoseconds) and the offset from UTC in seconds.
+pub type ZonedDateTimeFields = ((u32, u32, u32), (u8, u8, u8, u16, u16, u16), i32);
+
 impl SetEqualTo for ZonedDateTime {
-    fn set_equal_to(&mut self, _other: &Self) -> Result<(), crate::set_equal_to::SetEqualToError> {
-        // Implement the logic to set this ZonedDateTime equal to another ZonedDateTime.
-        todo!("Implement set_equal_to for ZonedDateTime");
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.check_zone_type(match other.time_zone {
+            Some(_) => ZoneType::Named,
+            None => ZoneType::Offset,
+        })?;
+        self.date.set_equal_to(&other.date)?;
+        self.zoned_time.set_equal_to(&other.zoned_time)?;
+        self.time_zone = other.time_zone.clone();
+        Ok(())
     }
 }
 
 impl Accessor for ZonedDateTime {}
+
+impl Display for ZonedDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}T{}", self.date, self.zoned_time)?;
+        if let Some(time_zone) = &self.time_zone {
+            write!(f, "[{}]", time_zone)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for ZonedDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ZonedDateTime {{ date: {:?}, zoned_time: {:?}, time_zone: {} }}",
+            self.date,
+            self.zoned_time,
+            self.time_zone
+                .as_ref()
+                .map(|time_zone| time_zone.name())
+                .unwrap_or("None")
+        )
+    }
+}
+
+impl PartialEq for ZonedDateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.utc_nanoseconds().unwrap() == other.utc_nanoseconds().unwrap()
+    }
+}
+
+impl Eq for ZonedDateTime {}
+
+impl PartialOrd for ZonedDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ZonedDateTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.utc_nanoseconds()
+            .unwrap()
+            .cmp(&other.utc_nanoseconds().unwrap())
+    }
+}
+
+impl Hash for ZonedDateTime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.utc_nanoseconds().unwrap().hash(state);
+    }
+}
+
+/// An error that can occur when working with zoned date-time values.
+#[derive(Debug, PartialEq)]
+pub enum ZonedDateTimeError {
+    /// A provider error.
+    ProviderError(ProviderError),
+    /// An error of the date component.
+    DateError(DateError),
+    /// An error of the zoned-time component.
+    ZonedTimeError(ZonedTimeError),
+    /// An error of the named time zone.
+    TimeZoneError(TimeZoneError),
+    /// Indicates that the zone is not of the type that the specification requires.
+    ZoneTypeMismatch(String),
+    /// Indicates an invalid format error.
+    InvalidFormat(String),
+}
+
+impl Display for ZonedDateTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ZonedDateTimeError::ProviderError(err) => write!(f, "Provider error: {}", err),
+            ZonedDateTimeError::DateError(err) => write!(f, "Date error: {}", err),
+            ZonedDateTimeError::ZonedTimeError(err) => write!(f, "Zoned time error: {}", err),
+            ZonedDateTimeError::TimeZoneError(err) => write!(f, "Time zone error: {}", err),
+            ZonedDateTimeError::ZoneTypeMismatch(msg) => write!(f, "Zone type mismatch: {}", msg),
+            ZonedDateTimeError::InvalidFormat(msg) => write!(f, "Invalid format: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ZonedDateTimeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ZonedDateTimeError::ProviderError(err) => Some(err),
+            ZonedDateTimeError::DateError(err) => Some(err),
+            ZonedDateTimeError::ZonedTimeError(err) => Some(err),
+            ZonedDateTimeError::TimeZoneError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProviderError> for ZonedDateTimeError {
+    fn from(error: ProviderError) -> Self {
+        ZonedDateTimeError::ProviderError(error)
+    }
+}
+
+impl From<DateError> for ZonedDateTimeError {
+    fn from(error: DateError) -> Self {
+        ZonedDateTimeError::DateError(error)
+    }
+}
+
+impl From<ZonedTimeError> for ZonedDateTimeError {
+    fn from(error: ZonedTimeError) -> Self {
+        ZonedDateTimeError::ZonedTimeError(error)
+    }
+}
+
+impl From<TimeZoneError> for ZonedDateTimeError {
+    fn from(error: TimeZoneError) -> Self {
+        ZonedDateTimeError::TimeZoneError(error)
+    }
+}
+
+impl From<TimeError> for ZonedDateTimeError {
+    fn from(error: TimeError) -> Self {
+        ZonedDateTimeError::ZonedTimeError(error.into())
+    }
+}
+
+impl From<ZonedDateTimeError> for SetEqualToError {
+    fn from(error: ZonedDateTimeError) -> Self {
+        SetEqualToError::ZonedDateTimeError(error)
+    }
+}
@@ -1,13 +1,33 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
 use crate::{
-    accessors::temporal::{day_to_second_duration::DayToSecondDuration, time::Time},
+    accessors::temporal::{
+        NANOSECONDS_PER_DAY, NANOSECONDS_PER_SECOND,
+        clock::{Clock, now},
+        day_to_second_duration::{DayToSecondDuration, DayToSecondDurationError},
+        format_pattern::{FormatError, FormatPattern, PatternFields},
+        time::{Time, TimeError},
+    },
     adaptors::temporal_adaptors::{
         day_to_second_duration_adaptor::DayToSecondDurationAdaptor, time_adaptor::TimeAdaptor,
     },
     primitive_def::Accessor,
+    provider_error::ProviderError,
     set_equal_to::{SetEqualTo, SetEqualToError},
 };
 
+/// The largest UTC offset, in seconds, that a zoned time can have (18 hours).
+pub const MAX_OFFSET_SECONDS: i32 = 18 * 3_600;
+
 /// An accessor for zoned time values.
+/// <p>
+/// A zoned time is a time of day combined with an offset from UTC, held as a day-to-second
+/// duration. Zoned times are compared by the instant they represent, so that 10:00:00+02:00
+/// is equal to 08:00:00Z.
+/// </p>
 pub struct ZonedTime {
     time: Time,
     zone: DayToSecondDuration,
@@ -27,17 +47,289 @@ impl ZonedTime {
         &self.time
     }
 
+    /// Returns the zoned-time's time component as mutable.
+    pub fn time_mut(&mut self) -> &mut Time {
+        &mut self.time
+    }
+
     /// Returns the zoned-time's zone component.
     pub fn zone(&self) -> &DayToSecondDuration {
         &self.zone
     }
+
+    /// Returns the zoned-time's offset from UTC in seconds.
+    pub fn offset_seconds(&self) -> Result<i32, ZonedTimeError> {
+        Ok((self.zone.total_nanoseconds()? / NANOSECONDS_PER_SECOND) as i32)
+    }
+
+    /// Sets the zoned-time's offset from UTC in seconds, leaving the time of day unchanged.
+    pub fn set_offset_seconds(&mut self, offset_seconds: i32) -> Result<(), ZonedTimeError> {
+        check_offset(offset_seconds)?;
+        self.zone
+            .set_total_nanoseconds(offset_seconds as i128 * NANOSECONDS_PER_SECOND)?;
+        Ok(())
+    }
+
+    /// Sets the zoned-time to the given time of day, as a tuple of (hours, minutes, seconds,
+    /// milliseconds, microseconds, nanoseconds), and offset from UTC in seconds.
+    /// The zoned-time is left unchanged if either the time or the offset is invalid.
+    pub fn set_zoned_time(
+        &mut self,
+        time: (u8, u8, u8, u16, u16, u16),
+        offset_seconds: i32,
+    ) -> Result<(), ZonedTimeError> {
+        check_offset(offset_seconds)?;
+        self.time.set_via_tuple(time)?;
+        self.set_offset_seconds(offset_seconds)
+    }
+
+    /// Sets the zoned-time to the current time of the given clock, with the clock's local offset
+    /// from UTC and the time truncated to the resolution of the time's spec.
+    /// The zoned-time is left unchanged if the clock's offset is out of bounds.
+    pub fn set_to_now(&mut self, clock: &dyn Clock) -> Result<(), ZonedTimeError> {
+        let (utc_nanoseconds, offset_seconds) = now(clock, self.time.spec().resolution().as_ref());
+        check_offset(offset_seconds)?;
+        let nanoseconds = (utc_nanoseconds + offset_seconds as i128 * NANOSECONDS_PER_SECOND)
+            .rem_euclid(NANOSECONDS_PER_DAY);
+        self.time.set_nanoseconds_of_day(nanoseconds as u64)?;
+        self.set_offset_seconds(offset_seconds)
+    }
+
+    /// Sets the zoned-time from a string in an ISO 8601 format: (hh:mm:ss[.fffffffff]) followed by
+    /// a UTC offset of (Z), (±hh:mm), (±hhmm) or (±hh), where the time may be given in any of the
+    /// forms that [`Time::set_from_string`] accepts.
+    /// The zoned-time is left unchanged if the string is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::data_spec_builders::time_spec_builder::TimeSpecBuilder;
+    /// use data::primitive_specs::time_spec::TimeType;
+    /// use data::variable::Variable;
+    ///
+    /// let spec = TimeSpecBuilder::new().set_time_type(TimeType::Zoned).build();
+    /// let mut local = Variable::new(&spec);
+    /// let mut utc = Variable::new(&spec);
+    ///
+    /// local.zoned_time_mut().set_from_string("10:00:00+02:00").unwrap();
+    /// utc.zoned_time_mut().set_from_string("08:00:00Z").unwrap();
+    /// assert_eq!(local.zoned_time().offset_seconds().unwrap(), 7_200);
+    /// assert_eq!(local, utc);
+    /// ```
+    pub fn set_from_string(&mut self, zoned_time_str: &str) -> Result<(), ZonedTimeError> {
+        crate::accessors::temporal::set_from_strings::set_zoned_time_from_string::set_zoned_time_from_string(
+            self,
+            zoned_time_str,
+        )
+    }
+
+    /// Sets the zoned-time from a string in the layout of the given format pattern, which must
+    /// contain a time as [`Time::set_from_format`] requires and a UTC offset.
+    /// The zoned-time is left unchanged if the string does not match the pattern.
+    pub fn set_from_format(
+        &mut self,
+        zoned_time_str: &str,
+        pattern: &FormatPattern,
+    ) -> Result<(), FormatError> {
+        let fields = pattern.parse(zoned_time_str)?;
+        self.set_zoned_time(fields.time()?, fields.offset_seconds()?)?;
+        Ok(())
+    }
+
+    /// Formats the zoned-time with the given format pattern, which may not contain date tokens.
+    pub fn format(&self, pattern: &FormatPattern) -> Result<String, FormatError> {
+        let (time, offset_seconds) = self.zoned_time()?;
+        pattern.format(&PatternFields {
+            time: Some(time),
+            offset_seconds: Some(offset_seconds),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the zoned-time as a tuple of (hours, minutes, seconds, milliseconds, microseconds,
+    /// nanoseconds) and the offset from UTC in seconds.
+    pub fn zoned_time(&self) -> Result<ZonedTimeFields, ZonedTimeError> {
+        Ok((self.time.time()?, self.offset_seconds()?))
+    }
+
+    /// Re-expresses the zoned-time in UTC, keeping the instant it represents.
+    pub fn to_utc(&mut self) -> Result<(), ZonedTimeError> {
+        self.convert_to_offset(0)
+    }
+
+    /// Re-expresses the zoned-time with the given offset from UTC in seconds, keeping the
+    /// instant it represents. The time of day wraps around midnight when needed.
+    pub fn convert_to_offset(&mut self, offset_seconds: i32) -> Result<(), ZonedTimeError> {
+        check_offset(offset_seconds)?;
+        let nanoseconds = (self.utc_nanoseconds_of_day()?
+            + offset_seconds as i64 * NANOSECONDS_PER_SECOND as i64)
+            .rem_euclid(NANOSECONDS_PER_DAY as i64);
+        self.time.set_nanoseconds_of_day(nanoseconds as u64)?;
+        self.set_offset_seconds(offset_seconds)
+    }
+
+    /// Returns the number of nanoseconds since midnight of the time of day expressed in UTC.
+    pub(crate) fn utc_nanoseconds_of_day(&self) -> Result<i64, ZonedTimeError> {
+        Ok(
+            (self.time.nanoseconds_of_day()? as i64 - self.offset_nanoseconds()?)
+                .rem_euclid(NANOSECONDS_PER_DAY as i64),
+        )
+    }
+
+    /// Returns the zoned-time's offset from UTC in nanoseconds.
+    pub(crate) fn offset_nanoseconds(&self) -> Result<i64, ZonedTimeError> {
+        Ok(self.offset_seconds()? as i64 * NANOSECONDS_PER_SECOND as i64)
+    }
+}
+
+/// The fields of a zoned time as a tuple of (hours, minutes, seconds, milliseconds, microseconds,
+/// nanoseconds) and the offset from UTC in seconds.
+pub type ZonedTimeFields = ((u8, u8, u8, u16, u16, u16), i32);
+
+/// Checks that the given offset from UTC in seconds is within bounds.
+pub(crate) fn check_offset(offset_seconds: i32) -> Result<(), ZonedTimeError> {
+    if !(-MAX_OFFSET_SECONDS..=MAX_OFFSET_SECONDS).contains(&offset_seconds) {
+        return Err(ZonedTimeError::OffsetOutOfBounds(offset_seconds));
+    }
+    Ok(())
+}
+
+/// Formats an offset from UTC in seconds as (Z), (±hh:mm) or (±hh:mm:ss).
+pub(crate) fn format_offset(offset_seconds: i32) -> String {
+    if offset_seconds == 0 {
+        return "Z".to_string();
+    }
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let offset = offset_seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (offset / 3_600, offset / 60 % 60, offset % 60);
+    if seconds == 0 {
+        format!("{}{:02}:{:02}", sign, hours, minutes)
+    } else {
+        format!("{}{:02}:{:02}:{:02}", sign, hours, minutes, seconds)
+    }
 }
 
 impl SetEqualTo for ZonedTime {
-    fn set_equal_to(&mut self, _other: &Self) -> Result<(), SetEqualToError> {
-        // Implement the logic to set this ZonedTime equal to another ZonedTime.
-        todo!("Implement set_equal_to for ZonedTime");
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.set_zoned_time(other.time.time()?, other.offset_seconds()?)?;
+        Ok(())
     }
 }
 
 impl Accessor for ZonedTime {}
+
+impl Display for ZonedTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            self.time,
+            format_offset(self.offset_seconds().unwrap())
+        )
+    }
+}
+
+impl Debug for ZonedTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ZonedTime {{ time: {:?}, offset: {} }}",
+            self.time,
+            format_offset(self.offset_seconds().unwrap())
+        )
+    }
+}
+
+impl PartialEq for ZonedTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.utc_nanoseconds_of_day().unwrap() == other.utc_nanoseconds_of_day().unwrap()
+    }
+}
+
+impl Eq for ZonedTime {}
+
+impl PartialOrd for ZonedTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ZonedTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.utc_nanoseconds_of_day()
+            .unwrap()
+            .cmp(&other.utc_nanoseconds_of_day().unwrap())
+    }
+}
+
+impl Hash for ZonedTime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.utc_nanoseconds_of_day().unwrap().hash(state);
+    }
+}
+
+/// An error that can occur when working with zoned time values.
+#[derive(Debug, PartialEq)]
+pub enum ZonedTimeError {
+    /// A provider error.
+    ProviderError(ProviderError),
+    /// An error of the time component.
+    TimeError(TimeError),
+    /// An error of the zone component.
+    ZoneError(DayToSecondDurationError),
+    /// Indicates that the offset from UTC, in seconds, is out of bounds.
+    OffsetOutOfBounds(i32),
+    /// Indicates an invalid format error.
+    InvalidFormat(String),
+}
+
+impl Display for ZonedTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ZonedTimeError::ProviderError(err) => write!(f, "Provider error: {}", err),
+            ZonedTimeError::TimeError(err) => write!(f, "Time error: {}", err),
+            ZonedTimeError::ZoneError(err) => write!(f, "Zone error: {}", err),
+            ZonedTimeError::OffsetOutOfBounds(offset) => write!(
+                f,
+                "Offset of {} seconds is out of bounds (-{} to {}).",
+                offset, MAX_OFFSET_SECONDS, MAX_OFFSET_SECONDS
+            ),
+            ZonedTimeError::InvalidFormat(msg) => write!(f, "Invalid format: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ZonedTimeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ZonedTimeError::ProviderError(err) => Some(err),
+            ZonedTimeError::TimeError(err) => Some(err),
+            ZonedTimeError::ZoneError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProviderError> for ZonedTimeError {
+    fn from(error: ProviderError) -> Self {
+        ZonedTimeError::ProviderError(error)
+    }
+}
+
+impl From<TimeError> for ZonedTimeError {
+    fn from(error: TimeError) -> Self {
+        ZonedTimeError::TimeError(error)
+    }
+}
+
+impl From<DayToSecondDurationError> for ZonedTimeError {
+    fn from(error: DayToSecondDurationError) -> Self {
+        ZonedTimeError::ZoneError(error)
+    }
+}
+
+impl From<ZonedTimeError> for SetEqualToError {
+    fn from(error: ZonedTimeError) -> Self {
+        SetEqualToError::ZonedTimeError(error)
+    }
+}
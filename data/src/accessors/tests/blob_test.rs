@@ -0,0 +1,159 @@
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    io::{Read, Seek, SeekFrom, Write},
+};
+
+use crate::{
+    accessors::blob::BlobError,
+    data_spec_builders::blob_spec_builder::BlobSpecBuilder,
+    set_equal_to::{SetEqualTo, SetEqualToError},
+    variable::Variable,
+};
+
+#[test]
+fn blob_read_and_write_at() {
+    let spec = BlobSpecBuilder::new().build();
+    let mut var = Variable::new(&spec);
+    let blob = var.blob_mut();
+    assert!(blob.is_empty());
+
+    blob.write_at(0, b"hello world").unwrap();
+    assert_eq!(blob.len(), 11);
+    blob.write_at(6, b"WORLD").unwrap();
+
+    let mut buffer = [0u8; 5];
+    assert_eq!(blob.read_at(6, &mut buffer).unwrap(), 5);
+    assert_eq!(&buffer, b"WORLD");
+    // Reads stop at the end of the BLOB.
+    assert_eq!(blob.read_at(9, &mut buffer).unwrap(), 2);
+    assert_eq!(&buffer[..2], b"LD");
+    assert_eq!(blob.read_at(20, &mut buffer).unwrap(), 0);
+
+    // Writing beyond the end fills the gap with zeros.
+    blob.write_at(13, b"!").unwrap();
+    assert_eq!(blob.bytes().unwrap(), b"hello WORLD\0\0!");
+
+    blob.truncate(5).unwrap();
+    assert_eq!(blob.bytes().unwrap(), b"hello");
+    blob.truncate(10).unwrap();
+    assert_eq!(blob.len(), 5);
+    blob.append(b", again").unwrap();
+    assert_eq!(blob.bytes().unwrap(), b"hello, again");
+    blob.clear().unwrap();
+    assert!(blob.is_empty());
+}
+
+#[test]
+fn blob_max_size() {
+    let spec = BlobSpecBuilder::new().set_max_size(8).build();
+    let mut var = Variable::new(&spec);
+    let blob = var.blob_mut();
+    blob.set_bytes(b"12345678").unwrap();
+    assert_eq!(blob.append(b"9"), Err(BlobError::MaxSizeViolation(8, 9)));
+    match blob.write_at(6, b"abc") {
+        Err(e) => assert_eq!(
+            e.to_string(),
+            "A length of 9 bytes would exceed the maximum size of 8 bytes"
+        ),
+        Ok(_) => panic!("Expected an error"),
+    }
+    // A rejected replacement leaves the BLOB unchanged.
+    assert!(blob.set_bytes(b"123456789").is_err());
+    assert_eq!(blob.bytes().unwrap(), b"12345678");
+    blob.set_bytes(b"abc").unwrap();
+    assert_eq!(blob.bytes().unwrap(), b"abc");
+}
+
+#[test]
+fn blob_write_out_of_range() {
+    let mut var = Variable::new(&BlobSpecBuilder::new().build());
+    let blob = var.blob_mut();
+    blob.set_bytes(b"abc").unwrap();
+    assert_eq!(
+        blob.write_at(u64::MAX - 1, b"abc"),
+        Err(BlobError::WriteOutOfRange(u64::MAX - 1, 3))
+    );
+    assert_eq!(
+        blob.write_at(1 << 60, b"x").unwrap_err().to_string(),
+        "A write of 1 bytes at offset 1152921504606846976 cannot be held by the BLOB's storage"
+    );
+    assert_eq!(blob.bytes().unwrap(), b"abc");
+}
+
+#[test]
+fn blob_chunks() {
+    let var = Variable::try_from(&b"abcdefgh"[..]).unwrap();
+    let chunks: Vec<Vec<u8>> = var.blob().chunks(3).map(|c| c.unwrap()).collect();
+    assert_eq!(
+        chunks,
+        vec![b"abc".to_vec(), b"def".to_vec(), b"gh".to_vec()]
+    );
+}
+
+#[test]
+fn blob_reader() {
+    let var = Variable::try_from(&b"0123456789"[..]).unwrap();
+    let mut reader = var.blob().reader();
+    let mut buffer = [0u8; 4];
+    reader.read_exact(&mut buffer).unwrap();
+    assert_eq!(&buffer, b"0123");
+
+    assert_eq!(reader.seek(SeekFrom::End(-3)).unwrap(), 7);
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, b"789");
+
+    assert_eq!(reader.seek(SeekFrom::Current(-5)).unwrap(), 5);
+    assert!(reader.seek(SeekFrom::Current(-6)).is_err());
+}
+
+#[test]
+fn blob_cursor() {
+    let spec = BlobSpecBuilder::new().set_max_size(16).build();
+    let mut var = Variable::new(&spec);
+    let blob = var.blob_mut();
+    {
+        let mut cursor = blob.cursor();
+        std::io::copy(&mut &b"streamed"[..], &mut cursor).unwrap();
+        cursor.seek(SeekFrom::Start(0)).unwrap();
+        cursor.write_all(b"S").unwrap();
+        let mut rest = String::new();
+        cursor.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "treamed");
+        // Writes beyond the maximum size surface as I/O errors.
+        assert!(cursor.write_all(b"0123456789").is_err());
+    }
+    assert_eq!(blob.bytes().unwrap(), b"Streamed");
+}
+
+#[test]
+fn blob_set_equal_to() {
+    let mut var1 = Variable::new(&BlobSpecBuilder::new().build());
+    let var2 = Variable::try_from(&b"data"[..]).unwrap();
+    var1.set_equal_to(&var2).unwrap();
+    assert_eq!(var1, var2);
+    let bytes: Vec<u8> = var1.clone().try_into().unwrap();
+    assert_eq!(bytes, b"data");
+
+    let var3 = Variable::new(&BlobSpecBuilder::new().set_media_type("image/png").build());
+    match var1.set_equal_to(&var3) {
+        Err(SetEqualToError::SpecError(_)) => {}
+        other => panic!("Expected SpecError, got {:?}", other),
+    }
+    assert_eq!(var3.to_string(), "<0 bytes of image/png>");
+}
+
+#[test]
+fn blob_hash() {
+    let a = Variable::try_from(&b"same"[..]).unwrap();
+    let b = Variable::try_from(&b"same"[..]).unwrap();
+    let c = Variable::try_from(&b"different"[..]).unwrap();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    let mut hasher1 = DefaultHasher::new();
+    a.hash(&mut hasher1);
+    let mut hasher2 = DefaultHasher::new();
+    b.hash(&mut hasher2);
+    assert_eq!(hasher1.finish(), hasher2.finish());
+}
@@ -0,0 +1,150 @@
+use crate::{
+    accessors::string::StringError,
+    data_spec_builders::string_spec_builder::StringSpecBuilder,
+    primitive_specs::string_spec::{ByteCodePage, StringEncoding, StringStorage},
+    set_equal_to::SetEqualTo,
+    variable::Variable,
+};
+
+#[test]
+fn byte_string_bytes() {
+    let spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.byte_string_mut();
+    assert!(string.is_empty());
+
+    string.set_bytes(&[0x41, 0x42, 0x43]).unwrap();
+    string.append_bytes(&[0x44]).unwrap();
+    assert_eq!(string.bytes().unwrap(), vec![0x41, 0x42, 0x43, 0x44]);
+    assert_eq!(string.len(), 4);
+    assert_eq!(string.byte(2).unwrap(), 0x43);
+
+    string.set_byte(0, 0x61).unwrap();
+    assert_eq!(string.string().unwrap(), "aBCD");
+    assert_eq!(string.slice(1..3).unwrap(), vec![0x42, 0x43]);
+
+    assert_eq!(string.byte(4), Err(StringError::IndexOutOfBounds(4, 4)));
+    assert_eq!(
+        string.set_byte(5, 0),
+        Err(StringError::IndexOutOfBounds(5, 4))
+    );
+    assert_eq!(string.slice(2..5), Err(StringError::IndexOutOfBounds(5, 4)));
+}
+
+#[test]
+fn byte_string_ascii() {
+    let spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Ascii)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.byte_string_mut();
+    string.set_string("plain").unwrap();
+    assert_eq!(string.bytes().unwrap(), b"plain".to_vec());
+
+    assert_eq!(
+        string.set_string("café"),
+        Err(StringError::UnmappableCharacter(3, 'é'))
+    );
+    string.set_bytes(&[0x61, 0xE9]).unwrap();
+    match string.string() {
+        Err(e) => assert_eq!(
+            e.to_string(),
+            "Byte 0xE9 at index 1 does not represent a character in the code page"
+        ),
+        Ok(_) => panic!("Expected an error"),
+    }
+    assert_eq!(string.to_string(), "a\u{FFFD}");
+}
+
+#[test]
+fn byte_string_latin1() {
+    let spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Latin1)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.byte_string_mut();
+    string.set_string("café").unwrap();
+    assert_eq!(string.bytes().unwrap(), vec![0x63, 0x61, 0x66, 0xE9]);
+    assert_eq!(string.string().unwrap(), "café");
+
+    string.set_bytes(&[0x80]).unwrap();
+    assert_eq!(string.string().unwrap(), "\u{80}");
+    assert_eq!(
+        string.set_string("€"),
+        Err(StringError::UnmappableCharacter(0, '€'))
+    );
+}
+
+#[test]
+fn byte_string_windows_1252() {
+    let spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Windows1252)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.byte_string_mut();
+    string.set_string("€5 – “ok”").unwrap();
+    assert_eq!(
+        string.bytes().unwrap(),
+        vec![0x80, 0x35, 0x20, 0x96, 0x20, 0x93, 0x6F, 0x6B, 0x94]
+    );
+    assert_eq!(string.string().unwrap(), "€5 – “ok”");
+
+    // 0x81 is undefined in Windows-1252.
+    string.set_bytes(&[0x81]).unwrap();
+    assert_eq!(string.string(), Err(StringError::UnmappableByte(0, 0x81)));
+    // C1 control characters have no Windows-1252 representation.
+    assert_eq!(
+        string.set_string("\u{81}"),
+        Err(StringError::UnmappableCharacter(0, '\u{81}'))
+    );
+}
+
+#[test]
+fn byte_string_fixed_size_record() {
+    let spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::FixedSize(8))
+        .set_code_page(ByteCodePage::Latin1)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.byte_string_mut();
+    assert_eq!(string.bytes().unwrap(), b"        ".to_vec());
+
+    string.set_string("ACME").unwrap();
+    assert_eq!(string.string().unwrap(), "ACME    ");
+    assert_eq!(
+        string.set_string("ACME CORP"),
+        Err(StringError::FixedSizeViolation(8, 9))
+    );
+    assert_eq!(
+        string.append_bytes(b"!"),
+        Err(StringError::FixedSizeViolation(8, 9))
+    );
+    string.set_byte(7, b'!').unwrap();
+    assert_eq!(string.string().unwrap(), "ACME   !");
+}
+
+#[test]
+fn byte_string_set_equal_to() {
+    let spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Latin1)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    var1.byte_string_mut().set_string("über").unwrap();
+    let mut var2 = Variable::new(&spec);
+    var2.set_equal_to(&var1).unwrap();
+    assert_eq!(var1, var2);
+    assert_eq!(var2.to_string(), "über");
+
+    let ascii_spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Ascii)
+        .build();
+    let mut var3 = Variable::new(&ascii_spec);
+    assert!(var3.set_equal_to(&var1).is_err());
+}
@@ -0,0 +1,221 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{
+    accessors::character::{CharacterError, GeneralCategory},
+    data_spec_builders::{
+        character_spec_builder::CharacterSpecBuilder, set_spec_builder::SetSpecBuilder,
+        string_spec_builder::StringSpecBuilder,
+    },
+    primitive_specs::{
+        character_spec::CharacterStorage,
+        set_spec::SetElementOrdering,
+        string_spec::{ByteCodePage, StringEncoding, StringStorage},
+    },
+    set_equal_to::{SetEqualTo, SetEqualToError},
+    variable::Variable,
+};
+
+#[test]
+fn character_set_and_get() {
+    let spec = CharacterSpecBuilder::new()
+        .set_storage(CharacterStorage::Scalar)
+        .build();
+    let mut var = Variable::new(&spec);
+    let character = var.character_mut();
+    assert_eq!(character.character().unwrap(), '\0');
+
+    character.set_character('😀').unwrap();
+    assert_eq!(character.character().unwrap(), '😀');
+    assert_eq!(var.to_string(), "😀");
+}
+
+#[test]
+fn character_storage_limits() {
+    let spec = CharacterSpecBuilder::new()
+        .set_storage(CharacterStorage::Utf16CodeUnit)
+        .build();
+    let mut var = Variable::new(&spec);
+    let character = var.character_mut();
+    character.set_character('€').unwrap();
+    assert_eq!(
+        character.set_character('😀'),
+        Err(CharacterError::UnrepresentableCharacter('😀'))
+    );
+    assert_eq!(character.character().unwrap(), '€');
+
+    let spec = CharacterSpecBuilder::new()
+        .set_storage(CharacterStorage::Byte)
+        .set_code_page(ByteCodePage::Windows1252)
+        .build();
+    let mut var = Variable::new(&spec);
+    let character = var.character_mut();
+    character.set_character('€').unwrap();
+    assert_eq!(character.character().unwrap(), '€');
+    match character.set_character('Ā') {
+        Err(e) => assert_eq!(
+            e.to_string(),
+            "Character 'Ā' cannot be represented by the character's storage"
+        ),
+        Ok(_) => panic!("Expected an error"),
+    }
+
+    // A byte character without a code page is ASCII.
+    let spec = CharacterSpecBuilder::new()
+        .set_storage(CharacterStorage::Byte)
+        .build();
+    let mut var = Variable::new(&spec);
+    assert!(var.character_mut().set_character('é').is_err());
+}
+
+#[test]
+fn character_classification() {
+    let var = Variable::try_from('A').unwrap();
+    let character = var.character();
+    assert_eq!(
+        character.general_category().unwrap(),
+        GeneralCategory::UppercaseLetter
+    );
+    assert!(character.is_alphabetic().unwrap());
+    assert!(character.is_uppercase().unwrap());
+    assert!(!character.is_lowercase().unwrap());
+    assert_eq!(character.to_lowercase().unwrap(), "a");
+
+    let var = Variable::try_from('ß').unwrap();
+    assert!(var.character().is_lowercase().unwrap());
+    assert_eq!(var.character().to_uppercase().unwrap(), "SS");
+
+    let var = Variable::try_from('٣').unwrap();
+    assert_eq!(
+        var.character().general_category().unwrap(),
+        GeneralCategory::DecimalNumber
+    );
+    assert!(var.character().is_numeric().unwrap());
+
+    let var = Variable::try_from('\u{2003}').unwrap();
+    assert!(var.character().is_whitespace().unwrap());
+    let category = var.character().general_category().unwrap();
+    assert_eq!(category, GeneralCategory::SpaceSeparator);
+    assert!(category.is_separator());
+    assert_eq!(category.to_string(), "Zs");
+
+    assert_eq!(GeneralCategory::of('€'), GeneralCategory::CurrencySymbol);
+    assert_eq!(
+        GeneralCategory::of('\u{0301}'),
+        GeneralCategory::NonspacingMark
+    );
+    assert_eq!(GeneralCategory::of('\u{E000}'), GeneralCategory::PrivateUse);
+    assert!(GeneralCategory::of('\u{7}').is_other());
+    assert!(var.character().is_control().is_ok_and(|control| !control));
+}
+
+#[test]
+fn character_set_equal_to() {
+    let mut var1 = Variable::try_from('x').unwrap();
+    let var2 = Variable::try_from('y').unwrap();
+    var1.set_equal_to(&var2).unwrap();
+    assert_eq!(var1, var2);
+
+    let byte_spec = CharacterSpecBuilder::new()
+        .set_storage(CharacterStorage::Byte)
+        .build();
+    let mut var3 = Variable::new(&byte_spec);
+    match var3.set_equal_to(&var2) {
+        Err(SetEqualToError::SpecError(_)) => {}
+        other => panic!("Expected SpecError, got {:?}", other),
+    }
+}
+
+#[test]
+fn character_try_from() {
+    let var = Variable::try_from('ü').unwrap();
+    let value: char = var.try_into().unwrap();
+    assert_eq!(value, 'ü');
+}
+
+#[test]
+fn character_ordering_and_hash() {
+    let a = Variable::try_from('a').unwrap();
+    let b = Variable::try_from('b').unwrap();
+    let upper_a = Variable::try_from('A').unwrap();
+    let a2 = Variable::try_from('a').unwrap();
+    // Characters are ordered by scalar value.
+    assert!(upper_a < a);
+    assert!(a < b);
+    assert_eq!(a, a2);
+
+    let mut hasher1 = DefaultHasher::new();
+    a.hash(&mut hasher1);
+    let mut hasher2 = DefaultHasher::new();
+    a2.hash(&mut hasher2);
+    assert_eq!(hasher1.finish(), hasher2.finish());
+
+    let set_spec = SetSpecBuilder::new()
+        .set_value_spec(
+            CharacterSpecBuilder::new()
+                .set_storage(CharacterStorage::Scalar)
+                .build(),
+        )
+        .set_storage(SetElementOrdering::Unordered)
+        .build();
+    let mut set_var = Variable::new(&set_spec);
+    let set = set_var.set_mut();
+    assert!(set.insert(a).unwrap());
+    assert!(!set.insert(a2).unwrap());
+    assert!(set.insert(b).unwrap());
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn string_characters() {
+    for encoding in [
+        StringEncoding::Utf8,
+        StringEncoding::Utf16,
+        StringEncoding::Utf32,
+    ] {
+        let spec = StringSpecBuilder::new(encoding)
+            .set_storage(StringStorage::VariableSize)
+            .build();
+        let mut var = Variable::new(&spec);
+        var.string_mut().set_string("a€😀").unwrap();
+        let characters: Vec<Variable> = var
+            .string()
+            .characters()
+            .unwrap()
+            .map(|c| c.unwrap())
+            .collect();
+        assert_eq!(characters.len(), 3);
+        assert_eq!(
+            *characters[2].character().spec().storage(),
+            Some(CharacterStorage::Scalar)
+        );
+        let values: Vec<char> = characters
+            .iter()
+            .map(|c| c.character().character().unwrap())
+            .collect();
+        assert_eq!(values, vec!['a', '€', '😀']);
+    }
+}
+
+#[test]
+fn byte_string_characters() {
+    let spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Windows1252)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.byte_string_mut().set_string("€5").unwrap();
+    let characters: Vec<Variable> = var
+        .string()
+        .characters()
+        .unwrap()
+        .map(|c| c.unwrap())
+        .collect();
+    let character = characters[0].character();
+    assert_eq!(*character.spec().storage(), Some(CharacterStorage::Byte));
+    assert_eq!(
+        *character.spec().code_page(),
+        Some(ByteCodePage::Windows1252)
+    );
+    assert_eq!(character.character().unwrap(), '€');
+    assert!(characters[1].character().is_numeric().unwrap());
+}
@@ -0,0 +1,168 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{
+    accessors::guid::{GuidError, GuidFormat},
+    data_spec_builders::{
+        guid_spec_builder::GuidSpecBuilder, integer_spec_builder::IntegerSpecBuilder,
+        map_spec_builder::MapSpecBuilder,
+    },
+    primitive_specs::{
+        guid_spec::GuidVersion,
+        integer_spec::{IntegerEncoding, IntegerStorage},
+        map_spec::MapKeyOrdering,
+    },
+    set_equal_to::{SetEqualTo, SetEqualToError},
+    variable::Variable,
+};
+
+const CANONICAL: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+#[test]
+fn guid_parse_and_format() {
+    let mut var = Variable::new(&GuidSpecBuilder::new().build());
+    let guid = var.guid_mut();
+    assert!(guid.is_nil().unwrap());
+    assert_eq!(guid.version().unwrap(), None);
+
+    for form in [
+        CANONICAL,
+        "67E55044-10B1-426F-9247-BB680E5FE0C8",
+        "67e5504410b1426f9247bb680e5fe0c8",
+        "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+        "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ] {
+        guid.set_from_string(form).unwrap();
+        assert_eq!(guid.to_string(), CANONICAL);
+    }
+    assert_eq!(guid.version().unwrap(), Some(GuidVersion::V4));
+    assert_eq!(
+        guid.format(GuidFormat::Simple).unwrap(),
+        "67e5504410b1426f9247bb680e5fe0c8"
+    );
+    assert_eq!(
+        guid.format(GuidFormat::Braced).unwrap(),
+        "{67e55044-10b1-426f-9247-bb680e5fe0c8}"
+    );
+    assert_eq!(
+        guid.format(GuidFormat::Urn).unwrap(),
+        "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"
+    );
+    assert_eq!(guid.bytes().unwrap()[..4], [0x67, 0xe5, 0x50, 0x44]);
+
+    assert!(matches!(
+        guid.set_from_string("67e55044-10b1-426f-9247"),
+        Err(GuidError::InvalidFormat(_))
+    ));
+    assert_eq!(guid.to_string(), CANONICAL);
+}
+
+#[test]
+fn guid_generate() {
+    let mut var = Variable::new(&GuidSpecBuilder::new().build());
+    let guid = var.guid_mut();
+    guid.generate().unwrap();
+    assert_eq!(guid.version().unwrap(), Some(GuidVersion::V4));
+    let first = guid.bytes().unwrap();
+    guid.generate_v4().unwrap();
+    assert_ne!(guid.bytes().unwrap(), first);
+
+    // Version 7 GUIDs sort by creation time.
+    let mut earlier = Variable::new(&GuidSpecBuilder::new().set_version(GuidVersion::V7).build());
+    earlier.guid_mut().generate().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    let mut later = Variable::new(&GuidSpecBuilder::new().set_version(GuidVersion::V7).build());
+    later.guid_mut().generate().unwrap();
+    assert_eq!(later.guid().version().unwrap(), Some(GuidVersion::V7));
+    assert!(earlier < later);
+
+    let mut v5 = Variable::new(&GuidSpecBuilder::new().set_version(GuidVersion::V5).build());
+    assert_eq!(
+        v5.guid_mut().generate(),
+        Err(GuidError::UnsupportedGeneration(GuidVersion::V5))
+    );
+}
+
+#[test]
+fn guid_pinned_version() {
+    let mut var = Variable::new(&GuidSpecBuilder::new().set_version(GuidVersion::V7).build());
+    let guid = var.guid_mut();
+    assert_eq!(
+        guid.set_from_string(CANONICAL),
+        Err(GuidError::VersionMismatch(7, 4))
+    );
+    assert_eq!(guid.generate_v4(), Err(GuidError::VersionMismatch(7, 4)));
+    // The nil GUID is always accepted.
+    guid.set_bytes(&[0; 16]).unwrap();
+    guid.generate_v7().unwrap();
+}
+
+#[test]
+fn guid_set_equal_to_and_try_from() {
+    let mut bytes = [0u8; 16];
+    bytes[6] = 0x40;
+    bytes[15] = 1;
+    let var1 = Variable::try_from(bytes).unwrap();
+    let mut var2 = Variable::new(&GuidSpecBuilder::new().build());
+    var2.set_equal_to(&var1).unwrap();
+    assert_eq!(var1, var2);
+    let value: [u8; 16] = var2.try_into().unwrap();
+    assert_eq!(value, bytes);
+
+    let mut var3 = Variable::new(&GuidSpecBuilder::new().build());
+    let pinned = Variable::new(&GuidSpecBuilder::new().set_version(GuidVersion::V4).build());
+    match var3.set_equal_to(&pinned) {
+        Err(SetEqualToError::SpecError(_)) => {}
+        other => panic!("Expected SpecError, got {:?}", other),
+    }
+}
+
+#[test]
+fn guid_ordering_and_hash() {
+    let mut low = [0u8; 16];
+    low[15] = 1;
+    let mut high = [0u8; 16];
+    high[0] = 1;
+    let a = Variable::try_from(low).unwrap();
+    let b = Variable::try_from(high).unwrap();
+    let a2 = Variable::try_from(low).unwrap();
+    assert!(a < b);
+    assert_eq!(a, a2);
+
+    let mut hasher1 = DefaultHasher::new();
+    a.hash(&mut hasher1);
+    let mut hasher2 = DefaultHasher::new();
+    a2.hash(&mut hasher2);
+    assert_eq!(hasher1.finish(), hasher2.finish());
+}
+
+#[test]
+fn guid_map_keys() {
+    let value_spec = IntegerSpecBuilder::new()
+        .set_encoding(IntegerEncoding::Signed)
+        .set_storage(IntegerStorage::B64)
+        .build();
+    for ordering in [MapKeyOrdering::Ordered, MapKeyOrdering::Unordered] {
+        let map_spec = MapSpecBuilder::new()
+            .set_key_spec(GuidSpecBuilder::new().build())
+            .set_value_spec(value_spec.clone())
+            .set_key_ordering(ordering)
+            .build();
+        let mut map_var = Variable::new(&map_spec);
+        let map = map_var.map_mut();
+
+        let mut key = Variable::new(&GuidSpecBuilder::new().build());
+        key.guid_mut().set_from_string(CANONICAL).unwrap();
+        map.insert(key.clone(), Variable::try_from(1i64).unwrap())
+            .unwrap();
+        let mut other = Variable::new(&GuidSpecBuilder::new().build());
+        other.guid_mut().generate().unwrap();
+        map.insert(other, Variable::try_from(2i64).unwrap())
+            .unwrap();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(
+            map.get(&key).unwrap(),
+            Some(Variable::try_from(1i64).unwrap())
+        );
+    }
+}
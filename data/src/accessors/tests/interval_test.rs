@@ -0,0 +1,555 @@
+use crate::{
+    accessors::interval::{AllenRelation, IntervalError},
+    data_spec_builders::{
+        date_spec_builder::DateSpecBuilder, float_spec_builder::FloatSpecBuilder,
+        integer_spec_builder::IntegerSpecBuilder, interval_spec_builder::IntervalSpecBuilder,
+    },
+    primitive_specs::{
+        float_spec::FloatStorage,
+        integer_spec::{IntegerEncoding, IntegerStorage},
+        interval_spec::BoundType,
+    },
+    set_equal_to::{SetEqualTo, SetEqualToError},
+    spec_compatibility::SpecError,
+    variable::Variable,
+};
+
+#[test]
+fn interval_set() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+
+    // Verify initial values, which make up an empty interval with the spec's bounds
+    assert_eq!(var.interval().start().unwrap().integer().i64().unwrap(), 0);
+    assert_eq!(var.interval().end().unwrap().integer().i64().unwrap(), 0);
+    assert_eq!(var.interval().start_bound().unwrap(), BoundType::Closed);
+    assert_eq!(var.interval().end_bound().unwrap(), BoundType::Open);
+    assert!(var.interval().is_empty().unwrap());
+
+    var.interval_mut()
+        .set(
+            Variable::try_from(3i64).unwrap(),
+            Variable::try_from(7i64).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(var.interval().start().unwrap().integer().i64().unwrap(), 3);
+    assert_eq!(var.interval().end().unwrap().integer().i64().unwrap(), 7);
+    assert!(!var.interval().is_empty().unwrap());
+    assert_eq!(var.to_string(), "[3, 7)");
+}
+
+#[test]
+fn interval_set_errors() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    assert_eq!(
+        var.interval_mut().set(
+            Variable::try_from(7i64).unwrap(),
+            Variable::try_from(3i64).unwrap(),
+        ),
+        Err(IntervalError::StartAfterEnd(
+            "7".to_string(),
+            "3".to_string()
+        ))
+    );
+    assert!(matches!(
+        var.interval_mut().set(
+            Variable::try_from(1.5f64).unwrap(),
+            Variable::try_from(3i64).unwrap(),
+        ),
+        Err(IntervalError::ValueSpecError(..))
+    ));
+}
+
+#[test]
+fn interval_empty() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let interval = |start: i64, start_bound: BoundType, end: i64, end_bound: BoundType| {
+        let mut var = Variable::new(&spec);
+        var.interval_mut()
+            .set_with_bounds(
+                Variable::try_from(start).unwrap(),
+                start_bound,
+                Variable::try_from(end).unwrap(),
+                end_bound,
+            )
+            .unwrap();
+        var
+    };
+    assert!(
+        interval(3, BoundType::Open, 3, BoundType::Open)
+            .interval()
+            .is_empty()
+            .unwrap()
+    );
+    assert!(
+        interval(3, BoundType::Closed, 3, BoundType::Open)
+            .interval()
+            .is_empty()
+            .unwrap()
+    );
+    assert!(
+        !interval(3, BoundType::Closed, 3, BoundType::Closed)
+            .interval()
+            .is_empty()
+            .unwrap()
+    );
+
+    let empty = interval(3, BoundType::Closed, 3, BoundType::Open);
+    assert_eq!(
+        empty
+            .interval()
+            .relation(interval(1, BoundType::Closed, 5, BoundType::Closed).interval()),
+        Err(IntervalError::Empty("[3, 3)".to_string()))
+    );
+}
+
+#[test]
+fn interval_contains() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let interval = |start: i64, start_bound: BoundType, end: i64, end_bound: BoundType| {
+        let mut var = Variable::new(&spec);
+        var.interval_mut()
+            .set_with_bounds(
+                Variable::try_from(start).unwrap(),
+                start_bound,
+                Variable::try_from(end).unwrap(),
+                end_bound,
+            )
+            .unwrap();
+        var
+    };
+    let var = interval(3, BoundType::Closed, 7, BoundType::Open);
+    let contains = |value: i64| {
+        var.interval()
+            .contains(&Variable::try_from(value).unwrap())
+            .unwrap()
+    };
+    assert!(!contains(2));
+    assert!(contains(3));
+    assert!(contains(6));
+    assert!(!contains(7));
+    let var = interval(3, BoundType::Open, 7, BoundType::Closed);
+    assert!(
+        !var.interval()
+            .contains(&Variable::try_from(3i64).unwrap())
+            .unwrap()
+    );
+    assert!(
+        var.interval()
+            .contains(&Variable::try_from(7i64).unwrap())
+            .unwrap()
+    );
+}
+
+#[test]
+fn interval_relations() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let interval = |start: i64, start_bound: BoundType, end: i64, end_bound: BoundType| {
+        let mut var = Variable::new(&spec);
+        var.interval_mut()
+            .set_with_bounds(
+                Variable::try_from(start).unwrap(),
+                start_bound,
+                Variable::try_from(end).unwrap(),
+                end_bound,
+            )
+            .unwrap();
+        var
+    };
+    let relation = |a: &Variable, b: &Variable| a.interval().relation(b.interval()).unwrap();
+    let cases = [
+        (
+            interval(1, BoundType::Closed, 2, BoundType::Closed),
+            interval(4, BoundType::Closed, 5, BoundType::Closed),
+            AllenRelation::Before,
+        ),
+        (
+            interval(1, BoundType::Closed, 3, BoundType::Open),
+            interval(3, BoundType::Closed, 5, BoundType::Open),
+            AllenRelation::Meets,
+        ),
+        (
+            interval(1, BoundType::Closed, 3, BoundType::Closed),
+            interval(2, BoundType::Closed, 5, BoundType::Closed),
+            AllenRelation::Overlaps,
+        ),
+        (
+            interval(1, BoundType::Closed, 3, BoundType::Closed),
+            interval(1, BoundType::Closed, 5, BoundType::Closed),
+            AllenRelation::Starts,
+        ),
+        (
+            interval(2, BoundType::Closed, 3, BoundType::Closed),
+            interval(1, BoundType::Closed, 5, BoundType::Closed),
+            AllenRelation::During,
+        ),
+        (
+            interval(3, BoundType::Closed, 5, BoundType::Closed),
+            interval(1, BoundType::Closed, 5, BoundType::Closed),
+            AllenRelation::Finishes,
+        ),
+        (
+            interval(1, BoundType::Closed, 5, BoundType::Closed),
+            interval(1, BoundType::Closed, 5, BoundType::Closed),
+            AllenRelation::Equals,
+        ),
+    ];
+    for (a, b, expected) in cases.iter() {
+        assert_eq!(relation(a, b), *expected, "{} to {}", a, b);
+        assert_eq!(relation(b, a), expected.inverse(), "{} to {}", b, a);
+    }
+}
+
+#[test]
+fn interval_relations_with_bounds() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let interval = |start: i64, start_bound: BoundType, end: i64, end_bound: BoundType| {
+        let mut var = Variable::new(&spec);
+        var.interval_mut()
+            .set_with_bounds(
+                Variable::try_from(start).unwrap(),
+                start_bound,
+                Variable::try_from(end).unwrap(),
+                end_bound,
+            )
+            .unwrap();
+        var
+    };
+    let relation = |a: &Variable, b: &Variable| a.interval().relation(b.interval()).unwrap();
+
+    // Intervals meet when exactly one of them includes the shared value
+    let a = interval(1, BoundType::Open, 3, BoundType::Closed);
+    let b = interval(3, BoundType::Open, 5, BoundType::Open);
+    assert_eq!(relation(&a, &b), AllenRelation::Meets);
+
+    // If both include it they overlap, and if neither does a gap lies between them
+    assert_eq!(
+        relation(
+            &interval(1, BoundType::Closed, 3, BoundType::Closed),
+            &interval(3, BoundType::Closed, 5, BoundType::Closed)
+        ),
+        AllenRelation::Overlaps
+    );
+    let b = interval(3, BoundType::Open, 5, BoundType::Closed);
+    assert_eq!(
+        relation(&interval(1, BoundType::Closed, 3, BoundType::Open), &b),
+        AllenRelation::Before
+    );
+
+    // An open start is later than a closed one at the same value
+    let b = interval(1, BoundType::Open, 5, BoundType::Closed);
+    assert_eq!(
+        relation(&interval(1, BoundType::Closed, 5, BoundType::Closed), &b),
+        AllenRelation::FinishedBy
+    );
+}
+
+#[test]
+fn interval_intersect() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let interval = |start: i64, start_bound: BoundType, end: i64, end_bound: BoundType| {
+        let mut var = Variable::new(&spec);
+        var.interval_mut()
+            .set_with_bounds(
+                Variable::try_from(start).unwrap(),
+                start_bound,
+                Variable::try_from(end).unwrap(),
+                end_bound,
+            )
+            .unwrap();
+        var
+    };
+    let mut var = interval(1, BoundType::Closed, 5, BoundType::Open);
+    var.interval_mut()
+        .intersect(interval(3, BoundType::Closed, 8, BoundType::Closed).interval())
+        .unwrap();
+    assert_eq!(var.to_string(), "[3, 5)");
+
+    let mut var = interval(1, BoundType::Closed, 3, BoundType::Open);
+    assert_eq!(
+        var.interval_mut()
+            .intersect(interval(3, BoundType::Closed, 5, BoundType::Open).interval()),
+        Err(IntervalError::Disjoint)
+    );
+    assert!(
+        var.interval()
+            .intersects(interval(2, BoundType::Closed, 3, BoundType::Closed).interval())
+            .unwrap()
+    );
+    assert!(
+        !var.interval()
+            .intersects(interval(3, BoundType::Closed, 4, BoundType::Closed).interval())
+            .unwrap()
+    );
+}
+
+#[test]
+fn interval_unite() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let interval = |start: i64, start_bound: BoundType, end: i64, end_bound: BoundType| {
+        let mut var = Variable::new(&spec);
+        var.interval_mut()
+            .set_with_bounds(
+                Variable::try_from(start).unwrap(),
+                start_bound,
+                Variable::try_from(end).unwrap(),
+                end_bound,
+            )
+            .unwrap();
+        var
+    };
+    let mut var = interval(1, BoundType::Closed, 3, BoundType::Open);
+    var.interval_mut()
+        .unite(interval(3, BoundType::Closed, 5, BoundType::Open).interval())
+        .unwrap();
+    assert_eq!(var.to_string(), "[1, 5)");
+
+    var.interval_mut()
+        .unite(interval(0, BoundType::Open, 4, BoundType::Closed).interval())
+        .unwrap();
+    assert_eq!(var.to_string(), "(0, 5)");
+
+    assert_eq!(
+        var.interval_mut()
+            .unite(interval(6, BoundType::Closed, 8, BoundType::Closed).interval()),
+        Err(IntervalError::Disjoint)
+    );
+}
+
+#[test]
+fn interval_set_to_gap() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let interval = |start: i64, start_bound: BoundType, end: i64, end_bound: BoundType| {
+        let mut var = Variable::new(&spec);
+        var.interval_mut()
+            .set_with_bounds(
+                Variable::try_from(start).unwrap(),
+                start_bound,
+                Variable::try_from(end).unwrap(),
+                end_bound,
+            )
+            .unwrap();
+        var
+    };
+    let mut var = interval(6, BoundType::Closed, 8, BoundType::Open);
+    var.interval_mut()
+        .set_to_gap(interval(1, BoundType::Closed, 3, BoundType::Closed).interval())
+        .unwrap();
+    assert_eq!(var.to_string(), "(3, 6)");
+
+    let mut var = interval(1, BoundType::Closed, 3, BoundType::Open);
+    var.interval_mut()
+        .set_to_gap(interval(3, BoundType::Closed, 5, BoundType::Open).interval())
+        .unwrap();
+    assert_eq!(var.to_string(), "[3, 3)");
+    assert!(var.interval().is_empty().unwrap());
+
+    let mut var = interval(1, BoundType::Closed, 3, BoundType::Closed);
+    assert_eq!(
+        var.interval_mut()
+            .set_to_gap(interval(3, BoundType::Closed, 5, BoundType::Closed).interval()),
+        Err(IntervalError::Overlap)
+    );
+}
+
+#[test]
+fn interval_of_dates() {
+    let date = |year: u32, month: u32, day: u32| {
+        let mut var = Variable::new(&DateSpecBuilder::new().build());
+        var.date_mut().set_date(year, month, day).unwrap();
+        var
+    };
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(DateSpecBuilder::new().build())
+        .build()
+        .unwrap();
+    let mut first_booking = Variable::new(&spec);
+    first_booking
+        .interval_mut()
+        .set(date(2024, 3, 1), date(2024, 3, 4))
+        .unwrap();
+    let mut second_booking = Variable::new(&spec);
+    second_booking
+        .interval_mut()
+        .set(date(2024, 3, 4), date(2024, 3, 9))
+        .unwrap();
+
+    // Checking out on the day of the next check-in does not double-book
+    assert_eq!(
+        first_booking
+            .interval()
+            .relation(second_booking.interval())
+            .unwrap(),
+        AllenRelation::Meets
+    );
+    assert!(first_booking.interval() < second_booking.interval());
+}
+
+#[test]
+fn interval_set_equal_to() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut source = Variable::new(&spec);
+    source
+        .interval_mut()
+        .set_with_bounds(
+            Variable::try_from(2i64).unwrap(),
+            BoundType::Open,
+            Variable::try_from(9i64).unwrap(),
+            BoundType::Closed,
+        )
+        .unwrap();
+    let mut target = Variable::new(&spec);
+    target.set_equal_to(&source).unwrap();
+    assert_eq!(target, source);
+    assert_eq!(target.to_string(), "(2, 9]");
+
+    let float_spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            FloatSpecBuilder::new()
+                .set_storage(FloatStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut target = Variable::new(&float_spec);
+    assert!(matches!(
+        target.set_equal_to(&source),
+        Err(SetEqualToError::SpecError(SpecError::IncompatibleSpec(..)))
+    ));
+}
+
+#[test]
+fn interval_order_with_different_specs() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let interval = |start: i64, start_bound: BoundType, end: i64, end_bound: BoundType| {
+        let mut var = Variable::new(&spec);
+        var.interval_mut()
+            .set_with_bounds(
+                Variable::try_from(start).unwrap(),
+                start_bound,
+                Variable::try_from(end).unwrap(),
+                end_bound,
+            )
+            .unwrap();
+        var
+    };
+    let integer = interval(2, BoundType::Closed, 5, BoundType::Closed);
+    let narrow_spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B32)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut narrow = Variable::new(&narrow_spec);
+    narrow
+        .interval_mut()
+        .set_with_bounds(
+            Variable::try_from(2i32).unwrap(),
+            BoundType::Closed,
+            Variable::try_from(5i32).unwrap(),
+            BoundType::Closed,
+        )
+        .unwrap();
+
+    // Intervals with the same points but different specs are neither equal nor unordered
+    assert_ne!(integer, narrow);
+    assert_ne!(integer.cmp(&narrow), std::cmp::Ordering::Equal);
+    assert_eq!(integer.cmp(&narrow), narrow.cmp(&integer).reverse());
+
+    // Otherwise the points order the intervals
+    assert!(interval(1, BoundType::Closed, 9, BoundType::Closed) < narrow);
+    assert!(narrow < interval(3, BoundType::Closed, 4, BoundType::Closed));
+    assert!(narrow < interval(2, BoundType::Closed, 6, BoundType::Open));
+}
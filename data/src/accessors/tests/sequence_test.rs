@@ -2,8 +2,8 @@ use std::rc::Rc;
 
 use crate::{
     accessors::sequence::{SequenceError, SequenceIter},
-    adaptors::sequence_adaptor::SequenceAdaptor,
-    data_provider::DataProvider,
+    adaptors::sequence_adaptor::{SequenceAdaptor, SequenceGenerator},
+    data_provider::{DataProvider, default_data_provider},
     data_spec_builders::{
         integer_spec_builder::IntegerSpecBuilder, sequence_spec_builder::SequenceSpecBuilder,
     },
@@ -69,6 +69,26 @@ impl DataProvider for TestDataProvider {
     }
 }
 
+struct DoublingGenerator {}
+
+impl SequenceGenerator for DoublingGenerator {
+    fn next_value(
+        &self,
+        index: usize,
+        previous: Option<&Variable>,
+    ) -> Option<Result<Variable, SequenceError>> {
+        match (index, previous) {
+            (0, _) => Some(Ok(Variable::try_from(1i64).unwrap())),
+            (3, _) => Some(Err(SequenceError::ReadOnlyError)),
+            (_, Some(previous)) => Some(Ok(Variable::try_from(
+                previous.integer().i64().unwrap() * 2,
+            )
+            .unwrap())),
+            _ => None,
+        }
+    }
+}
+
 #[test]
 fn test_sequence_adaptor() {
     let spec = Rc::new(SequenceSpec::new(&None));
@@ -141,3 +161,29 @@ fn sequence_set_equal_to() {
     );
     assert!(iter.next().is_none());
 }
+
+#[test]
+fn generated_sequence() {
+    let spec = Rc::new(SequenceSpec::new(&None));
+    let adaptor =
+        default_data_provider().generated_sequence_adaptor(&spec, Box::new(DoublingGenerator {}));
+
+    // The values are generated from the previous ones, and an error is returned as an item
+    let mut iter = adaptor.iter();
+    assert_eq!(iter.next().unwrap().unwrap().integer().i64().unwrap(), 1);
+    assert_eq!(iter.next().unwrap().unwrap().integer().i64().unwrap(), 2);
+    assert_eq!(iter.next().unwrap().unwrap().integer().i64().unwrap(), 4);
+    assert!(matches!(
+        iter.next(),
+        Some(Err(SequenceError::ReadOnlyError))
+    ));
+    assert!(iter.next().is_none());
+
+    // Generated values are kept, so iterating again yields the same values
+    let values: Vec<i64> = adaptor
+        .iter()
+        .take(3)
+        .map(|value| value.unwrap().integer().i64().unwrap())
+        .collect();
+    assert_eq!(values, [1, 2, 4]);
+}
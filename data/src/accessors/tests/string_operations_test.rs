@@ -0,0 +1,193 @@
+use crate::{
+    accessors::string::StringError,
+    data_spec_builders::{
+        list_spec_builder::ListSpecBuilder, string_spec_builder::StringSpecBuilder,
+    },
+    primitive_specs::{
+        list_spec::ListStorage,
+        string_spec::{ByteCodePage, StringEncoding, StringStorage},
+    },
+    variable::Variable,
+};
+
+#[test]
+fn substring_by_char() {
+    for encoding in [
+        StringEncoding::Utf8,
+        StringEncoding::Utf16,
+        StringEncoding::Utf32,
+    ] {
+        let spec = StringSpecBuilder::new(encoding)
+            .set_storage(StringStorage::VariableSize)
+            .build();
+        let mut var = Variable::new(&spec);
+        var.string_mut().set_string("héllo 😀 world").unwrap();
+        let string = var.string();
+        assert_eq!(string.char_count().unwrap(), 13);
+        let substring = string.substring(1, 7).unwrap();
+        assert_eq!(substring.string().string().unwrap(), "éllo 😀");
+        assert_eq!(*substring.string().spec().encoding(), encoding);
+        assert_eq!(
+            string.substring(4, 14).err(),
+            Some(StringError::IndexOutOfBounds(14, 13))
+        );
+        assert_eq!(
+            string.substring(5, 4).err(),
+            Some(StringError::IndexOutOfBounds(5, 4))
+        );
+    }
+}
+
+#[test]
+fn substring_by_grapheme() {
+    // "e" followed by a combining acute accent is one grapheme but two characters.
+    let spec = StringSpecBuilder::new(StringEncoding::Utf16)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.string_mut().set_string("cafe\u{301}s 👍🏽!").unwrap();
+    let string = var.string();
+    assert_eq!(string.char_count().unwrap(), 10);
+    assert_eq!(string.grapheme_count().unwrap(), 8);
+    assert_eq!(
+        string
+            .grapheme_substring(3, 7)
+            .unwrap()
+            .string()
+            .string()
+            .unwrap(),
+        "e\u{301}s 👍🏽"
+    );
+    assert_eq!(
+        string.grapheme_substring(0, 9).err(),
+        Some(StringError::IndexOutOfBounds(9, 8))
+    );
+}
+
+#[test]
+fn find_and_match() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf32)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.string_mut()
+        .set_string("größer als 😀 und mehr")
+        .unwrap();
+    let string = var.string();
+    assert_eq!(string.find("als").unwrap(), Some(7));
+    assert_eq!(string.find("und").unwrap(), Some(13));
+    assert_eq!(string.find("weniger").unwrap(), None);
+    assert!(string.contains("😀").unwrap());
+    assert!(!string.contains("xyz").unwrap());
+    assert!(string.starts_with("grö").unwrap());
+    assert!(!string.starts_with("als").unwrap());
+    assert!(string.ends_with("mehr").unwrap());
+}
+
+#[test]
+fn split_and_join() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf16)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.string_mut().set_string("a,bb,,ccc").unwrap();
+    let parts = var.string().split(",").unwrap();
+    let list = parts.list();
+    assert_eq!(list.len(), 4);
+    let values: Vec<String> = list
+        .iter()
+        .map(|v| v.unwrap().utf16_string().string().unwrap())
+        .collect();
+    assert_eq!(values, vec!["a", "bb", "", "ccc"]);
+
+    let joined_spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut joined = Variable::new(&joined_spec);
+    joined.string_mut().join(list, " | ").unwrap();
+    assert_eq!(joined.utf8_string().string().unwrap(), "a | bb |  | ccc");
+}
+
+#[test]
+fn join_rejects_non_strings() {
+    let list_spec = ListSpecBuilder::new()
+        .set_value_spec(
+            crate::data_spec_builders::boolean_spec_builder::BooleanSpecBuilder::new().build(),
+        )
+        .set_storage(ListStorage::VariableSize)
+        .build();
+    let mut list_var = Variable::new(&list_spec);
+    list_var
+        .list_mut()
+        .push(Variable::try_from(true).unwrap())
+        .unwrap();
+    let joined_spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut joined = Variable::new(&joined_spec);
+    assert_eq!(
+        joined.string_mut().join(list_var.list(), ","),
+        Err(StringError::NotAString(0))
+    );
+}
+
+#[test]
+fn trim() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.string_mut().set_string(" \t padded \n").unwrap();
+    var.string_mut().trim_start().unwrap();
+    assert_eq!(var.utf8_string().string().unwrap(), "padded \n");
+    var.string_mut().trim_end().unwrap();
+    assert_eq!(var.utf8_string().string().unwrap(), "padded");
+
+    let spec = StringSpecBuilder::new(StringEncoding::Utf32)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.string_mut().set_string("\u{3000}wide\u{3000}").unwrap();
+    var.string_mut().trim().unwrap();
+    assert_eq!(var.utf32_string().string().unwrap(), "wide");
+}
+
+#[test]
+fn case_mapping() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf16)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.string_mut().set_string("Straße ΣΑΣ").unwrap();
+    var.string_mut().make_uppercase().unwrap();
+    assert_eq!(var.utf16_string().string().unwrap(), "STRASSE ΣΑΣ");
+    var.string_mut().make_lowercase().unwrap();
+    // The final sigma is mapped to its word-final form.
+    assert_eq!(var.utf16_string().string().unwrap(), "strasse σας");
+}
+
+#[test]
+fn byte_string_operations() {
+    let spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::FixedSize(10))
+        .set_code_page(ByteCodePage::Latin1)
+        .build();
+    let mut var = Variable::new(&spec);
+    var.string_mut().set_string("  ÿes").unwrap();
+    var.string_mut().trim().unwrap();
+    // The fixed-size string is padded again after trimming.
+    assert_eq!(var.byte_string().string().unwrap(), "ÿes       ");
+
+    let substring = var.string().substring(0, 3).unwrap();
+    assert_eq!(substring.byte_string().string().unwrap(), "ÿes");
+    assert_eq!(
+        *substring.byte_string().spec().code_page(),
+        Some(ByteCodePage::Latin1)
+    );
+
+    // Upper-case "ÿ" is "Ÿ", which Latin-1 cannot represent.
+    assert_eq!(
+        var.string_mut().make_uppercase(),
+        Err(StringError::UnmappableCharacter(0, 'Ÿ'))
+    );
+}
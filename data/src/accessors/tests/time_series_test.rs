@@ -0,0 +1,557 @@
+use crate::{
+    accessors::time_series::{Aggregation, TimeSeriesError},
+    data_spec_builders::{
+        date_time_spec_builder::DateTimeSpecBuilder, duration_spec_builder::DurationSpecBuilder,
+        integer_spec_builder::IntegerSpecBuilder, time_series_spec_builder::TimeSeriesSpecBuilder,
+    },
+    primitive_specs::{
+        date_time_spec::DateTimeType,
+        duration_spec::DurationType,
+        integer_spec::{IntegerEncoding, IntegerStorage},
+    },
+    set_equal_to::SetEqualTo,
+    spec_compatibility::SpecError,
+    variable::Variable,
+};
+
+#[test]
+fn time_series_insert_get_remove() {
+    let timestamp_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_timestamp_spec(timestamp_spec.clone())
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let timestamp = |value: &str| {
+        let mut var = Variable::new(&timestamp_spec);
+        var.date_time_mut().set_from_string(value).unwrap();
+        var
+    };
+    let mut var = Variable::new(&spec);
+    let time_series = var.time_series_mut();
+    assert!(time_series.is_empty());
+    assert!(
+        time_series
+            .insert(
+                timestamp("2024-06-15T10:00:00"),
+                Variable::try_from(1i64).unwrap()
+            )
+            .unwrap()
+    );
+    // replaces the value at an existing timestamp
+    assert!(
+        !time_series
+            .insert(
+                timestamp("2024-06-15T10:00:00"),
+                Variable::try_from(2i64).unwrap()
+            )
+            .unwrap()
+    );
+    assert_eq!(time_series.len(), 1);
+    assert_eq!(
+        time_series
+            .get(&timestamp("2024-06-15T10:00:00"))
+            .unwrap()
+            .unwrap()
+            .integer()
+            .i64()
+            .unwrap(),
+        2
+    );
+    assert!(
+        time_series
+            .get(&timestamp("2024-06-15T11:00:00"))
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        time_series
+            .remove(&timestamp("2024-06-15T10:00:00"))
+            .unwrap()
+    );
+    assert!(
+        !time_series
+            .remove(&timestamp("2024-06-15T10:00:00"))
+            .unwrap()
+    );
+    assert!(time_series.is_empty());
+}
+
+#[test]
+fn time_series_insert_incompatible() {
+    let timestamp_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_timestamp_spec(timestamp_spec.clone())
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut timestamp = Variable::new(&timestamp_spec);
+    timestamp
+        .date_time_mut()
+        .set_from_string("2024-06-15T10:00:00")
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    let time_series = var.time_series_mut();
+    assert!(matches!(
+        time_series.insert(
+            Variable::try_from(1i64).unwrap(),
+            Variable::try_from(1i64).unwrap()
+        ),
+        Err(TimeSeriesError::TimestampSpecError(
+            SpecError::IncompatibleSpec(..)
+        ))
+    ));
+    assert!(matches!(
+        time_series.insert(timestamp, Variable::try_from(1.5).unwrap()),
+        Err(TimeSeriesError::ValueSpecError(
+            SpecError::IncompatibleSpec(..)
+        ))
+    ));
+    assert!(time_series.is_empty());
+}
+
+#[test]
+fn time_series_iter_and_display() {
+    let timestamp_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_timestamp_spec(timestamp_spec.clone())
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    for (ts, value) in [
+        ("2024-06-15T09:15:00", 4i64),
+        ("2024-06-15T09:45:00", 8),
+        ("2024-06-15T10:30:00", 3),
+        ("2024-06-15T12:00:00", 6),
+    ] {
+        let mut timestamp = Variable::new(&timestamp_spec);
+        timestamp.date_time_mut().set_from_string(ts).unwrap();
+        var.time_series_mut()
+            .insert(timestamp, Variable::try_from(value).unwrap())
+            .unwrap();
+    }
+    let timestamps: Vec<String> = var
+        .time_series()
+        .iter()
+        .map(|entry| entry.unwrap().0.to_string())
+        .collect();
+    assert_eq!(
+        timestamps,
+        [
+            "2024-06-15T09:15:00",
+            "2024-06-15T09:45:00",
+            "2024-06-15T10:30:00",
+            "2024-06-15T12:00:00"
+        ]
+    );
+
+    let mut var = Variable::new(&spec);
+    for (ts, value) in [("2024-06-15T09:15:00", 4i64), ("2024-06-15T09:45:00", 8)] {
+        let mut timestamp = Variable::new(&timestamp_spec);
+        timestamp.date_time_mut().set_from_string(ts).unwrap();
+        var.time_series_mut()
+            .insert(timestamp, Variable::try_from(value).unwrap())
+            .unwrap();
+    }
+    assert_eq!(
+        var.to_string(),
+        "TimeSeries {2024-06-15T09:15:00: 4, 2024-06-15T09:45:00: 8}"
+    );
+}
+
+#[test]
+fn time_series_range_and_slice() {
+    let timestamp_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_timestamp_spec(timestamp_spec.clone())
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    for (ts, value) in [
+        ("2024-06-15T09:15:00", 4i64),
+        ("2024-06-15T09:45:00", 8),
+        ("2024-06-15T10:30:00", 3),
+        ("2024-06-15T12:00:00", 6),
+    ] {
+        let mut timestamp = Variable::new(&timestamp_spec);
+        timestamp.date_time_mut().set_from_string(ts).unwrap();
+        var.time_series_mut()
+            .insert(timestamp, Variable::try_from(value).unwrap())
+            .unwrap();
+    }
+    let time_series = var.time_series();
+    let mut start = Variable::new(&timestamp_spec);
+    start
+        .date_time_mut()
+        .set_from_string("2024-06-15T09:45:00")
+        .unwrap();
+    let mut end = Variable::new(&timestamp_spec);
+    end.date_time_mut()
+        .set_from_string("2024-06-15T12:00:00")
+        .unwrap();
+    let values: Vec<i64> = time_series
+        .range(&start, &end)
+        .unwrap()
+        .map(|entry| entry.unwrap().1.integer().i64().unwrap())
+        .collect();
+    assert_eq!(values, [8, 3]);
+    assert_eq!(
+        time_series.slice(&start, &end).unwrap().to_string(),
+        "TimeSeries {2024-06-15T09:45:00: 8, 2024-06-15T10:30:00: 3}"
+    );
+    assert!(
+        time_series
+            .slice(&start, &start)
+            .unwrap()
+            .time_series()
+            .is_empty()
+    );
+    assert_eq!(
+        time_series.range(&end, &start).err(),
+        Some(TimeSeriesError::StartAfterEnd(
+            "2024-06-15T12:00:00".to_string(),
+            "2024-06-15T09:45:00".to_string()
+        ))
+    );
+}
+
+#[test]
+fn time_series_as_of() {
+    let timestamp_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_timestamp_spec(timestamp_spec.clone())
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    for (ts, value) in [
+        ("2024-06-15T09:15:00", 4i64),
+        ("2024-06-15T09:45:00", 8),
+        ("2024-06-15T10:30:00", 3),
+        ("2024-06-15T12:00:00", 6),
+    ] {
+        let mut timestamp = Variable::new(&timestamp_spec);
+        timestamp.date_time_mut().set_from_string(ts).unwrap();
+        var.time_series_mut()
+            .insert(timestamp, Variable::try_from(value).unwrap())
+            .unwrap();
+    }
+    let time_series = var.time_series();
+    let mut timestamp = Variable::new(&timestamp_spec);
+    timestamp
+        .date_time_mut()
+        .set_from_string("2024-06-15T10:29:59")
+        .unwrap();
+    let (ts, value) = time_series.as_of(&timestamp).unwrap().unwrap();
+    assert_eq!(ts.to_string(), "2024-06-15T09:45:00");
+    assert_eq!(value.integer().i64().unwrap(), 8);
+    // an entry at the timestamp is current as of it
+    timestamp
+        .date_time_mut()
+        .set_from_string("2024-06-15T10:30:00")
+        .unwrap();
+    let (ts, _) = time_series.as_of(&timestamp).unwrap().unwrap();
+    assert_eq!(ts.to_string(), "2024-06-15T10:30:00");
+    timestamp
+        .date_time_mut()
+        .set_from_string("2024-06-15T09:00:00")
+        .unwrap();
+    assert!(time_series.as_of(&timestamp).unwrap().is_none());
+}
+
+#[test]
+fn time_series_resample() {
+    let timestamp_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_timestamp_spec(timestamp_spec.clone())
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    for (ts, value) in [
+        ("2024-06-15T09:15:00", 4i64),
+        ("2024-06-15T09:45:00", 8),
+        ("2024-06-15T10:30:00", 3),
+        ("2024-06-15T12:00:00", 6),
+    ] {
+        let mut timestamp = Variable::new(&timestamp_spec);
+        timestamp.date_time_mut().set_from_string(ts).unwrap();
+        var.time_series_mut()
+            .insert(timestamp, Variable::try_from(value).unwrap())
+            .unwrap();
+    }
+    let time_series = var.time_series();
+    let duration_spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut duration = Variable::new(&duration_spec);
+    duration
+        .day_to_second_duration_mut()
+        .set_from_string("PT1H")
+        .unwrap();
+    for (aggregation, expected) in [
+        (
+            Aggregation::Sum,
+            "TimeSeries {2024-06-15T09:00:00: 12, 2024-06-15T10:00:00: 3, 2024-06-15T12:00:00: 6}",
+        ),
+        (
+            Aggregation::Mean,
+            "TimeSeries {2024-06-15T09:00:00: 6, 2024-06-15T10:00:00: 3, 2024-06-15T12:00:00: 6}",
+        ),
+        (
+            Aggregation::Min,
+            "TimeSeries {2024-06-15T09:00:00: 4, 2024-06-15T10:00:00: 3, 2024-06-15T12:00:00: 6}",
+        ),
+        (
+            Aggregation::Max,
+            "TimeSeries {2024-06-15T09:00:00: 8, 2024-06-15T10:00:00: 3, 2024-06-15T12:00:00: 6}",
+        ),
+        (
+            Aggregation::Last,
+            "TimeSeries {2024-06-15T09:00:00: 8, 2024-06-15T10:00:00: 3, 2024-06-15T12:00:00: 6}",
+        ),
+    ] {
+        let resampled = time_series
+            .resample(duration.day_to_second_duration(), aggregation)
+            .unwrap();
+        assert_eq!(resampled.to_string(), expected, "{}", aggregation);
+        assert_eq!(
+            resampled
+                .time_series()
+                .spec()
+                .value_spec()
+                .as_ref()
+                .unwrap()
+                .to_string()
+                != time_series
+                    .spec()
+                    .value_spec()
+                    .as_ref()
+                    .unwrap()
+                    .to_string(),
+            aggregation.is_float(),
+            "{}",
+            aggregation
+        );
+    }
+
+    // days start at midnight
+    duration
+        .day_to_second_duration_mut()
+        .set_from_string("P1D")
+        .unwrap();
+    let daily = time_series
+        .resample(duration.day_to_second_duration(), Aggregation::Sum)
+        .unwrap();
+    assert_eq!(daily.to_string(), "TimeSeries {2024-06-15T00:00:00: 21}");
+}
+
+#[test]
+fn time_series_rolling() {
+    let timestamp_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_timestamp_spec(timestamp_spec.clone())
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    for (ts, value) in [
+        ("2024-06-15T09:15:00", 4i64),
+        ("2024-06-15T09:45:00", 8),
+        ("2024-06-15T10:30:00", 3),
+        ("2024-06-15T12:00:00", 6),
+    ] {
+        let mut timestamp = Variable::new(&timestamp_spec);
+        timestamp.date_time_mut().set_from_string(ts).unwrap();
+        var.time_series_mut()
+            .insert(timestamp, Variable::try_from(value).unwrap())
+            .unwrap();
+    }
+    let duration_spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut duration = Variable::new(&duration_spec);
+    duration
+        .day_to_second_duration_mut()
+        .set_from_string("PT1H")
+        .unwrap();
+    let rolled = var
+        .time_series()
+        .rolling(duration.day_to_second_duration(), Aggregation::Sum)
+        .unwrap();
+    // the window ending at 10:30 excludes the entry at 09:30 or before
+    assert_eq!(
+        rolled.to_string(),
+        "TimeSeries {2024-06-15T09:15:00: 4, 2024-06-15T09:45:00: 12, \
+         2024-06-15T10:30:00: 11, 2024-06-15T12:00:00: 6}"
+    );
+    duration
+        .day_to_second_duration_mut()
+        .set_from_string("PT2H")
+        .unwrap();
+    let rolled = var
+        .time_series()
+        .rolling(duration.day_to_second_duration(), Aggregation::Max)
+        .unwrap();
+    assert_eq!(
+        rolled.to_string(),
+        "TimeSeries {2024-06-15T09:15:00: 4, 2024-06-15T09:45:00: 8, \
+         2024-06-15T10:30:00: 8, 2024-06-15T12:00:00: 6}"
+    );
+}
+
+#[test]
+fn time_series_non_positive_duration() {
+    let timestamp_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_timestamp_spec(timestamp_spec.clone())
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut var = Variable::new(&spec);
+    for (ts, value) in [
+        ("2024-06-15T09:15:00", 4i64),
+        ("2024-06-15T09:45:00", 8),
+        ("2024-06-15T10:30:00", 3),
+        ("2024-06-15T12:00:00", 6),
+    ] {
+        let mut timestamp = Variable::new(&timestamp_spec);
+        timestamp.date_time_mut().set_from_string(ts).unwrap();
+        var.time_series_mut()
+            .insert(timestamp, Variable::try_from(value).unwrap())
+            .unwrap();
+    }
+    let time_series = var.time_series();
+    let duration_spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .build()
+        .unwrap();
+    let mut duration = Variable::new(&duration_spec);
+    duration
+        .day_to_second_duration_mut()
+        .set_from_string("PT0S")
+        .unwrap();
+    assert!(matches!(
+        time_series.resample(duration.day_to_second_duration(), Aggregation::Sum),
+        Err(TimeSeriesError::NonPositiveDuration(..))
+    ));
+    duration
+        .day_to_second_duration_mut()
+        .set_from_string("-PT1H")
+        .unwrap();
+    assert!(matches!(
+        time_series.rolling(duration.day_to_second_duration(), Aggregation::Sum),
+        Err(TimeSeriesError::NonPositiveDuration(..))
+    ));
+}
+
+#[test]
+fn time_series_set_equal_to_and_eq() {
+    let timestamp_spec = DateTimeSpecBuilder::new()
+        .set_date_time_type(DateTimeType::Local)
+        .build();
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_timestamp_spec(timestamp_spec.clone())
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let mut source = Variable::new(&spec);
+    for (ts, value) in [
+        ("2024-06-15T09:15:00", 4i64),
+        ("2024-06-15T09:45:00", 8),
+        ("2024-06-15T10:30:00", 3),
+        ("2024-06-15T12:00:00", 6),
+    ] {
+        let mut timestamp = Variable::new(&timestamp_spec);
+        timestamp.date_time_mut().set_from_string(ts).unwrap();
+        source
+            .time_series_mut()
+            .insert(timestamp, Variable::try_from(value).unwrap())
+            .unwrap();
+    }
+    let mut target = Variable::new(&spec);
+    let mut timestamp = Variable::new(&timestamp_spec);
+    timestamp
+        .date_time_mut()
+        .set_from_string("2024-01-01T00:00:00")
+        .unwrap();
+    target
+        .time_series_mut()
+        .insert(timestamp, Variable::try_from(1i64).unwrap())
+        .unwrap();
+    assert_ne!(source, target);
+    assert!(target < source);
+    target
+        .time_series_mut()
+        .set_equal_to(source.time_series())
+        .unwrap();
+    assert_eq!(source, target);
+    assert_eq!(target.time_series().len(), 4);
+    target.time_series_mut().clear().unwrap();
+    assert!(target.time_series().is_empty());
+}
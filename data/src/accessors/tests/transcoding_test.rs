@@ -0,0 +1,159 @@
+use crate::{
+    accessors::string::{StringError, TranscodingPolicy},
+    data_spec_builders::string_spec_builder::StringSpecBuilder,
+    primitive_specs::string_spec::{ByteCodePage, StringEncoding, StringStorage},
+    set_equal_to::{SetEqualTo, SetEqualToError},
+    variable::Variable,
+};
+
+#[test]
+fn transcode_between_unicode_encodings() {
+    let source = Variable::try_from("Grüße 😀").unwrap();
+
+    let utf16_spec = StringSpecBuilder::new(StringEncoding::Utf16)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut utf16 = Variable::new(&utf16_spec);
+    utf16.set_equal_to(&source).unwrap();
+    assert_eq!(utf16.utf16_string().string().unwrap(), "Grüße 😀");
+    assert_eq!(utf16.utf16_string().len(), 8);
+
+    let utf32_spec = StringSpecBuilder::new(StringEncoding::Utf32)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut utf32 = Variable::new(&utf32_spec);
+    utf32.set_equal_to(&utf16).unwrap();
+    assert_eq!(utf32.utf32_string().string().unwrap(), "Grüße 😀");
+    assert_eq!(utf32.utf32_string().len(), 7);
+
+    let utf8_spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut utf8 = Variable::new(&utf8_spec);
+    utf8.set_equal_to(&utf32).unwrap();
+    assert_eq!(utf8, source);
+}
+
+#[test]
+fn transcode_byte_string_to_unicode() {
+    let latin1_spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Latin1)
+        .build();
+    let mut latin1 = Variable::new(&latin1_spec);
+    latin1
+        .byte_string_mut()
+        .set_bytes(&[0x63, 0x61, 0x66, 0xE9])
+        .unwrap();
+    let utf8_spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut utf8 = Variable::new(&utf8_spec);
+    utf8.set_equal_to(&latin1).unwrap();
+    assert_eq!(utf8.utf8_string().string().unwrap(), "café");
+
+    // An undefined byte is a transcoding failure rather than a loss.
+    let windows_spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Windows1252)
+        .build();
+    let mut windows = Variable::new(&windows_spec);
+    windows.byte_string_mut().set_bytes(&[0x41, 0x81]).unwrap();
+    assert_eq!(
+        utf8.set_equal_to(&windows),
+        Err(SetEqualToError::TranscodingError(
+            StringError::UnmappableByte(1, 0x81)
+        ))
+    );
+}
+
+#[test]
+fn transcode_respects_target_storage() {
+    let source = Variable::try_from("😀😀").unwrap();
+    let utf16_spec = StringSpecBuilder::new(StringEncoding::Utf16)
+        .set_storage(StringStorage::FixedCapacity(3))
+        .build();
+    let mut utf16 = Variable::new(&utf16_spec);
+    assert_eq!(
+        utf16.set_equal_to(&source),
+        Err(SetEqualToError::TranscodingError(
+            StringError::FixedCapacityViolation(3, 4)
+        ))
+    );
+}
+
+#[test]
+fn lossy_transcoding_requires_explicit_call() {
+    let source = Variable::try_from("naïve €").unwrap();
+    let latin1_spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Latin1)
+        .build();
+    let mut latin1 = Variable::new(&latin1_spec);
+    match latin1.set_equal_to(&source) {
+        Err(SetEqualToError::LossyTranscoding(from, to)) => {
+            assert_eq!(from, "String { encoding: Utf8, storage: VariableSize }");
+            assert_eq!(
+                to,
+                "String { encoding: Byte, storage: VariableSize, code page: Latin1 }"
+            );
+        }
+        other => panic!("Expected LossyTranscoding, got {:?}", other),
+    }
+
+    // The lossy pair fails even if the value happens to be representable.
+    let plain = Variable::try_from("plain").unwrap();
+    assert!(latin1.set_equal_to(&plain).is_err());
+
+    latin1
+        .string_mut()
+        .transcode_from(source.string(), TranscodingPolicy::Replace('?'))
+        .unwrap();
+    assert_eq!(latin1.byte_string().string().unwrap(), "naïve ?");
+
+    assert_eq!(
+        latin1
+            .string_mut()
+            .transcode_from(source.string(), TranscodingPolicy::Error),
+        Err(StringError::UnmappableCharacter(6, '€'))
+    );
+    assert_eq!(
+        latin1
+            .string_mut()
+            .transcode_from(source.string(), TranscodingPolicy::Replace('€')),
+        Err(StringError::UnmappableCharacter(6, '€'))
+    );
+}
+
+#[test]
+fn transcode_between_code_pages() {
+    let ascii_spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Ascii)
+        .build();
+    let mut ascii = Variable::new(&ascii_spec);
+    ascii.byte_string_mut().set_string("plain").unwrap();
+    let windows_spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Windows1252)
+        .build();
+    let mut windows = Variable::new(&windows_spec);
+    windows.set_equal_to(&ascii).unwrap();
+    assert_eq!(windows.byte_string().string().unwrap(), "plain");
+
+    windows.byte_string_mut().set_string("€").unwrap();
+    let latin1_spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::VariableSize)
+        .set_code_page(ByteCodePage::Latin1)
+        .build();
+    let mut latin1 = Variable::new(&latin1_spec);
+    assert!(matches!(
+        latin1.set_equal_to(&windows),
+        Err(SetEqualToError::LossyTranscoding(_, _))
+    ));
+    latin1
+        .string_mut()
+        .transcode_from(windows.string(), TranscodingPolicy::Replace('E'))
+        .unwrap();
+    assert_eq!(latin1.byte_string().bytes().unwrap(), vec![0x45]);
+}
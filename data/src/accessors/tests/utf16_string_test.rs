@@ -0,0 +1,107 @@
+use crate::{
+    accessors::string::StringError,
+    data_spec_builders::string_spec_builder::StringSpecBuilder,
+    primitive_specs::string_spec::{StringEncoding, StringStorage},
+    set_equal_to::SetEqualTo,
+    variable::Variable,
+};
+
+#[test]
+fn utf16_string_set_and_get() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf16)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.utf16_string_mut();
+    assert!(string.is_empty());
+
+    string.set_string("a𝄞b").unwrap();
+    assert_eq!(string.string().unwrap(), "a𝄞b");
+    // The G clef is outside the BMP and takes a surrogate pair.
+    assert_eq!(string.len(), 4);
+    assert_eq!(
+        string.code_units().unwrap(),
+        vec![0x0061, 0xD834, 0xDD1E, 0x0062]
+    );
+    assert_eq!(string.code_unit(1).unwrap(), 0xD834);
+    assert_eq!(
+        string.code_unit(4),
+        Err(StringError::IndexOutOfBounds(4, 4))
+    );
+}
+
+#[test]
+fn utf16_string_chars() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf16)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.utf16_string_mut();
+    string
+        .set_code_units(&[0x0048, 0xD83D, 0xDE00, 0x0069])
+        .unwrap();
+    let chars: Vec<char> = string.chars().unwrap().collect();
+    assert_eq!(chars, vec!['H', '😀', 'i']);
+}
+
+#[test]
+fn utf16_string_rejects_lone_surrogates() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf16)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.utf16_string_mut();
+    string.set_string("ok").unwrap();
+
+    assert_eq!(
+        string.set_code_units(&[0x0061, 0xD800]),
+        Err(StringError::LoneSurrogate(1))
+    );
+    assert_eq!(
+        string.set_code_units(&[0xDC00, 0x0061]),
+        Err(StringError::LoneSurrogate(0))
+    );
+    match string.append_code_units(&[0xD800, 0x0061]) {
+        Err(e) => assert_eq!(e.to_string(), "Unpaired surrogate at code unit 0"),
+        Ok(_) => panic!("Expected an error"),
+    }
+    assert_eq!(string.string().unwrap(), "ok");
+}
+
+#[test]
+fn utf16_string_fixed_capacity() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf16)
+        .set_storage(StringStorage::FixedCapacity(3))
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.utf16_string_mut();
+    string.set_string("ab").unwrap();
+    // A surrogate pair counts as two code units.
+    assert_eq!(
+        string.append("😀"),
+        Err(StringError::FixedCapacityViolation(3, 4))
+    );
+    string.append("c").unwrap();
+    assert_eq!(string.string().unwrap(), "abc");
+
+    string.clear().unwrap();
+    assert!(string.is_empty());
+}
+
+#[test]
+fn utf16_string_set_equal_to_and_compare() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf16)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    var1.utf16_string_mut().set_string("zebra").unwrap();
+    let mut var2 = Variable::new(&spec);
+    var2.set_equal_to(&var1).unwrap();
+    assert_eq!(var1, var2);
+    assert_eq!(var2.to_string(), "zebra");
+
+    // Ordering follows scalar values rather than code units.
+    var1.utf16_string_mut().set_string("😀").unwrap();
+    var2.utf16_string_mut().set_string("\u{FF61}").unwrap();
+    assert!(var2 < var1);
+}
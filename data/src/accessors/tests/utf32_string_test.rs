@@ -0,0 +1,85 @@
+use crate::{
+    accessors::string::StringError,
+    data_spec_builders::string_spec_builder::StringSpecBuilder,
+    primitive_specs::string_spec::{StringEncoding, StringStorage},
+    set_equal_to::SetEqualTo,
+    variable::Variable,
+};
+
+#[test]
+fn utf32_string_set_and_get() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf32)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.utf32_string_mut();
+
+    string.set_string("a😀b").unwrap();
+    assert_eq!(string.string().unwrap(), "a😀b");
+    assert_eq!(string.len(), 3);
+    assert_eq!(string.code_units().unwrap(), vec![0x61, 0x1F600, 0x62]);
+    assert_eq!(string.code_unit(1).unwrap(), 0x1F600);
+    let chars: Vec<char> = string.chars().unwrap().collect();
+    assert_eq!(chars, vec!['a', '😀', 'b']);
+}
+
+#[test]
+fn utf32_string_rejects_invalid_scalar_values() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf32)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.utf32_string_mut();
+
+    assert_eq!(
+        string.set_code_units(&[0x61, 0xD800]),
+        Err(StringError::InvalidScalarValue(1, 0xD800))
+    );
+    assert_eq!(
+        string.append_code_units(&[0x110000]),
+        Err(StringError::InvalidScalarValue(0, 0x110000))
+    );
+    assert!(string.is_empty());
+}
+
+#[test]
+fn utf32_string_fixed_size() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf32)
+        .set_storage(StringStorage::FixedSize(4))
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.utf32_string_mut();
+    assert_eq!(string.string().unwrap(), "    ");
+
+    string.set_string("ab").unwrap();
+    assert_eq!(string.string().unwrap(), "ab  ");
+    assert_eq!(
+        string.set_string("abcde"),
+        Err(StringError::FixedSizeViolation(4, 5))
+    );
+    assert_eq!(
+        string.append("x"),
+        Err(StringError::FixedSizeViolation(4, 5))
+    );
+
+    string.clear().unwrap();
+    assert_eq!(string.len(), 4);
+}
+
+#[test]
+fn utf32_string_set_equal_to() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf32)
+        .set_storage(StringStorage::FixedSize(3))
+        .build();
+    let mut var1 = Variable::new(&spec);
+    var1.utf32_string_mut().set_string("abc").unwrap();
+    let mut var2 = Variable::new(&spec);
+    var2.set_equal_to(&var1).unwrap();
+    assert_eq!(var1, var2);
+
+    let variable_size_spec = StringSpecBuilder::new(StringEncoding::Utf32)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var3 = Variable::new(&variable_size_spec);
+    assert!(var3.set_equal_to(&var1).is_err());
+}
@@ -0,0 +1,217 @@
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    rc::Rc,
+};
+
+use crate::{
+    accessors::string::StringError,
+    adaptors::string_adaptors::utf8_string_adaptor::Utf8StringAdaptor,
+    data_spec_builders::{
+        integer_spec_builder::IntegerSpecBuilder, map_spec_builder::MapSpecBuilder,
+        set_spec_builder::SetSpecBuilder, string_spec_builder::StringSpecBuilder,
+    },
+    default_providers::default_data_provider::default_adaptors::strings::transient_utf8_string_adaptor::TransientUtf8StringAdaptor,
+    primitive_specs::{
+        integer_spec::{IntegerEncoding, IntegerStorage},
+        map_spec::MapKeyOrdering,
+        set_spec::SetElementOrdering,
+        string_spec::{StringEncoding, StringSpec, StringStorage},
+    },
+    set_equal_to::{SetEqualTo, SetEqualToError},
+    variable::Variable,
+};
+
+#[test]
+fn utf8_string_set_and_get() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.utf8_string_mut();
+    assert!(string.is_empty());
+
+    string.set_string("Grüße").unwrap();
+    assert_eq!(string.string().unwrap(), "Grüße");
+    // Lengths are in bytes.
+    assert_eq!(string.len(), 7);
+}
+
+#[test]
+fn utf8_string_append_and_clear() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::InitialCapacity(16))
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.utf8_string_mut();
+
+    string.set_string("Hello").unwrap();
+    string.append(", world").unwrap();
+    assert_eq!(string.string().unwrap(), "Hello, world");
+
+    string.clear().unwrap();
+    assert!(string.is_empty());
+    assert_eq!(string.string().unwrap(), "");
+}
+
+#[test]
+fn utf8_string_fixed_capacity() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::FixedCapacity(5))
+        .build();
+    let mut var = Variable::new(&spec);
+    let string = var.utf8_string_mut();
+
+    string.set_string("abc").unwrap();
+    string.append("de").unwrap();
+    assert_eq!(string.string().unwrap(), "abcde");
+
+    match string.append("f") {
+        Err(StringError::FixedCapacityViolation(5, 6)) => {}
+        other => panic!("Expected FixedCapacityViolation, got {:?}", other),
+    }
+    match string.set_string("abcdef") {
+        Err(e) => assert_eq!(
+            e.to_string(),
+            "A value of length 6 would exceed the fixed capacity of 5"
+        ),
+        Ok(_) => panic!("Expected an error"),
+    }
+    // Multi-byte characters count towards the capacity in bytes.
+    assert!(string.set_string("ééé").is_err());
+    assert_eq!(string.string().unwrap(), "abcde");
+}
+
+#[test]
+fn utf8_string_fixed_size_adaptor() {
+    // The builder rejects fixed-size UTF-8 strings, but the adaptor still enforces the storage.
+    let spec = Rc::new(StringSpec::new(
+        StringEncoding::Utf8,
+        Some(StringStorage::FixedSize(4)),
+        None,
+    ));
+    let mut adaptor = TransientUtf8StringAdaptor::new(spec);
+    assert_eq!(adaptor.string().unwrap(), "    ");
+
+    adaptor.set_string("ab").unwrap();
+    assert_eq!(adaptor.string().unwrap(), "ab  ");
+    assert_eq!(adaptor.len(), 4);
+
+    assert_eq!(
+        adaptor.set_string("abcde"),
+        Err(StringError::FixedSizeViolation(4, 5))
+    );
+    assert_eq!(
+        adaptor.append("x"),
+        Err(StringError::FixedSizeViolation(4, 5))
+    );
+
+    adaptor.clear().unwrap();
+    assert_eq!(adaptor.string().unwrap(), "    ");
+}
+
+#[test]
+fn utf8_string_set_equal_to() {
+    let spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let mut var1 = Variable::new(&spec);
+    var1.utf8_string_mut().set_string("source").unwrap();
+    let mut var2 = Variable::new(&spec);
+    var2.set_equal_to(&var1).unwrap();
+    assert_eq!(var2.utf8_string().string().unwrap(), "source");
+    assert_eq!(var1, var2);
+
+    let other_spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::FixedCapacity(3))
+        .build();
+    let mut var3 = Variable::new(&other_spec);
+    match var3.set_equal_to(&var1) {
+        Err(SetEqualToError::SpecError(_)) => {}
+        other => panic!("Expected SpecError, got {:?}", other),
+    }
+}
+
+#[test]
+fn utf8_string_try_from() {
+    let var = Variable::try_from("hello").unwrap();
+    assert_eq!(var.to_string(), "hello");
+    let value: String = var.clone().try_into().unwrap();
+    assert_eq!(value, "hello");
+}
+
+#[test]
+fn utf8_string_ordering_and_hash() {
+    let a = Variable::try_from("apple").unwrap();
+    let b = Variable::try_from("banana").unwrap();
+    let a2 = Variable::try_from("apple").unwrap();
+    assert!(a < b);
+    assert_eq!(a, a2);
+
+    let mut hasher1 = DefaultHasher::new();
+    a.hash(&mut hasher1);
+    let mut hasher2 = DefaultHasher::new();
+    a2.hash(&mut hasher2);
+    assert_eq!(hasher1.finish(), hasher2.finish());
+}
+
+#[test]
+fn utf8_string_map_keys() {
+    let key_spec = StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_storage(StringStorage::VariableSize)
+        .build();
+    let value_spec = IntegerSpecBuilder::new()
+        .set_encoding(IntegerEncoding::Signed)
+        .set_storage(IntegerStorage::B64)
+        .build();
+    for ordering in [MapKeyOrdering::Ordered, MapKeyOrdering::Unordered] {
+        let map_spec = MapSpecBuilder::new()
+            .set_key_spec(key_spec.clone())
+            .set_value_spec(value_spec.clone())
+            .set_key_ordering(ordering)
+            .build();
+        let mut map_var = Variable::new(&map_spec);
+        let map = map_var.map_mut();
+
+        map.insert(
+            Variable::try_from("one").unwrap(),
+            Variable::try_from(1i64).unwrap(),
+        )
+        .unwrap();
+        map.insert(
+            Variable::try_from("two").unwrap(),
+            Variable::try_from(2i64).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(
+            map.get(&Variable::try_from("two").unwrap()).unwrap(),
+            Some(Variable::try_from(2i64).unwrap())
+        );
+        assert!(!map.contains(&Variable::try_from("three").unwrap()).unwrap());
+    }
+}
+
+#[test]
+fn utf8_string_set_members() {
+    let set_spec = SetSpecBuilder::new()
+        .set_value_spec(
+            StringSpecBuilder::new(StringEncoding::Utf8)
+                .set_storage(StringStorage::VariableSize)
+                .build(),
+        )
+        .set_storage(SetElementOrdering::Ordered)
+        .build();
+    let mut set_var = Variable::new(&set_spec);
+    let set = set_var.set_mut();
+
+    assert!(set.insert(Variable::try_from("b").unwrap()).unwrap());
+    assert!(set.insert(Variable::try_from("a").unwrap()).unwrap());
+    assert!(!set.insert(Variable::try_from("a").unwrap()).unwrap());
+
+    let members: Vec<String> = set
+        .iter()
+        .map(|v| v.unwrap().utf8_string().string().unwrap())
+        .collect();
+    assert_eq!(members, vec!["a", "b"]);
+}
@@ -0,0 +1,530 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::Hash,
+    rc::Rc,
+};
+
+use crate::{
+    accessors::{
+        float::FloatError,
+        integer::IntegerError,
+        temporal::{
+            date_time::DateTimeError,
+            day_to_second_duration::{DayToSecondDuration, DayToSecondDurationError},
+        },
+    },
+    adaptors::time_series_adaptor::TimeSeriesAdaptor,
+    data_spec::{DataSpec, DataSpecType},
+    data_spec_builders::{
+        float_spec_builder::FloatSpecBuilder, time_series_spec_builder::TimeSeriesSpecBuilder,
+    },
+    primitive::Primitive,
+    primitive_def::Accessor,
+    primitive_specs::{
+        float_spec::FloatStorage, integer_spec::IntegerEncoding, time_series_spec::TimeSeriesSpec,
+    },
+    provider_error::ProviderError,
+    set_equal_to::{SetEqualTo, SetEqualToError},
+    spec_compatibility::{SpecCompatibility, SpecError},
+    variable::Variable,
+};
+
+/// Aggregation defines an enumeration of the ways the values of a bucket or a window of a time
+/// series are combined into one.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Aggregation {
+    /// The sum of the values, as a 64-bit float.
+    Sum,
+    /// The arithmetic mean of the values, as a 64-bit float.
+    Mean,
+    /// The least value.
+    Min,
+    /// The greatest value.
+    Max,
+    /// The value with the latest timestamp.
+    Last,
+}
+
+impl Aggregation {
+    /// Returns true if the aggregation results in 64-bit floats rather than values of the time
+    /// series' value spec; false otherwise.
+    pub fn is_float(&self) -> bool {
+        matches!(self, Self::Sum | Self::Mean)
+    }
+}
+
+impl Display for Aggregation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Sum => "Sum",
+                Self::Mean => "Mean",
+                Self::Min => "Min",
+                Self::Max => "Max",
+                Self::Last => "Last",
+            }
+        )
+    }
+}
+
+/// The TimeSeries accessor provides access to time series, which map unique date-time
+/// timestamps to numeric values in the order of the timestamps.
+/// <p>
+/// Time series can be sliced by ranges of timestamps, looked up as of a timestamp, resampled to
+/// buckets of a fixed duration and aggregated over rolling windows. Buckets are aligned to
+/// 0001-01-01T00:00:00, a Monday, so that buckets of whole days start at midnight and buckets of
+/// weeks start on Mondays.
+/// </p>
+///
+/// # Examples
+///
+/// ```rust
+/// use data::accessors::time_series::Aggregation;
+/// use data::data_spec_builders::{time_series_spec_builder::TimeSeriesSpecBuilder, date_time_spec_builder::DateTimeSpecBuilder, duration_spec_builder::DurationSpecBuilder, float_spec_builder::FloatSpecBuilder};
+/// use data::primitive_specs::{date_time_spec::DateTimeType, duration_spec::DurationType, float_spec::FloatStorage};
+/// use data::variable::Variable;
+///
+/// let timestamp_spec = DateTimeSpecBuilder::new().set_date_time_type(DateTimeType::Local).build();
+/// let spec = TimeSeriesSpecBuilder::new()
+///     .set_timestamp_spec(timestamp_spec.clone())
+///     .set_value_spec(FloatSpecBuilder::new().set_storage(FloatStorage::B64).build())
+///     .build()
+///     .unwrap();
+/// let mut series = Variable::new(&spec);
+/// for (timestamp, value) in [("2024-06-15T12:05:00", 1.0), ("2024-06-15T12:40:00", 3.0), ("2024-06-15T13:10:00", 5.0)] {
+///     let mut var = Variable::new(&timestamp_spec);
+///     var.date_time_mut().set_from_string(timestamp).unwrap();
+///     series.time_series_mut().insert(var, Variable::try_from(value).unwrap()).unwrap();
+/// }
+///
+/// let mut hour = Variable::new(&DurationSpecBuilder::new().set_type(DurationType::DayToSecond).build().unwrap());
+/// hour.day_to_second_duration_mut().set_from_string("PT1H").unwrap();
+/// let hourly = series.time_series().resample(hour.day_to_second_duration(), Aggregation::Mean).unwrap();
+/// assert_eq!(hourly.to_string(), "TimeSeries {2024-06-15T12:00:00: 2, 2024-06-15T13:00:00: 5}");
+/// ```
+pub struct TimeSeries {
+    // The adaptor for the time series.
+    adaptor: Box<dyn TimeSeriesAdaptor>,
+}
+
+impl TimeSeries {
+    /// Creates a new `TimeSeries` accessor.
+    pub fn new(adaptor: Box<dyn TimeSeriesAdaptor>) -> Self {
+        Self { adaptor }
+    }
+
+    /// Returns the time series' specification.
+    pub fn spec(&self) -> &Rc<TimeSeriesSpec> {
+        self.adaptor.spec()
+    }
+
+    /// Checks if the time series is empty.
+    pub fn is_empty(&self) -> bool {
+        self.adaptor.is_empty()
+    }
+
+    /// Returns the number of entries in the time series.
+    pub fn len(&self) -> usize {
+        self.adaptor.len()
+    }
+
+    /// Gets the value at the timestamp, if any.
+    pub fn get(&self, timestamp: &Variable) -> Result<Option<&Variable>, TimeSeriesError> {
+        self.adaptor.get(timestamp)
+    }
+
+    /// Adds the value at the timestamp, replacing any value already there. Returns `true` if the
+    /// timestamp was added, `false` if it was already present.
+    ///
+    /// This method checks if the timestamp's and the value's data specifications are compatible
+    /// with those of the time series.
+    pub fn insert(
+        &mut self,
+        timestamp: Variable,
+        value: Variable,
+    ) -> Result<bool, TimeSeriesError> {
+        self.adaptor.insert(timestamp, value)
+    }
+
+    /// Removes the entry at the timestamp. Returns `true` if there was one, `false` otherwise.
+    pub fn remove(&mut self, timestamp: &Variable) -> Result<bool, TimeSeriesError> {
+        self.adaptor.remove(timestamp)
+    }
+
+    /// Clears the time series.
+    pub fn clear(&mut self) -> Result<(), TimeSeriesError> {
+        self.adaptor.clear()
+    }
+
+    /// Returns the latest entry at or before the timestamp, if any, which holds the value that
+    /// was current as of the timestamp.
+    pub fn as_of(
+        &self,
+        timestamp: &Variable,
+    ) -> Result<Option<(&Variable, &Variable)>, TimeSeriesError> {
+        self.adaptor.as_of(timestamp)
+    }
+
+    /// Returns an iterator for the entries in the order of their timestamps.
+    pub fn iter<'a>(&'a self) -> Box<dyn TimeSeriesIter<'a> + 'a> {
+        self.adaptor.iter()
+    }
+
+    /// Returns an iterator for the entries from the start timestamp, inclusive, to the end
+    /// timestamp, exclusive, in the order of their timestamps, or an error if the start is after
+    /// the end.
+    pub fn range<'a>(
+        &'a self,
+        start: &Variable,
+        end: &Variable,
+    ) -> Result<Box<dyn TimeSeriesIter<'a> + 'a>, TimeSeriesError> {
+        if start > end {
+            return Err(TimeSeriesError::StartAfterEnd(
+                start.to_string(),
+                end.to_string(),
+            ));
+        }
+        Ok(self.adaptor.range(start, end))
+    }
+
+    /// Returns a new time series with the entries from the start timestamp, inclusive, to the
+    /// end timestamp, exclusive.
+    pub fn slice(&self, start: &Variable, end: &Variable) -> Result<Variable, TimeSeriesError> {
+        let mut slice = Variable::new(&self.data_spec(false));
+        for entry in self.range(start, end)? {
+            let (timestamp, value) = entry?;
+            slice
+                .time_series_mut()
+                .insert(timestamp.try_clone()?, value.try_clone()?)?;
+        }
+        Ok(slice)
+    }
+
+    /// Returns a new time series with one entry for each bucket of the given duration that holds
+    /// entries of this time series, timestamped with the bucket's start and holding the
+    /// aggregate of the bucket's values.
+    pub fn resample(
+        &self,
+        bucket: &DayToSecondDuration,
+        aggregation: Aggregation,
+    ) -> Result<Variable, TimeSeriesError> {
+        let bucket = positive_nanoseconds(bucket)?;
+        let entries = self.entries()?;
+        let mut resampled = Variable::new(&self.data_spec(aggregation.is_float()));
+        let mut first = 0;
+        while first < entries.len() {
+            let bucket_start = entries[first].0.div_euclid(bucket) * bucket;
+            let count = entries[first..]
+                .iter()
+                .take_while(|(nanoseconds, ..)| *nanoseconds < bucket_start + bucket)
+                .count();
+            let mut timestamp = entries[first].1.try_clone()?;
+            timestamp.date_time_mut().set_nanoseconds(bucket_start)?;
+            let value = aggregate(&entries[first..first + count], aggregation)?;
+            resampled.time_series_mut().insert(timestamp, value)?;
+            first += count;
+        }
+        Ok(resampled)
+    }
+
+    /// Returns a new time series with an entry at each timestamp of this time series, holding the
+    /// aggregate of the values in the window of the given duration that ends with it. The window
+    /// holds the entries whose timestamps are after the timestamp less the duration and not
+    /// after the timestamp.
+    pub fn rolling(
+        &self,
+        window: &DayToSecondDuration,
+        aggregation: Aggregation,
+    ) -> Result<Variable, TimeSeriesError> {
+        let window = positive_nanoseconds(window)?;
+        let entries = self.entries()?;
+        let mut rolled = Variable::new(&self.data_spec(aggregation.is_float()));
+        let mut first = 0;
+        for (last, (nanoseconds, timestamp, _)) in entries.iter().enumerate() {
+            while entries[first].0 <= nanoseconds - window {
+                first += 1;
+            }
+            let value = aggregate(&entries[first..=last], aggregation)?;
+            rolled
+                .time_series_mut()
+                .insert(timestamp.try_clone()?, value)?;
+        }
+        Ok(rolled)
+    }
+
+    /// Returns the data spec of time series with this time series' timestamps and values, or
+    /// 64-bit float values if `is_float` is true.
+    fn data_spec(&self, is_float: bool) -> Rc<DataSpec> {
+        let mut builder = TimeSeriesSpecBuilder::new();
+        if let Some(timestamp_spec) = self.spec().timestamp_spec().as_ref() {
+            builder.set_timestamp_spec(timestamp_spec.clone());
+        }
+        if is_float {
+            builder.set_value_spec(
+                FloatSpecBuilder::new()
+                    .set_storage(FloatStorage::B64)
+                    .build(),
+            );
+        } else if let Some(value_spec) = self.spec().value_spec().as_ref() {
+            builder.set_value_spec(value_spec.clone());
+        }
+        // the specs are those of a time series, or a float spec, so they are supported
+        builder.build().unwrap()
+    }
+
+    /// Returns the entries with the nanoseconds of their timestamps from 0001-01-01T00:00:00.
+    fn entries(&self) -> Result<Vec<(i128, &Variable, &Variable)>, TimeSeriesError> {
+        let mut entries = Vec::with_capacity(self.len());
+        for entry in self.iter() {
+            let (timestamp, value) = entry?;
+            entries.push((timestamp.date_time().nanoseconds()?, timestamp, value));
+        }
+        Ok(entries)
+    }
+}
+
+/// Returns the total nanoseconds of the duration, or an error unless it is positive.
+fn positive_nanoseconds(duration: &DayToSecondDuration) -> Result<i128, TimeSeriesError> {
+    let nanoseconds = duration.total_nanoseconds()?;
+    if nanoseconds <= 0 {
+        return Err(TimeSeriesError::NonPositiveDuration(duration.to_string()));
+    }
+    Ok(nanoseconds)
+}
+
+/// Returns the aggregate of the values of the non-empty entries.
+fn aggregate(
+    entries: &[(i128, &Variable, &Variable)],
+    aggregation: Aggregation,
+) -> Result<Variable, TimeSeriesError> {
+    let values = entries.iter().map(|(_, _, value)| *value);
+    match aggregation {
+        Aggregation::Sum | Aggregation::Mean => {
+            let mut sum = 0.0;
+            for value in values {
+                sum += numeric_value(value)?;
+            }
+            if aggregation == Aggregation::Mean {
+                sum /= entries.len() as f64;
+            }
+            Ok(Variable::try_from(sum)?)
+        }
+        Aggregation::Min => Ok(values.min().unwrap().try_clone()?),
+        Aggregation::Max => Ok(values.max().unwrap().try_clone()?),
+        Aggregation::Last => Ok(entries.last().unwrap().2.try_clone()?),
+    }
+}
+
+/// Returns the value of an integer or a float as a 64-bit float.
+fn numeric_value(value: &Variable) -> Result<f64, TimeSeriesError> {
+    match value.data_spec().specification_type() {
+        DataSpecType::Primitive(Primitive::Integer(Some(def)))
+            if *def.spec().encoding() == Some(IntegerEncoding::Unsigned) =>
+        {
+            Ok(value.integer().u64()? as f64)
+        }
+        DataSpecType::Primitive(Primitive::Integer(..)) => Ok(value.integer().i64()? as f64),
+        _ => Ok(value.float().f64()?),
+    }
+}
+
+/// A trait for iterators over the entries of a time series.
+pub trait TimeSeriesIter<'a>:
+    Iterator<Item = Result<(&'a Variable, &'a Variable), TimeSeriesError>>
+{
+}
+
+impl<'a> IntoIterator for &'a TimeSeries {
+    type Item = Result<(&'a Variable, &'a Variable), TimeSeriesError>;
+    type IntoIter = Box<dyn TimeSeriesIter<'a> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl SetEqualTo for TimeSeries {
+    fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        self.spec().as_ref().check_compatible_with(other.spec())?;
+        self.clear()?;
+        for entry in other.iter() {
+            let (timestamp, value) = entry?;
+            self.insert(timestamp.try_clone()?, value.try_clone()?)?;
+        }
+        Ok(())
+    }
+}
+
+impl Accessor for TimeSeries {}
+
+impl PartialEq for TimeSeries {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(entry, other_entry)| entry.ok() == other_entry.ok())
+    }
+}
+
+impl Eq for TimeSeries {}
+
+impl PartialOrd for TimeSeries {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TimeSeries {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Time series are compared entry by entry, like sorted lists of timestamp/value pairs.
+        self.iter()
+            .map(|entry| entry.ok())
+            .cmp(other.iter().map(|entry| entry.ok()))
+    }
+}
+
+impl Hash for TimeSeries {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for (timestamp, value) in self.iter().flatten() {
+            timestamp.hash(state);
+            value.hash(state);
+        }
+    }
+}
+
+impl Display for TimeSeries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TimeSeries {{")?;
+        let mut first = true;
+        for result in self.iter() {
+            match result {
+                Ok((timestamp, value)) => {
+                    if !first {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", timestamp, value)?;
+                    first = false;
+                }
+                Err(e) => write!(f, "<error: {}>", e)?,
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
+impl Debug for TimeSeries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Errors that can occur when working with time series.
+#[derive(Debug, PartialEq)]
+pub enum TimeSeriesError {
+    /// A provider error.
+    ProviderError(ProviderError),
+    /// An error indicating that the timestamp specification is not compatible with the time
+    /// series' timestamp specification.
+    TimestampSpecError(SpecError),
+    /// An error indicating that the value specification is not compatible with the time series'
+    /// value specification.
+    ValueSpecError(SpecError),
+    /// An error indicating that the start timestamp of a range is after its end timestamp.
+    StartAfterEnd(String, String),
+    /// An error indicating that the duration of a bucket or a window is not positive.
+    NonPositiveDuration(String),
+    /// An error of a timestamp.
+    DateTimeError(DateTimeError),
+    /// An error of the duration of a bucket or a window.
+    DurationError(DayToSecondDurationError),
+    /// An error reading an integer value.
+    IntegerError(IntegerError),
+    /// An error reading or creating a float value.
+    FloatError(FloatError),
+    /// An error that occurred while copying a timestamp or a value.
+    ValueError(Box<SetEqualToError>),
+}
+
+impl From<ProviderError> for TimeSeriesError {
+    fn from(err: ProviderError) -> Self {
+        TimeSeriesError::ProviderError(err)
+    }
+}
+
+impl From<DateTimeError> for TimeSeriesError {
+    fn from(err: DateTimeError) -> Self {
+        TimeSeriesError::DateTimeError(err)
+    }
+}
+
+impl From<DayToSecondDurationError> for TimeSeriesError {
+    fn from(err: DayToSecondDurationError) -> Self {
+        TimeSeriesError::DurationError(err)
+    }
+}
+
+impl From<IntegerError> for TimeSeriesError {
+    fn from(err: IntegerError) -> Self {
+        TimeSeriesError::IntegerError(err)
+    }
+}
+
+impl From<FloatError> for TimeSeriesError {
+    fn from(err: FloatError) -> Self {
+        TimeSeriesError::FloatError(err)
+    }
+}
+
+impl From<SetEqualToError> for TimeSeriesError {
+    fn from(err: SetEqualToError) -> Self {
+        TimeSeriesError::ValueError(Box::new(err))
+    }
+}
+
+impl std::fmt::Display for TimeSeriesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeSeriesError::ProviderError(err) => write!(f, "Provider Error: {}", err),
+            TimeSeriesError::TimestampSpecError(err) => {
+                write!(f, "Timestamp specification error: {}", err)
+            }
+            TimeSeriesError::ValueSpecError(err) => {
+                write!(f, "Value specification error: {}", err)
+            }
+            TimeSeriesError::StartAfterEnd(start, end) => {
+                write!(f, "Start {} is after end {}", start, end)
+            }
+            TimeSeriesError::NonPositiveDuration(duration) => {
+                write!(f, "Duration {} is not positive", duration)
+            }
+            TimeSeriesError::DateTimeError(err) => write!(f, "Date-time error: {}", err),
+            TimeSeriesError::DurationError(err) => write!(f, "Duration error: {}", err),
+            TimeSeriesError::IntegerError(err) => write!(f, "Integer error: {}", err),
+            TimeSeriesError::FloatError(err) => write!(f, "Float error: {}", err),
+            TimeSeriesError::ValueError(err) => write!(f, "Value Error: {:?}", err),
+        }
+    }
+}
+
+impl std::error::Error for TimeSeriesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TimeSeriesError::ProviderError(e) => Some(e),
+            TimeSeriesError::TimestampSpecError(err) => Some(err),
+            TimeSeriesError::ValueSpecError(err) => Some(err),
+            TimeSeriesError::DateTimeError(err) => Some(err),
+            TimeSeriesError::DurationError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<TimeSeriesError> for SetEqualToError {
+    fn from(error: TimeSeriesError) -> Self {
+        SetEqualToError::TimeSeriesError(error)
+    }
+}
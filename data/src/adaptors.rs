@@ -2,6 +2,8 @@
 pub mod blob_adaptor;
 /// The `BooleanAdaptor` trait.
 pub mod boolean_adaptor;
+/// The `CharacterAdaptor` trait.
+pub mod character_adaptor;
 /// Collection adaptors.
 pub mod collection_adaptors;
 /// The `DataSpecAdaptor` trait.
@@ -18,6 +20,8 @@ pub mod guid_adaptor;
 pub mod identifier_adaptor;
 /// The `IntegerAdaptor` trait.
 pub mod integer_adaptor;
+/// The `IntervalAdaptor` trait.
+pub mod interval_adaptor;
 /// The `ReferenceAdaptor` trait.
 pub mod reference_adaptor;
 /// Schema adaptors.
@@ -26,5 +30,7 @@ pub mod schema_adaptors;
 pub mod sequence_adaptor;
 /// String adaptors.
 pub mod string_adaptors;
+/// The `TimeSeriesAdaptor` trait.
+pub mod time_series_adaptor;
 /// The `TupleAdaptor` trait.
 pub mod tuple_adaptor;
@@ -1,2 +1,57 @@
+use std::rc::Rc;
+
+use crate::{accessors::blob::BlobError, primitive_specs::blob_spec::BlobSpec};
+
 /// An adaptor for BLOBs.
-pub trait BlobAdaptor {}
+/// <p>
+/// Offsets and lengths are measured in bytes. Writes are checked against the
+/// spec's maximum size before they reach the adaptor.
+/// </p>
+pub trait BlobAdaptor {
+    /// Returns the BLOB's specification.
+    fn spec(&self) -> &Rc<BlobSpec>;
+
+    /// Returns the length of the BLOB in bytes.
+    fn len(&self) -> u64;
+
+    /// Returns whether the BLOB is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reads bytes starting at the offset into the buffer and returns the number of bytes read.
+    /// <p>
+    /// Fewer bytes than the buffer holds are read if the end of the BLOB is reached;
+    /// no bytes are read at or beyond the end.
+    /// </p>
+    fn read(&self, offset: u64, buffer: &mut [u8]) -> Result<usize, BlobError>;
+
+    /// Writes the bytes starting at the offset, extending the BLOB as needed.
+    /// <p>
+    /// Writing beyond the end of the BLOB fills the gap with zeros.
+    /// </p>
+    fn write(&mut self, offset: u64, bytes: &[u8]) -> Result<(), BlobError> {
+        let end = offset.saturating_add(bytes.len() as u64);
+        if let Some(max_size) = self.spec().max_size()
+            && end > max_size
+        {
+            return Err(BlobError::MaxSizeViolation(max_size, end));
+        }
+        self.do_write(offset, bytes)
+    }
+
+    /// Writes the bytes starting at the offset, extending the BLOB as needed.
+    fn do_write(&mut self, offset: u64, bytes: &[u8]) -> Result<(), BlobError>;
+
+    /// Shortens the BLOB to the given length. Has no effect if the BLOB is not longer.
+    fn truncate(&mut self, len: u64) -> Result<(), BlobError> {
+        if len < self.len() {
+            self.do_truncate(len)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Shortens the BLOB to the given length.
+    fn do_truncate(&mut self, len: u64) -> Result<(), BlobError>;
+}
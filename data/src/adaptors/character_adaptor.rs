@@ -0,0 +1,27 @@
+use std::rc::Rc;
+
+use crate::{accessors::character::CharacterError, primitive_specs::character_spec::CharacterSpec};
+
+/// An adaptor for characters.
+/// <p>
+/// Characters are validated against the spec's storage before they reach the
+/// adaptor, so an adaptor only ever holds characters its storage can represent.
+/// </p>
+pub trait CharacterAdaptor {
+    /// Returns the character's specification.
+    fn spec(&self) -> &Rc<CharacterSpec>;
+
+    /// Returns the value of the character.
+    fn character(&self) -> Result<char, CharacterError>;
+
+    /// Sets the value of the character.
+    fn set_character(&mut self, value: char) -> Result<(), CharacterError> {
+        if !self.spec().can_represent(value) {
+            return Err(CharacterError::UnrepresentableCharacter(value));
+        }
+        self.do_set_character(value)
+    }
+
+    /// Sets the value of the character.
+    fn do_set_character(&mut self, value: char) -> Result<(), CharacterError>;
+}
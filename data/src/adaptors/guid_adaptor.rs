@@ -1,9 +1,33 @@
-use std::sync::Arc;
+use std::rc::Rc;
 
-use crate::primitive_specs::guid_spec::GuidSpec;
+use crate::{accessors::guid::GuidError, primitive_specs::guid_spec::GuidSpec};
 
 /// An adaptor for GUID values.
+/// <p>
+/// GUIDs are exchanged as 16 bytes in big-endian (RFC 9562) order. Values are checked
+/// against the spec's pinned version before they reach the adaptor; the nil GUID is
+/// always accepted.
+/// </p>
 pub trait GuidAdaptor {
     /// Returns the GUID's specification.
-    fn spec(&self) -> &Arc<GuidSpec>;
+    fn spec(&self) -> &Rc<GuidSpec>;
+
+    /// Returns the GUID's bytes.
+    fn bytes(&self) -> Result<[u8; 16], GuidError>;
+
+    /// Sets the GUID's bytes.
+    fn set_bytes(&mut self, bytes: &[u8; 16]) -> Result<(), GuidError> {
+        if let Some(version) = self.spec().version()
+            && *bytes != [0; 16]
+        {
+            let actual = bytes[6] >> 4;
+            if actual != version.number() {
+                return Err(GuidError::VersionMismatch(version.number(), actual));
+            }
+        }
+        self.do_set_bytes(bytes)
+    }
+
+    /// Sets the GUID's bytes.
+    fn do_set_bytes(&mut self, bytes: &[u8; 16]) -> Result<(), GuidError>;
 }
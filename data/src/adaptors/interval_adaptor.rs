@@ -0,0 +1,58 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::interval::IntervalError,
+    primitive_specs::interval_spec::{BoundType, IntervalSpec},
+    spec_compatibility::SpecCompatibility,
+    variable::Variable,
+};
+
+/// An adaptor for intervals.
+pub trait IntervalAdaptor {
+    /// Returns the interval's specification.
+    fn spec(&self) -> &Rc<IntervalSpec>;
+
+    /// Returns the interval's start value.
+    fn start(&self) -> Result<&Variable, IntervalError>;
+
+    /// Returns the interval's end value.
+    fn end(&self) -> Result<&Variable, IntervalError>;
+
+    /// Returns the bound type of the interval's start.
+    fn start_bound(&self) -> Result<BoundType, IntervalError>;
+
+    /// Returns the bound type of the interval's end.
+    fn end_bound(&self) -> Result<BoundType, IntervalError>;
+
+    /// Sets the interval's start and end values and their bound types.
+    /// If a value is not compatible with the value spec, or the start is after the end, it will
+    /// return an error.
+    fn set(
+        &mut self,
+        start: Variable,
+        start_bound: BoundType,
+        end: Variable,
+        end_bound: BoundType,
+    ) -> Result<(), IntervalError> {
+        if let Some(value_spec) = self.spec().value_spec().as_ref() {
+            start.data_spec().check_compatible_with(value_spec)?;
+            end.data_spec().check_compatible_with(value_spec)?;
+        }
+        if start > end {
+            return Err(IntervalError::StartAfterEnd(
+                start.to_string(),
+                end.to_string(),
+            ));
+        }
+        self.do_set(start, start_bound, end, end_bound)
+    }
+
+    /// Sets the interval's start and end values and their bound types.
+    fn do_set(
+        &mut self,
+        start: Variable,
+        start_bound: BoundType,
+        end: Variable,
+        end_bound: BoundType,
+    ) -> Result<(), IntervalError>;
+}
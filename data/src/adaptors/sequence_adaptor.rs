@@ -4,6 +4,7 @@ use crate::{
     accessors::sequence::{Sequence, SequenceError, SequenceIter},
     primitive_specs::sequence_spec::SequenceSpec,
     set_equal_to::SetEqualToError,
+    variable::Variable,
 };
 
 /// An adaptor for sequences.
@@ -36,3 +37,15 @@ pub trait SequenceAdaptor {
         Err(SetEqualToError::SequenceError(SequenceError::ReadOnlyError))
     }
 }
+
+/// A generator of the values of a sequence, which are generated when first iterated to.
+pub trait SequenceGenerator {
+    /// Returns the value that follows the previous value, or the first value if there is no
+    /// previous value, with the given index from zero. Returns `None` at the end of the sequence,
+    /// or an error if the value cannot be generated.
+    fn next_value(
+        &self,
+        index: usize,
+        previous: Option<&Variable>,
+    ) -> Option<Result<Variable, SequenceError>>;
+}
@@ -1,2 +1,127 @@
+use std::rc::Rc;
+
+use crate::{accessors::string::StringError, primitive_specs::string_spec::StringSpec};
+
 /// An adaptor for byte strings.
-pub trait ByteStringAdaptor {}
+/// <p>
+/// Lengths, sizes, and capacities are measured in bytes.
+/// Bytes are stored as given; they are only interpreted through the
+/// spec's code page when converting to and from Rust strings.
+/// </p>
+pub trait ByteStringAdaptor {
+    /// Returns the string's specification.
+    fn spec(&self) -> &Rc<StringSpec>;
+
+    /// Returns the string's fixed size, if the string is of fixed size.
+    fn fixed_size(&self) -> Option<usize>;
+
+    /// Returns the string's fixed capacity, if the string is of fixed capacity.
+    fn fixed_capacity(&self) -> Option<usize>;
+
+    /// Returns the string's bytes.
+    fn bytes(&self) -> Result<Vec<u8>, StringError>;
+
+    /// Returns the length of the string in bytes.
+    fn len(&self) -> usize;
+
+    /// Returns whether the string is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the byte at the specified index.
+    fn byte(&self, index: usize) -> Result<u8, StringError> {
+        if index < self.len() {
+            self.do_byte(index)
+        } else {
+            Err(StringError::IndexOutOfBounds(index, self.len()))
+        }
+    }
+
+    /// Returns the byte at the specified index.
+    fn do_byte(&self, index: usize) -> Result<u8, StringError>;
+
+    /// Sets the byte at the specified index.
+    fn set_byte(&mut self, index: usize, value: u8) -> Result<(), StringError> {
+        if index < self.len() {
+            self.do_set_byte(index, value)
+        } else {
+            Err(StringError::IndexOutOfBounds(index, self.len()))
+        }
+    }
+
+    /// Sets the byte at the specified index.
+    fn do_set_byte(&mut self, index: usize, value: u8) -> Result<(), StringError>;
+
+    /// Returns the bytes in the range from `start` (inclusive) to `end` (exclusive).
+    fn slice(&self, start: usize, end: usize) -> Result<Vec<u8>, StringError> {
+        if start > end {
+            return Err(StringError::IndexOutOfBounds(start, end));
+        }
+        if end > self.len() {
+            return Err(StringError::IndexOutOfBounds(end, self.len()));
+        }
+        self.do_slice(start, end)
+    }
+
+    /// Returns the bytes in the range from `start` (inclusive) to `end` (exclusive).
+    fn do_slice(&self, start: usize, end: usize) -> Result<Vec<u8>, StringError>;
+
+    /// Sets the string's bytes.
+    /// <p>
+    /// A fixed-size string is padded with spaces up to its size.
+    /// </p>
+    fn set_bytes(&mut self, bytes: &[u8]) -> Result<(), StringError> {
+        if let Some(size) = self.fixed_size() {
+            if bytes.len() > size {
+                return Err(StringError::FixedSizeViolation(size, bytes.len()));
+            }
+            let mut padded = bytes.to_vec();
+            padded.resize(size, b' ');
+            return self.do_set_bytes(&padded);
+        }
+        if let Some(capacity) = self.fixed_capacity()
+            && bytes.len() > capacity
+        {
+            return Err(StringError::FixedCapacityViolation(capacity, bytes.len()));
+        }
+        self.do_set_bytes(bytes)
+    }
+
+    /// Sets the string's bytes.
+    fn do_set_bytes(&mut self, bytes: &[u8]) -> Result<(), StringError>;
+
+    /// Appends bytes to the end of the string.
+    fn append_bytes(&mut self, bytes: &[u8]) -> Result<(), StringError> {
+        let length = self.len() + bytes.len();
+        if let Some(size) = self.fixed_size()
+            && length != size
+        {
+            return Err(StringError::FixedSizeViolation(size, length));
+        }
+        if let Some(capacity) = self.fixed_capacity()
+            && length > capacity
+        {
+            return Err(StringError::FixedCapacityViolation(capacity, length));
+        }
+        self.do_append_bytes(bytes)
+    }
+
+    /// Appends bytes to the end of the string.
+    fn do_append_bytes(&mut self, bytes: &[u8]) -> Result<(), StringError>;
+
+    /// Clears the string.
+    /// <p>
+    /// A fixed-size string is reset to all spaces.
+    /// </p>
+    fn clear(&mut self) -> Result<(), StringError> {
+        if self.fixed_size().is_some() {
+            self.set_bytes(&[])
+        } else {
+            self.do_clear()
+        }
+    }
+
+    /// Clears the string.
+    fn do_clear(&mut self) -> Result<(), StringError>;
+}
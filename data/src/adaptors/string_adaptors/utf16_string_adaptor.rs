@@ -1,2 +1,122 @@
+use std::rc::Rc;
+
+use crate::{accessors::string::StringError, primitive_specs::string_spec::StringSpec};
+
 /// An adaptor for UTF-16 strings.
-pub trait Utf16StringAdaptor {}
+/// <p>
+/// Lengths, sizes, and capacities are measured in 16-bit code units.
+/// Code units are validated before they reach the adaptor, so an adaptor
+/// never holds an unpaired surrogate.
+/// </p>
+pub trait Utf16StringAdaptor {
+    /// Returns the string's specification.
+    fn spec(&self) -> &Rc<StringSpec>;
+
+    /// Returns the string's fixed size, if the string is of fixed size.
+    fn fixed_size(&self) -> Option<usize>;
+
+    /// Returns the string's fixed capacity, if the string is of fixed capacity.
+    fn fixed_capacity(&self) -> Option<usize>;
+
+    /// Returns the string's code units.
+    fn code_units(&self) -> Result<Vec<u16>, StringError>;
+
+    /// Returns the length of the string in code units.
+    fn len(&self) -> usize;
+
+    /// Returns whether the string is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the code unit at the specified index.
+    fn code_unit(&self, index: usize) -> Result<u16, StringError> {
+        if index < self.len() {
+            self.do_code_unit(index)
+        } else {
+            Err(StringError::IndexOutOfBounds(index, self.len()))
+        }
+    }
+
+    /// Returns the code unit at the specified index.
+    fn do_code_unit(&self, index: usize) -> Result<u16, StringError>;
+
+    /// Sets the string's code units.
+    /// <p>
+    /// A fixed-size string is padded with spaces up to its size.
+    /// </p>
+    fn set_code_units(&mut self, units: &[u16]) -> Result<(), StringError> {
+        validate_utf16(units)?;
+        if let Some(size) = self.fixed_size() {
+            if units.len() > size {
+                return Err(StringError::FixedSizeViolation(size, units.len()));
+            }
+            let mut padded = units.to_vec();
+            padded.resize(size, ' ' as u16);
+            return self.do_set_code_units(&padded);
+        }
+        if let Some(capacity) = self.fixed_capacity()
+            && units.len() > capacity
+        {
+            return Err(StringError::FixedCapacityViolation(capacity, units.len()));
+        }
+        self.do_set_code_units(units)
+    }
+
+    /// Sets the string's code units.
+    fn do_set_code_units(&mut self, units: &[u16]) -> Result<(), StringError>;
+
+    /// Appends code units to the end of the string.
+    fn append_code_units(&mut self, units: &[u16]) -> Result<(), StringError> {
+        validate_utf16(units)?;
+        let length = self.len() + units.len();
+        if let Some(size) = self.fixed_size()
+            && length != size
+        {
+            return Err(StringError::FixedSizeViolation(size, length));
+        }
+        if let Some(capacity) = self.fixed_capacity()
+            && length > capacity
+        {
+            return Err(StringError::FixedCapacityViolation(capacity, length));
+        }
+        self.do_append_code_units(units)
+    }
+
+    /// Appends code units to the end of the string.
+    fn do_append_code_units(&mut self, units: &[u16]) -> Result<(), StringError>;
+
+    /// Clears the string.
+    /// <p>
+    /// A fixed-size string is reset to all spaces.
+    /// </p>
+    fn clear(&mut self) -> Result<(), StringError> {
+        if self.fixed_size().is_some() {
+            self.set_code_units(&[])
+        } else {
+            self.do_clear()
+        }
+    }
+
+    /// Clears the string.
+    fn do_clear(&mut self) -> Result<(), StringError>;
+}
+
+/// Checks that the code units form well-formed UTF-16, i.e. that every
+/// surrogate is part of a surrogate pair.
+pub(crate) fn validate_utf16(units: &[u16]) -> Result<(), StringError> {
+    let mut index = 0;
+    while index < units.len() {
+        match units[index] {
+            0xD800..=0xDBFF => {
+                if !matches!(units.get(index + 1), Some(0xDC00..=0xDFFF)) {
+                    return Err(StringError::LoneSurrogate(index));
+                }
+                index += 2;
+            }
+            0xDC00..=0xDFFF => return Err(StringError::LoneSurrogate(index)),
+            _ => index += 1,
+        }
+    }
+    Ok(())
+}
@@ -1,2 +1,86 @@
+use std::rc::Rc;
+
+use crate::{accessors::string::StringError, primitive_specs::string_spec::StringSpec};
+
 /// An adaptor for UTF-8 strings.
-pub trait Utf8StringAdaptor {}
+/// <p>
+/// Lengths, sizes, and capacities are measured in code units, which for UTF-8 are bytes.
+/// </p>
+pub trait Utf8StringAdaptor {
+    /// Returns the string's specification.
+    fn spec(&self) -> &Rc<StringSpec>;
+
+    /// Returns the string's fixed size, if the string is of fixed size.
+    fn fixed_size(&self) -> Option<usize>;
+
+    /// Returns the string's fixed capacity, if the string is of fixed capacity.
+    fn fixed_capacity(&self) -> Option<usize>;
+
+    /// Returns the value of the string.
+    fn string(&self) -> Result<String, StringError>;
+
+    /// Returns the length of the string in bytes.
+    fn len(&self) -> usize;
+
+    /// Returns whether the string is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sets the value of the string.
+    /// <p>
+    /// A fixed-size string is padded with spaces up to its size.
+    /// </p>
+    fn set_string(&mut self, value: &str) -> Result<(), StringError> {
+        if let Some(size) = self.fixed_size() {
+            if value.len() > size {
+                return Err(StringError::FixedSizeViolation(size, value.len()));
+            }
+            let padded = value.to_string() + &" ".repeat(size - value.len());
+            return self.do_set_string(&padded);
+        }
+        if let Some(capacity) = self.fixed_capacity()
+            && value.len() > capacity
+        {
+            return Err(StringError::FixedCapacityViolation(capacity, value.len()));
+        }
+        self.do_set_string(value)
+    }
+
+    /// Sets the value of the string.
+    fn do_set_string(&mut self, value: &str) -> Result<(), StringError>;
+
+    /// Appends a value to the end of the string.
+    fn append(&mut self, value: &str) -> Result<(), StringError> {
+        let length = self.len() + value.len();
+        if let Some(size) = self.fixed_size()
+            && length != size
+        {
+            return Err(StringError::FixedSizeViolation(size, length));
+        }
+        if let Some(capacity) = self.fixed_capacity()
+            && length > capacity
+        {
+            return Err(StringError::FixedCapacityViolation(capacity, length));
+        }
+        self.do_append(value)
+    }
+
+    /// Appends a value to the end of the string.
+    fn do_append(&mut self, value: &str) -> Result<(), StringError>;
+
+    /// Clears the string.
+    /// <p>
+    /// A fixed-size string is reset to all spaces.
+    /// </p>
+    fn clear(&mut self) -> Result<(), StringError> {
+        if self.fixed_size().is_some() {
+            self.set_string("")
+        } else {
+            self.do_clear()
+        }
+    }
+
+    /// Clears the string.
+    fn do_clear(&mut self) -> Result<(), StringError>;
+}
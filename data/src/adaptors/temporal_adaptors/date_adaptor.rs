@@ -14,6 +14,9 @@ use std::rc::Rc;
 /// the actual storage and retrieval of the date value as a u32 without overriding `set_date` and `date`,
 /// or override the `set_date` and `date` methods without overriding `set_days` and `days`.
 ///
+/// An implementation that supports the extended date range, with dates before January 1, 0001,
+/// also overrides the `set_signed_days` and `signed_days` methods.
+///
 pub trait DateAdaptor: Adaptor {
     /// Returns the date's specification.
     fn spec(&self) -> &Rc<DateSpec>;
@@ -35,15 +38,39 @@ pub trait DateAdaptor: Adaptor {
         unimplemented!()
     }
 
+    /// Sets the date value to the specified signed days, the number of whole days from January 1,
+    /// 0001 A.D. (C.E.), which is negative for earlier dates of the proleptic Gregorian calendar.
+    /// <p>
+    /// The default implementation supports the dates from January 1, 0001 on only, and stores
+    /// them with `set_days` or `set_date`.
+    /// </p>
+    fn set_signed_days(&mut self, days: i64) -> Result<(), DateError> {
+        let Ok(unsigned_days) = u32::try_from(days) else {
+            return Err(DateError::DateBeforeYearOne(date_from_signed_days(days).0));
+        };
+        if self.stores_date_as_days() {
+            return self.set_days(unsigned_days);
+        }
+        let (year, month, day) = date_from_days(unsigned_days);
+        self.set_date(year, month, day)
+    }
+
+    /// Returns the date value as signed days, the number of whole days from January 1, 0001 A.D.
+    /// (C.E.), which is negative for earlier dates of the proleptic Gregorian calendar.
+    fn signed_days(&self) -> Result<i64, DateError> {
+        if self.stores_date_as_days() {
+            return Ok(self.days()? as i64);
+        }
+        let (year, month, day) = self.date()?;
+        Ok(days_from_date(year, month, day) as i64)
+    }
+
     /// Sets the date to the specified year, month, and day.
     fn set_date(&mut self, year: u32, month: u32, day: u32) -> Result<(), DateError> {
         if !self.stores_date_as_days() {
             unimplemented!()
         }
-        let y = year - 1;
-        let mut days = (365u32 * y) + (y / 4u32) - (y / 100u32) + (y / 400u32);
-        days += days_before_month(is_leap_year(year), month) + day - 1;
-        self.set_days(days)
+        self.set_days(days_from_date(year, month, day))
     }
 
     /// Returns the current date as a tuple of (year, month, day).
@@ -51,14 +78,7 @@ pub trait DateAdaptor: Adaptor {
         if !self.stores_date_as_days() {
             unimplemented!()
         }
-        let mut days = self.days()?;
-        let year = year_from_days(&mut days);
-        let month = month_from_days(is_leap_year(year), &mut days);
-        Ok((
-            year,
-            month,
-            days + 1, /* to got to one based from zero based */
-        ))
+        Ok(date_from_days(self.days()?))
     }
 
     /// Returns the year component of the current date.
@@ -94,6 +114,86 @@ pub trait DateAdaptor: Adaptor {
     }
 }
 
+/// Returns the number of days from January 1, 0001 to the given year, month, and day.
+pub(crate) fn days_from_date(year: u32, month: u32, day: u32) -> u32 {
+    let y = year - 1;
+    let mut days = (365u32 * y) + (y / 4u32) - (y / 100u32) + (y / 400u32);
+    days += days_before_month(is_leap_year(year), month) + day - 1;
+    days
+}
+
+/// Returns the (year, month, day) that lies the given number of days after January 1, 0001.
+pub(crate) fn date_from_days(mut days: u32) -> (u32, u32, u32) {
+    let year = year_from_days(&mut days);
+    let month = month_from_days(is_leap_year(year), &mut days);
+    (
+        year,
+        month,
+        days + 1, /* to got to one based from zero based */
+    )
+}
+
+/// Returns the signed number of days from January 1, 0001 to the given year, month, and day of
+/// the proleptic Gregorian calendar, where the years are numbered astronomically.
+pub(crate) fn signed_days_from_date(year: i64, month: u32, day: u32) -> i64 {
+    let y = year - 1;
+    let days = (365 * y) + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400);
+    days + (days_before_month(is_proleptic_leap_year(year), month) + day - 1) as i64
+}
+
+/// Returns the (year, month, day) of the proleptic Gregorian calendar that lies the given signed
+/// number of days after January 1, 0001.
+pub(crate) fn date_from_signed_days(days: i64) -> (i64, u32, u32) {
+    // the Gregorian calendar repeats every 400 years
+    let cycles = days.div_euclid(DAYS_PER_400_YEARS as i64);
+    let (year, month, day) = date_from_days(days.rem_euclid(DAYS_PER_400_YEARS as i64) as u32);
+    (cycles * 400 + year as i64, month, day)
+}
+
+/// Returns the signed number of days from January 1, 0001 of the Gregorian calendar to the given
+/// year, month, and day of the proleptic Julian calendar.
+pub(crate) fn signed_days_from_julian_date(year: i64, month: u32, day: u32) -> i64 {
+    let y = year - 1;
+    let days = (365 * y) + y.div_euclid(4);
+    // January 1, 0001 of the Julian calendar is December 30, 0000 of the Gregorian calendar
+    days + (days_before_month(is_julian_leap_year(year), month) + day - 1) as i64
+        - JULIAN_EPOCH_OFFSET
+}
+
+/// Returns the (year, month, day) of the proleptic Julian calendar that lies the given signed
+/// number of days after January 1, 0001 of the Gregorian calendar.
+pub(crate) fn julian_date_from_signed_days(days: i64) -> (i64, u32, u32) {
+    let days = days + JULIAN_EPOCH_OFFSET;
+    // the Julian calendar repeats every 4 years
+    let cycles = days.div_euclid(DAYS_PER_4_YEARS as i64);
+    let mut days = days.rem_euclid(DAYS_PER_4_YEARS as i64) as u32;
+    let mut years = days / DAYS_NON_LEAP_YEAR;
+    if years == 4 {
+        // This is the last day of a leap year
+        years -= 1;
+    }
+    days -= years * DAYS_NON_LEAP_YEAR;
+    let year = cycles * 4 + years as i64 + 1;
+    let month = month_from_days(is_julian_leap_year(year), &mut days);
+    (year, month, days + 1)
+}
+
+/// Determines if a given astronomically numbered year is a leap year of the proleptic Gregorian
+/// calendar.
+pub(crate) fn is_proleptic_leap_year(year: i64) -> bool {
+    (year.rem_euclid(4) == 0) && ((year.rem_euclid(100) != 0) || (year.rem_euclid(400) == 0))
+}
+
+/// Determines if a given astronomically numbered year is a leap year of the proleptic Julian
+/// calendar.
+pub(crate) fn is_julian_leap_year(year: i64) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+/// The number of days by which January 1, 0001 of the Julian calendar precedes January 1, 0001
+/// of the Gregorian calendar.
+const JULIAN_EPOCH_OFFSET: i64 = 2;
+
 /// Returns the number of days before the given month in a year according to if it is a leap year.
 fn days_before_month(is_leap_year: bool, month: u32) -> u32 {
     const PROCEDING_DAYS_FOR_MONTH: [u32; 12] =
@@ -120,7 +220,11 @@ fn year_from_days(days: &mut u32) -> u32 {
     let number_of_400_years = *days / DAYS_PER_400_YEARS;
     *days -= number_of_400_years * DAYS_PER_400_YEARS;
 
-    let number_of_100_years = *days / DAYS_PER_100_YEARS;
+    let mut number_of_100_years = *days / DAYS_PER_100_YEARS;
+    if number_of_100_years == 4 {
+        // This is the last day of a 400 year cycle, which is a leap year
+        number_of_100_years -= 1;
+    }
     *days -= number_of_100_years * DAYS_PER_100_YEARS;
 
     let number_of_4_years = *days / DAYS_PER_4_YEARS;
@@ -1,8 +1,12 @@
 use std::rc::Rc;
 
 use crate::{
-    accessors::temporal::day_to_second_duration::DayToSecondDurationError, adaptor::Adaptor,
+    accessors::temporal::day_to_second_duration::{
+        DayToSecondDurationError, from_total_nanoseconds, to_total_nanoseconds,
+    },
+    adaptor::Adaptor,
     primitive_specs::duration_spec::DurationSpec,
+    provider_error::ProviderError,
 };
 
 /// An adaptor for day-second durations.
@@ -10,7 +14,30 @@ pub trait DayToSecondDurationAdaptor: Adaptor {
     /// Returns the duration's specification.
     fn spec(&self) -> &Rc<DurationSpec>;
 
+    /// Returns true if the duration is stored as total nanoseconds, false otherwise.
+    fn stores_duration_as_nanoseconds(&self) -> bool;
+
+    /// Sets the duration in total nanoseconds.
+    /// Adaptors that store the duration as total nanoseconds must implement this; the default
+    /// returns a provider error.
+    fn set_total_nanoseconds(
+        &mut self,
+        _nanoseconds: i128,
+    ) -> Result<(), DayToSecondDurationError> {
+        Err(not_implemented("set_total_nanoseconds"))
+    }
+
+    /// Returns the duration in total nanoseconds.
+    /// Adaptors that store the duration as total nanoseconds must implement this; the default
+    /// returns a provider error.
+    fn total_nanoseconds(&self) -> Result<i128, DayToSecondDurationError> {
+        Err(not_implemented("total_nanoseconds"))
+    }
+
     /// Sets the duration in days, hours, minutes, seconds, milliseconds, microseconds, and nanoseconds.
+    /// Adaptors that store the duration as fields must implement this; the default returns a
+    /// provider error.
+    #[allow(clippy::too_many_arguments)]
     fn set_duration(
         &mut self,
         days: i32,
@@ -20,10 +47,32 @@ pub trait DayToSecondDurationAdaptor: Adaptor {
         milliseconds: i32,
         microseconds: i32,
         nanoseconds: i32,
-    ) -> Result<(), DayToSecondDurationError>;
+    ) -> Result<(), DayToSecondDurationError> {
+        if self.stores_duration_as_nanoseconds() {
+            self.set_total_nanoseconds(to_total_nanoseconds((
+                days,
+                hours,
+                minutes,
+                seconds,
+                milliseconds,
+                microseconds,
+                nanoseconds,
+            )))
+        } else {
+            Err(not_implemented("set_duration"))
+        }
+    }
 
     /// Returns the duration as a tuple of (days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
-    fn duration(&self) -> Result<(i32, i32, i32, i32, i32, i32, i32), DayToSecondDurationError>;
+    /// Adaptors that store the duration as fields must implement this; the default returns a
+    /// provider error.
+    fn duration(&self) -> Result<(i32, i32, i32, i32, i32, i32, i32), DayToSecondDurationError> {
+        if self.stores_duration_as_nanoseconds() {
+            from_total_nanoseconds(self.total_nanoseconds()?)
+        } else {
+            Err(not_implemented("duration"))
+        }
+    }
 
     /// Returns the number of days in the duration.
     fn days(&self) -> Result<i32, DayToSecondDurationError> {
@@ -67,3 +116,11 @@ pub trait DayToSecondDurationAdaptor: Adaptor {
         Ok(nanoseconds)
     }
 }
+
+/// Returns the error of a default method that the adaptor needed to implement.
+fn not_implemented(method: &str) -> DayToSecondDurationError {
+    DayToSecondDurationError::ProviderError(ProviderError::General(format!(
+        "The day-to-second-duration adaptor does not implement '{}'.",
+        method
+    )))
+}
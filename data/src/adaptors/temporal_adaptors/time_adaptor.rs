@@ -1,7 +1,13 @@
 use std::rc::Rc;
 
 use crate::{
-    accessors::temporal::time::TimeError, adaptor::Adaptor, primitive_specs::time_spec::TimeSpec,
+    accessors::temporal::{
+        NANOSECONDS_PER_DAY,
+        clock::{Clock, local_now},
+        time::{TimeError, time_from_nanoseconds_of_day},
+    },
+    adaptor::Adaptor,
+    primitive_specs::time_spec::TimeSpec,
 };
 
 /// An adaptor for times.
@@ -30,9 +36,14 @@ pub trait TimeAdaptor: Adaptor {
         nanosecond: u16,
     ) -> Result<(), TimeError>;
 
-    /// Sets the time to the current system time.
-    fn set_to_now(&mut self) -> Result<(), TimeError> {
-        unimplemented!()
+    /// Sets the time to the current local time of the given clock, truncated to the resolution of
+    /// the time's spec.
+    fn set_to_now(&mut self, clock: &dyn Clock) -> Result<(), TimeError> {
+        let nanoseconds =
+            local_now(clock, self.spec().resolution().as_ref()).rem_euclid(NANOSECONDS_PER_DAY);
+        let (hour, minute, second, millisecond, microsecond, nanosecond) =
+            time_from_nanoseconds_of_day(nanoseconds as u64);
+        self.set_time(hour, minute, second, millisecond, microsecond, nanosecond)
     }
 
     /// Returns the current time as a tuple of (hour, minute, second, millisecond, microsecond, nanosecond).
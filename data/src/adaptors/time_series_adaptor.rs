@@ -0,0 +1,69 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::time_series::{TimeSeriesError, TimeSeriesIter},
+    primitive_specs::time_series_spec::TimeSeriesSpec,
+    spec_compatibility::SpecCompatibility,
+    variable::Variable,
+};
+
+/// An adaptor for time series.
+pub trait TimeSeriesAdaptor {
+    /// Returns the time series' specification.
+    fn spec(&self) -> &Rc<TimeSeriesSpec>;
+
+    /// Returns the number of entries in the time series.
+    fn len(&self) -> usize;
+
+    /// Returns whether the time series is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the value at the timestamp, if any.
+    fn get(&self, timestamp: &Variable) -> Result<Option<&Variable>, TimeSeriesError>;
+
+    /// Adds the value at the timestamp, replacing any value already there. Returns `true` if the
+    /// timestamp was added, `false` if it was already present.
+    ///
+    /// This method checks if the timestamp's and the value's data specifications are compatible
+    /// with those of the time series.
+    fn insert(&mut self, timestamp: Variable, value: Variable) -> Result<bool, TimeSeriesError> {
+        if let Some(timestamp_spec) = self.spec().timestamp_spec().as_ref() {
+            timestamp
+                .data_spec()
+                .check_compatible_with(timestamp_spec)
+                .map_err(TimeSeriesError::TimestampSpecError)?;
+        }
+        if let Some(value_spec) = self.spec().value_spec().as_ref() {
+            value
+                .data_spec()
+                .check_compatible_with(value_spec)
+                .map_err(TimeSeriesError::ValueSpecError)?;
+        }
+        self.do_insert(timestamp, value)
+    }
+
+    /// Adds the value at the timestamp, replacing any value already there. Returns `true` if the
+    /// timestamp was added, `false` if it was already present.
+    fn do_insert(&mut self, timestamp: Variable, value: Variable) -> Result<bool, TimeSeriesError>;
+
+    /// Removes the entry at the timestamp. Returns `true` if there was one, `false` otherwise.
+    fn remove(&mut self, timestamp: &Variable) -> Result<bool, TimeSeriesError>;
+
+    /// Clears the time series.
+    fn clear(&mut self) -> Result<(), TimeSeriesError>;
+
+    /// Returns the latest entry at or before the timestamp, if any.
+    fn as_of(
+        &self,
+        timestamp: &Variable,
+    ) -> Result<Option<(&Variable, &Variable)>, TimeSeriesError>;
+
+    /// Returns an iterator for the entries in the order of their timestamps.
+    fn iter<'a>(&'a self) -> Box<dyn TimeSeriesIter<'a> + 'a>;
+
+    /// Returns an iterator for the entries from the start timestamp, inclusive, to the end
+    /// timestamp, exclusive, in the order of their timestamps. The start is not after the end.
+    fn range<'a>(&'a self, start: &Variable, end: &Variable) -> Box<dyn TimeSeriesIter<'a> + 'a>;
+}
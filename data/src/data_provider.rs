@@ -4,30 +4,37 @@ use crate::{
     accessors::{
         blob::Blob,
         boolean::Boolean,
+        character::Character,
         collections::{list::List, map::Map, set::Set},
         float::Float,
+        guid::Guid,
         integer::Integer,
+        interval::Interval,
         sequence::Sequence,
         strings::{
             byte_string::ByteString, utf8_string::Utf8String, utf16_string::Utf16String,
             utf32_string::Utf32String,
         },
         temporal::{
-            date::Date, date_time::DateTime, time::Time,
-            year_to_month_duration::YearToMonthDuration, zoned_date_time::ZonedDateTime,
-            zoned_time::ZonedTime,
+            date::Date, date_time::DateTime, day_to_second_duration::DayToSecondDuration,
+            time::Time, year_to_month_duration::YearToMonthDuration,
+            zoned_date_time::ZonedDateTime, zoned_time::ZonedTime,
         },
+        time_series::TimeSeries,
         tuple::Tuple,
     },
     adaptors::{
         blob_adaptor::BlobAdaptor,
         boolean_adaptor::BooleanAdaptor,
+        character_adaptor::CharacterAdaptor,
         collection_adaptors::{
             list_adaptor::ListAdaptor, map_adaptor::MapAdaptor, set_adaptor::SetAdaptor,
         },
         float_adaptor::FloatAdaptor,
+        guid_adaptor::GuidAdaptor,
         integer_adaptor::IntegerAdaptor,
-        sequence_adaptor::SequenceAdaptor,
+        interval_adaptor::IntervalAdaptor,
+        sequence_adaptor::{SequenceAdaptor, SequenceGenerator},
         string_adaptors::{
             byte_string_adaptor::ByteStringAdaptor, utf8_string_adaptor::Utf8StringAdaptor,
             utf16_string_adaptor::Utf16StringAdaptor, utf32_string_adaptor::Utf32StringAdaptor,
@@ -36,6 +43,7 @@ use crate::{
             date_adaptor::DateAdaptor, day_to_second_duration_adaptor::DayToSecondDurationAdaptor,
             time_adaptor::TimeAdaptor, year_to_month_duration_adaptor::YearToMonthDurationAdaptor,
         },
+        time_series_adaptor::TimeSeriesAdaptor,
         tuple_adaptor::TupleAdaptor,
     },
     data_spec::{DataSpec, DataSpecLevel, DataSpecType},
@@ -43,11 +51,12 @@ use crate::{
     primitive::Primitive,
     primitive_def::PrimitiveDef,
     primitive_specs::{
-        blob_spec::BlobSpec, boolean_spec::BooleanSpec, date_spec::DateSpec,
-        date_time_spec::DateTimeSpec, duration_spec::DurationSpec, float_spec::FloatSpec,
-        integer_spec::IntegerSpec, list_spec::ListSpec, map_spec::MapSpec,
+        blob_spec::BlobSpec, boolean_spec::BooleanSpec, character_spec::CharacterSpec,
+        date_spec::DateSpec, date_time_spec::DateTimeSpec, duration_spec::DurationSpec,
+        float_spec::FloatSpec, guid_spec::GuidSpec, integer_spec::IntegerSpec,
+        interval_spec::IntervalSpec, list_spec::ListSpec, map_spec::MapSpec,
         sequence_spec::SequenceSpec, set_spec::SetSpec, string_spec::StringSpec,
-        time_spec::TimeSpec, tuple_spec::TupleSpec,
+        time_series_spec::TimeSeriesSpec, time_spec::TimeSpec, tuple_spec::TupleSpec,
     },
     variable::Variable,
 };
@@ -95,6 +104,12 @@ pub trait DataProvider {
                 let def = Some(PrimitiveDef::new(boolean_spec.clone(), Some(accessor)));
                 Variable::new_primitive(Primitive::Boolean(def))
             }
+            Primitive::Character(character_def) => {
+                let character_spec = character_def.as_ref().unwrap().spec();
+                let accessor = Character::new(self.character_adaptor(character_spec));
+                let def = Some(PrimitiveDef::new(character_spec.clone(), Some(accessor)));
+                Variable::new_primitive(Primitive::Character(def))
+            }
             Primitive::ByteString(string_def) => {
                 let string_spec = string_def.as_ref().unwrap().spec();
                 let accessor = ByteString::new(self.byte_string_adaptor(string_spec));
@@ -125,6 +140,12 @@ pub trait DataProvider {
                 let def = Some(PrimitiveDef::new(blob_spec.clone(), Some(accessor)));
                 Variable::new_primitive(Primitive::Blob(def))
             }
+            Primitive::Guid(guid_def) => {
+                let guid_spec = guid_def.as_ref().unwrap().spec();
+                let accessor = Guid::new(self.guid_adaptor(guid_spec));
+                let def = Some(PrimitiveDef::new(guid_spec.clone(), Some(accessor)));
+                Variable::new_primitive(Primitive::Guid(def))
+            }
             Primitive::List(list_def) => {
                 let list_spec = list_def.as_ref().unwrap().spec();
                 let accessor = List::new(self.list_adaptor(list_spec));
@@ -155,6 +176,18 @@ pub trait DataProvider {
                 let def = Some(PrimitiveDef::new(tuple_spec.clone(), Some(accessor)));
                 Variable::new_primitive(Primitive::Tuple(def))
             }
+            Primitive::Interval(interval_def) => {
+                let interval_spec = interval_def.as_ref().unwrap().spec();
+                let accessor = Interval::new(self.interval_adaptor(interval_spec));
+                let def = Some(PrimitiveDef::new(interval_spec.clone(), Some(accessor)));
+                Variable::new_primitive(Primitive::Interval(def))
+            }
+            Primitive::TimeSeries(time_series_def) => {
+                let time_series_spec = time_series_def.as_ref().unwrap().spec();
+                let accessor = TimeSeries::new(self.time_series_adaptor(time_series_spec));
+                let def = Some(PrimitiveDef::new(time_series_spec.clone(), Some(accessor)));
+                Variable::new_primitive(Primitive::TimeSeries(def))
+            }
             Primitive::YearToMonthDuration(duration_def) => {
                 let duration_spec = duration_def.as_ref().unwrap().spec();
                 let accessor =
@@ -162,11 +195,23 @@ pub trait DataProvider {
                 let def = Some(PrimitiveDef::new(duration_spec.clone(), Some(accessor)));
                 Variable::new_primitive(Primitive::YearToMonthDuration(def))
             }
+            Primitive::DayToSecondDuration(duration_def) => {
+                let duration_spec = duration_def.as_ref().unwrap().spec();
+                let accessor =
+                    DayToSecondDuration::new(self.day_time_duration_adaptor(duration_spec));
+                let def = Some(PrimitiveDef::new(duration_spec.clone(), Some(accessor)));
+                Variable::new_primitive(Primitive::DayToSecondDuration(def))
+            }
             Primitive::ZonedDateTime(zoned_date_time_def) => {
                 let date_time_spec = zoned_date_time_def.as_ref().unwrap().spec();
                 let (date_adaptor, time_adaptor, zone_adaptor) =
                     self.zoned_date_time_adaptors(date_time_spec);
-                let accessor = ZonedDateTime::new(date_adaptor, time_adaptor, zone_adaptor);
+                let accessor = ZonedDateTime::new(
+                    date_time_spec.clone(),
+                    date_adaptor,
+                    time_adaptor,
+                    zone_adaptor,
+                );
                 let def = Some(PrimitiveDef::new(date_time_spec.clone(), Some(accessor)));
                 Variable::new_primitive(Primitive::ZonedDateTime(def))
             }
@@ -208,6 +253,14 @@ pub trait DataProvider {
         );
     }
 
+    /// Returns a character adaptor according to the given spec.
+    fn character_adaptor(&self, _spec: &Rc<CharacterSpec>) -> Box<dyn CharacterAdaptor> {
+        panic!(
+            "Characters are not supported by the {} data provider",
+            self.name()
+        );
+    }
+
     /// Returns an blob adaptor according to the given spec.
     fn blob_adaptor(&self, _spec: &Rc<BlobSpec>) -> Box<dyn BlobAdaptor> {
         panic!(
@@ -216,6 +269,14 @@ pub trait DataProvider {
         );
     }
 
+    /// Returns a GUID adaptor according to the given spec.
+    fn guid_adaptor(&self, _spec: &Rc<GuidSpec>) -> Box<dyn GuidAdaptor> {
+        panic!(
+            "GUIDs are not supported by the {} data provider",
+            self.name()
+        );
+    }
+
     /// Returns an integer adaptor according to the given spec.
     fn integer_adaptor(&self, _spec: &Rc<IntegerSpec>) -> Box<dyn IntegerAdaptor> {
         panic!(
@@ -296,6 +357,18 @@ pub trait DataProvider {
         );
     }
 
+    /// Returns a read-only sequence adaptor for the values of the generator.
+    fn generated_sequence_adaptor(
+        &self,
+        _spec: &Rc<SequenceSpec>,
+        _generator: Box<dyn SequenceGenerator>,
+    ) -> Box<dyn SequenceAdaptor> {
+        panic!(
+            "Generated sequences are not supported by the {} data provider",
+            self.name()
+        );
+    }
+
     /// Returns a tuple adaptor according to the given spec.
     fn tuple_adaptor(&self, _spec: &Rc<TupleSpec>) -> Box<dyn TupleAdaptor> {
         panic!(
@@ -304,6 +377,22 @@ pub trait DataProvider {
         );
     }
 
+    /// Returns an interval adaptor according to the given spec.
+    fn interval_adaptor(&self, _spec: &Rc<IntervalSpec>) -> Box<dyn IntervalAdaptor> {
+        panic!(
+            "Intervals are not supported by the {} data provider",
+            self.name()
+        );
+    }
+
+    /// Returns a time series adaptor according to the given spec.
+    fn time_series_adaptor(&self, _spec: &Rc<TimeSeriesSpec>) -> Box<dyn TimeSeriesAdaptor> {
+        panic!(
+            "Time series are not supported by the {} data provider",
+            self.name()
+        );
+    }
+
     /// Returns a date adaptor according to the given spec.
     fn date_adaptor(&self, _spec: &Rc<DateSpec>) -> Box<dyn DateAdaptor> {
         panic!(
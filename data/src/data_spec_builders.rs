@@ -10,8 +10,14 @@ pub mod boolean_spec_builder;
 /// Data spec builder for strings.
 pub mod string_spec_builder;
 
-// /// Data spec builder for characters.
-// pub mod character_spec_builder;
+/// Data spec builder for BLOBs.
+pub mod blob_spec_builder;
+
+/// Data spec builder for GUIDs.
+pub mod guid_spec_builder;
+
+/// Data spec builder for characters.
+pub mod character_spec_builder;
 
 /// Data spec builder for lists.
 pub mod list_spec_builder;
@@ -28,6 +34,12 @@ pub mod sequence_spec_builder;
 /// Data spec builder for tuples.
 pub mod tuple_spec_builder;
 
+/// Data spec builder for intervals.
+pub mod interval_spec_builder;
+
+/// Data spec builder for time series.
+pub mod time_series_spec_builder;
+
 /// Data spec builder for date-times.
 pub mod date_time_spec_builder;
 
@@ -42,17 +54,22 @@ pub mod duration_spec_builder;
 
 #[cfg(test)]
 mod tests {
+    mod blob_spec_builder_test;
     mod boolean_spec_builder_test;
+    mod character_spec_builder_test;
     mod date_spec_builder_test;
     mod date_time_spec_builder_test;
     mod float_spec_builder_test;
+    mod guid_spec_builder_test;
     mod integer_spec_builder_test;
+    mod interval_spec_builder_test;
     mod duration_spec_builder_test;
     mod list_spec_builder_test;
     mod map_spec_builder_test;
     mod sequence_spec_builder_test;
     mod set_spec_builder_test;
     mod string_spec_builder_test;
+    mod time_series_spec_builder_test;
     mod time_spec_builder_test;
     mod tuple_spec_builder_test;
 }
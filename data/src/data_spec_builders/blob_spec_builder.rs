@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+use crate::{
+    data_spec::{DataSpec, DataSpecLevel},
+    primitive::Primitive,
+    primitive_def::PrimitiveDef,
+    primitive_specs::blob_spec::BlobSpec,
+};
+
+/// A data specification builder for BLOBs.
+/// # Examples
+/// Create a BLOB data specification for PNG images of at most 1 MiB:
+/// ```rust
+/// use data::data_spec_builders::blob_spec_builder::BlobSpecBuilder;
+/// let blob_data_spec = BlobSpecBuilder::new()
+///     .set_max_size(1024 * 1024)
+///     .set_media_type("image/png")
+///     .build();
+/// ```
+pub struct BlobSpecBuilder {
+    /// The maximum size of the BLOB in bytes.
+    max_size: Option<u64>,
+    /// The media type of the BLOB.
+    media_type: Option<String>,
+}
+
+impl BlobSpecBuilder {
+    /// Returns an initialized BlobSpecBuilder.
+    pub fn new() -> BlobSpecBuilder {
+        BlobSpecBuilder {
+            max_size: None,
+            media_type: None,
+        }
+    }
+
+    /// Sets the BLOB's maximum size in bytes.
+    pub fn set_max_size(&mut self, max_size: u64) -> &mut BlobSpecBuilder {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets the BLOB's media type, such as "image/png".
+    pub fn set_media_type(&mut self, media_type: &str) -> &mut BlobSpecBuilder {
+        self.media_type = Some(media_type.to_string());
+        self
+    }
+
+    /// Builds and returns an initialized data specification.
+    pub fn build(&self) -> Rc<DataSpec> {
+        let primitive_spec = Rc::new(BlobSpec::new(self.max_size, self.media_type.clone()));
+        let primitive_def = Some(PrimitiveDef::new(primitive_spec, None));
+        Rc::new(DataSpec::new_primitive(
+            Primitive::Blob(primitive_def),
+            DataSpecLevel::Access,
+        ))
+    }
+}
+
+impl Default for BlobSpecBuilder {
+    fn default() -> Self {
+        BlobSpecBuilder::new()
+    }
+}
@@ -0,0 +1,89 @@
+use std::rc::Rc;
+
+use crate::{
+    data_spec::{DataSpec, DataSpecLevel},
+    primitive::Primitive,
+    primitive_def::PrimitiveDef,
+    primitive_specs::{
+        character_spec::{CharacterSpec, CharacterStorage},
+        string_spec::ByteCodePage,
+    },
+};
+
+/// A data specification builder for characters.
+/// # Examples
+/// Create a character data specification that can hold any Unicode character:
+/// ```rust
+/// use data::data_spec_builders::character_spec_builder::CharacterSpecBuilder;
+/// use data::primitive_specs::character_spec::CharacterStorage;
+/// let character_data_spec = CharacterSpecBuilder::new()
+///     .set_storage(CharacterStorage::Scalar)
+///     .build();
+/// ```
+///
+/// Create a byte character data specification with the Latin-1 code page:
+/// ```rust
+/// use data::data_spec_builders::character_spec_builder::CharacterSpecBuilder;
+/// use data::primitive_specs::character_spec::CharacterStorage;
+/// use data::primitive_specs::string_spec::ByteCodePage;
+/// let character_data_spec = CharacterSpecBuilder::new()
+///     .set_storage(CharacterStorage::Byte)
+///     .set_code_page(ByteCodePage::Latin1)
+///     .build();
+/// ```
+pub struct CharacterSpecBuilder {
+    /// The storage of the character.
+    storage: Option<CharacterStorage>,
+    /// The code page of a byte character.
+    code_page: Option<ByteCodePage>,
+}
+
+impl CharacterSpecBuilder {
+    /// Returns an initialized CharacterSpecBuilder.
+    pub fn new() -> CharacterSpecBuilder {
+        CharacterSpecBuilder {
+            storage: None,
+            code_page: None,
+        }
+    }
+
+    /// Sets the character's storage type.
+    pub fn set_storage(&mut self, storage: CharacterStorage) -> &mut CharacterSpecBuilder {
+        self.storage = Some(storage);
+        self
+    }
+
+    /// Sets the byte character's code page.
+    pub fn set_code_page(&mut self, code_page: ByteCodePage) -> &mut CharacterSpecBuilder {
+        self.code_page = Some(code_page);
+        self
+    }
+
+    /// Builds and returns an initialized data specification.
+    ///
+    /// # Panics
+    ///
+    /// If a code page is combined with a storage other than Byte.
+    pub fn build(&self) -> Rc<DataSpec> {
+        if self.code_page.is_some() && self.storage != Some(CharacterStorage::Byte) {
+            panic!("Code pages are only available for byte characters.");
+        }
+        let specification_level = if self.storage.is_some() {
+            DataSpecLevel::Access
+        } else {
+            DataSpecLevel::Compare
+        };
+        let primitive_spec = Rc::new(CharacterSpec::new(self.storage, self.code_page));
+        let primitive_def = Some(PrimitiveDef::new(primitive_spec, None));
+        Rc::new(DataSpec::new_primitive(
+            Primitive::Character(primitive_def),
+            specification_level,
+        ))
+    }
+}
+
+impl Default for CharacterSpecBuilder {
+    fn default() -> Self {
+        CharacterSpecBuilder::new()
+    }
+}
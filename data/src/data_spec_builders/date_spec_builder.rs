@@ -2,7 +2,7 @@ use crate::{
     data_spec::{DataSpec, DataSpecLevel},
     primitive::Primitive,
     primitive_def::PrimitiveDef,
-    primitive_specs::date_spec::DateSpec,
+    primitive_specs::date_spec::{DateRange, DateSpec},
 };
 use std::rc::Rc;
 
@@ -17,17 +17,37 @@ use std::rc::Rc;
 /// let date_data_spec = DateSpecBuilder::new()
 ///   .build();
 /// ```
-pub struct DateSpecBuilder {}
+///
+/// Create a date data specification for the extended range of years:
+/// ```rust
+/// use data::data_spec_builders::date_spec_builder::DateSpecBuilder;
+/// use data::primitive_specs::date_spec::DateRange;
+///
+/// let date_data_spec = DateSpecBuilder::new()
+///   .set_range(DateRange::Extended)
+///   .build();
+/// ```
+pub struct DateSpecBuilder {
+    range: DateRange,
+}
 
 impl DateSpecBuilder {
     /// Returns an initialized DateSpecBuilder.
     pub fn new() -> DateSpecBuilder {
-        DateSpecBuilder {}
+        DateSpecBuilder {
+            range: DateRange::Standard,
+        }
+    }
+
+    /// Sets the date's range of years; the default is the standard range.
+    pub fn set_range(&mut self, range: DateRange) -> &mut DateSpecBuilder {
+        self.range = range;
+        self
     }
 
     /// Builds and returns an initialized data specification.
     pub fn build(&self) -> Rc<DataSpec> {
-        let primitive_spec = Rc::new(DateSpec::new());
+        let primitive_spec = Rc::new(DateSpec::new(self.range));
         let primitive_def = Some(PrimitiveDef::new(primitive_spec, None));
         Rc::new(DataSpec::new_primitive(
             Primitive::Date(primitive_def),
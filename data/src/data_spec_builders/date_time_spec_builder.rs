@@ -3,7 +3,7 @@ use crate::{
     data_spec::{DataSpec, DataSpecLevel},
     primitive::Primitive,
     primitive_def::PrimitiveDef,
-    primitive_specs::date_time_spec::{DateTimeSpec, DateTimeType},
+    primitive_specs::date_time_spec::{DateTimeSpec, DateTimeType, ZoneType},
 };
 use std::rc::Rc;
 /// Builder for date-time data specifications.
@@ -31,8 +31,18 @@ use std::rc::Rc;
 ///     .set_date_time_type(DateTimeType::Zoned)
 /// .build();
 /// ```
+/// Create a zoned date-time data specification that requires a named time zone:
+/// ```rust
+/// use data::data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder;
+/// use data::primitive_specs::date_time_spec::{DateTimeType, ZoneType};
+/// let date_time_spec = DateTimeSpecBuilder::new()
+///     .set_date_time_type(DateTimeType::Zoned)
+///     .set_zone_type(ZoneType::Named)
+///     .build();
+/// ```
 pub struct DateTimeSpecBuilder {
     date_time_type: Option<DateTimeType>,
+    zone_type: Option<ZoneType>,
 }
 
 impl DateTimeSpecBuilder {
@@ -40,6 +50,7 @@ impl DateTimeSpecBuilder {
     pub fn new() -> DateTimeSpecBuilder {
         DateTimeSpecBuilder {
             date_time_type: (None),
+            zone_type: (None),
         }
     }
 
@@ -49,6 +60,13 @@ impl DateTimeSpecBuilder {
         self
     }
 
+    /// Sets the zone type that a zoned date-time requires.
+    /// The zone type is ignored unless the date-time's type is `Zoned`.
+    pub fn set_zone_type(&mut self, zone_type: ZoneType) -> &mut DateTimeSpecBuilder {
+        self.zone_type = Some(zone_type);
+        self
+    }
+
     /// Builds and returns an initialized data specification.
     pub fn build(&self) -> Rc<DataSpec> {
         let specification_level = if self.date_time_type.is_some() {
@@ -56,7 +74,11 @@ impl DateTimeSpecBuilder {
         } else {
             DataSpecLevel::Compare
         };
-        let primitive_spec = Rc::new(DateTimeSpec::new(self.date_time_type));
+        let zone_type = match self.date_time_type {
+            Some(DateTimeType::Zoned) => self.zone_type,
+            _ => None,
+        };
+        let primitive_spec = Rc::new(DateTimeSpec::new(self.date_time_type, zone_type));
         match self.date_time_type {
             Some(DateTimeType::Local) => {
                 let primitive_def: Option<PrimitiveDef<DateTimeSpec, DateTime>> =
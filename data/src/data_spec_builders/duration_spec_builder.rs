@@ -4,7 +4,10 @@ use crate::{
     data_spec::{DataSpec, DataSpecLevel},
     primitive::Primitive,
     primitive_def::PrimitiveDef,
-    primitive_specs::duration_spec::{DurationResolution, DurationSpec, DurationType},
+    primitive_specs::{
+        duration_spec::{DurationResolution, DurationSpec, DurationType},
+        rounding_policy::RoundingPolicy,
+    },
 };
 use std::{fmt::Display, rc::Rc};
 
@@ -46,9 +49,23 @@ use std::{fmt::Display, rc::Rc};
 ///     .set_resolution(DurationResolution::Millisecond)
 ///     .build();
 /// ```
+/// Create an duration data specification with a DayToSecond type and a second resolution that
+/// truncates finer durations:
+/// ```rust
+/// use data::data_spec_builders::duration_spec_builder::DurationSpecBuilder;
+/// use data::primitive_specs::duration_spec::{DurationType, DurationResolution};
+/// use data::primitive_specs::rounding_policy::RoundingPolicy;
+///
+/// let duration_spec = DurationSpecBuilder::new()
+///     .set_type(DurationType::DayToSecond)
+///     .set_resolution(DurationResolution::Second)
+///     .set_rounding_policy(RoundingPolicy::Truncate)
+///     .build();
+/// ```
 pub struct DurationSpecBuilder {
     duration_type: Option<DurationType>,
     resolution: Option<DurationResolution>,
+    rounding_policy: RoundingPolicy,
 }
 impl DurationSpecBuilder {
     /// Returns an initialized DurationSpecBuilder.
@@ -56,6 +73,7 @@ impl DurationSpecBuilder {
         DurationSpecBuilder {
             duration_type: None,
             resolution: None,
+            rounding_policy: RoundingPolicy::Reject,
         }
     }
 
@@ -71,6 +89,16 @@ impl DurationSpecBuilder {
         self
     }
 
+    /// Sets the duration's policy for durations finer than its resolution, which defaults to
+    /// `RoundingPolicy::Reject`.
+    pub fn set_rounding_policy(
+        &mut self,
+        rounding_policy: RoundingPolicy,
+    ) -> &mut DurationSpecBuilder {
+        self.rounding_policy = rounding_policy;
+        self
+    }
+
     /// Builds and returns an initialized data specification.
     pub fn build(&self) -> Result<Rc<DataSpec>, DurationSpecBuildError> {
         let specification_level = if self.duration_type.is_some() {
@@ -78,7 +106,11 @@ impl DurationSpecBuilder {
         } else {
             DataSpecLevel::Compare
         };
-        let primitive_spec = Rc::new(DurationSpec::new(self.duration_type, self.resolution));
+        let primitive_spec = Rc::new(DurationSpec::new_with_rounding(
+            self.duration_type,
+            self.resolution,
+            self.rounding_policy,
+        ));
         match self.duration_type {
             Some(DurationType::YearToMonth) => {
                 if let Some(resolution) = self.resolution {
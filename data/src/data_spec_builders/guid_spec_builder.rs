@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use crate::{
+    data_spec::{DataSpec, DataSpecLevel},
+    primitive::Primitive,
+    primitive_def::PrimitiveDef,
+    primitive_specs::guid_spec::{GuidSpec, GuidVersion},
+};
+
+/// A data specification builder for GUIDs.
+/// # Examples
+/// Create a GUID data specification that only accepts time-ordered (version 7) GUIDs:
+/// ```rust
+/// use data::data_spec_builders::guid_spec_builder::GuidSpecBuilder;
+/// use data::primitive_specs::guid_spec::GuidVersion;
+/// let guid_data_spec = GuidSpecBuilder::new()
+///     .set_version(GuidVersion::V7)
+///     .build();
+/// ```
+pub struct GuidSpecBuilder {
+    /// The version of the GUID.
+    version: Option<GuidVersion>,
+}
+
+impl GuidSpecBuilder {
+    /// Returns an initialized GuidSpecBuilder.
+    pub fn new() -> GuidSpecBuilder {
+        GuidSpecBuilder { version: None }
+    }
+
+    /// Pins the GUID's version.
+    pub fn set_version(&mut self, version: GuidVersion) -> &mut GuidSpecBuilder {
+        self.version = Some(version);
+        self
+    }
+
+    /// Builds and returns an initialized data specification.
+    pub fn build(&self) -> Rc<DataSpec> {
+        let primitive_spec = Rc::new(GuidSpec::new(self.version));
+        let primitive_def = Some(PrimitiveDef::new(primitive_spec, None));
+        Rc::new(DataSpec::new_primitive(
+            Primitive::Guid(primitive_def),
+            DataSpecLevel::Access,
+        ))
+    }
+}
+
+impl Default for GuidSpecBuilder {
+    fn default() -> Self {
+        GuidSpecBuilder::new()
+    }
+}
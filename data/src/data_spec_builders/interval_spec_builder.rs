@@ -0,0 +1,127 @@
+use std::{fmt::Display, rc::Rc};
+
+use crate::{
+    accessors::interval::Interval,
+    data_spec::{DataSpec, DataSpecLevel, DataSpecType},
+    primitive::Primitive,
+    primitive_def::PrimitiveDef,
+    primitive_specs::interval_spec::{BoundType, IntervalSpec},
+};
+
+/// Builder for interval data specifications.
+///
+/// The value spec must be a numeric, date, time or date-time primitive. Bounds are closed at the
+/// start and open at the end unless set otherwise.
+///
+/// # Examples
+///
+/// Create a data specification for a half-open interval of dates:
+/// ```rust
+/// use data::data_spec_builders::{interval_spec_builder::IntervalSpecBuilder, date_spec_builder::DateSpecBuilder};
+/// use data::primitive_specs::interval_spec::BoundType;
+///
+/// let interval_data_spec = IntervalSpecBuilder::new()
+///     .set_value_spec(DateSpecBuilder::new().build())
+///     .set_bounds(BoundType::Closed, BoundType::Open)
+///     .build()
+///     .unwrap();
+/// ```
+pub struct IntervalSpecBuilder {
+    value_spec: Option<Rc<DataSpec>>,
+    bounds: Option<(BoundType, BoundType)>,
+}
+
+impl IntervalSpecBuilder {
+    /// Creates a new `IntervalSpecBuilder`.
+    pub fn new() -> Self {
+        Self {
+            value_spec: None,
+            bounds: None,
+        }
+    }
+
+    /// Sets the specification of the interval's start and end values.
+    pub fn set_value_spec(&mut self, value_spec: Rc<DataSpec>) -> &mut Self {
+        self.value_spec = Some(value_spec);
+        self
+    }
+
+    /// Sets the default bound types of the interval's start and end.
+    pub fn set_bounds(&mut self, start_bound: BoundType, end_bound: BoundType) -> &mut Self {
+        self.bounds = Some((start_bound, end_bound));
+        self
+    }
+
+    /// Builds the interval specification.
+    pub fn build(&self) -> Result<Rc<DataSpec>, IntervalSpecBuildError> {
+        let mut primitive_def: Option<PrimitiveDef<IntervalSpec, Interval>> = None;
+        let mut specification_level = DataSpecLevel::Compare;
+        if let Some(value_spec) = self.value_spec.as_ref() {
+            let is_ordered_value = match value_spec.specification_type() {
+                DataSpecType::Primitive(primitive) => {
+                    primitive.is_numberic()
+                        || primitive.is_time()
+                        || primitive.is_date_time()
+                        || matches!(primitive, Primitive::Date(..))
+                }
+                DataSpecType::PrimitiveCategory(..) => false,
+            };
+            if !is_ordered_value {
+                return Err(IntervalSpecBuildError::UnsupportedValueSpec(
+                    value_spec.to_string(),
+                ));
+            }
+            specification_level = value_spec.specification_level();
+            let (start_bound, end_bound) =
+                self.bounds.unwrap_or((BoundType::Closed, BoundType::Open));
+            let primitive_spec = Rc::new(IntervalSpec::new(
+                Some(value_spec.clone()),
+                start_bound,
+                end_bound,
+            ));
+            primitive_def = Some(PrimitiveDef::new(primitive_spec, None));
+        } else if self.bounds.is_some() {
+            return Err(IntervalSpecBuildError::BoundsWithoutValueSpec);
+        }
+
+        Ok(Rc::new(DataSpec::new_primitive(
+            Primitive::Interval(primitive_def),
+            specification_level,
+        )))
+    }
+}
+
+impl Default for IntervalSpecBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Errors that can occur when building `IntervalSpec`s.
+#[derive(Debug, PartialEq)]
+pub enum IntervalSpecBuildError {
+    /// Indicates that the value spec is not one of a numeric, date, time or date-time primitive.
+    UnsupportedValueSpec(String),
+
+    /// Indicates that bounds were specified without a value spec.
+    BoundsWithoutValueSpec,
+}
+
+impl Display for IntervalSpecBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntervalSpecBuildError::UnsupportedValueSpec(value_spec) => {
+                write!(
+                    f,
+                    "Unsupported value spec ({}) for an interval.",
+                    value_spec
+                )
+            }
+            IntervalSpecBuildError::BoundsWithoutValueSpec => {
+                write!(f, "Cannot set bounds without setting a value spec.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntervalSpecBuildError {}
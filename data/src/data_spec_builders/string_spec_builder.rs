@@ -8,7 +8,7 @@ use crate::{
     data_spec::{DataSpec, DataSpecLevel},
     primitive::Primitive,
     primitive_def::PrimitiveDef,
-    primitive_specs::string_spec::{StringEncoding, StringSpec, StringStorage},
+    primitive_specs::string_spec::{ByteCodePage, StringEncoding, StringSpec, StringStorage},
 };
 
 /// A data specification builder for strings.
@@ -22,11 +22,23 @@ use crate::{
 /// .build();
 /// ```
 ///
+/// Create a fixed-size byte string data specification with the Windows-1252 code page:
+/// ```rust
+/// use data::data_spec_builders::string_spec_builder::StringSpecBuilder;
+/// use data::primitive_specs::string_spec::{ByteCodePage, StringEncoding, StringStorage};
+/// let string_data_spec = StringSpecBuilder::new(StringEncoding::Byte)
+///     .set_storage(StringStorage::FixedSize(10))
+///     .set_code_page(ByteCodePage::Windows1252)
+///     .build();
+/// ```
+///
 pub struct StringSpecBuilder {
     /// The encoding of the string.
     encoding: StringEncoding,
     /// The storage of the string.
     storage: Option<StringStorage>,
+    /// The code page of a byte string.
+    code_page: Option<ByteCodePage>,
 }
 
 impl StringSpecBuilder {
@@ -35,6 +47,7 @@ impl StringSpecBuilder {
         StringSpecBuilder {
             encoding,
             storage: None,
+            code_page: None,
         }
     }
 
@@ -44,18 +57,28 @@ impl StringSpecBuilder {
         self
     }
 
+    /// Sets the byte string's code page.
+    pub fn set_code_page(&mut self, code_page: ByteCodePage) -> &mut StringSpecBuilder {
+        self.code_page = Some(code_page);
+        self
+    }
+
     /// Builds and returns an initialized data specification.
     ///
     /// # Panics
     ///
-    /// If the fixed-sized storage is combined with UTF-8 or UTF-16 encoding.
+    /// If the fixed-sized storage is combined with UTF-8 or UTF-16 encoding,
+    /// or if a code page is combined with an encoding other than Byte.
     pub fn build(&self) -> Rc<DataSpec> {
+        if self.code_page.is_some() && self.encoding != StringEncoding::Byte {
+            panic!("Code pages are only available for byte strings.");
+        }
         let specification_level = if self.storage.is_some() {
             DataSpecLevel::Access
         } else {
             DataSpecLevel::Compare
         };
-        let primitive_spec = Rc::new(StringSpec::new(self.encoding, self.storage));
+        let primitive_spec = Rc::new(StringSpec::new(self.encoding, self.storage, self.code_page));
         match self.encoding {
             StringEncoding::Byte => {
                 let primitive_def: Option<PrimitiveDef<StringSpec, ByteString>> =
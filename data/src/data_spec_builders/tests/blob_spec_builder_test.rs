@@ -0,0 +1,35 @@
+use crate::{
+    data_spec::{DataSpecLevel, DataSpecType},
+    data_spec_builders::blob_spec_builder::BlobSpecBuilder,
+    primitive::Primitive,
+};
+
+#[test]
+fn blob_spec() {
+    let spec = BlobSpecBuilder::new()
+        .set_max_size(512)
+        .set_media_type("image/png")
+        .build();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Blob(def)) => {
+            let blob_spec = def.as_ref().unwrap().spec();
+            assert_eq!(blob_spec.max_size(), Some(512));
+            assert_eq!(blob_spec.media_type(), Some("image/png"));
+        }
+        _ => panic!("Expected a BLOB spec"),
+    }
+    assert_eq!(spec.specification_level(), DataSpecLevel::Access);
+}
+
+#[test]
+fn blob_spec_default() {
+    let spec = BlobSpecBuilder::default().build();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Blob(def)) => {
+            let blob_spec = def.as_ref().unwrap().spec();
+            assert_eq!(blob_spec.max_size(), None);
+            assert_eq!(blob_spec.media_type(), None);
+        }
+        _ => panic!("Expected a BLOB spec"),
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    data_spec::{DataSpecLevel, DataSpecType},
+    data_spec_builders::character_spec_builder::CharacterSpecBuilder,
+    primitive::Primitive,
+    primitive_specs::{character_spec::CharacterStorage, string_spec::ByteCodePage},
+};
+
+#[test]
+fn character_spec_with_storage() {
+    let spec = CharacterSpecBuilder::new()
+        .set_storage(CharacterStorage::Byte)
+        .set_code_page(ByteCodePage::Windows1252)
+        .build();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Character(def)) => {
+            let character_spec = def.as_ref().unwrap().spec();
+            assert_eq!(*character_spec.storage(), Some(CharacterStorage::Byte));
+            assert_eq!(*character_spec.code_page(), Some(ByteCodePage::Windows1252));
+        }
+        _ => panic!("Expected a character spec"),
+    }
+    assert_eq!(spec.specification_level(), DataSpecLevel::Access);
+}
+
+#[test]
+fn character_spec_without_storage() {
+    let spec = CharacterSpecBuilder::new().build();
+    assert_eq!(spec.specification_level(), DataSpecLevel::Compare);
+}
+
+#[test]
+#[should_panic(expected = "Code pages are only available for byte characters.")]
+fn code_page_requires_byte_storage() {
+    CharacterSpecBuilder::new()
+        .set_storage(CharacterStorage::Scalar)
+        .set_code_page(ByteCodePage::Latin1)
+        .build();
+}
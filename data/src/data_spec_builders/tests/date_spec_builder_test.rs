@@ -1,7 +1,8 @@
 use crate::{
-    data_spec::{DataSpecLevel, DataSpecType},
+    data_spec::{DataSpec, DataSpecLevel, DataSpecType},
     data_spec_builders::date_spec_builder::DateSpecBuilder,
     primitive::Primitive,
+    primitive_specs::date_spec::DateRange,
 };
 
 #[test]
@@ -21,3 +22,20 @@ fn build() {
         _ => assert!(false),
     }
 }
+
+#[test]
+fn build_with_range() {
+    let range = |spec: &DataSpec| match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Date(Some(def))) => *def.spec().range(),
+        _ => panic!("expected a date primitive"),
+    };
+    assert_eq!(range(&DateSpecBuilder::new().build()), DateRange::Standard);
+    assert_eq!(
+        range(
+            &DateSpecBuilder::new()
+                .set_range(DateRange::Extended)
+                .build()
+        ),
+        DateRange::Extended
+    );
+}
@@ -2,7 +2,7 @@ use crate::{
     data_spec::{DataSpecLevel, DataSpecType},
     data_spec_builders::date_time_spec_builder::DateTimeSpecBuilder,
     primitive::Primitive,
-    primitive_specs::date_time_spec::DateTimeType,
+    primitive_specs::date_time_spec::{DateTimeType, ZoneType},
 };
 
 #[test]
@@ -54,3 +54,22 @@ fn zoned_date_time_type() {
     }
     assert_eq!(date_time_spec.specification_level(), DataSpecLevel::Access);
 }
+#[test]
+fn zoned_date_time_zone_type() {
+    let zone_type = |date_time_type: DateTimeType| {
+        let date_time_spec = DateTimeSpecBuilder::new()
+            .set_date_time_type(date_time_type)
+            .set_zone_type(ZoneType::Named)
+            .build();
+        match date_time_spec.specification_type() {
+            DataSpecType::Primitive(Primitive::ZonedDateTime(Some(value))) => {
+                *value.spec().zone_type()
+            }
+            DataSpecType::Primitive(Primitive::DateTime(Some(value))) => *value.spec().zone_type(),
+            _ => panic!("expected a date-time primitive"),
+        }
+    };
+    assert_eq!(zone_type(DateTimeType::Zoned), Some(ZoneType::Named));
+    // local date-times have no zone
+    assert_eq!(zone_type(DateTimeType::Local), None);
+}
@@ -2,7 +2,7 @@ use crate::{
     data_spec::{DataSpecLevel, DataSpecType},
     data_spec_builders::duration_spec_builder::DurationSpecBuilder,
     primitive::Primitive,
-    primitive_specs::duration_spec::DurationType,
+    primitive_specs::{duration_spec::DurationType, rounding_policy::RoundingPolicy},
 };
 
 #[test]
@@ -299,3 +299,30 @@ fn day_time_type_incompatible_resolution() {
         }
     }
 }
+
+#[test]
+fn day_time_type_with_rounding_policy() {
+    let duration_spec = DurationSpecBuilder::new()
+        .set_type(DurationType::DayToSecond)
+        .set_resolution(crate::primitive_specs::duration_spec::DurationResolution::Second)
+        .set_rounding_policy(RoundingPolicy::RoundHalfUp)
+        .build()
+        .unwrap();
+    match duration_spec.specification_type() {
+        DataSpecType::Primitive(Primitive::DayToSecondDuration(Some(value))) => {
+            assert_eq!(*value.spec().rounding_policy(), RoundingPolicy::RoundHalfUp)
+        }
+        _ => panic!("expected a day-to-second duration spec"),
+    }
+
+    let duration_spec = DurationSpecBuilder::new()
+        .set_type(DurationType::YearToMonth)
+        .build()
+        .unwrap();
+    match duration_spec.specification_type() {
+        DataSpecType::Primitive(Primitive::YearToMonthDuration(Some(value))) => {
+            assert_eq!(*value.spec().rounding_policy(), RoundingPolicy::Reject)
+        }
+        _ => panic!("expected a year-to-month duration spec"),
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    data_spec::{DataSpecLevel, DataSpecType},
+    data_spec_builders::guid_spec_builder::GuidSpecBuilder,
+    primitive::Primitive,
+    primitive_specs::guid_spec::GuidVersion,
+};
+
+#[test]
+fn guid_spec() {
+    let spec = GuidSpecBuilder::new().set_version(GuidVersion::V7).build();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Guid(def)) => {
+            assert_eq!(
+                def.as_ref().unwrap().spec().version(),
+                Some(GuidVersion::V7)
+            );
+        }
+        _ => panic!("Expected a GUID spec"),
+    }
+    assert_eq!(spec.specification_level(), DataSpecLevel::Access);
+}
+
+#[test]
+fn guid_spec_without_version() {
+    let spec = GuidSpecBuilder::default().build();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Guid(def)) => {
+            assert_eq!(def.as_ref().unwrap().spec().version(), None);
+        }
+        _ => panic!("Expected a GUID spec"),
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    data_spec::{DataSpecLevel, DataSpecType},
+    data_spec_builders::{
+        boolean_spec_builder::BooleanSpecBuilder,
+        date_spec_builder::DateSpecBuilder,
+        integer_spec_builder::IntegerSpecBuilder,
+        interval_spec_builder::{IntervalSpecBuildError, IntervalSpecBuilder},
+    },
+    primitive::Primitive,
+    primitive_specs::{
+        integer_spec::{IntegerEncoding, IntegerStorage},
+        interval_spec::BoundType,
+    },
+};
+
+#[test]
+fn interval_no_value_spec() {
+    let spec = IntervalSpecBuilder::new().build().unwrap();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Interval(def)) => assert!(def.is_none()),
+        _ => panic!("Not an interval."),
+    }
+    assert_eq!(spec.specification_level(), DataSpecLevel::Compare);
+}
+
+#[test]
+fn interval_with_access_value_spec() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Interval(def)) => {
+            let spec = def.as_ref().unwrap().spec();
+            assert!(spec.value_spec().is_some());
+            assert_eq!(spec.start_bound(), BoundType::Closed);
+            assert_eq!(spec.end_bound(), BoundType::Open);
+        }
+        _ => panic!("Not an interval."),
+    }
+    assert_eq!(spec.specification_level(), DataSpecLevel::Access);
+}
+
+#[test]
+fn interval_with_bounds() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(DateSpecBuilder::new().build())
+        .set_bounds(BoundType::Closed, BoundType::Closed)
+        .build()
+        .unwrap();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Interval(def)) => {
+            let spec = def.as_ref().unwrap().spec();
+            assert_eq!(spec.start_bound(), BoundType::Closed);
+            assert_eq!(spec.end_bound(), BoundType::Closed);
+        }
+        _ => panic!("Not an interval."),
+    }
+}
+
+#[test]
+fn interval_with_compare_value_spec() {
+    let spec = IntervalSpecBuilder::new()
+        .set_value_spec(IntegerSpecBuilder::new().build())
+        .build()
+        .unwrap();
+    assert_eq!(spec.specification_level(), DataSpecLevel::Compare);
+}
+
+#[test]
+fn interval_errors() {
+    assert!(matches!(
+        IntervalSpecBuilder::new()
+            .set_value_spec(BooleanSpecBuilder::new().build())
+            .build(),
+        Err(IntervalSpecBuildError::UnsupportedValueSpec(..))
+    ));
+    assert_eq!(
+        IntervalSpecBuilder::new()
+            .set_bounds(BoundType::Open, BoundType::Open)
+            .build(),
+        Err(IntervalSpecBuildError::BoundsWithoutValueSpec)
+    );
+}
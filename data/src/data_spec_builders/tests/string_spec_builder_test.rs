@@ -0,0 +1,30 @@
+use crate::{
+    data_spec::DataSpecType,
+    data_spec_builders::string_spec_builder::StringSpecBuilder,
+    primitive::Primitive,
+    primitive_specs::string_spec::{ByteCodePage, StringEncoding, StringStorage},
+};
+
+#[test]
+fn byte_string_with_code_page() {
+    let spec = StringSpecBuilder::new(StringEncoding::Byte)
+        .set_storage(StringStorage::FixedSize(8))
+        .set_code_page(ByteCodePage::Latin1)
+        .build();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::ByteString(def)) => {
+            let string_spec = def.as_ref().unwrap().spec();
+            assert_eq!(*string_spec.code_page(), Some(ByteCodePage::Latin1));
+            assert_eq!(*string_spec.storage(), Some(StringStorage::FixedSize(8)));
+        }
+        _ => panic!("Expected a byte string spec"),
+    }
+}
+
+#[test]
+#[should_panic(expected = "Code pages are only available for byte strings.")]
+fn code_page_requires_byte_encoding() {
+    StringSpecBuilder::new(StringEncoding::Utf8)
+        .set_code_page(ByteCodePage::Ascii)
+        .build();
+}
@@ -0,0 +1,91 @@
+use crate::{
+    data_spec::{DataSpecLevel, DataSpecType},
+    data_spec_builders::{
+        boolean_spec_builder::BooleanSpecBuilder,
+        date_spec_builder::DateSpecBuilder,
+        date_time_spec_builder::DateTimeSpecBuilder,
+        float_spec_builder::FloatSpecBuilder,
+        integer_spec_builder::IntegerSpecBuilder,
+        time_series_spec_builder::{TimeSeriesSpecBuildError, TimeSeriesSpecBuilder},
+    },
+    primitive::Primitive,
+    primitive_specs::{
+        date_time_spec::DateTimeType,
+        float_spec::FloatStorage,
+        integer_spec::{IntegerEncoding, IntegerStorage},
+    },
+};
+
+#[test]
+fn time_series_no_specs() {
+    let spec = TimeSeriesSpecBuilder::new().build().unwrap();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::TimeSeries(def)) => assert!(def.is_none()),
+        _ => panic!("Not a time series."),
+    }
+    assert_eq!(spec.specification_level(), DataSpecLevel::Compare);
+}
+
+#[test]
+fn time_series_with_access_specs() {
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_timestamp_spec(
+            DateTimeSpecBuilder::new()
+                .set_date_time_type(DateTimeType::Local)
+                .build(),
+        )
+        .set_value_spec(
+            IntegerSpecBuilder::new()
+                .set_encoding(IntegerEncoding::Signed)
+                .set_storage(IntegerStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::TimeSeries(def)) => {
+            let spec = def.as_ref().unwrap().spec();
+            assert!(spec.timestamp_spec().is_some());
+            assert!(spec.value_spec().is_some());
+        }
+        _ => panic!("Not a time series."),
+    }
+    assert_eq!(spec.specification_level(), DataSpecLevel::Access);
+}
+
+#[test]
+fn time_series_with_one_spec() {
+    let spec = TimeSeriesSpecBuilder::new()
+        .set_value_spec(
+            FloatSpecBuilder::new()
+                .set_storage(FloatStorage::B64)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    match spec.specification_type() {
+        DataSpecType::Primitive(Primitive::TimeSeries(def)) => {
+            let spec = def.as_ref().unwrap().spec();
+            assert!(spec.timestamp_spec().is_none());
+            assert!(spec.value_spec().is_some());
+        }
+        _ => panic!("Not a time series."),
+    }
+    assert_eq!(spec.specification_level(), DataSpecLevel::Compare);
+}
+
+#[test]
+fn time_series_unsupported_specs() {
+    assert!(matches!(
+        TimeSeriesSpecBuilder::new()
+            .set_timestamp_spec(DateSpecBuilder::new().build())
+            .build(),
+        Err(TimeSeriesSpecBuildError::UnsupportedTimestampSpec(..))
+    ));
+    assert!(matches!(
+        TimeSeriesSpecBuilder::new()
+            .set_value_spec(BooleanSpecBuilder::new().build())
+            .build(),
+        Err(TimeSeriesSpecBuildError::UnsupportedValueSpec(..))
+    ));
+}
@@ -2,7 +2,7 @@ use crate::{
     data_spec::{DataSpecLevel, DataSpecType},
     data_spec_builders::time_spec_builder::TimeSpecBuilder,
     primitive::Primitive,
-    primitive_specs::time_spec::TimeType,
+    primitive_specs::{rounding_policy::RoundingPolicy, time_spec::TimeType},
 };
 
 #[test]
@@ -83,3 +83,28 @@ fn time_type_with_resolution() {
     }
     assert_eq!(time_spec.specification_level(), DataSpecLevel::Access);
 }
+
+#[test]
+fn time_type_with_rounding_policy() {
+    let time_spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Local)
+        .set_resolution(crate::primitive_specs::time_spec::TimeResolution::Millisecond)
+        .set_rounding_policy(RoundingPolicy::Truncate)
+        .build();
+    match time_spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Time(Some(value))) => {
+            assert_eq!(*value.spec().rounding_policy(), RoundingPolicy::Truncate)
+        }
+        _ => panic!("expected a time spec"),
+    }
+
+    let time_spec = TimeSpecBuilder::new()
+        .set_time_type(TimeType::Local)
+        .build();
+    match time_spec.specification_type() {
+        DataSpecType::Primitive(Primitive::Time(Some(value))) => {
+            assert_eq!(*value.spec().rounding_policy(), RoundingPolicy::Reject)
+        }
+        _ => panic!("expected a time spec"),
+    }
+}
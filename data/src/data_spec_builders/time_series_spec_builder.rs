@@ -0,0 +1,141 @@
+use std::{fmt::Display, rc::Rc};
+
+use crate::{
+    accessors::time_series::TimeSeries,
+    data_spec::{DataSpec, DataSpecLevel, DataSpecType},
+    primitive::Primitive,
+    primitive_def::PrimitiveDef,
+    primitive_specs::time_series_spec::TimeSeriesSpec,
+};
+
+/// Builder for time series data specifications.
+///
+/// The timestamp spec must be a date-time primitive without a time zone and the value spec a
+/// numeric primitive. Both must be set for a spec that gives access to time series.
+///
+/// # Examples
+///
+/// Create a data specification for a time series of floats:
+/// ```rust
+/// use data::data_spec_builders::{time_series_spec_builder::TimeSeriesSpecBuilder, date_time_spec_builder::DateTimeSpecBuilder, float_spec_builder::FloatSpecBuilder};
+/// use data::primitive_specs::{date_time_spec::DateTimeType, float_spec::FloatStorage};
+///
+/// let time_series_data_spec = TimeSeriesSpecBuilder::new()
+///     .set_timestamp_spec(DateTimeSpecBuilder::new().set_date_time_type(DateTimeType::Local).build())
+///     .set_value_spec(FloatSpecBuilder::new().set_storage(FloatStorage::B64).build())
+///     .build()
+///     .unwrap();
+/// ```
+pub struct TimeSeriesSpecBuilder {
+    timestamp_spec: Option<Rc<DataSpec>>,
+    value_spec: Option<Rc<DataSpec>>,
+}
+
+impl TimeSeriesSpecBuilder {
+    /// Creates a new `TimeSeriesSpecBuilder`.
+    pub fn new() -> Self {
+        Self {
+            timestamp_spec: None,
+            value_spec: None,
+        }
+    }
+
+    /// Sets the specification of the time series' timestamps.
+    pub fn set_timestamp_spec(&mut self, timestamp_spec: Rc<DataSpec>) -> &mut Self {
+        self.timestamp_spec = Some(timestamp_spec);
+        self
+    }
+
+    /// Sets the specification of the time series' values.
+    pub fn set_value_spec(&mut self, value_spec: Rc<DataSpec>) -> &mut Self {
+        self.value_spec = Some(value_spec);
+        self
+    }
+
+    /// Builds the time series specification.
+    pub fn build(&self) -> Result<Rc<DataSpec>, TimeSeriesSpecBuildError> {
+        if let Some(timestamp_spec) = self.timestamp_spec.as_ref()
+            && !matches!(
+                timestamp_spec.specification_type(),
+                DataSpecType::Primitive(Primitive::DateTime(..))
+            )
+        {
+            return Err(TimeSeriesSpecBuildError::UnsupportedTimestampSpec(
+                timestamp_spec.to_string(),
+            ));
+        }
+        if let Some(value_spec) = self.value_spec.as_ref()
+            && !matches!(
+                value_spec.specification_type(),
+                DataSpecType::Primitive(primitive) if primitive.is_numberic()
+            )
+        {
+            return Err(TimeSeriesSpecBuildError::UnsupportedValueSpec(
+                value_spec.to_string(),
+            ));
+        }
+
+        let mut primitive_def: Option<PrimitiveDef<TimeSeriesSpec, TimeSeries>> = None;
+        if self.timestamp_spec.is_some() || self.value_spec.is_some() {
+            let primitive_spec = Rc::new(TimeSeriesSpec::new(
+                self.timestamp_spec.clone(),
+                self.value_spec.clone(),
+            ));
+            primitive_def = Some(PrimitiveDef::new(primitive_spec, None));
+        }
+        let is_access = |spec: &Option<Rc<DataSpec>>| {
+            spec.as_ref()
+                .is_some_and(|spec| spec.specification_level() == DataSpecLevel::Access)
+        };
+        let specification_level = if is_access(&self.timestamp_spec) && is_access(&self.value_spec)
+        {
+            DataSpecLevel::Access
+        } else {
+            DataSpecLevel::Compare
+        };
+
+        Ok(Rc::new(DataSpec::new_primitive(
+            Primitive::TimeSeries(primitive_def),
+            specification_level,
+        )))
+    }
+}
+
+impl Default for TimeSeriesSpecBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Errors that can occur when building `TimeSeriesSpec`s.
+#[derive(Debug, PartialEq)]
+pub enum TimeSeriesSpecBuildError {
+    /// Indicates that the timestamp spec is not one of a date-time primitive.
+    UnsupportedTimestampSpec(String),
+
+    /// Indicates that the value spec is not one of a numeric primitive.
+    UnsupportedValueSpec(String),
+}
+
+impl Display for TimeSeriesSpecBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeSeriesSpecBuildError::UnsupportedTimestampSpec(timestamp_spec) => {
+                write!(
+                    f,
+                    "Unsupported timestamp spec ({}) for a time series.",
+                    timestamp_spec
+                )
+            }
+            TimeSeriesSpecBuildError::UnsupportedValueSpec(value_spec) => {
+                write!(
+                    f,
+                    "Unsupported value spec ({}) for a time series.",
+                    value_spec
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TimeSeriesSpecBuildError {}
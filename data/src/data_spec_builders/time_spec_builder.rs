@@ -3,7 +3,10 @@ use crate::{
     data_spec::{DataSpec, DataSpecLevel},
     primitive::Primitive,
     primitive_def::PrimitiveDef,
-    primitive_specs::time_spec::{TimeResolution, TimeSpec, TimeType},
+    primitive_specs::{
+        rounding_policy::RoundingPolicy,
+        time_spec::{TimeResolution, TimeSpec, TimeType},
+    },
 };
 use std::rc::Rc;
 /// Builder for time data specifications.
@@ -55,9 +58,26 @@ use std::rc::Rc;
 ///    .set_resolution(TimeResolution::Microsecond100)
 ///   .build();
 /// ```
+/// Create a time data specification with millisecond resolution that rounds finer times:
+/// ```rust
+/// use data::data_spec_builders::time_spec_builder::TimeSpecBuilder;
+/// use data::primitive_specs::rounding_policy::RoundingPolicy;
+/// use data::primitive_specs::time_spec::{TimeType, TimeResolution};
+/// use data::variable::Variable;
+///
+/// let time_data_spec = TimeSpecBuilder::new()
+///     .set_time_type(TimeType::Local)
+///     .set_resolution(TimeResolution::Millisecond)
+///     .set_rounding_policy(RoundingPolicy::RoundHalfEven)
+///     .build();
+/// let mut var = Variable::new(&time_data_spec);
+/// var.time_mut().set_time(12, 34, 56, 789, 500, 0).unwrap();
+/// assert_eq!(var.to_string(), "12:34:56.79");
+/// ```
 pub struct TimeSpecBuilder {
     time_type: Option<TimeType>,
     resolution: Option<TimeResolution>,
+    rounding_policy: RoundingPolicy,
 }
 
 impl TimeSpecBuilder {
@@ -66,6 +86,7 @@ impl TimeSpecBuilder {
         TimeSpecBuilder {
             time_type: (None),
             resolution: (None),
+            rounding_policy: RoundingPolicy::Reject,
         }
     }
 
@@ -81,6 +102,13 @@ impl TimeSpecBuilder {
         self
     }
 
+    /// Sets the time's policy for times finer than its resolution, which defaults to
+    /// `RoundingPolicy::Reject`.
+    pub fn set_rounding_policy(&mut self, rounding_policy: RoundingPolicy) -> &mut TimeSpecBuilder {
+        self.rounding_policy = rounding_policy;
+        self
+    }
+
     /// Builds and returns an initialized data specification.
     pub fn build(&self) -> Rc<DataSpec> {
         let specification_level = if self.time_type.is_some() {
@@ -88,7 +116,11 @@ impl TimeSpecBuilder {
         } else {
             DataSpecLevel::Compare
         };
-        let time_spec = Rc::new(TimeSpec::new(self.time_type, self.resolution));
+        let time_spec = Rc::new(TimeSpec::new_with_rounding(
+            self.time_type,
+            self.resolution,
+            self.rounding_policy,
+        ));
         match self.time_type {
             Some(TimeType::Local) => {
                 let primitive_def: Option<PrimitiveDef<TimeSpec, Time>> =
@@ -1,14 +1,27 @@
+pub(crate) mod transient_blob_adaptor;
 pub(crate) mod transient_boolean_adaptor;
+pub(crate) mod transient_character_adaptor;
 pub(crate) mod transient_float_adaptor;
+pub(crate) mod transient_generated_sequence_adaptor;
+pub(crate) mod transient_guid_adaptor;
 pub(crate) mod transient_integer_adaptor;
+pub(crate) mod transient_interval_adaptor;
 pub(crate) mod transient_list_adaptor;
 pub(crate) mod transient_map_adaptor;
 pub(crate) mod transient_sequence_adaptor;
 pub(crate) mod transient_set_adaptor;
+pub(crate) mod transient_time_series_adaptor;
 pub(crate) mod transient_tuple_adaptor;
 pub(crate) mod temporal {
     pub(crate) mod transient_date_adaptor;
+    pub(crate) mod transient_day_to_second_duration_adaptor;
     pub(crate) mod transient_time_micro_adaptor;
     pub(crate) mod transient_time_nano_adaptor;
     pub(crate) mod transient_year_to_month_duration_adaptor;
 }
+pub(crate) mod strings {
+    pub(crate) mod transient_byte_string_adaptor;
+    pub(crate) mod transient_utf8_string_adaptor;
+    pub(crate) mod transient_utf16_string_adaptor;
+    pub(crate) mod transient_utf32_string_adaptor;
+}
@@ -0,0 +1,108 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::string::StringError,
+    adaptors::string_adaptors::byte_string_adaptor::ByteStringAdaptor,
+    primitive_specs::string_spec::{StringSpec, StringStorage},
+};
+
+pub struct TransientByteStringAdaptor {
+    /// The specification of the string.
+    spec: Rc<StringSpec>,
+    /// The bytes of the string.
+    bytes: Vec<u8>,
+    /// The fixed size of the string in bytes, if applicable.
+    fixed_size: Option<usize>,
+    /// The fixed capacity of the string in bytes, if applicable.
+    fixed_capacity: Option<usize>,
+}
+
+impl TransientByteStringAdaptor {
+    /// Creates a new TransientByteStringAdaptor with the given specification.
+    pub fn new(spec: Rc<StringSpec>) -> Self {
+        let mut bytes = Vec::new();
+        let mut fixed_size: Option<usize> = None;
+        let mut fixed_capacity: Option<usize> = None;
+        match spec.storage() {
+            Some(StringStorage::FixedSize(size)) => {
+                if *size == 0 {
+                    panic!("Cannot create a TransientByteStringAdaptor with a fixed size of 0.");
+                }
+                bytes.resize(*size as usize, b' ');
+                fixed_size = Some(*size as usize);
+            }
+            Some(StringStorage::FixedCapacity(capacity)) => {
+                if *capacity == 0 {
+                    panic!(
+                        "Cannot create a TransientByteStringAdaptor with a fixed capacity of 0."
+                    );
+                }
+                bytes.reserve_exact(*capacity as usize);
+                fixed_capacity = Some(*capacity as usize);
+            }
+            Some(StringStorage::InitialCapacity(capacity)) => {
+                bytes.reserve(*capacity as usize);
+            }
+            Some(StringStorage::VariableSize) | None => {
+                // Variable size strings can be created without any restrictions.
+            }
+        }
+        Self {
+            spec,
+            bytes,
+            fixed_size,
+            fixed_capacity,
+        }
+    }
+}
+
+impl ByteStringAdaptor for TransientByteStringAdaptor {
+    fn spec(&self) -> &Rc<StringSpec> {
+        &self.spec
+    }
+
+    fn fixed_size(&self) -> Option<usize> {
+        self.fixed_size
+    }
+
+    fn fixed_capacity(&self) -> Option<usize> {
+        self.fixed_capacity
+    }
+
+    fn bytes(&self) -> Result<Vec<u8>, StringError> {
+        Ok(self.bytes.clone())
+    }
+
+    fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn do_byte(&self, index: usize) -> Result<u8, StringError> {
+        Ok(self.bytes[index])
+    }
+
+    fn do_set_byte(&mut self, index: usize, value: u8) -> Result<(), StringError> {
+        self.bytes[index] = value;
+        Ok(())
+    }
+
+    fn do_slice(&self, start: usize, end: usize) -> Result<Vec<u8>, StringError> {
+        Ok(self.bytes[start..end].to_vec())
+    }
+
+    fn do_set_bytes(&mut self, bytes: &[u8]) -> Result<(), StringError> {
+        self.bytes.clear();
+        self.bytes.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    fn do_append_bytes(&mut self, bytes: &[u8]) -> Result<(), StringError> {
+        self.bytes.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    fn do_clear(&mut self) -> Result<(), StringError> {
+        self.bytes.clear();
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::string::StringError,
+    adaptors::string_adaptors::utf16_string_adaptor::Utf16StringAdaptor,
+    primitive_specs::string_spec::{StringSpec, StringStorage},
+};
+
+pub struct TransientUtf16StringAdaptor {
+    /// The specification of the string.
+    spec: Rc<StringSpec>,
+    /// The code units of the string.
+    units: Vec<u16>,
+    /// The fixed size of the string in code units, if applicable.
+    fixed_size: Option<usize>,
+    /// The fixed capacity of the string in code units, if applicable.
+    fixed_capacity: Option<usize>,
+}
+
+impl TransientUtf16StringAdaptor {
+    /// Creates a new TransientUtf16StringAdaptor with the given specification.
+    pub fn new(spec: Rc<StringSpec>) -> Self {
+        let mut units = Vec::new();
+        let mut fixed_size: Option<usize> = None;
+        let mut fixed_capacity: Option<usize> = None;
+        match spec.storage() {
+            Some(StringStorage::FixedSize(size)) => {
+                if *size == 0 {
+                    panic!("Cannot create a TransientUtf16StringAdaptor with a fixed size of 0.");
+                }
+                units.resize(*size as usize, ' ' as u16);
+                fixed_size = Some(*size as usize);
+            }
+            Some(StringStorage::FixedCapacity(capacity)) => {
+                if *capacity == 0 {
+                    panic!(
+                        "Cannot create a TransientUtf16StringAdaptor with a fixed capacity of 0."
+                    );
+                }
+                units.reserve_exact(*capacity as usize);
+                fixed_capacity = Some(*capacity as usize);
+            }
+            Some(StringStorage::InitialCapacity(capacity)) => {
+                units.reserve(*capacity as usize);
+            }
+            Some(StringStorage::VariableSize) | None => {
+                // Variable size strings can be created without any restrictions.
+            }
+        }
+        Self {
+            spec,
+            units,
+            fixed_size,
+            fixed_capacity,
+        }
+    }
+}
+
+impl Utf16StringAdaptor for TransientUtf16StringAdaptor {
+    fn spec(&self) -> &Rc<StringSpec> {
+        &self.spec
+    }
+
+    fn fixed_size(&self) -> Option<usize> {
+        self.fixed_size
+    }
+
+    fn fixed_capacity(&self) -> Option<usize> {
+        self.fixed_capacity
+    }
+
+    fn code_units(&self) -> Result<Vec<u16>, StringError> {
+        Ok(self.units.clone())
+    }
+
+    fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    fn do_code_unit(&self, index: usize) -> Result<u16, StringError> {
+        Ok(self.units[index])
+    }
+
+    fn do_set_code_units(&mut self, units: &[u16]) -> Result<(), StringError> {
+        self.units.clear();
+        self.units.extend_from_slice(units);
+        Ok(())
+    }
+
+    fn do_append_code_units(&mut self, units: &[u16]) -> Result<(), StringError> {
+        self.units.extend_from_slice(units);
+        Ok(())
+    }
+
+    fn do_clear(&mut self) -> Result<(), StringError> {
+        self.units.clear();
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::string::StringError,
+    adaptors::string_adaptors::utf8_string_adaptor::Utf8StringAdaptor,
+    primitive_specs::string_spec::{StringSpec, StringStorage},
+};
+
+pub struct TransientUtf8StringAdaptor {
+    /// The specification of the string.
+    spec: Rc<StringSpec>,
+    /// The value of the string.
+    value: String,
+    /// The fixed size of the string in bytes, if applicable.
+    fixed_size: Option<usize>,
+    /// The fixed capacity of the string in bytes, if applicable.
+    fixed_capacity: Option<usize>,
+}
+
+impl TransientUtf8StringAdaptor {
+    /// Creates a new TransientUtf8StringAdaptor with the given specification.
+    pub fn new(spec: Rc<StringSpec>) -> Self {
+        let mut value = String::new();
+        let mut fixed_size: Option<usize> = None;
+        let mut fixed_capacity: Option<usize> = None;
+        match spec.storage() {
+            Some(StringStorage::FixedSize(size)) => {
+                if *size == 0 {
+                    panic!("Cannot create a TransientUtf8StringAdaptor with a fixed size of 0.");
+                }
+                value = " ".repeat(*size as usize);
+                fixed_size = Some(*size as usize);
+            }
+            Some(StringStorage::FixedCapacity(capacity)) => {
+                if *capacity == 0 {
+                    panic!(
+                        "Cannot create a TransientUtf8StringAdaptor with a fixed capacity of 0."
+                    );
+                }
+                value.reserve_exact(*capacity as usize);
+                fixed_capacity = Some(*capacity as usize);
+            }
+            Some(StringStorage::InitialCapacity(capacity)) => {
+                value.reserve(*capacity as usize);
+            }
+            Some(StringStorage::VariableSize) | None => {
+                // Variable size strings can be created without any restrictions.
+            }
+        }
+        Self {
+            spec,
+            value,
+            fixed_size,
+            fixed_capacity,
+        }
+    }
+}
+
+impl Utf8StringAdaptor for TransientUtf8StringAdaptor {
+    fn spec(&self) -> &Rc<StringSpec> {
+        &self.spec
+    }
+
+    fn fixed_size(&self) -> Option<usize> {
+        self.fixed_size
+    }
+
+    fn fixed_capacity(&self) -> Option<usize> {
+        self.fixed_capacity
+    }
+
+    fn string(&self) -> Result<String, StringError> {
+        Ok(self.value.clone())
+    }
+
+    fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    fn do_set_string(&mut self, value: &str) -> Result<(), StringError> {
+        self.value.clear();
+        self.value.push_str(value);
+        Ok(())
+    }
+
+    fn do_append(&mut self, value: &str) -> Result<(), StringError> {
+        self.value.push_str(value);
+        Ok(())
+    }
+
+    fn do_clear(&mut self) -> Result<(), StringError> {
+        self.value.clear();
+        Ok(())
+    }
+}
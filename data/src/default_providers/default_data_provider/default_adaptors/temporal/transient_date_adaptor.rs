@@ -2,13 +2,13 @@ use std::rc::Rc;
 
 use crate::accessors::temporal::date::DateError;
 use crate::adaptor::Adaptor;
-use crate::adaptors::temporal_adaptors::date_adaptor::DateAdaptor;
+use crate::adaptors::temporal_adaptors::date_adaptor::{DateAdaptor, date_from_signed_days};
 use crate::primitive_specs::date_spec::DateSpec;
 
-/// A transient date adaptor that stores the date as the number of days in memory.
+/// A transient date adaptor that stores the date as the signed number of days in memory.
 pub struct TransientDateAdaptor {
     spec: Rc<DateSpec>,
-    days: u32,
+    days: i64,
 }
 
 impl TransientDateAdaptor {
@@ -33,10 +33,20 @@ impl DateAdaptor for TransientDateAdaptor {
     }
 
     fn days(&self) -> Result<u32, DateError> {
-        Ok(self.days)
+        u32::try_from(self.days)
+            .map_err(|_| DateError::DateBeforeYearOne(date_from_signed_days(self.days).0))
     }
 
     fn set_days(&mut self, days: u32) -> Result<(), DateError> {
+        self.days = days as i64;
+        Ok(())
+    }
+
+    fn signed_days(&self) -> Result<i64, DateError> {
+        Ok(self.days)
+    }
+
+    fn set_signed_days(&mut self, days: i64) -> Result<(), DateError> {
         self.days = days;
         Ok(())
     }
@@ -0,0 +1,50 @@
+use std::rc::Rc;
+
+use crate::{
+    adaptor::Adaptor,
+    adaptors::temporal_adaptors::day_to_second_duration_adaptor::DayToSecondDurationAdaptor,
+    primitive_specs::duration_spec::DurationSpec,
+};
+
+pub struct TransientDayToSecondDurationAdaptor {
+    spec: Rc<DurationSpec>,
+    total_nanoseconds: i128,
+}
+
+impl TransientDayToSecondDurationAdaptor {
+    // creates a new TransientDayToSecondDurationAdaptor with the given DurationSpec
+    pub fn new(spec: Rc<DurationSpec>) -> Self {
+        Self {
+            spec,
+            total_nanoseconds: 0,
+        }
+    }
+}
+
+impl Adaptor for TransientDayToSecondDurationAdaptor {}
+
+impl DayToSecondDurationAdaptor for TransientDayToSecondDurationAdaptor {
+    fn spec(&self) -> &Rc<DurationSpec> {
+        &self.spec
+    }
+
+    fn stores_duration_as_nanoseconds(&self) -> bool {
+        true
+    }
+
+    fn set_total_nanoseconds(
+        &mut self,
+        nanoseconds: i128,
+    ) -> Result<(), crate::accessors::temporal::day_to_second_duration::DayToSecondDurationError>
+    {
+        self.total_nanoseconds = nanoseconds;
+        Ok(())
+    }
+
+    fn total_nanoseconds(
+        &self,
+    ) -> Result<i128, crate::accessors::temporal::day_to_second_duration::DayToSecondDurationError>
+    {
+        Ok(self.total_nanoseconds)
+    }
+}
@@ -0,0 +1,59 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::blob::BlobError, adaptors::blob_adaptor::BlobAdaptor,
+    primitive_specs::blob_spec::BlobSpec,
+};
+
+pub struct TransientBlobAdaptor {
+    spec: Rc<BlobSpec>,
+    bytes: Vec<u8>,
+}
+
+impl TransientBlobAdaptor {
+    /// Creates a new, empty transient BLOB adaptor.
+    pub fn new(spec: Rc<BlobSpec>) -> Self {
+        Self {
+            spec,
+            bytes: Vec::new(),
+        }
+    }
+}
+
+impl BlobAdaptor for TransientBlobAdaptor {
+    fn spec(&self) -> &Rc<BlobSpec> {
+        &self.spec
+    }
+
+    fn len(&self) -> u64 {
+        self.bytes.len() as u64
+    }
+
+    fn read(&self, offset: u64, buffer: &mut [u8]) -> Result<usize, BlobError> {
+        let start = usize::try_from(offset)
+            .unwrap_or(usize::MAX)
+            .min(self.bytes.len());
+        let count = buffer.len().min(self.bytes.len() - start);
+        buffer[..count].copy_from_slice(&self.bytes[start..start + count]);
+        Ok(count)
+    }
+
+    fn do_write(&mut self, offset: u64, bytes: &[u8]) -> Result<(), BlobError> {
+        let out_of_range = || BlobError::WriteOutOfRange(offset, bytes.len() as u64);
+        let start = usize::try_from(offset).map_err(|_| out_of_range())?;
+        let end = start.checked_add(bytes.len()).ok_or_else(out_of_range)?;
+        if end > self.bytes.len() {
+            self.bytes
+                .try_reserve(end - self.bytes.len())
+                .map_err(|_| out_of_range())?;
+            self.bytes.resize(end, 0);
+        }
+        self.bytes[start..end].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    fn do_truncate(&mut self, len: u64) -> Result<(), BlobError> {
+        self.bytes.truncate(len as usize);
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::character::CharacterError,
+    adaptors::character_adaptor::CharacterAdaptor,
+    primitive_specs::{
+        character_spec::{CharacterSpec, CharacterStorage},
+        string_spec::ByteCodePage,
+    },
+};
+
+/// The stored representation of a transient character.
+enum StoredCharacter {
+    Byte(u8),
+    Utf16CodeUnit(u16),
+    Scalar(char),
+}
+
+pub struct TransientCharacterAdaptor {
+    spec: Rc<CharacterSpec>,
+    code_page: ByteCodePage,
+    value: StoredCharacter,
+}
+
+impl TransientCharacterAdaptor {
+    /// Creates a new transient character adaptor holding the NUL character.
+    pub fn new(spec: Rc<CharacterSpec>) -> Self {
+        let code_page = spec.code_page().unwrap_or(ByteCodePage::Ascii);
+        let value = match spec.storage() {
+            Some(CharacterStorage::Byte) => StoredCharacter::Byte(0),
+            Some(CharacterStorage::Utf16CodeUnit) => StoredCharacter::Utf16CodeUnit(0),
+            Some(CharacterStorage::Scalar) | None => StoredCharacter::Scalar('\0'),
+        };
+        Self {
+            spec,
+            code_page,
+            value,
+        }
+    }
+}
+
+impl CharacterAdaptor for TransientCharacterAdaptor {
+    fn spec(&self) -> &Rc<CharacterSpec> {
+        &self.spec
+    }
+
+    fn character(&self) -> Result<char, CharacterError> {
+        // Stored values are validated on the way in, so they always decode.
+        Ok(match self.value {
+            StoredCharacter::Byte(byte) => self.code_page.decode(byte).unwrap(),
+            StoredCharacter::Utf16CodeUnit(unit) => char::from_u32(unit as u32).unwrap(),
+            StoredCharacter::Scalar(c) => c,
+        })
+    }
+
+    fn do_set_character(&mut self, value: char) -> Result<(), CharacterError> {
+        self.value = match self.value {
+            StoredCharacter::Byte(_) => StoredCharacter::Byte(
+                self.code_page
+                    .encode(value)
+                    .ok_or(CharacterError::UnrepresentableCharacter(value))?,
+            ),
+            StoredCharacter::Utf16CodeUnit(_) => StoredCharacter::Utf16CodeUnit(
+                u16::try_from(value as u32)
+                    .map_err(|_| CharacterError::UnrepresentableCharacter(value))?,
+            ),
+            StoredCharacter::Scalar(_) => StoredCharacter::Scalar(value),
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use std::{cell::OnceCell, rc::Rc};
+
+use crate::{
+    accessors::sequence::{SequenceError, SequenceIter},
+    adaptors::sequence_adaptor::{SequenceAdaptor, SequenceGenerator},
+    primitive_specs::sequence_spec::SequenceSpec,
+    variable::Variable,
+};
+
+/// A read-only sequence adaptor for the values of a sequence generator.
+/// <p>
+/// Values are generated when first iterated to, and are kept in a chain so that iterators can
+/// return references to them.
+/// </p>
+pub(crate) struct TransientGeneratedSequenceAdaptor {
+    spec: Rc<SequenceSpec>,
+    generator: Box<dyn SequenceGenerator>,
+    first: OnceCell<Option<Box<Generated>>>,
+}
+
+/// A generated value, linked to the next one once generated.
+struct Generated {
+    value: Variable,
+    next: OnceCell<Option<Box<Generated>>>,
+}
+
+impl TransientGeneratedSequenceAdaptor {
+    /// Creates a new TransientGeneratedSequenceAdaptor with the given specification and
+    /// generator.
+    pub(crate) fn new(spec: Rc<SequenceSpec>, generator: Box<dyn SequenceGenerator>) -> Self {
+        Self {
+            spec,
+            generator,
+            first: OnceCell::new(),
+        }
+    }
+}
+
+impl SequenceAdaptor for TransientGeneratedSequenceAdaptor {
+    fn spec(&self) -> &Rc<SequenceSpec> {
+        &self.spec
+    }
+
+    fn iter<'a>(&'a self) -> Box<dyn SequenceIter<'a> + 'a> {
+        Box::new(GeneratedIter {
+            adaptor: self,
+            previous: None,
+            index: 0,
+            done: false,
+        })
+    }
+}
+
+impl Drop for TransientGeneratedSequenceAdaptor {
+    fn drop(&mut self) {
+        // unlink the chain iteratively, as dropping it recursively could overflow the stack
+        let mut next = self.first.take().flatten();
+        while let Some(mut generated) = next {
+            next = generated.next.take().flatten();
+        }
+    }
+}
+
+struct GeneratedIter<'a> {
+    adaptor: &'a TransientGeneratedSequenceAdaptor,
+    previous: Option<&'a Generated>,
+    index: usize,
+    done: bool,
+}
+
+impl<'a> std::iter::Iterator for GeneratedIter<'a> {
+    type Item = Result<&'a Variable, SequenceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let cell = match self.previous {
+            Some(previous) => &previous.next,
+            None => &self.adaptor.first,
+        };
+        if cell.get().is_none() {
+            let previous = self.previous.map(|previous| &previous.value);
+            match self.adaptor.generator.next_value(self.index, previous) {
+                Some(Ok(value)) => {
+                    let _ = cell.set(Some(Box::new(Generated {
+                        value,
+                        next: OnceCell::new(),
+                    })));
+                }
+                Some(Err(error)) => {
+                    // the value is not cached, so a later iteration tries again
+                    self.done = true;
+                    return Some(Err(error));
+                }
+                None => {
+                    let _ = cell.set(None);
+                }
+            }
+        }
+        match cell.get().and_then(Option::as_deref) {
+            Some(generated) => {
+                self.previous = Some(generated);
+                self.index += 1;
+                Some(Ok(&generated.value))
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<'a> SequenceIter<'a> for GeneratedIter<'a> {}
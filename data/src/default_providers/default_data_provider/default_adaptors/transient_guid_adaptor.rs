@@ -0,0 +1,36 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::guid::GuidError, adaptors::guid_adaptor::GuidAdaptor,
+    primitive_specs::guid_spec::GuidSpec,
+};
+
+pub struct TransientGuidAdaptor {
+    spec: Rc<GuidSpec>,
+    bytes: [u8; 16],
+}
+
+impl TransientGuidAdaptor {
+    /// Creates a new transient GUID adaptor holding the nil GUID.
+    pub fn new(spec: Rc<GuidSpec>) -> Self {
+        Self {
+            spec,
+            bytes: [0; 16],
+        }
+    }
+}
+
+impl GuidAdaptor for TransientGuidAdaptor {
+    fn spec(&self) -> &Rc<GuidSpec> {
+        &self.spec
+    }
+
+    fn bytes(&self) -> Result<[u8; 16], GuidError> {
+        Ok(self.bytes)
+    }
+
+    fn do_set_bytes(&mut self, bytes: &[u8; 16]) -> Result<(), GuidError> {
+        self.bytes = *bytes;
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use std::rc::Rc;
+
+use crate::{
+    accessors::interval::IntervalError,
+    adaptors::interval_adaptor::IntervalAdaptor,
+    primitive_specs::interval_spec::{BoundType, IntervalSpec},
+    variable::Variable,
+};
+
+pub struct TransientIntervalAdaptor {
+    spec: Rc<IntervalSpec>,
+    start: Variable,
+    start_bound: BoundType,
+    end: Variable,
+    end_bound: BoundType,
+}
+
+impl TransientIntervalAdaptor {
+    pub fn new(spec: Rc<IntervalSpec>) -> Self {
+        let value_spec = spec.value_spec().as_ref().unwrap();
+        Self {
+            start: Variable::new(value_spec),
+            start_bound: spec.start_bound(),
+            end: Variable::new(value_spec),
+            end_bound: spec.end_bound(),
+            spec,
+        }
+    }
+}
+
+impl IntervalAdaptor for TransientIntervalAdaptor {
+    fn spec(&self) -> &Rc<IntervalSpec> {
+        &self.spec
+    }
+
+    fn start(&self) -> Result<&Variable, IntervalError> {
+        Ok(&self.start)
+    }
+
+    fn end(&self) -> Result<&Variable, IntervalError> {
+        Ok(&self.end)
+    }
+
+    fn start_bound(&self) -> Result<BoundType, IntervalError> {
+        Ok(self.start_bound)
+    }
+
+    fn end_bound(&self) -> Result<BoundType, IntervalError> {
+        Ok(self.end_bound)
+    }
+
+    fn do_set(
+        &mut self,
+        start: Variable,
+        start_bound: BoundType,
+        end: Variable,
+        end_bound: BoundType,
+    ) -> Result<(), IntervalError> {
+        self.start = start;
+        self.start_bound = start_bound;
+        self.end = end;
+        self.end_bound = end_bound;
+        Ok(())
+    }
+}
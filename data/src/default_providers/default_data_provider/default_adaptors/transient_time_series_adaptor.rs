@@ -0,0 +1,82 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use crate::{
+    accessors::time_series::{TimeSeriesError, TimeSeriesIter},
+    adaptors::time_series_adaptor::TimeSeriesAdaptor,
+    primitive_specs::time_series_spec::TimeSeriesSpec,
+    variable::Variable,
+};
+
+pub struct TransientTimeSeriesAdaptor {
+    spec: Rc<TimeSeriesSpec>,
+    items: BTreeMap<Variable, Variable>,
+}
+
+impl TransientTimeSeriesAdaptor {
+    pub fn new(spec: Rc<TimeSeriesSpec>) -> Self {
+        Self {
+            spec,
+            items: BTreeMap::new(),
+        }
+    }
+}
+
+impl TimeSeriesAdaptor for TransientTimeSeriesAdaptor {
+    fn spec(&self) -> &Rc<TimeSeriesSpec> {
+        &self.spec
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn get(&self, timestamp: &Variable) -> Result<Option<&Variable>, TimeSeriesError> {
+        Ok(self.items.get(timestamp))
+    }
+
+    fn do_insert(&mut self, timestamp: Variable, value: Variable) -> Result<bool, TimeSeriesError> {
+        Ok(self.items.insert(timestamp, value).is_none())
+    }
+
+    fn remove(&mut self, timestamp: &Variable) -> Result<bool, TimeSeriesError> {
+        Ok(self.items.remove(timestamp).is_some())
+    }
+
+    fn clear(&mut self) -> Result<(), TimeSeriesError> {
+        self.items.clear();
+        Ok(())
+    }
+
+    fn as_of(
+        &self,
+        timestamp: &Variable,
+    ) -> Result<Option<(&Variable, &Variable)>, TimeSeriesError> {
+        Ok(self.items.range(..=timestamp).next_back())
+    }
+
+    fn iter<'a>(&'a self) -> Box<dyn TimeSeriesIter<'a> + 'a> {
+        Box::new(EntryIter {
+            iter: self.items.range::<Variable, _>(..),
+        })
+    }
+
+    fn range<'a>(&'a self, start: &Variable, end: &Variable) -> Box<dyn TimeSeriesIter<'a> + 'a> {
+        Box::new(EntryIter {
+            iter: self.items.range::<Variable, _>(start..end),
+        })
+    }
+}
+
+struct EntryIter<'a> {
+    iter: std::collections::btree_map::Range<'a, Variable, Variable>,
+}
+
+impl<'a> std::iter::Iterator for EntryIter<'a> {
+    type Item = Result<(&'a Variable, &'a Variable), TimeSeriesError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Ok)
+    }
+}
+
+impl<'a> TimeSeriesIter<'a> for EntryIter<'a> {}
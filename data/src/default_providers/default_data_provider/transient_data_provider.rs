@@ -1,10 +1,21 @@
 use std::rc::Rc;
 
+use crate::default_providers::default_data_provider::default_adaptors::strings::{
+    transient_byte_string_adaptor::TransientByteStringAdaptor,
+    transient_utf8_string_adaptor::TransientUtf8StringAdaptor,
+    transient_utf16_string_adaptor::TransientUtf16StringAdaptor,
+    transient_utf32_string_adaptor::TransientUtf32StringAdaptor,
+};
 use crate::default_providers::default_data_provider::default_adaptors::temporal::transient_year_to_month_duration_adaptor::TransientYearToMonthDurationAdaptor;
 use crate::default_providers::default_data_provider::default_adaptors::{
-    transient_boolean_adaptor::TransientBooleanAdaptor,
+    transient_blob_adaptor::TransientBlobAdaptor, transient_boolean_adaptor::TransientBooleanAdaptor,
+    transient_character_adaptor::TransientCharacterAdaptor,
     transient_float_adaptor::TransientFloatAdaptor,
+    transient_generated_sequence_adaptor::TransientGeneratedSequenceAdaptor,
+    transient_guid_adaptor::TransientGuidAdaptor,
     transient_integer_adaptor::TransientIntegerAdaptor,
+    transient_interval_adaptor::TransientIntervalAdaptor,
+    transient_time_series_adaptor::TransientTimeSeriesAdaptor,
     transient_list_adaptor::TransientListAdaptor, transient_map_adaptor::TransientMapAdaptor,
     transient_sequence_adaptor::TransientSequenceAdaptor,
     transient_set_adaptor::TransientSetAdaptor, transient_tuple_adaptor::TransientTupleAdaptor,
@@ -12,20 +23,25 @@ use crate::default_providers::default_data_provider::default_adaptors::{
 
 use crate::default_providers::default_data_provider::default_adaptors::temporal::{
     transient_date_adaptor::TransientDateAdaptor,
+    transient_day_to_second_duration_adaptor::TransientDayToSecondDurationAdaptor,
     transient_time_micro_adaptor::TransientTimeMicroAdaptor,
     transient_time_nano_adaptor::TransientTimeNanoAdaptor,
 };
 
-use crate::primitive_specs::time_spec::TimeResolution;
+use crate::primitive_specs::date_spec::{DateRange, DateSpec};
+use crate::primitive_specs::duration_spec::{DurationResolution, DurationSpec, DurationType};
+use crate::primitive_specs::time_spec::{TimeResolution, TimeSpec, TimeType};
 use crate::{
     adaptors::{
-        blob_adaptor::BlobAdaptor, boolean_adaptor::BooleanAdaptor, float_adaptor::FloatAdaptor,
-        integer_adaptor::IntegerAdaptor,
+        blob_adaptor::BlobAdaptor, boolean_adaptor::BooleanAdaptor,
+        character_adaptor::CharacterAdaptor, float_adaptor::FloatAdaptor,
+        guid_adaptor::GuidAdaptor, integer_adaptor::IntegerAdaptor,
+        temporal_adaptors::day_to_second_duration_adaptor::DayToSecondDurationAdaptor,
     },
     data_provider::DataProvider,
     primitive_specs::{
-        blob_spec::BlobSpec, boolean_spec::BooleanSpec, float_spec::FloatSpec,
-        integer_spec::IntegerSpec,
+        blob_spec::BlobSpec, boolean_spec::BooleanSpec, character_spec::CharacterSpec,
+        float_spec::FloatSpec, guid_spec::GuidSpec, integer_spec::IntegerSpec,
     },
 };
 
@@ -47,9 +63,19 @@ impl DataProvider for TransientDataProvider {
     fn boolean_adaptor(&self, _spec: &Rc<BooleanSpec>) -> Box<dyn BooleanAdaptor> {
         Box::new(TransientBooleanAdaptor::new())
     }
-    fn blob_adaptor(&self, _spec: &Rc<BlobSpec>) -> Box<dyn BlobAdaptor> {
-        todo!()
+
+    fn character_adaptor(&self, spec: &Rc<CharacterSpec>) -> Box<dyn CharacterAdaptor> {
+        Box::new(TransientCharacterAdaptor::new(spec.clone()))
+    }
+
+    fn guid_adaptor(&self, spec: &Rc<GuidSpec>) -> Box<dyn GuidAdaptor> {
+        Box::new(TransientGuidAdaptor::new(spec.clone()))
     }
+
+    fn blob_adaptor(&self, spec: &Rc<BlobSpec>) -> Box<dyn BlobAdaptor> {
+        Box::new(TransientBlobAdaptor::new(spec.clone()))
+    }
+
     fn list_adaptor(
         &self,
         spec: &Rc<crate::primitive_specs::list_spec::ListSpec>,
@@ -74,29 +100,39 @@ impl DataProvider for TransientDataProvider {
     ) -> Box<dyn crate::adaptors::sequence_adaptor::SequenceAdaptor> {
         Box::new(TransientSequenceAdaptor::new(spec.clone()))
     }
+    fn generated_sequence_adaptor(
+        &self,
+        spec: &Rc<crate::primitive_specs::sequence_spec::SequenceSpec>,
+        generator: Box<dyn crate::adaptors::sequence_adaptor::SequenceGenerator>,
+    ) -> Box<dyn crate::adaptors::sequence_adaptor::SequenceAdaptor> {
+        Box::new(TransientGeneratedSequenceAdaptor::new(
+            spec.clone(),
+            generator,
+        ))
+    }
     fn byte_string_adaptor(
         &self,
-        _spec: &Rc<crate::primitive_specs::string_spec::StringSpec>,
+        spec: &Rc<crate::primitive_specs::string_spec::StringSpec>,
     ) -> Box<dyn crate::adaptors::string_adaptors::byte_string_adaptor::ByteStringAdaptor> {
-        todo!()
+        Box::new(TransientByteStringAdaptor::new(spec.clone()))
     }
     fn utf_8_string_adaptor(
         &self,
-        _spec: &Rc<crate::primitive_specs::string_spec::StringSpec>,
+        spec: &Rc<crate::primitive_specs::string_spec::StringSpec>,
     ) -> Box<dyn crate::adaptors::string_adaptors::utf8_string_adaptor::Utf8StringAdaptor> {
-        todo!()
+        Box::new(TransientUtf8StringAdaptor::new(spec.clone()))
     }
     fn utf_16_string_adaptor(
         &self,
-        _spec: &Rc<crate::primitive_specs::string_spec::StringSpec>,
+        spec: &Rc<crate::primitive_specs::string_spec::StringSpec>,
     ) -> Box<dyn crate::adaptors::string_adaptors::utf16_string_adaptor::Utf16StringAdaptor> {
-        todo!()
+        Box::new(TransientUtf16StringAdaptor::new(spec.clone()))
     }
     fn utf_32_string_adaptor(
         &self,
-        _spec: &Rc<crate::primitive_specs::string_spec::StringSpec>,
+        spec: &Rc<crate::primitive_specs::string_spec::StringSpec>,
     ) -> Box<dyn crate::adaptors::string_adaptors::utf32_string_adaptor::Utf32StringAdaptor> {
-        todo!()
+        Box::new(TransientUtf32StringAdaptor::new(spec.clone()))
     }
     fn tuple_adaptor(
         &self,
@@ -104,6 +140,18 @@ impl DataProvider for TransientDataProvider {
     ) -> Box<dyn crate::adaptors::tuple_adaptor::TupleAdaptor> {
         Box::new(TransientTupleAdaptor::new(spec.clone()))
     }
+    fn interval_adaptor(
+        &self,
+        spec: &Rc<crate::primitive_specs::interval_spec::IntervalSpec>,
+    ) -> Box<dyn crate::adaptors::interval_adaptor::IntervalAdaptor> {
+        Box::new(TransientIntervalAdaptor::new(spec.clone()))
+    }
+    fn time_series_adaptor(
+        &self,
+        spec: &Rc<crate::primitive_specs::time_series_spec::TimeSeriesSpec>,
+    ) -> Box<dyn crate::adaptors::time_series_adaptor::TimeSeriesAdaptor> {
+        Box::new(TransientTimeSeriesAdaptor::new(spec.clone()))
+    }
     fn date_adaptor(
         &self,
         spec: &Rc<crate::primitive_specs::date_spec::DateSpec>,
@@ -123,10 +171,69 @@ impl DataProvider for TransientDataProvider {
             _ => Box::new(TransientTimeNanoAdaptor::new(spec.clone())),
         }
     }
+    fn zoned_time_adaptors(
+        &self,
+        spec: &Rc<crate::primitive_specs::time_spec::TimeSpec>,
+    ) -> (
+        Box<dyn crate::adaptors::temporal_adaptors::time_adaptor::TimeAdaptor>,
+        Box<dyn DayToSecondDurationAdaptor>,
+    ) {
+        (self.time_adaptor(spec), zone_adaptor())
+    }
+    fn local_date_time_adaptors(
+        &self,
+        _spec: &Rc<crate::primitive_specs::date_time_spec::DateTimeSpec>,
+    ) -> (
+        Box<dyn crate::adaptors::temporal_adaptors::date_adaptor::DateAdaptor>,
+        Box<dyn crate::adaptors::temporal_adaptors::time_adaptor::TimeAdaptor>,
+    ) {
+        (
+            Box::new(TransientDateAdaptor::new(Rc::new(DateSpec::new(DateRange::Standard)))),
+            Box::new(TransientTimeNanoAdaptor::new(Rc::new(TimeSpec::new(
+                Some(TimeType::Local),
+                None,
+            )))),
+        )
+    }
+    fn zoned_date_time_adaptors(
+        &self,
+        _spec: &Rc<crate::primitive_specs::date_time_spec::DateTimeSpec>,
+    ) -> (
+        Box<dyn crate::adaptors::temporal_adaptors::date_adaptor::DateAdaptor>,
+        Box<dyn crate::adaptors::temporal_adaptors::time_adaptor::TimeAdaptor>,
+        Box<dyn DayToSecondDurationAdaptor>,
+    ) {
+        (
+            Box::new(TransientDateAdaptor::new(Rc::new(DateSpec::new(DateRange::Standard)))),
+            Box::new(TransientTimeNanoAdaptor::new(Rc::new(TimeSpec::new(
+                Some(TimeType::Zoned),
+                None,
+            )))),
+            zone_adaptor(),
+        )
+    }
     fn year_to_month_duration_adaptor(
             &self,
             _spec: &Rc<crate::primitive_specs::duration_spec::DurationSpec>,
     ) -> Box<dyn crate::adaptors::temporal_adaptors::year_to_month_duration_adaptor::YearToMonthDurationAdaptor>{
         Box::new(TransientYearToMonthDurationAdaptor::new(_spec.clone()))
     }
+    fn day_time_duration_adaptor(
+        &self,
+        spec: &Rc<crate::primitive_specs::duration_spec::DurationSpec>,
+    ) -> Box<dyn crate::adaptors::temporal_adaptors::day_to_second_duration_adaptor::DayToSecondDurationAdaptor>
+    {
+        Box::new(TransientDayToSecondDurationAdaptor::new(spec.clone()))
+    }
+}
+
+/// Returns an adaptor for the UTC offset of a zoned time, held as a day-to-second duration
+/// with second resolution.
+fn zone_adaptor() -> Box<dyn DayToSecondDurationAdaptor> {
+    Box::new(TransientDayToSecondDurationAdaptor::new(Rc::new(
+        DurationSpec::new(
+            Some(DurationType::DayToSecond),
+            Some(DurationResolution::Second),
+        ),
+    )))
 }
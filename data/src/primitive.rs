@@ -8,6 +8,7 @@ use crate::accessors::graph::edge::Edge;
 use crate::accessors::graph::walk::Walk;
 use crate::accessors::guid::Guid;
 use crate::accessors::identifier::Identifier;
+use crate::accessors::interval::Interval;
 use crate::accessors::object::Object;
 use crate::accessors::reference::Reference;
 use crate::accessors::schema::attribute::Attribute;
@@ -16,6 +17,7 @@ use crate::accessors::schema::enum_class::EnumClass;
 use crate::accessors::schema::property::Property;
 use crate::accessors::sequence::Sequence;
 use crate::accessors::temporal::day_to_second_duration::DayToSecondDuration;
+use crate::accessors::time_series::TimeSeries;
 use crate::accessors::tuple::Tuple;
 use crate::primitive_category::PrimitiveCategory;
 use crate::primitive_def::{IsOrdered, PrimitiveDef};
@@ -35,6 +37,7 @@ use crate::primitive_specs::float_spec::FloatSpec;
 use crate::primitive_specs::guid_spec::GuidSpec;
 use crate::primitive_specs::identifier_spec::IdentifierSpec;
 use crate::primitive_specs::integer_spec::IntegerSpec;
+use crate::primitive_specs::interval_spec::IntervalSpec;
 use crate::primitive_specs::list_spec::ListSpec;
 use crate::primitive_specs::map_spec::MapSpec;
 use crate::primitive_specs::object_spec::ObjectSpec;
@@ -43,6 +46,7 @@ use crate::primitive_specs::reference_spec::ReferenceSpec;
 use crate::primitive_specs::sequence_spec::SequenceSpec;
 use crate::primitive_specs::set_spec::SetSpec;
 use crate::primitive_specs::string_spec::StringSpec;
+use crate::primitive_specs::time_series_spec::TimeSeriesSpec;
 use crate::primitive_specs::time_spec::TimeSpec;
 
 use crate::accessors::boolean::Boolean;
@@ -139,6 +143,11 @@ pub enum Primitive {
     Blob(Option<PrimitiveDef<BlobSpec, Blob>>),
     /// An object identifier
     Identifier(Option<PrimitiveDef<IdentifierSpec, Identifier>>),
+    /// A range of ordered values between a start and an end, each of which is either included
+    /// or excluded.
+    Interval(Option<PrimitiveDef<IntervalSpec, Interval>>),
+    /// A series of numeric values at unique, ordered date-time timestamps.
+    TimeSeries(Option<PrimitiveDef<TimeSeriesSpec, TimeSeries>>),
 }
 
 impl Primitive {
@@ -465,6 +474,26 @@ impl Primitive {
                     !(p.is_none() && r.is_some())
                 }
             }
+            (Self::Interval(p), Self::Interval(r)) => {
+                if p.is_some() && r.is_some() {
+                    p.as_ref()
+                        .unwrap()
+                        .spec()
+                        .is_compatible_with(r.as_ref().unwrap().spec())
+                } else {
+                    !(p.is_none() && r.is_some())
+                }
+            }
+            (Self::TimeSeries(p), Self::TimeSeries(r)) => {
+                if p.is_some() && r.is_some() {
+                    p.as_ref()
+                        .unwrap()
+                        .spec()
+                        .is_compatible_with(r.as_ref().unwrap().spec())
+                } else {
+                    !(p.is_none() && r.is_some())
+                }
+            }
             (Self::Identifier(p), Self::Identifier(r)) => {
                 if p.is_some() && r.is_some() {
                     p.as_ref()
@@ -816,6 +845,20 @@ impl Display for Primitive {
                         "Identifier".to_string()
                     }
                 }
+                Self::Interval(def) => {
+                    if let Some(as_def) = def {
+                        as_def.spec().to_string()
+                    } else {
+                        "Interval".to_string()
+                    }
+                }
+                Self::TimeSeries(def) => {
+                    if let Some(as_def) = def {
+                        as_def.spec().to_string()
+                    } else {
+                        "TimeSeries".to_string()
+                    }
+                }
             }
         )
     }
@@ -1054,6 +1097,20 @@ impl PartialEq for Primitive {
                     p.is_none() && r.is_none()
                 }
             }
+            (Self::Interval(p), Self::Interval(r)) => {
+                if let (Some(p), Some(r)) = (p, r) {
+                    p.spec() == r.spec()
+                } else {
+                    p.is_none() && r.is_none()
+                }
+            }
+            (Self::TimeSeries(p), Self::TimeSeries(r)) => {
+                if let (Some(p), Some(r)) = (p, r) {
+                    p.spec() == r.spec()
+                } else {
+                    p.is_none() && r.is_none()
+                }
+            }
             // If the primitives are of different types, they are not equal.
             _ => false,
         }
@@ -1096,6 +1153,8 @@ impl IsOrdered for Primitive {
             Self::Tuple(p) => p.as_ref().is_none_or(|p| p.spec().is_ordered()),
             Self::Blob(p) => p.as_ref().is_none_or(|p| p.spec().is_ordered()),
             Self::Identifier(p) => p.as_ref().is_none_or(|p| p.spec().is_ordered()),
+            Self::Interval(p) => p.as_ref().is_none_or(|p| p.spec().is_ordered()),
+            Self::TimeSeries(p) => p.as_ref().is_none_or(|p| p.spec().is_ordered()),
         }
     }
 }
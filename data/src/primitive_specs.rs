@@ -30,6 +30,8 @@ pub mod guid_spec;
 pub mod identifier_spec;
 /// The `IntegerSpec` struct.
 pub mod integer_spec;
+/// The `IntervalSpec` struct.
+pub mod interval_spec;
 /// The `ListSpec` struct.
 pub mod list_spec;
 /// The `MapSpec` struct.
@@ -40,12 +42,16 @@ pub mod object_spec;
 pub mod property_spec;
 /// The `R`eferenceSpec` struct.
 pub mod reference_spec;
+/// The `RoundingPolicy` enumeration for values finer than the resolution of their spec.
+pub mod rounding_policy;
 /// The `SequenceSpec` struct.
 pub mod sequence_spec;
 /// The `SetSpec` struct.
 pub mod set_spec;
 /// The `StringSpec` struct.
 pub mod string_spec;
+/// The `TimeSeriesSpec` struct.
+pub mod time_series_spec;
 /// The `TimeSpec` struct.
 pub mod time_spec;
 /// The `TupleSpec` struct.
@@ -71,14 +77,17 @@ mod tests {
     mod guid_spec_test;
     mod identifier_spec_test;
     mod integer_spec_test;
+    mod interval_spec_test;
     mod list_spec_test;
     mod map_spec_test;
     mod object_spec_test;
     mod property_spec_test;
     mod reference_spec_test;
+    mod rounding_policy_test;
     mod sequence_spec_test;
     mod set_spec_test;
     mod string_spec_test;
+    mod time_series_spec_test;
     mod time_spec_test;
     mod tuple_spec_test;
     mod walk_spec_test;
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use crate::{
     primitive_def::{IsOrdered, PrimitiveSpec},
     spec_compatibility::SpecCompatibility,
@@ -5,20 +7,47 @@ use crate::{
 
 /// A primitive spec for BLOBs.
 #[derive(Debug, PartialEq)]
-pub struct BlobSpec {}
+pub struct BlobSpec {
+    max_size: Option<u64>,
+    media_type: Option<String>,
+}
 
 impl BlobSpec {
     /// Creates a new BLOB spec.
-    pub(crate) fn new() -> Self {
-        Self {}
+    pub(crate) fn new(max_size: Option<u64>, media_type: Option<String>) -> Self {
+        Self {
+            max_size,
+            media_type,
+        }
+    }
+
+    /// Returns the BLOB's maximum size in bytes, if the BLOB's size is limited.
+    pub fn max_size(&self) -> Option<u64> {
+        self.max_size
+    }
+
+    /// Returns the BLOB's media type, such as "image/png".
+    pub fn media_type(&self) -> Option<&str> {
+        self.media_type.as_deref()
     }
 }
 
 impl SpecCompatibility for BlobSpec {
-    fn is_compatible_with(&self, _required: &Self) -> bool {
-        // For now, we assume all BLOB specs are compatible with each other.
-        // This can be extended later to check specific compatibility rules.
-        true
+    fn is_compatible_with(&self, required: &Self) -> bool {
+        match (self.max_size, required.max_size) {
+            (Some(max_size), Some(required_max_size)) if max_size != required_max_size => {
+                return false;
+            }
+            (None, Some(_)) => return false,
+            _ => {}
+        }
+        match (&self.media_type, &required.media_type) {
+            (Some(media_type), Some(required_media_type)) => {
+                media_type.eq_ignore_ascii_case(required_media_type)
+            }
+            (None, Some(_)) => false,
+            _ => true,
+        }
     }
 }
 
@@ -31,8 +60,19 @@ impl IsOrdered for BlobSpec {
 
 impl PrimitiveSpec for BlobSpec {}
 
-impl std::fmt::Display for BlobSpec {
+impl Display for BlobSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "BLOB")
+        write!(f, "BLOB")?;
+        let mut details = Vec::new();
+        if let Some(max_size) = self.max_size {
+            details.push(format!("max size: {}", max_size));
+        }
+        if let Some(media_type) = &self.media_type {
+            details.push(format!("media type: {}", media_type));
+        }
+        if !details.is_empty() {
+            write!(f, " {{ {} }}", details.join(", "))?;
+        }
+        Ok(())
     }
 }
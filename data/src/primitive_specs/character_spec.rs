@@ -1,24 +1,97 @@
+use std::fmt::Display;
+
 use crate::{
     primitive_def::{IsOrdered, PrimitiveSpec},
+    primitive_specs::string_spec::ByteCodePage,
     spec_compatibility::SpecCompatibility,
 };
 
+/// CharacterStorage defines an enumeration that captures the supported storage
+/// characteristics for characters.
+/// <p>
+/// A CharacterStorage option constrains the characters a character variable can hold
+/// to those that fit into a single code unit of the corresponding string encoding.
+/// </p>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CharacterStorage {
+    /// The character is stored as a single byte, interpreted through the spec's code page.
+    /// A character without a code page is interpreted as ASCII.
+    Byte,
+    /// The character is stored as a single UTF-16 code unit, so it must be a character
+    /// of the Basic Multilingual Plane.
+    Utf16CodeUnit,
+    /// The character is stored as a Unicode scalar value, so it can be any character.
+    Scalar,
+}
+
+impl Display for CharacterStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Byte => "Byte".to_string(),
+                Self::Utf16CodeUnit => "Utf16CodeUnit".to_string(),
+                Self::Scalar => "Scalar".to_string(),
+            }
+        )
+    }
+}
+
 /// A primitive spec for characters.
 #[derive(Debug, PartialEq)]
-pub struct CharacterSpec {}
+pub struct CharacterSpec {
+    storage: Option<CharacterStorage>,
+    code_page: Option<ByteCodePage>,
+}
 
 impl CharacterSpec {
     /// Returns an initialized Character spec.
-    pub(crate) fn new() -> CharacterSpec {
-        CharacterSpec {}
+    pub(crate) fn new(
+        storage: Option<CharacterStorage>,
+        code_page: Option<ByteCodePage>,
+    ) -> CharacterSpec {
+        CharacterSpec { storage, code_page }
+    }
+
+    /// Returns the character's storage.
+    pub fn storage(&self) -> &Option<CharacterStorage> {
+        &self.storage
+    }
+
+    /// Returns the code page of a byte character.
+    pub fn code_page(&self) -> &Option<ByteCodePage> {
+        &self.code_page
+    }
+
+    /// Returns whether a character with this spec can hold the given character.
+    pub fn can_represent(&self, c: char) -> bool {
+        match self.storage {
+            Some(CharacterStorage::Byte) => self
+                .code_page
+                .unwrap_or(ByteCodePage::Ascii)
+                .encode(c)
+                .is_some(),
+            Some(CharacterStorage::Utf16CodeUnit) => c.len_utf16() == 1,
+            Some(CharacterStorage::Scalar) | None => true,
+        }
     }
 }
 
 impl SpecCompatibility for CharacterSpec {
-    fn is_compatible_with(&self, _required: &Self) -> bool {
-        // For now, we assume all Character specs are compatible with each other.
-        // This can be extended later to check specific compatibility rules.
-        true
+    fn is_compatible_with(&self, required: &Self) -> bool {
+        match (&self.storage, &required.storage) {
+            (Some(storage), Some(required_storage)) if storage != required_storage => {
+                return false;
+            }
+            (None, Some(_)) => return false,
+            _ => {}
+        }
+        match (&self.code_page, &required.code_page) {
+            (Some(code_page), Some(required_code_page)) => code_page == required_code_page,
+            (None, Some(_)) => false,
+            _ => true,
+        }
     }
 }
 
@@ -30,8 +103,19 @@ impl IsOrdered for CharacterSpec {
 
 impl PrimitiveSpec for CharacterSpec {}
 
-impl std::fmt::Display for CharacterSpec {
+impl Display for CharacterSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Character")
+        write!(
+            f,
+            "Character {{ storage: {}",
+            self.storage
+                .as_ref()
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "None".to_string())
+        )?;
+        if let Some(code_page) = &self.code_page {
+            write!(f, ", code page: {}", code_page)?;
+        }
+        write!(f, " }}")
     }
 }
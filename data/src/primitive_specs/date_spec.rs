@@ -1,24 +1,85 @@
+use std::fmt::Display;
+
 use crate::{
     primitive_def::{IsOrdered, PrimitiveSpec},
     spec_compatibility::SpecCompatibility,
 };
 
+/// DateRange defines an enumeration that captures the supported ranges of years for dates.
+/// <p>
+/// Years are numbered astronomically on the proleptic Gregorian calendar: year 0 is the year
+/// 1 B.C. (B.C.E.), year -1 is the year 2 B.C. (B.C.E.), and so on.
+/// </p>
+/// <p>
+/// The ranges are ordered from the narrowest (Standard) to the widest (Extended).
+/// </p>
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum DateRange {
+    /// The standard range of years, from 1 to 9999.
+    Standard,
+    /// The extended range of years, from -999999 to 999999.
+    Extended,
+}
+
+impl DateRange {
+    /// Returns the earliest year of the range.
+    pub fn min_year(&self) -> i32 {
+        match self {
+            Self::Standard => 1,
+            Self::Extended => -999_999,
+        }
+    }
+
+    /// Returns the latest year of the range.
+    pub fn max_year(&self) -> i32 {
+        match self {
+            Self::Standard => 9999,
+            Self::Extended => 999_999,
+        }
+    }
+
+    /// Returns true if the given year lies in the range; false otherwise.
+    pub fn contains(&self, year: i64) -> bool {
+        (self.min_year() as i64..=self.max_year() as i64).contains(&year)
+    }
+}
+
+impl Display for DateRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Standard => "Standard".to_string(),
+                Self::Extended => "Extended".to_string(),
+            }
+        )
+    }
+}
+
 /// A primitive spec for dates.
 #[derive(Debug, PartialEq)]
-pub struct DateSpec {}
+pub struct DateSpec {
+    range: DateRange,
+}
 
 impl DateSpec {
     /// Returns an initialized Date spec.
-    pub(crate) fn new() -> DateSpec {
-        DateSpec {}
+    pub(crate) fn new(range: DateRange) -> DateSpec {
+        DateSpec { range }
+    }
+
+    /// Returns the date's range of years.
+    pub fn range(&self) -> &DateRange {
+        &self.range
     }
 }
 
 impl SpecCompatibility for DateSpec {
-    fn is_compatible_with(&self, _required: &Self) -> bool {
-        // For now, we assume all Date specs are compatible with each other.
-        // This can be extended later to check specific compatibility rules.
-        true
+    /// Checks if the range is compatible with the required range: the spec's range must be at
+    /// least as wide as the required range.
+    fn is_compatible_with(&self, required: &Self) -> bool {
+        self.range >= required.range
     }
 }
 
@@ -32,6 +93,9 @@ impl PrimitiveSpec for DateSpec {}
 
 impl std::fmt::Display for DateSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Date")
+        match self.range {
+            DateRange::Standard => write!(f, "Date"),
+            _ => write!(f, "Date {{ range: {} }}", self.range),
+        }
     }
 }
@@ -27,32 +27,72 @@ impl Display for DateTimeType {
     }
 }
 
+/// ZoneType defines an enumeration that captures the kinds of zones a zoned date-time can hold.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ZoneType {
+    /// A fixed offset from UTC, such as +02:00.
+    Offset,
+    /// A named time zone of the IANA time zone database, such as Europe/Berlin.
+    Named,
+}
+
+impl Display for ZoneType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Offset => "Offset".to_string(),
+                Self::Named => "Named".to_string(),
+            }
+        )
+    }
+}
+
 /// A primitive spec for date-times.
 #[derive(Debug, PartialEq)]
 pub struct DateTimeSpec {
     date_time_type: Option<DateTimeType>,
+    zone_type: Option<ZoneType>,
 }
 
 impl DateTimeSpec {
     /// Returns an initialized date-time spec.
-    pub(crate) fn new(date_time_type: Option<DateTimeType>) -> DateTimeSpec {
-        DateTimeSpec { date_time_type }
+    pub(crate) fn new(
+        date_time_type: Option<DateTimeType>,
+        zone_type: Option<ZoneType>,
+    ) -> DateTimeSpec {
+        DateTimeSpec {
+            date_time_type,
+            zone_type,
+        }
     }
 
     /// Returns the date-time's date_time_type.
     pub fn date_time_type(&self) -> &Option<DateTimeType> {
         &self.date_time_type
     }
+
+    /// Returns the zone type that a zoned date-time requires, if any.
+    pub fn zone_type(&self) -> &Option<ZoneType> {
+        &self.zone_type
+    }
 }
 
 impl SpecCompatibility for DateTimeSpec {
     fn is_compatible_with(&self, required: &Self) -> bool {
-        match (self.date_time_type, required.date_time_type) {
+        let is_type_compatible = match (self.date_time_type, required.date_time_type) {
             (Some(s), Some(r)) => s == r,
             (None, None) => true,
             (Some(_), None) => true,
             (None, Some(_)) => false,
-        }
+        };
+        let is_zone_compatible = match (self.zone_type, required.zone_type) {
+            (Some(s), Some(r)) => s == r,
+            (_, None) => true,
+            (None, Some(_)) => false,
+        };
+        is_type_compatible && is_zone_compatible
     }
 }
 
@@ -69,10 +109,14 @@ impl Display for DateTimeSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "DateTime {{ type: {} }}",
+            "DateTime {{ type: {}",
             self.date_time_type
                 .map(|s| s.to_string())
                 .unwrap_or_else(|| "None".to_string())
-        )
+        )?;
+        if let Some(zone_type) = self.zone_type {
+            write!(f, ", zone: {}", zone_type)?;
+        }
+        write!(f, " }}")
     }
 }
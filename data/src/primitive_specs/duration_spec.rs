@@ -2,6 +2,7 @@ use std::fmt::Display;
 
 use crate::{
     primitive_def::{IsOrdered, PrimitiveSpec},
+    primitive_specs::rounding_policy::RoundingPolicy,
     spec_compatibility::SpecCompatibility,
 };
 
@@ -77,17 +78,30 @@ pub struct DurationSpec {
     duration_type: Option<DurationType>,
     /// The resolution of the duration.
     resolution: Option<DurationResolution>,
+    /// The policy for durations finer than the resolution.
+    rounding_policy: RoundingPolicy,
 }
 
 impl DurationSpec {
-    /// Creates a new duration spec.
+    /// Creates a new duration spec that rejects durations finer than its resolution.
     pub(crate) fn new(
         duration_type: Option<DurationType>,
         resolution: Option<DurationResolution>,
+    ) -> Self {
+        Self::new_with_rounding(duration_type, resolution, RoundingPolicy::Reject)
+    }
+
+    /// Creates a new duration spec with the given policy for durations finer than its
+    /// resolution.
+    pub(crate) fn new_with_rounding(
+        duration_type: Option<DurationType>,
+        resolution: Option<DurationResolution>,
+        rounding_policy: RoundingPolicy,
     ) -> Self {
         Self {
             duration_type,
             resolution,
+            rounding_policy,
         }
     }
 
@@ -101,6 +115,11 @@ impl DurationSpec {
         &self.resolution
     }
 
+    /// Returns the duration's policy for durations finer than its resolution.
+    pub fn rounding_policy(&self) -> &RoundingPolicy {
+        &self.rounding_policy
+    }
+
     /// Checks if the duration types of two `DurationSpec`s are compatible.
     fn compatible_duration_type(&self, other: &Self) -> bool {
         match (self.duration_type, other.duration_type) {
@@ -145,13 +164,17 @@ impl std::fmt::Display for DurationSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Duration {{ type: {}, resolution: {} }}",
+            "Duration {{ type: {}, resolution: {}",
             self.duration_type
                 .map(|s| s.to_string())
                 .unwrap_or_else(|| "None".to_string()),
             self.resolution
                 .map(|r| r.to_string())
                 .unwrap_or_else(|| "None".to_string())
-        )
+        )?;
+        if self.rounding_policy != RoundingPolicy::Reject {
+            write!(f, ", rounding: {}", self.rounding_policy)?;
+        }
+        write!(f, " }}")
     }
 }
@@ -1,24 +1,93 @@
+use std::fmt::Display;
+
 use crate::{
     primitive_def::{IsOrdered, PrimitiveSpec},
     spec_compatibility::SpecCompatibility,
 };
 
+/// GuidVersion defines an enumeration of the GUID versions of RFC 9562.
+/// <p>
+/// You set a GuidVersion option in a GuidSpec to pin the version of the GUIDs
+/// a variable accepts.
+/// </p>
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum GuidVersion {
+    /// Version 1: based on a Gregorian timestamp and a node ID.
+    V1,
+    /// Version 3: based on an MD5 hash of a namespace and a name.
+    V3,
+    /// Version 4: random.
+    V4,
+    /// Version 5: based on a SHA-1 hash of a namespace and a name.
+    V5,
+    /// Version 6: a reordered version 1 that sorts by time.
+    V6,
+    /// Version 7: based on a Unix timestamp in milliseconds and random bits, and sorts by time.
+    V7,
+    /// Version 8: custom.
+    V8,
+}
+
+impl GuidVersion {
+    /// Returns the version number.
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::V1 => 1,
+            Self::V3 => 3,
+            Self::V4 => 4,
+            Self::V5 => 5,
+            Self::V6 => 6,
+            Self::V7 => 7,
+            Self::V8 => 8,
+        }
+    }
+
+    /// Returns the version with the given number, or `None` if there is no such version.
+    pub fn from_number(number: u8) -> Option<GuidVersion> {
+        match number {
+            1 => Some(Self::V1),
+            3 => Some(Self::V3),
+            4 => Some(Self::V4),
+            5 => Some(Self::V5),
+            6 => Some(Self::V6),
+            7 => Some(Self::V7),
+            8 => Some(Self::V8),
+            _ => None,
+        }
+    }
+}
+
+impl Display for GuidVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "V{}", self.number())
+    }
+}
+
 /// A primitive spec for GUIDs.
 #[derive(Debug, PartialEq)]
-pub struct GuidSpec {}
+pub struct GuidSpec {
+    version: Option<GuidVersion>,
+}
 
 impl GuidSpec {
     /// Returns an initialized GUID spec.
-    pub(crate) fn new() -> GuidSpec {
-        GuidSpec {}
+    pub(crate) fn new(version: Option<GuidVersion>) -> GuidSpec {
+        GuidSpec { version }
+    }
+
+    /// Returns the GUID version the spec is pinned to, if any.
+    pub fn version(&self) -> Option<GuidVersion> {
+        self.version
     }
 }
 
 impl SpecCompatibility for GuidSpec {
-    fn is_compatible_with(&self, _required: &Self) -> bool {
-        // For now, we assume all GUID specs are compatible with each other.
-        // This can be extended later to check specific compatibility rules.
-        true
+    fn is_compatible_with(&self, required: &Self) -> bool {
+        match (self.version, required.version) {
+            (Some(version), Some(required_version)) => version == required_version,
+            (None, Some(_)) => false,
+            _ => true,
+        }
     }
 }
 
@@ -32,12 +101,15 @@ impl PrimitiveSpec for GuidSpec {}
 
 impl Default for GuidSpec {
     fn default() -> Self {
-        Self::new()
+        Self::new(None)
     }
 }
 
-impl std::fmt::Display for GuidSpec {
+impl Display for GuidSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Guid")
+        match self.version {
+            Some(version) => write!(f, "Guid {{ version: {} }}", version),
+            None => write!(f, "Guid"),
+        }
     }
 }
@@ -0,0 +1,125 @@
+use std::{fmt::Display, rc::Rc};
+
+use crate::{
+    data_spec::DataSpec,
+    primitive_def::{IsOrdered, PrimitiveSpec},
+    spec_compatibility::SpecCompatibility,
+};
+
+/// BoundType defines an enumeration of the ways an interval's start or end can relate to the
+/// value it lies at.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum BoundType {
+    /// The value is included in the interval.
+    Closed,
+    /// The value is excluded from the interval.
+    Open,
+}
+
+impl BoundType {
+    /// Returns the other bound type, which is `Open` for `Closed` and vice versa.
+    pub fn flipped(&self) -> Self {
+        match self {
+            Self::Closed => Self::Open,
+            Self::Open => Self::Closed,
+        }
+    }
+}
+
+impl Display for BoundType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Closed => "Closed",
+                Self::Open => "Open",
+            }
+        )
+    }
+}
+
+/// A primitive spec for intervals.
+/// <p>
+/// An interval ranges over ordered values of its value spec. Its start and end bounds default to
+/// those of the spec, but can be chosen for each value.
+/// </p>
+#[derive(Debug, PartialEq)]
+pub struct IntervalSpec {
+    value_spec: Option<Rc<DataSpec>>,
+    start_bound: BoundType,
+    end_bound: BoundType,
+}
+
+impl IntervalSpec {
+    /// Creates a new interval spec.
+    pub(crate) fn new(
+        value_spec: Option<Rc<DataSpec>>,
+        start_bound: BoundType,
+        end_bound: BoundType,
+    ) -> Self {
+        Self {
+            value_spec,
+            start_bound,
+            end_bound,
+        }
+    }
+
+    /// Returns the spec of the interval's start and end values.
+    pub fn value_spec(&self) -> &Option<Rc<DataSpec>> {
+        &self.value_spec
+    }
+
+    /// Returns the default bound type of the interval's start.
+    pub fn start_bound(&self) -> BoundType {
+        self.start_bound
+    }
+
+    /// Returns the default bound type of the interval's end.
+    pub fn end_bound(&self) -> BoundType {
+        self.end_bound
+    }
+}
+
+impl SpecCompatibility for IntervalSpec {
+    fn is_compatible_with(&self, required: &Self) -> bool {
+        match (self.value_spec.as_ref(), required.value_spec.as_ref()) {
+            (Some(value_spec), Some(required_value_spec)) => {
+                value_spec.is_compatible_with(required_value_spec)
+            }
+            (None, None) => true,
+            (Some(_), None) => true, // required does not specify a value spec, so we assume compatibility
+            (None, Some(_)) => false,
+        }
+    }
+}
+
+impl IsOrdered for IntervalSpec {
+    fn is_ordered(&self) -> bool {
+        true // Intervals are ordered by their starts, then by their ends.
+    }
+}
+
+impl PrimitiveSpec for IntervalSpec {}
+
+impl Display for IntervalSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Interval {{ value_spec: ")?;
+        match self.value_spec.as_ref() {
+            Some(value_spec) => write!(f, "{}", value_spec)?,
+            None => write!(f, "None")?,
+        }
+        write!(
+            f,
+            ", bounds: {}{} }}",
+            match self.start_bound {
+                BoundType::Closed => "[",
+                BoundType::Open => "(",
+            },
+            match self.end_bound {
+                BoundType::Closed => "]",
+                BoundType::Open => ")",
+            }
+        )
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt::Display;
+
+/// RoundingPolicy defines an enumeration that captures how a temporal value that is finer than
+/// the resolution of its spec is brought to that resolution.
+/// <p>
+/// Values are rounded as a whole, so that 23:59:59.9996 rounded to milliseconds carries into the
+/// seconds, minutes and hours. Negative durations are rounded symmetrically to positive ones.
+/// </p>
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum RoundingPolicy {
+    /// Values finer than the resolution are rejected with an error, the default.
+    #[default]
+    Reject,
+    /// Values are truncated toward zero.
+    Truncate,
+    /// Values are rounded to the nearest unit of the resolution, with ties rounded to the even
+    /// unit.
+    RoundHalfEven,
+    /// Values are rounded to the nearest unit of the resolution, with ties rounded away from
+    /// zero.
+    RoundHalfUp,
+}
+
+impl RoundingPolicy {
+    /// Returns the given value rounded to a multiple of the given unit according to the policy,
+    /// or None if the policy is `Reject` and the value is not a multiple of the unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use data::primitive_specs::rounding_policy::RoundingPolicy;
+    ///
+    /// assert_eq!(RoundingPolicy::Reject.round(1_500, 1_000), None);
+    /// assert_eq!(RoundingPolicy::Truncate.round(1_500, 1_000), Some(1_000));
+    /// assert_eq!(RoundingPolicy::RoundHalfEven.round(1_500, 1_000), Some(2_000));
+    /// assert_eq!(RoundingPolicy::RoundHalfEven.round(2_500, 1_000), Some(2_000));
+    /// assert_eq!(RoundingPolicy::RoundHalfUp.round(-2_500, 1_000), Some(-3_000));
+    /// ```
+    pub fn round(&self, value: i128, unit: i128) -> Option<i128> {
+        let (quotient, remainder) = (value / unit, value % unit);
+        if remainder == 0 {
+            return Some(value);
+        }
+        let away_from_zero = match self {
+            Self::Reject => return None,
+            Self::Truncate => false,
+            Self::RoundHalfEven => match (2 * remainder.abs()).cmp(&unit) {
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Equal => quotient % 2 != 0,
+                std::cmp::Ordering::Greater => true,
+            },
+            Self::RoundHalfUp => 2 * remainder.abs() >= unit,
+        };
+        if away_from_zero {
+            Some((quotient + value.signum()) * unit)
+        } else {
+            Some(quotient * unit)
+        }
+    }
+}
+
+impl Display for RoundingPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Reject => "Reject",
+                Self::Truncate => "Truncate",
+                Self::RoundHalfEven => "RoundHalfEven",
+                Self::RoundHalfUp => "RoundHalfUp",
+            }
+        )
+    }
+}
@@ -61,6 +61,103 @@ impl Display for StringEncoding {
     }
 }
 
+/// ByteCodePage defines an enumeration that captures the supported single-byte
+/// code pages for byte strings.
+/// <p>
+/// You set a ByteCodePage option in a DataSpec for a byte string to state how its
+/// bytes map to characters.
+/// </p>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ByteCodePage {
+    /// US-ASCII, where only bytes 0x00 to 0x7F represent characters.
+    Ascii,
+    /// ISO 8859-1 (Latin-1), where every byte represents the Unicode character of the same value.
+    Latin1,
+    /// Windows-1252, which is Latin-1 with printable characters in the range 0x80 to 0x9F.
+    /// Bytes 0x81, 0x8D, 0x8F, 0x90, and 0x9D are undefined.
+    Windows1252,
+}
+
+/// The characters represented by Windows-1252 bytes 0x80 to 0x9F; `None` marks undefined bytes.
+const WINDOWS_1252_HIGH: [Option<char>; 32] = [
+    Some('\u{20AC}'),
+    None,
+    Some('\u{201A}'),
+    Some('\u{0192}'),
+    Some('\u{201E}'),
+    Some('\u{2026}'),
+    Some('\u{2020}'),
+    Some('\u{2021}'),
+    Some('\u{02C6}'),
+    Some('\u{2030}'),
+    Some('\u{0160}'),
+    Some('\u{2039}'),
+    Some('\u{0152}'),
+    None,
+    Some('\u{017D}'),
+    None,
+    None,
+    Some('\u{2018}'),
+    Some('\u{2019}'),
+    Some('\u{201C}'),
+    Some('\u{201D}'),
+    Some('\u{2022}'),
+    Some('\u{2013}'),
+    Some('\u{2014}'),
+    Some('\u{02DC}'),
+    Some('\u{2122}'),
+    Some('\u{0161}'),
+    Some('\u{203A}'),
+    Some('\u{0153}'),
+    None,
+    Some('\u{017E}'),
+    Some('\u{0178}'),
+];
+
+impl ByteCodePage {
+    /// Returns the character that the byte represents, or `None` if the byte is undefined in the code page.
+    pub fn decode(&self, byte: u8) -> Option<char> {
+        match self {
+            Self::Ascii => byte.is_ascii().then_some(byte as char),
+            Self::Latin1 => Some(byte as char),
+            Self::Windows1252 => match byte {
+                0x80..=0x9F => WINDOWS_1252_HIGH[(byte - 0x80) as usize],
+                _ => Some(byte as char),
+            },
+        }
+    }
+
+    /// Returns the byte that represents the character, or `None` if the code page cannot represent it.
+    pub fn encode(&self, c: char) -> Option<u8> {
+        match self {
+            Self::Ascii => c.is_ascii().then_some(c as u8),
+            Self::Latin1 => u8::try_from(c).ok(),
+            Self::Windows1252 => match c as u32 {
+                0x80..=0x9F => None,
+                0x00..=0xFF => Some(c as u8),
+                _ => WINDOWS_1252_HIGH
+                    .iter()
+                    .position(|high| *high == Some(c))
+                    .map(|index| 0x80 + index as u8),
+            },
+        }
+    }
+}
+
+impl Display for ByteCodePage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Self::Ascii => "Ascii".to_string(),
+                Self::Latin1 => "Latin1".to_string(),
+                Self::Windows1252 => "Windows1252".to_string(),
+            }
+        )
+    }
+}
+
 /// StringStorage defines an enumeration that captures the supported storage
 /// characteristics for strings.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -97,14 +194,20 @@ impl Display for StringStorage {
 pub struct StringSpec {
     encoding: StringEncoding,
     storage: Option<StringStorage>,
+    code_page: Option<ByteCodePage>,
 }
 
 impl StringSpec {
     /// Returns an initialized string spec.
-    pub(crate) fn new(encoding: StringEncoding, storage: Option<StringStorage>) -> StringSpec {
+    pub(crate) fn new(
+        encoding: StringEncoding,
+        storage: Option<StringStorage>,
+        code_page: Option<ByteCodePage>,
+    ) -> StringSpec {
         StringSpec {
             encoding: (encoding),
             storage: (storage),
+            code_page: (code_page),
         }
     }
 
@@ -117,6 +220,31 @@ impl StringSpec {
     pub fn storage(&self) -> &Option<StringStorage> {
         &self.storage
     }
+
+    /// Returns the byte string's code page.
+    pub fn code_page(&self) -> &Option<ByteCodePage> {
+        &self.code_page
+    }
+
+    /// Returns whether every value of a string with the source spec can be
+    /// represented by a string with this spec.
+    /// <p>
+    /// Conversions between the Unicode encodings and from byte strings to Unicode
+    /// are lossless. Conversions to byte strings are only lossless from a byte string
+    /// with the same code page, or from ASCII. A byte string without a code page is
+    /// treated as ASCII.
+    /// </p>
+    pub fn transcodes_losslessly_from(&self, source: &StringSpec) -> bool {
+        if self.encoding != StringEncoding::Byte {
+            return true;
+        }
+        if source.encoding != StringEncoding::Byte {
+            return false;
+        }
+        let code_page = self.code_page.unwrap_or(ByteCodePage::Ascii);
+        let source_code_page = source.code_page.unwrap_or(ByteCodePage::Ascii);
+        source_code_page == code_page || source_code_page == ByteCodePage::Ascii
+    }
 }
 
 impl SpecCompatibility for StringSpec {
@@ -125,13 +253,19 @@ impl SpecCompatibility for StringSpec {
             return false;
         }
         if let Some(storage) = &self.storage {
-            if let Some(required_storage) = &required.storage {
-                return storage == required_storage;
+            if let Some(required_storage) = &required.storage
+                && storage != required_storage
+            {
+                return false;
             }
         } else if required.storage.is_some() {
             return false;
         }
-        true
+        match (&self.code_page, &required.code_page) {
+            (Some(code_page), Some(required_code_page)) => code_page == required_code_page,
+            (None, Some(_)) => false,
+            _ => true,
+        }
     }
 }
 
@@ -147,12 +281,16 @@ impl Display for StringSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "String {{ encoding: {}, storage: {} }}",
+            "String {{ encoding: {}, storage: {}",
             self.encoding,
             self.storage
                 .as_ref()
                 .map(|s| s.to_string())
                 .unwrap_or_else(|| "None".to_string())
-        )
+        )?;
+        if let Some(code_page) = &self.code_page {
+            write!(f, ", code page: {}", code_page)?;
+        }
+        write!(f, " }}")
     }
 }
@@ -0,0 +1,25 @@
+use crate::primitive_specs::blob_spec::BlobSpec;
+use crate::spec_compatibility::SpecCompatibility;
+
+#[test]
+fn blob_spec_compatible() {
+    let spec = BlobSpec::new(Some(1024), Some("image/png".to_string()));
+    assert!(spec.is_compatible_with(&BlobSpec::new(None, None)));
+    assert!(spec.is_compatible_with(&BlobSpec::new(Some(1024), Some("IMAGE/PNG".to_string()))));
+    assert!(!spec.is_compatible_with(&BlobSpec::new(Some(2048), None)));
+    assert!(!spec.is_compatible_with(&BlobSpec::new(None, Some("image/jpeg".to_string()))));
+    assert!(!BlobSpec::new(None, None).is_compatible_with(&spec));
+}
+
+#[test]
+fn blob_spec_to_string() {
+    assert_eq!(BlobSpec::new(None, None).to_string(), "BLOB");
+    assert_eq!(
+        BlobSpec::new(Some(16), None).to_string(),
+        "BLOB { max size: 16 }"
+    );
+    assert_eq!(
+        BlobSpec::new(Some(16), Some("application/octet-stream".to_string())).to_string(),
+        "BLOB { max size: 16, media type: application/octet-stream }"
+    );
+}
@@ -0,0 +1,62 @@
+use crate::primitive_specs::character_spec::{CharacterSpec, CharacterStorage};
+use crate::primitive_specs::string_spec::ByteCodePage;
+use crate::spec_compatibility::SpecCompatibility;
+
+#[test]
+fn character_spec_compatible() {
+    let spec = CharacterSpec::new(Some(CharacterStorage::Scalar), None);
+    assert!(spec.is_compatible_with(&CharacterSpec::new(Some(CharacterStorage::Scalar), None)));
+    assert!(spec.is_compatible_with(&CharacterSpec::new(None, None)));
+    assert!(!spec.is_compatible_with(&CharacterSpec::new(
+        Some(CharacterStorage::Utf16CodeUnit),
+        None
+    )));
+    assert!(
+        !CharacterSpec::new(None, None)
+            .is_compatible_with(&CharacterSpec::new(Some(CharacterStorage::Scalar), None))
+    );
+}
+
+#[test]
+fn character_spec_code_page_compatible() {
+    let spec = CharacterSpec::new(Some(CharacterStorage::Byte), Some(ByteCodePage::Latin1));
+    assert!(spec.is_compatible_with(&CharacterSpec::new(Some(CharacterStorage::Byte), None)));
+    assert!(!spec.is_compatible_with(&CharacterSpec::new(
+        Some(CharacterStorage::Byte),
+        Some(ByteCodePage::Windows1252)
+    )));
+    assert!(!CharacterSpec::new(Some(CharacterStorage::Byte), None).is_compatible_with(&spec));
+}
+
+#[test]
+fn character_spec_can_represent() {
+    let ascii = CharacterSpec::new(Some(CharacterStorage::Byte), None);
+    assert!(ascii.can_represent('a'));
+    assert!(!ascii.can_represent('é'));
+    let windows_1252 = CharacterSpec::new(
+        Some(CharacterStorage::Byte),
+        Some(ByteCodePage::Windows1252),
+    );
+    assert!(windows_1252.can_represent('€'));
+    let utf16 = CharacterSpec::new(Some(CharacterStorage::Utf16CodeUnit), None);
+    assert!(utf16.can_represent('€'));
+    assert!(!utf16.can_represent('😀'));
+    let scalar = CharacterSpec::new(Some(CharacterStorage::Scalar), None);
+    assert!(scalar.can_represent('😀'));
+}
+
+#[test]
+fn character_spec_to_string() {
+    assert_eq!(
+        CharacterSpec::new(Some(CharacterStorage::Scalar), None).to_string(),
+        "Character { storage: Scalar }"
+    );
+    assert_eq!(
+        CharacterSpec::new(Some(CharacterStorage::Byte), Some(ByteCodePage::Latin1)).to_string(),
+        "Character { storage: Byte, code page: Latin1 }"
+    );
+    assert_eq!(
+        CharacterSpec::new(None, None).to_string(),
+        "Character { storage: None }"
+    );
+}
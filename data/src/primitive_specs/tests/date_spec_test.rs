@@ -1,14 +1,34 @@
-use crate::primitive_specs::date_spec::DateSpec;
+use crate::primitive_specs::date_spec::{DateRange, DateSpec};
 use crate::spec_compatibility::SpecCompatibility;
 
 #[test]
 fn date_spec() {
-    let spec = DateSpec::new();
-    assert!(spec.is_compatible_with(&DateSpec::new()));
+    let spec = DateSpec::new(DateRange::Standard);
+    assert!(spec.is_compatible_with(&DateSpec::new(DateRange::Standard)));
 }
 
 #[test]
 fn date_spec_to_string() {
-    let spec = DateSpec::new();
+    let spec = DateSpec::new(DateRange::Standard);
     assert_eq!(spec.to_string(), "Date");
-}
\ No newline at end of file
+}
+#[test]
+fn date_spec_range() {
+    let standard = DateSpec::new(DateRange::Standard);
+    let extended = DateSpec::new(DateRange::Extended);
+    assert_eq!(*extended.range(), DateRange::Extended);
+    assert_eq!(extended.to_string(), "Date { range: Extended }");
+
+    // an extended date can hold any standard date, but not the other way around
+    assert!(extended.is_compatible_with(&standard));
+    assert!(extended.is_compatible_with(&extended));
+    assert!(!standard.is_compatible_with(&extended));
+
+    assert_eq!(DateRange::Standard.min_year(), 1);
+    assert_eq!(DateRange::Standard.max_year(), 9999);
+    assert!(!DateRange::Standard.contains(0));
+    assert!(DateRange::Extended.contains(0));
+    assert!(DateRange::Extended.contains(-999_999));
+    assert!(!DateRange::Extended.contains(1_000_000));
+    assert_eq!(DateRange::Extended.to_string(), "Extended");
+}
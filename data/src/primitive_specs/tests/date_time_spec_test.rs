@@ -1,40 +1,61 @@
-use crate::primitive_specs::date_time_spec::{DateTimeSpec, DateTimeType};
+use crate::primitive_specs::date_time_spec::{DateTimeSpec, DateTimeType, ZoneType};
 use crate::spec_compatibility::SpecCompatibility;
 
 #[test]
 fn date_time_no_type() {
-    let spec = DateTimeSpec::new(None);
+    let spec = DateTimeSpec::new(None, None);
     assert!(spec.date_time_type().is_none());
-    assert!(spec.is_compatible_with(&DateTimeSpec::new(None)));
-    assert!(!spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Local))));
-    assert!(!spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Zoned))));
+    assert!(spec.is_compatible_with(&DateTimeSpec::new(None, None)));
+    assert!(!spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Local), None)));
+    assert!(!spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Zoned), None)));
 }
 #[test]
 fn date_time_local_type() {
-    let spec = DateTimeSpec::new(Some(DateTimeType::Local));
+    let spec = DateTimeSpec::new(Some(DateTimeType::Local), None);
     assert_eq!(*spec.date_time_type(), Some(DateTimeType::Local));
-    assert!(spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Local))));
-    assert!(!spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Zoned))));
-    assert!(spec.is_compatible_with(&DateTimeSpec::new(None)));
+    assert!(spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Local), None)));
+    assert!(!spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Zoned), None)));
+    assert!(spec.is_compatible_with(&DateTimeSpec::new(None, None)));
 }
 #[test]
 fn date_time_zoned_type() {
-    let spec = DateTimeSpec::new(Some(DateTimeType::Zoned));
+    let spec = DateTimeSpec::new(Some(DateTimeType::Zoned), None);
     assert_eq!(*spec.date_time_type(), Some(DateTimeType::Zoned));
-    assert!(spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Zoned))));
-    assert!(!spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Local))));
-    assert!(spec.is_compatible_with(&DateTimeSpec::new(None)));
+    assert!(spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Zoned), None)));
+    assert!(!spec.is_compatible_with(&DateTimeSpec::new(Some(DateTimeType::Local), None)));
+    assert!(spec.is_compatible_with(&DateTimeSpec::new(None, None)));
 }
 #[test]
 fn date_time_type_to_string() {
     {
-        let spec = DateTimeSpec::new(Some(DateTimeType::Local));
+        let spec = DateTimeSpec::new(Some(DateTimeType::Local), None);
         assert_eq!(spec.date_time_type().unwrap().to_string(), "Local");
         assert_eq!(spec.to_string(), "DateTime { type: Local }");
     }
     {
-        let spec = DateTimeSpec::new(Some(DateTimeType::Zoned));
+        let spec = DateTimeSpec::new(Some(DateTimeType::Zoned), None);
         assert_eq!(spec.date_time_type().unwrap().to_string(), "Zoned");
         assert_eq!(spec.to_string(), "DateTime { type: Zoned }");
     }
+    {
+        let spec = DateTimeSpec::new(Some(DateTimeType::Zoned), Some(ZoneType::Named));
+        assert_eq!(spec.zone_type().unwrap().to_string(), "Named");
+        assert_eq!(spec.to_string(), "DateTime { type: Zoned, zone: Named }");
+    }
+}
+#[test]
+fn date_time_zone_type() {
+    let zoned = |zone_type| DateTimeSpec::new(Some(DateTimeType::Zoned), zone_type);
+    let named = zoned(Some(ZoneType::Named));
+    assert_eq!(*named.zone_type(), Some(ZoneType::Named));
+    assert!(named.is_compatible_with(&zoned(Some(ZoneType::Named))));
+    assert!(!named.is_compatible_with(&zoned(Some(ZoneType::Offset))));
+    assert!(named.is_compatible_with(&zoned(None)));
+    assert!(named.is_compatible_with(&DateTimeSpec::new(None, None)));
+
+    let any = zoned(None);
+    assert!(any.zone_type().is_none());
+    assert!(any.is_compatible_with(&zoned(None)));
+    assert!(!any.is_compatible_with(&zoned(Some(ZoneType::Named))));
+    assert!(!any.is_compatible_with(&zoned(Some(ZoneType::Offset))));
 }
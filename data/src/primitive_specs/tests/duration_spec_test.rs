@@ -1,4 +1,5 @@
 use crate::primitive_specs::duration_spec::{DurationResolution, DurationSpec, DurationType};
+use crate::primitive_specs::rounding_policy::RoundingPolicy;
 use crate::spec_compatibility::SpecCompatibility;
 
 #[test]
@@ -101,6 +102,30 @@ fn duration_spec_debug() {
     );
     assert_eq!(
         format!("{:?}", spec),
-        "DurationSpec { duration_type: Some(YearToMonth), resolution: Some(Month) }"
+        "DurationSpec { duration_type: Some(YearToMonth), resolution: Some(Month), rounding_policy: Reject }"
     );
 }
+
+#[test]
+fn duration_spec_rounding_policy() {
+    let spec = DurationSpec::new(
+        Some(DurationType::DayToSecond),
+        Some(DurationResolution::Second),
+    );
+    assert_eq!(*spec.rounding_policy(), RoundingPolicy::Reject);
+
+    let spec = DurationSpec::new_with_rounding(
+        Some(DurationType::DayToSecond),
+        Some(DurationResolution::Second),
+        RoundingPolicy::Truncate,
+    );
+    assert_eq!(*spec.rounding_policy(), RoundingPolicy::Truncate);
+    assert_eq!(
+        spec.to_string(),
+        "Duration { type: DayToSecond, resolution: Second, rounding: Truncate }"
+    );
+    assert!(spec.is_compatible_with(&DurationSpec::new(
+        Some(DurationType::DayToSecond),
+        Some(DurationResolution::Second),
+    )));
+}
@@ -0,0 +1,30 @@
+use crate::primitive_specs::guid_spec::{GuidSpec, GuidVersion};
+use crate::spec_compatibility::SpecCompatibility;
+
+#[test]
+fn guid_spec_compatible() {
+    let spec = GuidSpec::new(Some(GuidVersion::V4));
+    assert!(spec.is_compatible_with(&GuidSpec::new(Some(GuidVersion::V4))));
+    assert!(spec.is_compatible_with(&GuidSpec::default()));
+    assert!(!spec.is_compatible_with(&GuidSpec::new(Some(GuidVersion::V7))));
+    assert!(!GuidSpec::default().is_compatible_with(&spec));
+}
+
+#[test]
+fn guid_version_numbers() {
+    for number in 0..=9 {
+        match GuidVersion::from_number(number) {
+            Some(version) => assert_eq!(version.number(), number),
+            None => assert!(matches!(number, 0 | 2 | 9)),
+        }
+    }
+}
+
+#[test]
+fn guid_spec_to_string() {
+    assert_eq!(GuidSpec::default().to_string(), "Guid");
+    assert_eq!(
+        GuidSpec::new(Some(GuidVersion::V7)).to_string(),
+        "Guid { version: V7 }"
+    );
+}
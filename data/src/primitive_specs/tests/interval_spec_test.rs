@@ -0,0 +1,55 @@
+use crate::{
+    data_spec_builders::{
+        float_spec_builder::FloatSpecBuilder, integer_spec_builder::IntegerSpecBuilder,
+    },
+    primitive_def::IsOrdered,
+    primitive_specs::interval_spec::{BoundType, IntervalSpec},
+    spec_compatibility::SpecCompatibility,
+};
+
+#[test]
+fn interval_spec_no_value_spec() {
+    let interval_spec = IntervalSpec::new(None, BoundType::Closed, BoundType::Open);
+    assert!(interval_spec.value_spec().is_none());
+    assert_eq!(interval_spec.start_bound(), BoundType::Closed);
+    assert_eq!(interval_spec.end_bound(), BoundType::Open);
+}
+
+#[test]
+fn interval_spec_compatible_with() {
+    let int_spec = IntegerSpecBuilder::new().build();
+    let float_spec = FloatSpecBuilder::new().build();
+    let interval_spec =
+        IntervalSpec::new(Some(int_spec.clone()), BoundType::Closed, BoundType::Open);
+
+    // Compatible with itself and with other bounds, which may differ for each value
+    assert!(interval_spec.is_compatible_with(&interval_spec));
+    let closed_spec = IntervalSpec::new(Some(int_spec), BoundType::Closed, BoundType::Closed);
+    assert!(interval_spec.is_compatible_with(&closed_spec));
+
+    // Not compatible with an interval spec with a different value spec
+    let float_interval_spec =
+        IntervalSpec::new(Some(float_spec), BoundType::Closed, BoundType::Open);
+    assert!(!interval_spec.is_compatible_with(&float_interval_spec));
+
+    // Compatible with a spec without a value spec, but not the other way around
+    let none_spec = IntervalSpec::new(None, BoundType::Closed, BoundType::Open);
+    assert!(interval_spec.is_compatible_with(&none_spec));
+    assert!(!none_spec.is_compatible_with(&interval_spec));
+}
+
+#[test]
+fn interval_spec_is_ordered() {
+    let interval_spec = IntervalSpec::new(None, BoundType::Open, BoundType::Open);
+    assert!(interval_spec.is_ordered());
+}
+
+#[test]
+fn interval_spec_display() {
+    let interval_spec = IntervalSpec::new(None, BoundType::Open, BoundType::Closed);
+    assert_eq!(
+        interval_spec.to_string(),
+        "Interval { value_spec: None, bounds: (] }"
+    );
+    assert_eq!(BoundType::Open.flipped(), BoundType::Closed);
+}
@@ -0,0 +1,41 @@
+use crate::primitive_specs::rounding_policy::RoundingPolicy;
+
+#[test]
+fn rounding_policy_default() {
+    assert_eq!(RoundingPolicy::default(), RoundingPolicy::Reject);
+}
+
+#[test]
+fn rounding_policy_round() {
+    // (value, reject, truncate, half even, half up) for a unit of 10
+    for (value, reject, truncate, half_even, half_up) in [
+        (20, Some(20), 20, 20, 20),
+        (24, None, 20, 20, 20),
+        (25, None, 20, 20, 30),
+        (26, None, 20, 30, 30),
+        (35, None, 30, 40, 40),
+        (-24, None, -20, -20, -20),
+        (-25, None, -20, -20, -30),
+        (-26, None, -20, -30, -30),
+        (-35, None, -30, -40, -40),
+        (0, Some(0), 0, 0, 0),
+        (5, None, 0, 0, 10),
+        (-5, None, 0, 0, -10),
+    ] {
+        assert_eq!(RoundingPolicy::Reject.round(value, 10), reject);
+        assert_eq!(RoundingPolicy::Truncate.round(value, 10), Some(truncate));
+        assert_eq!(
+            RoundingPolicy::RoundHalfEven.round(value, 10),
+            Some(half_even)
+        );
+        assert_eq!(RoundingPolicy::RoundHalfUp.round(value, 10), Some(half_up));
+    }
+}
+
+#[test]
+fn rounding_policy_to_string() {
+    assert_eq!(RoundingPolicy::Reject.to_string(), "Reject");
+    assert_eq!(RoundingPolicy::Truncate.to_string(), "Truncate");
+    assert_eq!(RoundingPolicy::RoundHalfEven.to_string(), "RoundHalfEven");
+    assert_eq!(RoundingPolicy::RoundHalfUp.to_string(), "RoundHalfUp");
+}
@@ -0,0 +1,96 @@
+use crate::primitive_specs::string_spec::{
+    ByteCodePage, StringEncoding, StringSpec, StringStorage,
+};
+use crate::spec_compatibility::SpecCompatibility;
+
+#[test]
+fn string_spec_code_page() {
+    let spec = StringSpec::new(
+        StringEncoding::Byte,
+        Some(StringStorage::VariableSize),
+        Some(ByteCodePage::Latin1),
+    );
+    assert_eq!(*spec.code_page(), Some(ByteCodePage::Latin1));
+    assert!(spec.is_compatible_with(&StringSpec::new(
+        StringEncoding::Byte,
+        Some(StringStorage::VariableSize),
+        Some(ByteCodePage::Latin1)
+    )));
+    assert!(!spec.is_compatible_with(&StringSpec::new(
+        StringEncoding::Byte,
+        Some(StringStorage::VariableSize),
+        Some(ByteCodePage::Windows1252)
+    )));
+    assert!(spec.is_compatible_with(&StringSpec::new(
+        StringEncoding::Byte,
+        Some(StringStorage::VariableSize),
+        None
+    )));
+    assert!(!spec.is_compatible_with(&StringSpec::new(
+        StringEncoding::Byte,
+        Some(StringStorage::FixedSize(4)),
+        Some(ByteCodePage::Latin1)
+    )));
+}
+
+#[test]
+fn string_spec_no_code_page() {
+    let spec = StringSpec::new(StringEncoding::Byte, None, None);
+    assert!(spec.code_page().is_none());
+    assert!(!spec.is_compatible_with(&StringSpec::new(
+        StringEncoding::Byte,
+        None,
+        Some(ByteCodePage::Ascii)
+    )));
+}
+
+#[test]
+fn string_spec_display() {
+    let spec = StringSpec::new(
+        StringEncoding::Byte,
+        Some(StringStorage::FixedSize(10)),
+        Some(ByteCodePage::Windows1252),
+    );
+    assert_eq!(
+        spec.to_string(),
+        "String { encoding: Byte, storage: FixedSize(10), code page: Windows1252 }"
+    );
+    let spec = StringSpec::new(StringEncoding::Utf8, None, None);
+    assert_eq!(spec.to_string(), "String { encoding: Utf8, storage: None }");
+}
+
+#[test]
+fn byte_code_page_round_trip() {
+    for code_page in [
+        ByteCodePage::Ascii,
+        ByteCodePage::Latin1,
+        ByteCodePage::Windows1252,
+    ] {
+        for byte in 0..=255u8 {
+            if let Some(c) = code_page.decode(byte) {
+                assert_eq!(code_page.encode(c), Some(byte));
+            }
+        }
+    }
+    assert_eq!(ByteCodePage::Windows1252.decode(0x9F), Some('Ÿ'));
+    assert_eq!(ByteCodePage::Windows1252.encode('Ÿ'), Some(0x9F));
+}
+
+#[test]
+fn string_spec_transcodes_losslessly_from() {
+    let utf8 = StringSpec::new(StringEncoding::Utf8, None, None);
+    let utf16 = StringSpec::new(StringEncoding::Utf16, None, None);
+    let ascii = StringSpec::new(StringEncoding::Byte, None, Some(ByteCodePage::Ascii));
+    let latin1 = StringSpec::new(StringEncoding::Byte, None, Some(ByteCodePage::Latin1));
+    let windows = StringSpec::new(StringEncoding::Byte, None, Some(ByteCodePage::Windows1252));
+    let no_code_page = StringSpec::new(StringEncoding::Byte, None, None);
+
+    assert!(utf16.transcodes_losslessly_from(&utf8));
+    assert!(utf8.transcodes_losslessly_from(&latin1));
+    assert!(!latin1.transcodes_losslessly_from(&utf8));
+    assert!(latin1.transcodes_losslessly_from(&ascii));
+    assert!(windows.transcodes_losslessly_from(&no_code_page));
+    assert!(!windows.transcodes_losslessly_from(&latin1));
+    assert!(!latin1.transcodes_losslessly_from(&windows));
+    assert!(!ascii.transcodes_losslessly_from(&latin1));
+}
@@ -0,0 +1,57 @@
+use crate::{
+    data_spec_builders::{
+        date_time_spec_builder::DateTimeSpecBuilder, float_spec_builder::FloatSpecBuilder,
+        integer_spec_builder::IntegerSpecBuilder,
+    },
+    primitive_def::IsOrdered,
+    primitive_specs::time_series_spec::TimeSeriesSpec,
+    spec_compatibility::SpecCompatibility,
+};
+
+#[test]
+fn time_series_spec_no_specs() {
+    let time_series_spec = TimeSeriesSpec::new(None, None);
+    assert!(time_series_spec.timestamp_spec().is_none());
+    assert!(time_series_spec.value_spec().is_none());
+}
+
+#[test]
+fn time_series_spec_compatible_with() {
+    let timestamp_spec = DateTimeSpecBuilder::new().build();
+    let int_spec = IntegerSpecBuilder::new().build();
+    let float_spec = FloatSpecBuilder::new().build();
+    let time_series_spec =
+        TimeSeriesSpec::new(Some(timestamp_spec.clone()), Some(int_spec.clone()));
+
+    // Compatible with itself
+    assert!(time_series_spec.is_compatible_with(&time_series_spec));
+
+    // Not compatible with a time series spec with a different value spec
+    let float_series_spec = TimeSeriesSpec::new(Some(timestamp_spec.clone()), Some(float_spec));
+    assert!(!time_series_spec.is_compatible_with(&float_series_spec));
+
+    // Compatible with a spec without a timestamp or a value spec, but not the other way around
+    let no_timestamp_spec = TimeSeriesSpec::new(None, Some(int_spec));
+    assert!(time_series_spec.is_compatible_with(&no_timestamp_spec));
+    assert!(!no_timestamp_spec.is_compatible_with(&time_series_spec));
+    let no_value_spec = TimeSeriesSpec::new(Some(timestamp_spec), None);
+    assert!(time_series_spec.is_compatible_with(&no_value_spec));
+    assert!(!no_value_spec.is_compatible_with(&time_series_spec));
+}
+
+#[test]
+fn time_series_spec_is_ordered() {
+    let time_series_spec = TimeSeriesSpec::new(None, None);
+    assert!(time_series_spec.is_ordered());
+    let time_series_spec = TimeSeriesSpec::new(None, Some(IntegerSpecBuilder::new().build()));
+    assert!(time_series_spec.is_ordered());
+}
+
+#[test]
+fn time_series_spec_display() {
+    let time_series_spec = TimeSeriesSpec::new(None, None);
+    assert_eq!(
+        time_series_spec.to_string(),
+        "TimeSeries { timestamp_spec: None, value_spec: None }"
+    );
+}
@@ -1,4 +1,7 @@
-use crate::{primitive_specs::time_spec::TimeSpec, spec_compatibility::SpecCompatibility};
+use crate::{
+    primitive_specs::{rounding_policy::RoundingPolicy, time_spec::TimeSpec},
+    spec_compatibility::SpecCompatibility,
+};
 
 #[test]
 fn time_spec_new_with_none() {
@@ -586,4 +589,38 @@ fn time_spec_to_string() {
     );
     let spec_str = spec.to_string();
     assert_eq!(spec_str, "Time { type: Local, resolution: Microsecond }");
-}
\ No newline at end of file
+}
+#[test]
+fn time_spec_rounding_policy() {
+    let spec = TimeSpec::new(
+        Some(crate::primitive_specs::time_spec::TimeType::Local),
+        Some(crate::primitive_specs::time_spec::TimeResolution::Millisecond),
+    );
+    assert_eq!(*spec.rounding_policy(), RoundingPolicy::Reject);
+
+    let spec = TimeSpec::new_with_rounding(
+        Some(crate::primitive_specs::time_spec::TimeType::Local),
+        Some(crate::primitive_specs::time_spec::TimeResolution::Millisecond),
+        RoundingPolicy::RoundHalfEven,
+    );
+    assert_eq!(*spec.rounding_policy(), RoundingPolicy::RoundHalfEven);
+    assert_eq!(
+        spec.to_string(),
+        "Time { type: Local, resolution: Millisecond, rounding: RoundHalfEven }"
+    );
+    // the rounding policy does not affect compatibility
+    assert!(spec.is_compatible_with(&TimeSpec::new(
+        Some(crate::primitive_specs::time_spec::TimeType::Local),
+        Some(crate::primitive_specs::time_spec::TimeResolution::Millisecond),
+    )));
+}
+
+#[test]
+fn time_resolution_nanoseconds() {
+    use crate::primitive_specs::time_spec::TimeResolution;
+    assert_eq!(TimeResolution::Second.nanoseconds(), 1_000_000_000);
+    assert_eq!(TimeResolution::Millisecond.nanoseconds(), 1_000_000);
+    assert_eq!(TimeResolution::Microsecond100.nanoseconds(), 100_000);
+    assert_eq!(TimeResolution::Microsecond.nanoseconds(), 1_000);
+    assert_eq!(TimeResolution::Nanosecond.nanoseconds(), 1);
+}
@@ -0,0 +1,82 @@
+use std::{fmt::Display, rc::Rc};
+
+use crate::{
+    data_spec::DataSpec,
+    primitive_def::{IsOrdered, PrimitiveSpec},
+    spec_compatibility::SpecCompatibility,
+};
+
+/// A primitive spec for time series.
+/// <p>
+/// A time series maps unique timestamps of its timestamp spec to values of its value spec, in
+/// the order of the timestamps.
+/// </p>
+#[derive(Debug, PartialEq)]
+pub struct TimeSeriesSpec {
+    timestamp_spec: Option<Rc<DataSpec>>,
+    value_spec: Option<Rc<DataSpec>>,
+}
+
+impl TimeSeriesSpec {
+    /// Creates a new time series spec.
+    pub(crate) fn new(
+        timestamp_spec: Option<Rc<DataSpec>>,
+        value_spec: Option<Rc<DataSpec>>,
+    ) -> Self {
+        Self {
+            timestamp_spec,
+            value_spec,
+        }
+    }
+
+    /// Returns the spec of the time series' timestamps.
+    pub fn timestamp_spec(&self) -> &Option<Rc<DataSpec>> {
+        &self.timestamp_spec
+    }
+
+    /// Returns the spec of the time series' values.
+    pub fn value_spec(&self) -> &Option<Rc<DataSpec>> {
+        &self.value_spec
+    }
+}
+
+impl SpecCompatibility for TimeSeriesSpec {
+    fn is_compatible_with(&self, required: &Self) -> bool {
+        let is_compatible = |spec: &Option<Rc<DataSpec>>, required: &Option<Rc<DataSpec>>| {
+            match (spec.as_ref(), required.as_ref()) {
+                (Some(spec), Some(required)) => spec.is_compatible_with(required),
+                (_, None) => true, // required does not specify a spec, so we assume compatibility
+                (None, Some(_)) => false,
+            }
+        };
+        is_compatible(&self.timestamp_spec, &required.timestamp_spec)
+            && is_compatible(&self.value_spec, &required.value_spec)
+    }
+}
+
+impl IsOrdered for TimeSeriesSpec {
+    fn is_ordered(&self) -> bool {
+        // Time series are ordered by their timestamps, then by their values.
+        self.value_spec
+            .as_ref()
+            .is_none_or(|spec| spec.is_ordered())
+    }
+}
+
+impl PrimitiveSpec for TimeSeriesSpec {}
+
+impl Display for TimeSeriesSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TimeSeries {{ timestamp_spec: ")?;
+        match self.timestamp_spec.as_ref() {
+            Some(timestamp_spec) => write!(f, "{}", timestamp_spec)?,
+            None => write!(f, "None")?,
+        }
+        write!(f, ", value_spec: ")?;
+        match self.value_spec.as_ref() {
+            Some(value_spec) => write!(f, "{}", value_spec)?,
+            None => write!(f, "None")?,
+        }
+        write!(f, " }}")
+    }
+}
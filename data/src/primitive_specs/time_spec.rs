@@ -2,6 +2,7 @@ use std::fmt::Display;
 
 use crate::{
     primitive_def::{IsOrdered, PrimitiveSpec},
+    primitive_specs::rounding_policy::RoundingPolicy,
     spec_compatibility::SpecCompatibility,
 };
 
@@ -48,6 +49,19 @@ pub enum TimeResolution {
     Nanosecond,
 }
 
+impl TimeResolution {
+    /// Returns the length of the resolution's unit in nanoseconds.
+    pub fn nanoseconds(&self) -> u64 {
+        match self {
+            Self::Second => 1_000_000_000,
+            Self::Millisecond => 1_000_000,
+            Self::Microsecond100 => 100_000,
+            Self::Microsecond => 1_000,
+            Self::Nanosecond => 1,
+        }
+    }
+}
+
 impl Display for TimeResolution {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -69,14 +83,26 @@ impl Display for TimeResolution {
 pub struct TimeSpec {
     time_type: Option<TimeType>,
     resolution: Option<TimeResolution>,
+    rounding_policy: RoundingPolicy,
 }
 
 impl TimeSpec {
-    /// Returns an initialized Time spec.
+    /// Returns an initialized Time spec that rejects times finer than its resolution.
     pub(crate) fn new(time_type: Option<TimeType>, resolution: Option<TimeResolution>) -> TimeSpec {
+        TimeSpec::new_with_rounding(time_type, resolution, RoundingPolicy::Reject)
+    }
+
+    /// Returns an initialized Time spec with the given policy for times finer than its
+    /// resolution.
+    pub(crate) fn new_with_rounding(
+        time_type: Option<TimeType>,
+        resolution: Option<TimeResolution>,
+        rounding_policy: RoundingPolicy,
+    ) -> TimeSpec {
         TimeSpec {
             time_type,
             resolution,
+            rounding_policy,
         }
     }
 
@@ -90,6 +116,11 @@ impl TimeSpec {
         &self.resolution
     }
 
+    /// Returns the time's policy for times finer than its resolution.
+    pub fn rounding_policy(&self) -> &RoundingPolicy {
+        &self.rounding_policy
+    }
+
     /// Checks if the time type is compatible with the required time type.
     ///
     /// Compatibility rules:
@@ -140,13 +171,17 @@ impl std::fmt::Display for TimeSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Time {{ type: {}, resolution: {} }}",
+            "Time {{ type: {}, resolution: {}",
             self.time_type
                 .map(|s| s.to_string())
                 .unwrap_or_else(|| "None".to_string()),
             self.resolution
                 .map(|r| r.to_string())
                 .unwrap_or_else(|| "None".to_string())
-        )
+        )?;
+        if self.rounding_policy != RoundingPolicy::Reject {
+            write!(f, ", rounding: {}", self.rounding_policy)?;
+        }
+        write!(f, " }}")
     }
 }
@@ -1,12 +1,21 @@
 use crate::{
     accessors::{
+        blob::BlobError,
+        character::CharacterError,
         collections::{list::ListError, map::MapError, set::SetError},
         float::FloatError,
+        guid::GuidError,
         integer::IntegerError,
+        interval::IntervalError,
         sequence::SequenceError,
+        string::StringError,
         temporal::{
-            date::DateError, time::TimeError, year_to_month_duration::YearToMonthDurationError,
+            date::DateError, date_time::DateTimeError,
+            day_to_second_duration::DayToSecondDurationError, time::TimeError,
+            year_to_month_duration::YearToMonthDurationError, zoned_date_time::ZonedDateTimeError,
+            zoned_time::ZonedTimeError,
         },
+        time_series::TimeSeriesError,
         tuple::TupleError,
     },
     provider_error::ProviderError,
@@ -39,12 +48,37 @@ pub enum SetEqualToError {
     SequenceError(SequenceError),
     /// A tuple error.
     TupleError(TupleError),
+    /// An interval error.
+    IntervalError(IntervalError),
+    /// A time series error.
+    TimeSeriesError(TimeSeriesError),
     /// A date error.
     DateError(DateError),
     /// A time error.
     TimeError(TimeError),
+    /// A date-time error.
+    DateTimeError(DateTimeError),
+    /// A zoned time error.
+    ZonedTimeError(ZonedTimeError),
+    /// A zoned date-time error.
+    ZonedDateTimeError(ZonedDateTimeError),
     /// A year-month duration error.
     YearToMonthDurationError(YearToMonthDurationError),
+    /// A day-second duration error.
+    DayToSecondDurationError(DayToSecondDurationError),
+    /// A string error.
+    StringError(StringError),
+    /// An error indicating that converting between string encodings could lose characters.
+    /// Holds the source and target string specifications.
+    LossyTranscoding(String, String),
+    /// An error indicating that converting between string encodings failed.
+    TranscodingError(StringError),
+    /// A character error.
+    CharacterError(CharacterError),
+    /// A BLOB error.
+    BlobError(BlobError),
+    /// A GUID error.
+    GuidError(GuidError),
 }
 
 impl From<SpecError> for SetEqualToError {
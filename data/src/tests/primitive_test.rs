@@ -31,6 +31,8 @@ fn is_numberic() {
     assert!(!Primitive::Tuple(None).is_numberic());
     assert!(!Primitive::Blob(None).is_numberic());
     assert!(!Primitive::Identifier(None).is_numberic());
+    assert!(!Primitive::Interval(None).is_numberic());
+    assert!(!Primitive::TimeSeries(None).is_numberic());
 }
 
 #[test]
@@ -63,6 +65,8 @@ fn is_basic() {
     assert!(!Primitive::Tuple(None).is_basic());
     assert!(!Primitive::Blob(None).is_basic());
     assert!(Primitive::Identifier(None).is_basic());
+    assert!(!Primitive::Interval(None).is_basic());
+    assert!(!Primitive::TimeSeries(None).is_basic());
 }
 
 #[test]
@@ -95,6 +99,8 @@ fn is_collection() {
     assert!(!Primitive::Tuple(None).is_collection());
     assert!(!Primitive::Blob(None).is_collection());
     assert!(!Primitive::Identifier(None).is_collection());
+    assert!(!Primitive::Interval(None).is_collection());
+    assert!(!Primitive::TimeSeries(None).is_collection());
 }
 
 #[test]
@@ -127,6 +133,8 @@ fn is_object_or_reference() {
     assert!(!Primitive::Tuple(None).is_object_or_reference());
     assert!(!Primitive::Blob(None).is_object_or_reference());
     assert!(!Primitive::Identifier(None).is_object_or_reference());
+    assert!(!Primitive::Interval(None).is_object_or_reference());
+    assert!(!Primitive::TimeSeries(None).is_object_or_reference());
 }
 
 #[test]
@@ -161,6 +169,8 @@ fn is_sequenceable() {
     assert!(!Primitive::Tuple(None).is_sequenceable());
     assert!(!Primitive::Blob(None).is_sequenceable());
     assert!(!Primitive::Identifier(None).is_sequenceable());
+    assert!(!Primitive::Interval(None).is_sequenceable());
+    assert!(!Primitive::TimeSeries(None).is_sequenceable());
 }
 
 #[test]
@@ -193,6 +203,8 @@ fn is_schema() {
     assert!(!Primitive::Tuple(None).is_schema());
     assert!(!Primitive::Blob(None).is_schema());
     assert!(!Primitive::Identifier(None).is_schema());
+    assert!(!Primitive::Interval(None).is_schema());
+    assert!(!Primitive::TimeSeries(None).is_schema());
 }
 
 #[test]
@@ -235,6 +247,8 @@ fn is_compatible_with_none_none() {
     assert!(Primitive::Tuple(None).is_compatible_with(&Primitive::Tuple(None)));
     assert!(Primitive::Blob(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(Primitive::Identifier(None).is_compatible_with(&Primitive::Identifier(None)));
+    assert!(Primitive::Interval(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(Primitive::TimeSeries(None).is_compatible_with(&Primitive::TimeSeries(None)));
 }
 
 #[test]
@@ -268,6 +282,8 @@ fn boolean_is_not_compatible_with() {
     assert!(!Primitive::Boolean(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Boolean(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Boolean(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Boolean(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Boolean(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Boolean(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Boolean(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -302,6 +318,8 @@ fn character_is_not_compatible_with() {
     assert!(!Primitive::Character(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Character(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Character(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Character(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Character(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Character(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Character(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -336,6 +354,8 @@ fn integer_is_not_compatible_with() {
     assert!(!Primitive::Integer(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Integer(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Integer(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Integer(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Integer(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Integer(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Integer(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -370,6 +390,8 @@ fn float_is_not_compatible_with() {
     assert!(!Primitive::Float(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Float(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Float(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Float(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Float(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Float(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Float(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -403,6 +425,8 @@ fn enum_object_is_not_compatible_with() {
     assert!(!Primitive::EnumObject(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::EnumObject(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::EnumObject(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::EnumObject(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::EnumObject(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::EnumObject(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::EnumObject(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -437,6 +461,8 @@ fn date_is_not_compatible_with() {
     assert!(!Primitive::Date(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Date(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Date(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Date(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Date(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Date(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Date(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -470,6 +496,8 @@ fn local_date_time_is_not_compatible_with() {
     assert!(!Primitive::DateTime(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::DateTime(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::DateTime(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::DateTime(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::DateTime(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::DateTime(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::DateTime(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -508,6 +536,8 @@ fn zoned_date_time_is_not_compatible_with() {
     assert!(!Primitive::ZonedDateTime(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::ZonedDateTime(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::ZonedDateTime(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::ZonedDateTime(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::ZonedDateTime(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::ZonedDateTime(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::ZonedDateTime(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -542,6 +572,8 @@ fn data_spec_is_not_compatible_with() {
     assert!(!Primitive::DataSpec(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::DataSpec(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::DataSpec(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::DataSpec(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::DataSpec(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::DataSpec(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::DataSpec(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -583,6 +615,8 @@ fn year_to_month_duration_is_not_compatible_with() {
     assert!(!Primitive::YearToMonthDuration(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::YearToMonthDuration(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::YearToMonthDuration(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::YearToMonthDuration(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::YearToMonthDuration(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::YearToMonthDuration(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::YearToMonthDuration(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -624,6 +658,8 @@ fn day_to_second_duration_is_not_compatible_with() {
     assert!(!Primitive::DayToSecondDuration(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::DayToSecondDuration(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::DayToSecondDuration(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::DayToSecondDuration(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::DayToSecondDuration(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::DayToSecondDuration(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::DayToSecondDuration(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -658,6 +694,8 @@ fn reference_is_not_compatible_with() {
     assert!(!Primitive::Reference(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Reference(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Reference(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Reference(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Reference(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Reference(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Reference(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -692,6 +730,8 @@ fn object_is_not_compatible_with() {
     assert!(!Primitive::Object(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Object(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Object(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Object(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Object(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Object(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Object(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -726,6 +766,8 @@ fn list_is_not_compatible_with() {
     assert!(!Primitive::List(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::List(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::List(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::List(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::List(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::List(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::List(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -760,6 +802,8 @@ fn set_is_not_compatible_with() {
     assert!(!Primitive::Set(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Set(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Set(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Set(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Set(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Set(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Set(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -795,6 +839,8 @@ fn map_is_not_compatible_with() {
     assert!(!Primitive::Map(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Map(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Map(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Map(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Map(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Map(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Map(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -830,6 +876,8 @@ fn sequence_is_not_compatible_with() {
     assert!(!Primitive::Sequence(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Sequence(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Sequence(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Sequence(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Sequence(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Sequence(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Sequence(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -864,6 +912,8 @@ fn edge_is_not_compatible_with() {
     assert!(!Primitive::Edge(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Edge(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Edge(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Edge(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Edge(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Edge(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Edge(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -898,6 +948,8 @@ fn walk_is_not_compatible_with() {
     assert!(!Primitive::Walk(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Walk(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Walk(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Walk(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Walk(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Walk(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Walk(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -932,6 +984,8 @@ fn enum_class_is_not_compatible_with() {
     assert!(!Primitive::EnumClass(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::EnumClass(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::EnumClass(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::EnumClass(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::EnumClass(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::EnumClass(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::EnumClass(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -966,6 +1020,8 @@ fn property_is_not_compatible_with() {
     assert!(!Primitive::Property(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Property(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Property(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Property(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Property(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Property(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Property(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -1000,6 +1056,8 @@ fn attribute_is_not_compatible_with() {
     assert!(!Primitive::Attribute(None).is_compatible_with(&Primitive::Property(None)));
     assert!(!Primitive::Attribute(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Attribute(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Attribute(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Attribute(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Attribute(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Attribute(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -1034,6 +1092,8 @@ fn class_is_not_compatible_with() {
     assert!(!Primitive::Class(None).is_compatible_with(&Primitive::Property(None)));
     assert!(!Primitive::Class(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Class(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Class(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Class(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Class(None).is_compatible_with(&Primitive::Blob(None)));
     assert!(!Primitive::Class(None).is_compatible_with(&Primitive::Identifier(None)));
 }
@@ -1103,6 +1163,8 @@ fn blob_is_not_compatible_with() {
     assert!(!Primitive::Blob(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Blob(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Blob(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Blob(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Blob(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Blob(None).is_compatible_with(&Primitive::Identifier(None)));
 }
 #[test]
@@ -1137,5 +1199,81 @@ fn identifier_is_not_compatible_with() {
     assert!(!Primitive::Identifier(None).is_compatible_with(&Primitive::Attribute(None)));
     assert!(!Primitive::Identifier(None).is_compatible_with(&Primitive::Class(None)));
     assert!(!Primitive::Identifier(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Identifier(None).is_compatible_with(&Primitive::Interval(None)));
+    assert!(!Primitive::Identifier(None).is_compatible_with(&Primitive::TimeSeries(None)));
     assert!(!Primitive::Identifier(None).is_compatible_with(&Primitive::Blob(None)));
 }
+
+#[test]
+fn interval_is_not_compatible_with() {
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Boolean(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Character(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Integer(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Float(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::EnumObject(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Date(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::DateTime(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::ZonedDateTime(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::DataSpec(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::YearToMonthDuration(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::DayToSecondDuration(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Time(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::ZonedTime(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::ByteString(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Utf8String(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Utf32String(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Guid(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Reference(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Object(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::List(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Set(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Map(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Sequence(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Edge(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Walk(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::EnumClass(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Property(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Attribute(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Class(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Identifier(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::Blob(None)));
+    assert!(!Primitive::Interval(None).is_compatible_with(&Primitive::TimeSeries(None)));
+}
+
+#[test]
+fn time_series_is_not_compatible_with() {
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Boolean(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Character(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Integer(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Float(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::EnumObject(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Date(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::DateTime(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::ZonedDateTime(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::DataSpec(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::YearToMonthDuration(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::DayToSecondDuration(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Time(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::ZonedTime(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::ByteString(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Utf8String(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Utf32String(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Guid(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Reference(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Object(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::List(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Set(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Map(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Sequence(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Edge(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Walk(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::EnumClass(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Property(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Attribute(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Class(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Tuple(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Identifier(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Blob(None)));
+    assert!(!Primitive::TimeSeries(None).is_compatible_with(&Primitive::Interval(None)));
+}
@@ -2,16 +2,27 @@ use std::{fmt::Display, hash::Hash};
 
 use crate::{
     accessors::{
+        blob::Blob,
         boolean::Boolean,
+        character::Character,
         collections::{list::List, map::Map, set::Set},
         float::Float,
+        guid::Guid,
         integer::Integer,
+        interval::Interval,
         sequence::Sequence,
+        string::{self, TranscodingPolicy},
+        strings::{
+            byte_string::ByteString, utf8_string::Utf8String, utf16_string::Utf16String,
+            utf32_string::Utf32String,
+        },
+        time_series::TimeSeries,
         tuple::Tuple,
     },
     data_provider::{DataProvider, default_data_provider},
     data_spec::{DataSpec, DataSpecLevel, DataSpecType},
     primitive::Primitive,
+    primitive_specs::string_spec::StringEncoding,
     set_equal_to::{SetEqualTo, SetEqualToError},
     spec_compatibility::SpecCompatibility,
 };
@@ -262,6 +273,66 @@ impl Variable {
         }
     }
 
+    /// Extracts and returns the Interval accessor within the variable.
+    /// Panics if unable to do so.
+    pub fn interval(&self) -> &Interval {
+        match self.data_spec.specification_type() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Interval(interval_def) => {
+                    let def = &interval_def.as_ref().unwrap();
+                    def.borrow_access()
+                }
+                _ => panic!("Not an interval."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Interval accessor within the variable as mutable.
+    /// Panics if unable to do so.
+    pub fn interval_mut(&mut self) -> &mut Interval {
+        match self.data_spec.specification_type_mut() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Interval(interval_def) => {
+                    let def = interval_def.as_mut().unwrap();
+                    def.mut_access()
+                }
+                _ => panic!("Not an interval."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the TimeSeries accessor within the variable.
+    /// Panics if unable to do so.
+    pub fn time_series(&self) -> &TimeSeries {
+        match self.data_spec.specification_type() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::TimeSeries(time_series_def) => {
+                    let def = &time_series_def.as_ref().unwrap();
+                    def.borrow_access()
+                }
+                _ => panic!("Not a time series."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the TimeSeries accessor within the variable as mutable.
+    /// Panics if unable to do so.
+    pub fn time_series_mut(&mut self) -> &mut TimeSeries {
+        match self.data_spec.specification_type_mut() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::TimeSeries(time_series_def) => {
+                    let def = time_series_def.as_mut().unwrap();
+                    def.mut_access()
+                }
+                _ => panic!("Not a time series."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
     /// Extracts and returns the Date accessor within the variable.
     /// Panics if unable to do so.
     pub fn date(&self) -> &crate::accessors::temporal::date::Date {
@@ -482,6 +553,266 @@ impl Variable {
         }
     }
 
+    /// Extracts and returns the Guid accessor within the variable.
+    /// Panics if unable to do so.
+    pub fn guid(&self) -> &Guid {
+        match self.data_spec.specification_type() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Guid(guid_def) => {
+                    let def = &guid_def.as_ref().unwrap();
+                    def.borrow_access()
+                }
+                _ => panic!("Not a GUID."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Guid accessor within the variable as mutable.
+    /// Panics if unable to do so.
+    pub fn guid_mut(&mut self) -> &mut Guid {
+        match self.data_spec.specification_type_mut() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Guid(guid_def) => {
+                    let def = guid_def.as_mut().unwrap();
+                    def.mut_access()
+                }
+                _ => panic!("Not a GUID."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Blob accessor within the variable.
+    /// Panics if unable to do so.
+    pub fn blob(&self) -> &Blob {
+        match self.data_spec.specification_type() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Blob(blob_def) => {
+                    let def = &blob_def.as_ref().unwrap();
+                    def.borrow_access()
+                }
+                _ => panic!("Not a BLOB."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Blob accessor within the variable as mutable.
+    /// Panics if unable to do so.
+    pub fn blob_mut(&mut self) -> &mut Blob {
+        match self.data_spec.specification_type_mut() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Blob(blob_def) => {
+                    let def = blob_def.as_mut().unwrap();
+                    def.mut_access()
+                }
+                _ => panic!("Not a BLOB."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Character accessor within the variable.
+    /// Panics if unable to do so.
+    pub fn character(&self) -> &Character {
+        match self.data_spec.specification_type() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Character(character_def) => {
+                    let def = &character_def.as_ref().unwrap();
+                    def.borrow_access()
+                }
+                _ => panic!("Not a character."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Character accessor within the variable as mutable.
+    /// Panics if unable to do so.
+    pub fn character_mut(&mut self) -> &mut Character {
+        match self.data_spec.specification_type_mut() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Character(character_def) => {
+                    let def = character_def.as_mut().unwrap();
+                    def.mut_access()
+                }
+                _ => panic!("Not a character."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the ByteString accessor within the variable.
+    /// Panics if unable to do so.
+    pub fn byte_string(&self) -> &ByteString {
+        match self.data_spec.specification_type() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::ByteString(string_def) => {
+                    let def = &string_def.as_ref().unwrap();
+                    def.borrow_access()
+                }
+                _ => panic!("Not a byte string."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the ByteString accessor within the variable as mutable.
+    /// Panics if unable to do so.
+    pub fn byte_string_mut(&mut self) -> &mut ByteString {
+        match self.data_spec.specification_type_mut() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::ByteString(string_def) => {
+                    let def = string_def.as_mut().unwrap();
+                    def.mut_access()
+                }
+                _ => panic!("Not a byte string."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Utf8String accessor within the variable.
+    /// Panics if unable to do so.
+    pub fn utf8_string(&self) -> &Utf8String {
+        match self.data_spec.specification_type() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Utf8String(string_def) => {
+                    let def = &string_def.as_ref().unwrap();
+                    def.borrow_access()
+                }
+                _ => panic!("Not a UTF-8 string."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Utf8String accessor within the variable as mutable.
+    /// Panics if unable to do so.
+    pub fn utf8_string_mut(&mut self) -> &mut Utf8String {
+        match self.data_spec.specification_type_mut() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Utf8String(string_def) => {
+                    let def = string_def.as_mut().unwrap();
+                    def.mut_access()
+                }
+                _ => panic!("Not a UTF-8 string."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Utf16String accessor within the variable.
+    /// Panics if unable to do so.
+    pub fn utf16_string(&self) -> &Utf16String {
+        match self.data_spec.specification_type() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Utf16String(string_def) => {
+                    let def = &string_def.as_ref().unwrap();
+                    def.borrow_access()
+                }
+                _ => panic!("Not a UTF-16 string."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Utf16String accessor within the variable as mutable.
+    /// Panics if unable to do so.
+    pub fn utf16_string_mut(&mut self) -> &mut Utf16String {
+        match self.data_spec.specification_type_mut() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Utf16String(string_def) => {
+                    let def = string_def.as_mut().unwrap();
+                    def.mut_access()
+                }
+                _ => panic!("Not a UTF-16 string."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Utf32String accessor within the variable.
+    /// Panics if unable to do so.
+    pub fn utf32_string(&self) -> &Utf32String {
+        match self.data_spec.specification_type() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Utf32String(string_def) => {
+                    let def = &string_def.as_ref().unwrap();
+                    def.borrow_access()
+                }
+                _ => panic!("Not a UTF-32 string."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the Utf32String accessor within the variable as mutable.
+    /// Panics if unable to do so.
+    pub fn utf32_string_mut(&mut self) -> &mut Utf32String {
+        match self.data_spec.specification_type_mut() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::Utf32String(string_def) => {
+                    let def = string_def.as_mut().unwrap();
+                    def.mut_access()
+                }
+                _ => panic!("Not a UTF-32 string."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the String accessor within the variable, whatever the string's encoding.
+    /// Panics if unable to do so.
+    pub fn string(&self) -> &dyn string::String {
+        match self.data_spec.specification_type() {
+            DataSpecType::Primitive(primitive) => match primitive {
+                Primitive::ByteString(_) => self.byte_string(),
+                Primitive::Utf8String(_) => self.utf8_string(),
+                Primitive::Utf16String(_) => self.utf16_string(),
+                Primitive::Utf32String(_) => self.utf32_string(),
+                _ => panic!("Not a string."),
+            },
+            _ => panic!("Not a primitive."),
+        }
+    }
+
+    /// Extracts and returns the String accessor within the variable as mutable, whatever the string's encoding.
+    /// Panics if unable to do so.
+    pub fn string_mut(&mut self) -> &mut dyn string::String {
+        match *self.string().spec().encoding() {
+            StringEncoding::Byte => self.byte_string_mut(),
+            StringEncoding::Utf8 => self.utf8_string_mut(),
+            StringEncoding::Utf16 => self.utf16_string_mut(),
+            StringEncoding::Utf32 => self.utf32_string_mut(),
+        }
+    }
+
+    /// Returns whether the variable holds a string.
+    pub(crate) fn is_string(&self) -> bool {
+        matches!(
+            self.data_spec.specification_type(),
+            DataSpecType::Primitive(primitive) if primitive.is_string()
+        )
+    }
+
+    /// Sets the variable, which holds a string, equal to another string of a
+    /// different encoding or code page. Only lossless conversions are performed.
+    fn transcode_equal_to(&mut self, other: &Variable) -> Result<(), SetEqualToError> {
+        let spec = self.string().spec().clone();
+        let source = other.string();
+        if !spec.transcodes_losslessly_from(source.spec()) {
+            return Err(SetEqualToError::LossyTranscoding(
+                source.spec().to_string(),
+                spec.to_string(),
+            ));
+        }
+        self.string_mut()
+            .transcode_from(source, TranscodingPolicy::Error)
+            .map_err(SetEqualToError::TranscodingError)
+    }
+
     /// Attempts to clone the variable, returning a new Variable with the same data specification and value.
     pub fn try_clone(&self) -> Result<Variable, SetEqualToError> {
         // Attempt to clone the variable
@@ -534,6 +865,16 @@ impl PartialEq for Variable {
                     let a2 = t2.as_ref().unwrap().borrow_access();
                     a1 == a2
                 }
+                (Primitive::Interval(i1), Primitive::Interval(i2)) => {
+                    let a1 = i1.as_ref().unwrap().borrow_access();
+                    let a2 = i2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
+                (Primitive::TimeSeries(t1), Primitive::TimeSeries(t2)) => {
+                    let a1 = t1.as_ref().unwrap().borrow_access();
+                    let a2 = t2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
                 (Primitive::Date(d1), Primitive::Date(d2)) => {
                     let a1 = d1.as_ref().unwrap().borrow_access();
                     let a2 = d2.as_ref().unwrap().borrow_access();
@@ -544,11 +885,66 @@ impl PartialEq for Variable {
                     let a2 = t2.as_ref().unwrap().borrow_access();
                     a1 == a2
                 }
+                (Primitive::DateTime(dt1), Primitive::DateTime(dt2)) => {
+                    let a1 = dt1.as_ref().unwrap().borrow_access();
+                    let a2 = dt2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
+                (Primitive::ZonedTime(zt1), Primitive::ZonedTime(zt2)) => {
+                    let a1 = zt1.as_ref().unwrap().borrow_access();
+                    let a2 = zt2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
+                (Primitive::ZonedDateTime(zdt1), Primitive::ZonedDateTime(zdt2)) => {
+                    let a1 = zdt1.as_ref().unwrap().borrow_access();
+                    let a2 = zdt2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
                 (Primitive::YearToMonthDuration(ymd1), Primitive::YearToMonthDuration(ymd2)) => {
                     let a1 = ymd1.as_ref().unwrap().borrow_access();
                     let a2 = ymd2.as_ref().unwrap().borrow_access();
                     a1 == a2
                 }
+                (Primitive::DayToSecondDuration(dsd1), Primitive::DayToSecondDuration(dsd2)) => {
+                    let a1 = dsd1.as_ref().unwrap().borrow_access();
+                    let a2 = dsd2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
+                (Primitive::Character(c1), Primitive::Character(c2)) => {
+                    let a1 = c1.as_ref().unwrap().borrow_access();
+                    let a2 = c2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
+                (Primitive::Guid(g1), Primitive::Guid(g2)) => {
+                    let a1 = g1.as_ref().unwrap().borrow_access();
+                    let a2 = g2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
+                (Primitive::Blob(b1), Primitive::Blob(b2)) => {
+                    let a1 = b1.as_ref().unwrap().borrow_access();
+                    let a2 = b2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
+                (Primitive::ByteString(s1), Primitive::ByteString(s2)) => {
+                    let a1 = s1.as_ref().unwrap().borrow_access();
+                    let a2 = s2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
+                (Primitive::Utf8String(s1), Primitive::Utf8String(s2)) => {
+                    let a1 = s1.as_ref().unwrap().borrow_access();
+                    let a2 = s2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
+                (Primitive::Utf16String(s1), Primitive::Utf16String(s2)) => {
+                    let a1 = s1.as_ref().unwrap().borrow_access();
+                    let a2 = s2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
+                (Primitive::Utf32String(s1), Primitive::Utf32String(s2)) => {
+                    let a1 = s1.as_ref().unwrap().borrow_access();
+                    let a2 = s2.as_ref().unwrap().borrow_access();
+                    a1 == a2
+                }
                 _ => false,
             },
             _ => false,
@@ -606,6 +1002,16 @@ impl Ord for Variable {
                     let a2 = t2.as_ref().unwrap().borrow_access();
                     a1.cmp(a2)
                 }
+                (Primitive::Interval(i1), Primitive::Interval(i2)) => {
+                    let a1 = i1.as_ref().unwrap().borrow_access();
+                    let a2 = i2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
+                (Primitive::TimeSeries(t1), Primitive::TimeSeries(t2)) => {
+                    let a1 = t1.as_ref().unwrap().borrow_access();
+                    let a2 = t2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
                 (Primitive::Date(d1), Primitive::Date(d2)) => {
                     let a1 = d1.as_ref().unwrap().borrow_access();
                     let a2 = d2.as_ref().unwrap().borrow_access();
@@ -616,11 +1022,61 @@ impl Ord for Variable {
                     let a2 = t2.as_ref().unwrap().borrow_access();
                     a1.cmp(a2)
                 }
+                (Primitive::DateTime(dt1), Primitive::DateTime(dt2)) => {
+                    let a1 = dt1.as_ref().unwrap().borrow_access();
+                    let a2 = dt2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
+                (Primitive::ZonedTime(zt1), Primitive::ZonedTime(zt2)) => {
+                    let a1 = zt1.as_ref().unwrap().borrow_access();
+                    let a2 = zt2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
+                (Primitive::ZonedDateTime(zdt1), Primitive::ZonedDateTime(zdt2)) => {
+                    let a1 = zdt1.as_ref().unwrap().borrow_access();
+                    let a2 = zdt2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
                 (Primitive::YearToMonthDuration(ymd1), Primitive::YearToMonthDuration(ymd2)) => {
                     let a1 = ymd1.as_ref().unwrap().borrow_access();
                     let a2 = ymd2.as_ref().unwrap().borrow_access();
                     a1.cmp(a2)
                 }
+                (Primitive::DayToSecondDuration(dsd1), Primitive::DayToSecondDuration(dsd2)) => {
+                    let a1 = dsd1.as_ref().unwrap().borrow_access();
+                    let a2 = dsd2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
+                (Primitive::Character(c1), Primitive::Character(c2)) => {
+                    let a1 = c1.as_ref().unwrap().borrow_access();
+                    let a2 = c2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
+                (Primitive::Guid(g1), Primitive::Guid(g2)) => {
+                    let a1 = g1.as_ref().unwrap().borrow_access();
+                    let a2 = g2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
+                (Primitive::ByteString(s1), Primitive::ByteString(s2)) => {
+                    let a1 = s1.as_ref().unwrap().borrow_access();
+                    let a2 = s2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
+                (Primitive::Utf8String(s1), Primitive::Utf8String(s2)) => {
+                    let a1 = s1.as_ref().unwrap().borrow_access();
+                    let a2 = s2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
+                (Primitive::Utf16String(s1), Primitive::Utf16String(s2)) => {
+                    let a1 = s1.as_ref().unwrap().borrow_access();
+                    let a2 = s2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
+                (Primitive::Utf32String(s1), Primitive::Utf32String(s2)) => {
+                    let a1 = s1.as_ref().unwrap().borrow_access();
+                    let a2 = s2.as_ref().unwrap().borrow_access();
+                    a1.cmp(a2)
+                }
                 _ => std::cmp::Ordering::Equal,
             },
             _ => std::cmp::Ordering::Equal,
@@ -668,6 +1124,16 @@ impl Hash for Variable {
                         def.borrow_access().hash(state);
                     }
                 }
+                Primitive::Interval(interval_def) => {
+                    if let Some(def) = interval_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
+                Primitive::TimeSeries(time_series_def) => {
+                    if let Some(def) = time_series_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
                 Primitive::Date(date_def) => {
                     if let Some(def) = date_def.as_ref() {
                         def.borrow_access().hash(state);
@@ -678,11 +1144,66 @@ impl Hash for Variable {
                         def.borrow_access().hash(state);
                     }
                 }
+                Primitive::DateTime(date_time_def) => {
+                    if let Some(def) = date_time_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
+                Primitive::ZonedTime(zoned_time_def) => {
+                    if let Some(def) = zoned_time_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
+                Primitive::ZonedDateTime(zoned_date_time_def) => {
+                    if let Some(def) = zoned_date_time_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
                 Primitive::YearToMonthDuration(ymd_def) => {
                     if let Some(def) = ymd_def.as_ref() {
                         def.borrow_access().hash(state);
                     }
                 }
+                Primitive::DayToSecondDuration(dsd_def) => {
+                    if let Some(def) = dsd_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
+                Primitive::Character(character_def) => {
+                    if let Some(def) = character_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
+                Primitive::Guid(guid_def) => {
+                    if let Some(def) = guid_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
+                Primitive::Blob(blob_def) => {
+                    if let Some(def) = blob_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
+                Primitive::ByteString(string_def) => {
+                    if let Some(def) = string_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
+                Primitive::Utf8String(string_def) => {
+                    if let Some(def) = string_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
+                Primitive::Utf16String(string_def) => {
+                    if let Some(def) = string_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
+                Primitive::Utf32String(string_def) => {
+                    if let Some(def) = string_def.as_ref() {
+                        def.borrow_access().hash(state);
+                    }
+                }
                 _ => {}
             }
         }
@@ -691,6 +1212,16 @@ impl Hash for Variable {
 
 impl SetEqualTo for Variable {
     fn set_equal_to(&mut self, other: &Self) -> Result<(), SetEqualToError> {
+        // Strings of a different encoding or code page are transcoded
+        if self.is_string() && other.is_string() {
+            let (spec, other_spec) = (self.string().spec(), other.string().spec());
+            if spec.encoding() != other_spec.encoding()
+                || spec.code_page() != other_spec.code_page()
+            {
+                return self.transcode_equal_to(other);
+            }
+        }
+
         // Check if the data specifications are compatible
         self.data_spec.check_compatible_with(other.data_spec())?;
 
@@ -729,6 +1260,14 @@ impl SetEqualTo for Variable {
                     let def = tuple_def.as_mut().unwrap();
                     def.mut_access().set_equal_to(other.tuple())?;
                 }
+                Primitive::Interval(interval_def) => {
+                    let def = interval_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.interval())?;
+                }
+                Primitive::TimeSeries(time_series_def) => {
+                    let def = time_series_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.time_series())?;
+                }
                 Primitive::Date(date_def) => {
                     let def = date_def.as_mut().unwrap();
                     def.mut_access().set_equal_to(other.date())?;
@@ -737,11 +1276,56 @@ impl SetEqualTo for Variable {
                     let def = time_def.as_mut().unwrap();
                     def.mut_access().set_equal_to(other.time())?;
                 }
+                Primitive::DateTime(date_time_def) => {
+                    let def = date_time_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.date_time())?;
+                }
+                Primitive::ZonedTime(zoned_time_def) => {
+                    let def = zoned_time_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.zoned_time())?;
+                }
+                Primitive::ZonedDateTime(zoned_date_time_def) => {
+                    let def = zoned_date_time_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.zoned_date_time())?;
+                }
                 Primitive::YearToMonthDuration(ymd_def) => {
                     let def = ymd_def.as_mut().unwrap();
                     def.mut_access()
                         .set_equal_to(other.year_to_month_duration())?;
                 }
+                Primitive::DayToSecondDuration(dsd_def) => {
+                    let def = dsd_def.as_mut().unwrap();
+                    def.mut_access()
+                        .set_equal_to(other.day_to_second_duration())?;
+                }
+                Primitive::Character(character_def) => {
+                    let def = character_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.character())?;
+                }
+                Primitive::Guid(guid_def) => {
+                    let def = guid_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.guid())?;
+                }
+                Primitive::Blob(blob_def) => {
+                    let def = blob_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.blob())?;
+                }
+                Primitive::ByteString(string_def) => {
+                    let def = string_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.byte_string())?;
+                }
+                Primitive::Utf8String(string_def) => {
+                    let def = string_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.utf8_string())?;
+                }
+                Primitive::Utf16String(string_def) => {
+                    let def = string_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.utf16_string())?;
+                }
+                Primitive::Utf32String(string_def) => {
+                    let def = string_def.as_mut().unwrap();
+                    def.mut_access().set_equal_to(other.utf32_string())?;
+                }
                 _ => {
                     todo!("Implement setting equal to for other primitive types");
                 }
@@ -775,9 +1359,22 @@ impl Display for Variable {
                     Primitive::Map(_) => self.map().to_string(),
                     Primitive::Sequence(_) => "Sequence does not support display.".to_string(),
                     Primitive::Tuple(_) => self.tuple().to_string(),
+                    Primitive::Interval(_) => self.interval().to_string(),
+                    Primitive::TimeSeries(_) => self.time_series().to_string(),
                     Primitive::Date(_) => self.date().to_string(),
                     Primitive::Time(_) => self.time().to_string(),
+                    Primitive::DateTime(_) => self.date_time().to_string(),
+                    Primitive::ZonedTime(_) => self.zoned_time().to_string(),
+                    Primitive::ZonedDateTime(_) => self.zoned_date_time().to_string(),
                     Primitive::YearToMonthDuration(_) => self.year_to_month_duration().to_string(),
+                    Primitive::DayToSecondDuration(_) => self.day_to_second_duration().to_string(),
+                    Primitive::Character(_) => self.character().to_string(),
+                    Primitive::Blob(_) => self.blob().to_string(),
+                    Primitive::Guid(_) => self.guid().to_string(),
+                    Primitive::ByteString(_) => self.byte_string().to_string(),
+                    Primitive::Utf8String(_) => self.utf8_string().to_string(),
+                    Primitive::Utf16String(_) => self.utf16_string().to_string(),
+                    Primitive::Utf32String(_) => self.utf32_string().to_string(),
                     _ => "Unsupported primitive".to_string(),
                 },
                 _ => "Unsupported data spec type".to_string(),